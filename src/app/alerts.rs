@@ -0,0 +1,147 @@
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+use super::config::AlertsConfig;
+use super::TabType;
+use crate::monitors::{CpuData, DiskData, GpuData, RamData};
+
+/// Latest values of the metrics that can raise an alert. `None` means the
+/// monitor has not produced data (or the metric is unavailable).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AlertMetrics {
+    pub cpu_usage: Option<f32>,
+    pub ram_usage: Option<f32>,
+    pub gpu_temp: Option<f32>,
+    pub disk_usage: Option<f32>,
+    /// Some disk's health assessment predicts failure
+    pub disk_failure: bool,
+}
+
+impl AlertMetrics {
+    pub fn collect(
+        cpu: Option<&CpuData>,
+        ram: Option<&RamData>,
+        gpu: Option<&GpuData>,
+        disk: Option<&DiskData>,
+    ) -> Self {
+        Self {
+            cpu_usage: cpu.map(|c| c.overall_usage),
+            ram_usage: ram
+                .filter(|r| r.total > 0)
+                .map(|r| r.used as f32 / r.total as f32 * 100.0),
+            gpu_temp: gpu.and_then(|g| {
+                g.devices
+                    .iter()
+                    .map(|d| d.temperature)
+                    .fold(None, |max: Option<f32>, t| Some(max.map_or(t, |m| m.max(t))))
+            }),
+            disk_usage: disk.and_then(|d| {
+                d.logical_drives
+                    .iter()
+                    .filter(|drive| drive.total > 0)
+                    .map(|drive| drive.used as f32 / drive.total as f32 * 100.0)
+                    .fold(None, |max: Option<f32>, u| Some(max.map_or(u, |m| m.max(u))))
+            }),
+            disk_failure: disk.is_some_and(|d| {
+                d.physical_disks.iter().any(|disk| disk.health.predicted_failure)
+            }),
+        }
+    }
+}
+
+/// Tabs whose metric is at or above its threshold, plus Disk when a disk is
+/// predicted to fail. A threshold of 0 disables that alert.
+pub fn evaluate(config: &AlertsConfig, metrics: &AlertMetrics) -> HashSet<TabType> {
+    let checks = [
+        (TabType::Cpu, config.cpu_usage, metrics.cpu_usage),
+        (TabType::Ram, config.ram_usage, metrics.ram_usage),
+        (TabType::Gpu, config.gpu_temp, metrics.gpu_temp),
+        (TabType::Disk, config.disk_usage, metrics.disk_usage),
+    ];
+
+    let mut active: HashSet<TabType> = checks
+        .iter()
+        .filter(|(_, threshold, value)| {
+            *threshold > 0.0 && value.is_some_and(|v| v >= *threshold)
+        })
+        .map(|(tab, _, _)| *tab)
+        .collect();
+    if config.disk_failure && metrics.disk_failure {
+        active.insert(TabType::Disk);
+    }
+    active
+}
+
+/// Tracks which tabs are alerting and when the bell last rang.
+#[derive(Debug, Default)]
+pub struct AlertState {
+    active: HashSet<TabType>,
+    last_bell: Option<Instant>,
+}
+
+impl AlertState {
+    pub fn is_active(&self, tab: TabType) -> bool {
+        self.active.contains(&tab)
+    }
+
+    /// Store the alerting tabs and return whether the bell should ring, which
+    /// happens at most once per `cooldown` while any alert is active.
+    pub fn update(&mut self, active: HashSet<TabType>, cooldown: Duration) -> bool {
+        self.active = active;
+        if self.active.is_empty() {
+            return false;
+        }
+
+        let now = Instant::now();
+        let ring = self
+            .last_bell
+            .is_none_or(|last| now.duration_since(last) >= cooldown);
+        if ring {
+            self.last_bell = Some(now);
+        }
+        ring
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_threshold_disables_alert() {
+        let config = AlertsConfig {
+            cpu_usage: 90.0,
+            ram_usage: 0.0,
+            gpu_temp: 83.0,
+            disk_usage: 95.0,
+            disk_failure: true,
+            bell: true,
+            cooldown_seconds: 60,
+        };
+        let metrics = AlertMetrics {
+            cpu_usage: Some(95.0),
+            ram_usage: Some(99.0),
+            gpu_temp: Some(70.0),
+            disk_usage: None,
+            disk_failure: false,
+        };
+
+        let active = evaluate(&config, &metrics);
+        assert!(active.contains(&TabType::Cpu));
+        assert!(!active.contains(&TabType::Ram));
+        assert!(!active.contains(&TabType::Gpu));
+        assert!(!active.contains(&TabType::Disk));
+    }
+
+    #[test]
+    fn bell_respects_cooldown() {
+        let mut state = AlertState::default();
+        let cooldown = Duration::from_secs(60);
+        let cpu: HashSet<TabType> = [TabType::Cpu].into_iter().collect();
+
+        assert!(state.update(cpu.clone(), cooldown));
+        assert!(!state.update(cpu, cooldown));
+        assert!(!state.update(HashSet::new(), cooldown));
+        assert!(!state.is_active(TabType::Cpu));
+    }
+}
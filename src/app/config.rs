@@ -1,9 +1,18 @@
 use anyhow::{Context, Result};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
+
+use super::notifications::{Notification, NotificationLevel, NotificationSender};
+use super::state::ProcessColumn;
+use super::TabType;
+use crate::integrations::remote::SshTarget;
+use crate::monitors::gpu::GpuSource;
 
 // Embedded default configuration that can be written next to the executable
 // when an external config file is missing. This prevents the application from
@@ -19,8 +28,19 @@ pub struct Config {
     pub integrations: IntegrationsConfig,
     pub ui: UiConfig,
     pub hotkeys: HotkeysConfig,
+    #[serde(default)]
+    pub keybindings: KeybindingsConfig,
     pub powershell: PowerShellConfig,
+    #[serde(default)]
+    pub alerts: AlertsConfig,
+    #[serde(default)]
+    pub metrics: MetricsConfig,
+    #[serde(default)]
+    pub recording: RecordingConfig,
+    #[serde(default)]
     pub theme: ThemeConfig,
+    #[serde(default)]
+    pub remote: RemoteConfig,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -29,12 +49,53 @@ pub struct GeneralConfig {
     pub refresh_rate_ms: u64,
     pub compact_mode: bool,
     pub theme: String,
+    /// Write `+`/`-` refresh changes back to this file instead of keeping
+    /// them for the session only
+    #[serde(default)]
+    pub persist_refresh_hotkeys: bool,
+    /// Disable killing, service control, deletes and commands; `--read-only`
+    /// turns it on for a single run
+    #[serde(default)]
+    pub read_only: bool,
+    /// "jedec" (1024-based, KB/MB), "binary" (KiB/MiB) or "decimal" (1000-based)
+    #[serde(default = "default_byte_units")]
+    pub byte_units: String,
+    #[serde(default = "default_decimal_places")]
+    pub decimal_places: usize,
+    /// "bits" for Mbps, "bytes" for MB/s
+    #[serde(default = "default_network_units")]
+    pub network_units: String,
+    /// Samples kept for each trend graph; see `history_len`
+    #[serde(default = "default_history_samples")]
+    pub history_samples: usize,
+    /// Seconds without a key or mouse event before refreshing slows down;
+    /// 0 never idles
+    #[serde(default = "default_idle_after_seconds")]
+    pub idle_after_seconds: u64,
+    /// Refresh intervals are multiplied by this while idle
+    #[serde(default = "default_idle_factor")]
+    pub idle_factor: u32,
+}
+
+/// Lower bound for `history_samples`; fewer samples hardly make a trend.
+pub const MIN_HISTORY_SAMPLES: usize = 10;
+/// Upper bound for `history_samples`, to keep the buffers small.
+pub const MAX_HISTORY_SAMPLES: usize = 600;
+
+impl GeneralConfig {
+    /// `history_samples` clamped to `MIN_HISTORY_SAMPLES..=MAX_HISTORY_SAMPLES`.
+    pub fn history_len(&self) -> usize {
+        self.history_samples.clamp(MIN_HISTORY_SAMPLES, MAX_HISTORY_SAMPLES)
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct TabsConfig {
     pub enabled: Vec<String>,
     pub default: String,
+    /// Start on the tab open at the last exit instead of `default`
+    #[serde(default)]
+    pub restore_last: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -56,6 +117,9 @@ pub struct CpuMonitorConfig {
     pub refresh_interval_ms: u64,
     pub show_per_core: bool,
     pub show_frequency: bool,
+    /// Live clock next to each core's usage bar
+    #[serde(default = "default_true")]
+    pub show_core_frequency: bool,
     pub show_temperature: bool,
     pub top_processes_count: usize,
 }
@@ -68,6 +132,40 @@ pub struct GpuMonitorConfig {
     pub show_processes: bool,
     pub show_memory: bool,
     pub top_processes_count: usize,
+    /// Data sources in the order they are tried; see `GpuSource::from_name`.
+    #[serde(default = "default_gpu_sources")]
+    pub source_priority: Vec<String>,
+}
+
+impl GpuMonitorConfig {
+    /// Known sources in config order, or the default order if none are.
+    pub fn sources(&self) -> Vec<GpuSource> {
+        let mut sources: Vec<GpuSource> = Vec::new();
+        for source in self.source_priority.iter().filter_map(|name| GpuSource::from_name(name)) {
+            if !sources.contains(&source) {
+                sources.push(source);
+            }
+        }
+        if sources.is_empty() {
+            sources = GpuSource::DEFAULT.to_vec();
+        }
+        sources
+    }
+
+    fn warn_invalid(&self) {
+        for name in &self.source_priority {
+            if GpuSource::from_name(name).is_none() {
+                log::warn!("Unknown GPU source '{}', skipping it", name);
+            }
+        }
+    }
+}
+
+fn default_gpu_sources() -> Vec<String> {
+    GpuSource::DEFAULT
+        .iter()
+        .map(|source| source.config_name().to_string())
+        .collect()
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -94,14 +192,43 @@ pub struct NetworkMonitorConfig {
     pub refresh_interval_ms: u64,
     pub show_graph: bool,
     pub graph_duration_seconds: u64,
+    /// Collect and show the connections table
     pub show_connections: bool,
-    pub max_connections: usize,
+    /// Hosts pinged for the latency panel
+    #[serde(default = "default_ping_targets")]
+    pub ping_targets: Vec<String>,
+    /// Initial connection list filter: "established", "listen" or "all"
+    #[serde(default = "default_connection_filter")]
+    pub connection_filter: String,
+    /// Interfaces shown and summed into the traffic totals outside the All view
+    #[serde(default)]
+    pub interface_filter: InterfaceFilterConfig,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct InterfaceFilterConfig {
+    /// Regexes matched against name or description; empty keeps every interface
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// Regexes matched against name or description; wins over `include`
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    /// Also drop loopback, VPN tunnels, bridges and VM/container adapters
+    #[serde(default)]
+    pub hide_virtual: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ProcessMonitorConfig {
     pub enabled: bool,
     pub refresh_interval_ms: u64,
+    /// Table columns in display order; see `ProcessColumn::from_name`.
+    #[serde(default = "default_process_columns")]
+    pub columns: Vec<String>,
+    /// Sort at startup as `column:asc|desc`, e.g. `memory:desc`. Rewritten
+    /// at exit with the sort in effect then.
+    #[serde(default = "default_process_sort")]
+    pub default_sort: String,
 }
 
 impl Default for ProcessMonitorConfig {
@@ -109,14 +236,53 @@ impl Default for ProcessMonitorConfig {
         Self {
             enabled: true,
             refresh_interval_ms: 2000,
+            columns: default_process_columns(),
+            default_sort: default_process_sort(),
         }
     }
 }
 
+impl ProcessMonitorConfig {
+    /// Known columns in config order, or the full default set if none are.
+    pub fn columns(&self) -> Vec<ProcessColumn> {
+        let mut columns: Vec<ProcessColumn> = Vec::new();
+        for column in self.columns.iter().filter_map(|name| ProcessColumn::from_name(name)) {
+            if !columns.contains(&column) {
+                columns.push(column);
+            }
+        }
+        if columns.is_empty() {
+            columns = ProcessColumn::DEFAULT.to_vec();
+        }
+        columns
+    }
+
+    fn warn_invalid(&self) {
+        for name in &self.columns {
+            if ProcessColumn::from_name(name).is_none() {
+                log::warn!("Unknown process column '{}', skipping it", name);
+            }
+        }
+    }
+}
+
+fn default_process_sort() -> String {
+    "cpu:desc".to_string()
+}
+
+fn default_process_columns() -> Vec<String> {
+    ["pid", "name", "cpu", "memory", "threads", "user"]
+        .map(String::from)
+        .to_vec()
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ServiceMonitorConfig {
     pub enabled: bool,
     pub refresh_interval_ms: u64,
+    /// Sort at startup, like `processes.default_sort`
+    #[serde(default = "default_service_sort")]
+    pub default_sort: String,
 }
 
 impl Default for ServiceMonitorConfig {
@@ -124,10 +290,15 @@ impl Default for ServiceMonitorConfig {
         Self {
             enabled: true,
             refresh_interval_ms: 3000,
+            default_sort: default_service_sort(),
         }
     }
 }
 
+fn default_service_sort() -> String {
+    "name:asc".to_string()
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct IntegrationsConfig {
     pub ollama: OllamaConfig,
@@ -149,6 +320,15 @@ pub struct EverythingConfig {
     pub max_depth: usize,
     #[serde(default = "default_everything_refresh_interval_ms")]
     pub refresh_interval_ms: u64,
+    /// Deleting anything larger than this asks twice (0 = always once)
+    #[serde(default = "default_delete_double_confirm_mb")]
+    pub delete_double_confirm_mb: u64,
+    /// "csv" or "json" for the analyzer's export action
+    #[serde(default = "default_export_format")]
+    pub export_format: String,
+    /// Smaller files are ignored by the duplicate scan
+    #[serde(default = "default_duplicate_min_size_mb")]
+    pub duplicate_min_size_mb: u64,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -173,6 +353,9 @@ pub struct GraphConfig {
 pub struct CommandHistoryConfig {
     pub max_entries: usize,
     pub circular_menu_radius: u16,
+    /// Keep the history in `command_history.txt` next to config.toml
+    #[serde(default = "default_true")]
+    pub persist: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -186,6 +369,8 @@ pub struct SectionHighlightConfig {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct HotkeysConfig {
+    #[serde(default = "default_overview_hotkey")]
+    pub overview: String,
     pub cpu: String,
     pub gpu: String,
     pub ram: String,
@@ -198,21 +383,263 @@ pub struct HotkeysConfig {
     pub settings: String,
 }
 
+/// Action name -> key string (e.g. `nav_down = "j"`). Actions that are not
+/// listed keep their built-in binding.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct KeybindingsConfig {
+    #[serde(flatten)]
+    pub bindings: BTreeMap<String, String>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PowerShellConfig {
     pub executable: String,
     pub timeout_seconds: u64,
     pub use_cache: bool,
     pub cache_ttl_seconds: u64,
+    #[serde(default)]
+    pub persistent_session: bool,
+    /// PowerShell commands monitors may run at once (0 = unlimited)
+    #[serde(default = "default_powershell_max_concurrent")]
+    pub max_concurrent: usize,
+}
+
+/// Thresholds that flag a tab in red and ring the terminal bell. A value of
+/// 0 disables that alert.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AlertsConfig {
+    pub cpu_usage: f32,
+    pub ram_usage: f32,
+    pub gpu_temp: f32,
+    pub disk_usage: f32,
+    /// Flag the Disk tab when a disk's health assessment predicts failure
+    #[serde(default)]
+    pub disk_failure: bool,
+    pub bell: bool,
+    pub cooldown_seconds: u64,
+}
+
+impl Default for AlertsConfig {
+    fn default() -> Self {
+        Self {
+            cpu_usage: 0.0,
+            ram_usage: 0.0,
+            gpu_temp: 0.0,
+            disk_usage: 0.0,
+            disk_failure: false,
+            bell: false,
+            cooldown_seconds: 60,
+        }
+    }
+}
+
+/// Prometheus `/metrics` endpoint. Only served when built with the `metrics`
+/// feature.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MetricsConfig {
+    pub enabled: bool,
+    pub bind_address: String,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bind_address: "127.0.0.1:9184".to_string(),
+        }
+    }
+}
+
+/// Append sampled monitor data to a newline-delimited JSON file.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RecordingConfig {
+    pub enabled: bool,
+    pub path: String,
+    pub interval_seconds: u64,
+    /// Start a new file once the current one passes this size
+    pub max_file_mb: u64,
+}
+
+impl Default for RecordingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: "logs/metrics.ndjson".to_string(),
+            interval_seconds: 10,
+            max_file_mb: 50,
+        }
+    }
+}
+
+/// Monitor another machine over SSH. Leave `host` empty to monitor this one.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RemoteConfig {
+    pub host: String,
+    pub user: String,
+    pub key_path: String,
+    /// 0 uses ssh's default port
+    pub port: u16,
+}
+
+impl RemoteConfig {
+    pub fn target(&self) -> Option<SshTarget> {
+        let non_empty = |value: &str| (!value.trim().is_empty()).then(|| value.trim().to_string());
+        Some(SshTarget {
+            host: non_empty(&self.host)?,
+            user: non_empty(&self.user),
+            key_path: non_empty(&self.key_path),
+            port: (self.port != 0).then_some(self.port),
+        })
+    }
+}
+
+fn default_byte_units() -> String {
+    "jedec".to_string()
+}
+
+fn default_decimal_places() -> usize {
+    2
+}
+
+fn default_network_units() -> String {
+    "bits".to_string()
+}
+
+fn default_history_samples() -> usize {
+    60
+}
+
+fn default_idle_after_seconds() -> u64 {
+    300
+}
+
+fn default_idle_factor() -> u32 {
+    4
 }
 
 fn default_everything_refresh_interval_ms() -> u64 {
     5000
 }
 
+fn default_delete_double_confirm_mb() -> u64 {
+    10 * 1024
+}
+
+fn default_duplicate_min_size_mb() -> u64 {
+    1
+}
+
+fn default_export_format() -> String {
+    "csv".to_string()
+}
+
+fn default_overview_hotkey() -> String {
+    "`".to_string()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_ping_targets() -> Vec<String> {
+    vec!["8.8.8.8".to_string(), "1.1.1.1".to_string()]
+}
+
+fn default_connection_filter() -> String {
+    "established".to_string()
+}
+
+fn default_powershell_max_concurrent() -> usize {
+    2
+}
+
+/// Colors start from `preset` (`dark`, `light`, `nord`); any role set here
+/// (hex `"#e06c75"` or a name like `"lightblue"`) overrides the preset.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ThemeConfig {
-    pub dark: DarkTheme,
+    #[serde(default = "default_theme_preset")]
+    pub preset: String,
+    #[serde(flatten)]
+    pub overrides: ThemeColors,
+    /// Legacy `[theme.dark]` table, applied as overrides below the ones above.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dark: Option<DarkTheme>,
+}
+
+impl Default for ThemeConfig {
+    fn default() -> Self {
+        Self {
+            preset: default_theme_preset(),
+            overrides: ThemeColors::default(),
+            dark: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ThemeColors {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub foreground: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gpu_color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ram_color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disk_color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning_color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success_color: Option<String>,
+}
+
+impl ThemeColors {
+    pub fn entries(&self) -> [(&'static str, Option<&str>); 10] {
+        [
+            ("background", self.background.as_deref()),
+            ("foreground", self.foreground.as_deref()),
+            ("cpu_color", self.cpu_color.as_deref()),
+            ("gpu_color", self.gpu_color.as_deref()),
+            ("ram_color", self.ram_color.as_deref()),
+            ("disk_color", self.disk_color.as_deref()),
+            ("network_color", self.network_color.as_deref()),
+            ("warning_color", self.warning_color.as_deref()),
+            ("error_color", self.error_color.as_deref()),
+            ("success_color", self.success_color.as_deref()),
+        ]
+    }
+}
+
+impl ThemeConfig {
+    /// Log every color that will fall back to the preset.
+    fn warn_invalid(&self) {
+        if crate::ui::theme::preset(&self.preset).is_none() {
+            log::warn!("Unknown theme preset '{}', using 'dark'", self.preset);
+        }
+
+        let legacy = self.dark.as_ref().map(DarkTheme::as_colors).unwrap_or_default();
+        for colors in [&legacy, &self.overrides] {
+            for (role, value) in colors.entries() {
+                if let Some(value) = value {
+                    if crate::ui::theme::parse_color(value).is_none() {
+                        log::warn!("Invalid theme color '{}' for {}, using preset", value, role);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn default_theme_preset() -> String {
+    "dark".to_string()
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -229,13 +656,80 @@ pub struct DarkTheme {
     pub success_color: String,
 }
 
+impl DarkTheme {
+    pub fn as_colors(&self) -> ThemeColors {
+        ThemeColors {
+            background: Some(self.background.clone()),
+            foreground: Some(self.foreground.clone()),
+            cpu_color: Some(self.cpu_color.clone()),
+            gpu_color: Some(self.gpu_color.clone()),
+            ram_color: Some(self.ram_color.clone()),
+            disk_color: Some(self.disk_color.clone()),
+            network_color: Some(self.network_color.clone()),
+            warning_color: Some(self.warning_color.clone()),
+            error_color: Some(self.error_color.clone()),
+            success_color: Some(self.success_color.clone()),
+        }
+    }
+}
+
+/// Why `config.toml` could not be used.
+#[derive(Debug)]
+pub enum ConfigLoadError {
+    Read(std::io::Error),
+    /// Bad TOML syntax or a value of the wrong type; `line` is 1-based
+    Parse {
+        line: Option<usize>,
+        message: String,
+    },
+}
+
+impl ConfigLoadError {
+    fn parse(content: &str, error: &toml::de::Error) -> Self {
+        let line = error.span().map(|span| {
+            content[..span.start.min(content.len())]
+                .matches('\n')
+                .count()
+                + 1
+        });
+        Self::Parse {
+            line,
+            message: error.message().trim().to_string(),
+        }
+    }
+
+    fn is_missing_file(&self) -> bool {
+        matches!(self, Self::Read(e) if e.kind() == std::io::ErrorKind::NotFound)
+    }
+}
+
+impl std::fmt::Display for ConfigLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read(e) => write!(f, "cannot read config.toml: {}", e),
+            Self::Parse {
+                line: Some(line),
+                message,
+            } => write!(f, "config.toml line {}: {}", line, message),
+            Self::Parse {
+                line: None,
+                message,
+            } => write!(f, "config.toml: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ConfigLoadError {}
+
 impl Config {
-    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let content = fs::read_to_string(path.as_ref())
-            .with_context(|| format!("Failed to read config file: {:?}", path.as_ref()))?;
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ConfigLoadError> {
+        let content = fs::read_to_string(path.as_ref()).map_err(ConfigLoadError::Read)?;
 
         let config: Config =
-            toml::from_str(&content).with_context(|| "Failed to parse config file")?;
+            toml::from_str(&content).map_err(|e| ConfigLoadError::parse(&content, &e))?;
+        config.theme.warn_invalid();
+        config.monitors.processes.warn_invalid();
+        config.monitors.gpu.warn_invalid();
 
         Ok(config)
     }
@@ -248,33 +742,109 @@ impl Config {
 
         Ok(())
     }
-    pub fn load_or_default<P: AsRef<Path>>(path: P) -> Result<Self> {
+    /// Load `path`, falling back to the bundled defaults. A missing file is
+    /// created from the defaults; a broken one is left for the user to fix
+    /// and its error is returned alongside the defaults so it can be shown.
+    pub fn load_or_default<P: AsRef<Path>>(path: P) -> Result<(Self, Option<ConfigLoadError>)> {
         match Self::load(path.as_ref()) {
-            Ok(config) => Ok(config),
+            Ok(config) => Ok((config, None)),
             Err(load_err) => {
-                log::warn!(
-                    "Falling back to bundled default config: {}. A new config will be written to {:?} if possible.",
-                    load_err,
-                    path.as_ref()
-                );
-
                 let default_config: Config = toml::from_str(DEFAULT_CONFIG)
                     .context("Failed to parse bundled default config")?;
 
-                if let Err(save_err) = default_config.save(path.as_ref()) {
-                    log::warn!("Failed to write default config: {}", save_err);
+                if load_err.is_missing_file() {
+                    log::info!("No config found, writing defaults to {:?}", path.as_ref());
+                    if let Err(save_err) = default_config.save(path.as_ref()) {
+                        log::warn!("Failed to write default config: {}", save_err);
+                    }
+                    return Ok((default_config, None));
                 }
 
-                Ok(default_config)
+                log::warn!("Using bundled default config: {}", load_err);
+                Ok((default_config, Some(load_err)))
             }
         }
     }
 
+    /// Whether the monitor behind `tab` is switched on; `None` for tabs without one.
+    pub fn monitor_enabled(&self, tab: TabType) -> Option<bool> {
+        let monitors = &self.monitors;
+        Some(match tab {
+            TabType::Cpu => monitors.cpu.enabled,
+            TabType::Gpu => monitors.gpu.enabled,
+            TabType::Ram => monitors.ram.enabled,
+            TabType::Disk => monitors.disk.enabled,
+            TabType::Network => monitors.network.enabled,
+            TabType::Processes => monitors.processes.enabled,
+            TabType::Services => monitors.services.enabled,
+            TabType::Ollama => self.integrations.ollama.enabled,
+            TabType::DiskAnalyzer => self.integrations.everything.enabled,
+            TabType::Overview | TabType::Settings => return None,
+        })
+    }
+
+    /// How often the monitor behind `tab` refreshes; `None` for tabs without one.
+    pub fn refresh_interval_ms(&self, tab: TabType) -> Option<u64> {
+        let monitors = &self.monitors;
+        Some(match tab {
+            TabType::Cpu => monitors.cpu.refresh_interval_ms,
+            TabType::Gpu => monitors.gpu.refresh_interval_ms,
+            TabType::Ram => monitors.ram.refresh_interval_ms,
+            TabType::Disk => monitors.disk.refresh_interval_ms,
+            TabType::Network => monitors.network.refresh_interval_ms,
+            TabType::Processes => monitors.processes.refresh_interval_ms,
+            TabType::Services => monitors.services.refresh_interval_ms,
+            TabType::Ollama => self.integrations.ollama.refresh_interval_ms,
+            TabType::DiskAnalyzer => self.integrations.everything.refresh_interval_ms,
+            TabType::Overview | TabType::Settings => return None,
+        })
+    }
+
+    pub fn refresh_interval_mut(&mut self, tab: TabType) -> Option<&mut u64> {
+        let monitors = &mut self.monitors;
+        Some(match tab {
+            TabType::Cpu => &mut monitors.cpu.refresh_interval_ms,
+            TabType::Gpu => &mut monitors.gpu.refresh_interval_ms,
+            TabType::Ram => &mut monitors.ram.refresh_interval_ms,
+            TabType::Disk => &mut monitors.disk.refresh_interval_ms,
+            TabType::Network => &mut monitors.network.refresh_interval_ms,
+            TabType::Processes => &mut monitors.processes.refresh_interval_ms,
+            TabType::Services => &mut monitors.services.refresh_interval_ms,
+            TabType::Ollama => &mut self.integrations.ollama.refresh_interval_ms,
+            TabType::DiskAnalyzer => &mut self.integrations.everything.refresh_interval_ms,
+            TabType::Overview | TabType::Settings => return None,
+        })
+    }
+
+    /// Where `refresh_interval_ms` for `tab` lives in `config.toml`.
+    pub fn refresh_interval_path(tab: TabType) -> Option<&'static [&'static str]> {
+        Some(match tab {
+            TabType::Cpu => &["monitors", "cpu", "refresh_interval_ms"],
+            TabType::Gpu => &["monitors", "gpu", "refresh_interval_ms"],
+            TabType::Ram => &["monitors", "ram", "refresh_interval_ms"],
+            TabType::Disk => &["monitors", "disk", "refresh_interval_ms"],
+            TabType::Network => &["monitors", "network", "refresh_interval_ms"],
+            TabType::Processes => &["monitors", "processes", "refresh_interval_ms"],
+            TabType::Services => &["monitors", "services", "refresh_interval_ms"],
+            TabType::Ollama => &["integrations", "ollama", "refresh_interval_ms"],
+            TabType::DiskAnalyzer => &["integrations", "everything", "refresh_interval_ms"],
+            TabType::Overview | TabType::Settings => return None,
+        })
+    }
 }
 
+/// Editors often save in several writes; reload once they go quiet.
+const RELOAD_DEBOUNCE: Duration = Duration::from_millis(300);
+
 pub struct ConfigManager {
     config: Arc<RwLock<Config>>,
     config_path: std::path::PathBuf,
+    /// Why the file on disk isn't the config in use, until it loads cleanly
+    load_error: Arc<RwLock<Option<String>>>,
+    /// Where watcher reloads report their outcome, once the UI is up
+    notifier: Mutex<Option<NotificationSender>>,
+    /// Bumped whenever the live config is replaced
+    generation: AtomicU64,
 }
 
 impl ConfigManager {
@@ -282,14 +852,121 @@ impl ConfigManager {
         Arc::new(Self {
             config: Arc::new(RwLock::new(config)),
             config_path,
+            load_error: Arc::new(RwLock::new(None)),
+            notifier: Mutex::new(None),
+            generation: AtomicU64::new(0),
         })
     }
 
-    #[allow(dead_code)]
     pub fn get_config(&self) -> Arc<RwLock<Config>> {
         Arc::clone(&self.config)
     }
 
+    /// Changes each time a reload or `set_value` replaces the live config, so
+    /// state derived from it knows to rebuild.
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Relaxed)
+    }
+
+    fn replace_config(&self, config: Config) {
+        *self.config.write() = config;
+        self.generation.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn config_path(&self) -> &Path {
+        &self.config_path
+    }
+
+    pub fn load_error(&self) -> Option<String> {
+        self.load_error.read().clone()
+    }
+
+    pub fn set_load_error(&self, error: Option<&ConfigLoadError>) {
+        *self.load_error.write() = error.map(ToString::to_string);
+    }
+
+    pub fn set_notifier(&self, notifier: NotificationSender) {
+        *self.notifier.lock() = Some(notifier);
+    }
+
+    fn notify(&self, notification: Notification) {
+        if let Some(notifier) = self.notifier.lock().as_ref() {
+            let _ = notifier.send(notification);
+        }
+    }
+
+    /// Store `value` at `path` (e.g. `["monitors", "cpu", "refresh_interval_ms"]`)
+    /// in the config file, keeping its comments, and apply it right away.
+    pub fn set_value(&self, path: &[&str], value: toml_edit::Value) -> Result<()> {
+        let config = self.store_value(path, value)?;
+        self.replace_config(config);
+        Ok(())
+    }
+
+    /// Like `set_value`, but leaves the running config alone so live changes
+    /// that were never saved survive.
+    pub fn write_value(&self, path: &[&str], value: toml_edit::Value) -> Result<()> {
+        self.store_value(path, value).map(|_| ())
+    }
+
+    /// Write `value` at `path` to the file and return the config it now holds.
+    fn store_value(&self, path: &[&str], value: toml_edit::Value) -> Result<Config> {
+        let Some((key, tables)) = path.split_last() else {
+            anyhow::bail!("Empty config path");
+        };
+
+        let content =
+            fs::read_to_string(&self.config_path).unwrap_or_else(|_| DEFAULT_CONFIG.to_string());
+        let mut document: toml_edit::DocumentMut =
+            content.parse().context("Failed to parse config file")?;
+
+        let mut table = document.as_table_mut();
+        for name in tables {
+            table = table
+                .entry(name)
+                .or_insert_with(toml_edit::table)
+                .as_table_mut()
+                .with_context(|| format!("Config key '{}' is not a table", name))?;
+        }
+
+        // Keep the trailing comment that documents most settings
+        let decor = table
+            .get(key)
+            .and_then(|item| item.as_value())
+            .map(|old| old.decor().clone());
+        let mut value = value;
+        if let Some(decor) = decor {
+            *value.decor_mut() = decor;
+        }
+        table.insert(key, toml_edit::Item::Value(value));
+
+        let updated = document.to_string();
+        let config: Config = toml::from_str(&updated).context("Updated config is invalid")?;
+        fs::write(&self.config_path, updated)
+            .with_context(|| format!("Failed to write config file: {:?}", self.config_path))?;
+        Ok(config)
+    }
+
+    /// Re-read the file, keeping the current config if it doesn't parse.
+    fn reload(&self) {
+        match Config::load(&self.config_path) {
+            Ok(new_config) => {
+                self.replace_config(new_config);
+                self.set_load_error(None);
+                log::info!("Configuration reloaded successfully");
+                self.notify(Notification::info("Configuration reloaded"));
+            }
+            Err(e) => {
+                log::error!("Failed to reload config, keeping the previous one: {}", e);
+                self.notify(Notification::new(
+                    NotificationLevel::Warn,
+                    format!("config.toml not applied: {}", e),
+                ));
+                self.set_load_error(Some(&e));
+            }
+        }
+    }
+
     pub fn watch(self: Arc<Self>) -> Result<()> {
         use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
         use std::sync::mpsc::channel;
@@ -303,9 +980,6 @@ impl ConfigManager {
             .watch(self.config_path.as_ref(), RecursiveMode::NonRecursive)
             .context("Failed to watch config file")?;
 
-        let config = Arc::clone(&self.config);
-        let config_path = self.config_path.clone();
-
         // Spawn watcher thread
         std::thread::spawn(move || {
             // Keep watcher alive
@@ -317,18 +991,9 @@ impl ConfigManager {
                         use notify::EventKind;
                         match event.kind {
                             EventKind::Modify(_) | EventKind::Create(_) => {
-                                // Small delay to ensure file is fully written
-                                std::thread::sleep(std::time::Duration::from_millis(100));
-
-                                match Config::load(&config_path) {
-                                    Ok(new_config) => {
-                                        *config.write() = new_config;
-                                        log::info!("Configuration reloaded successfully");
-                                    }
-                                    Err(e) => {
-                                        log::error!("Failed to reload config: {}", e);
-                                    }
-                                }
+                                // Swallow the rest of this save's events
+                                while rx.recv_timeout(RELOAD_DEBOUNCE).is_ok() {}
+                                self.reload();
                             }
                             _ => {}
                         }
@@ -347,3 +1012,40 @@ impl ConfigManager {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_errors_report_their_line() {
+        let path =
+            std::env::temp_dir().join(format!("tui-plus-config-{}.toml", std::process::id()));
+        fs::write(&path, "[general]\nrefresh_rate_ms = \"fast\n").unwrap();
+        let result = Config::load(&path);
+        fs::remove_file(&path).ok();
+
+        match result {
+            Err(ConfigLoadError::Parse { line, .. }) => assert_eq!(line, Some(2)),
+            other => panic!("expected a parse error, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn process_columns_keep_order_and_skip_unknown_names() {
+        let config = ProcessMonitorConfig {
+            columns: ["PID", "name", "bogus", "cpu", "pid"].map(String::from).to_vec(),
+            ..ProcessMonitorConfig::default()
+        };
+        assert_eq!(
+            config.columns(),
+            [ProcessColumn::Pid, ProcessColumn::Name, ProcessColumn::Cpu]
+        );
+
+        let empty = ProcessMonitorConfig {
+            columns: vec!["bogus".to_string()],
+            ..ProcessMonitorConfig::default()
+        };
+        assert_eq!(empty.columns(), ProcessColumn::DEFAULT);
+    }
+}
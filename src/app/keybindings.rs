@@ -0,0 +1,832 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
+
+use super::config::{HotkeysConfig, KeybindingsConfig};
+use super::TabType;
+
+/// Actions that can be remapped through the `[keybindings]` config section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyAction {
+    Quit,
+    OpenHistory,
+    ToggleHelp,
+    NextTab,
+    PrevTab,
+    ToggleCompact,
+    NavUp,
+    NavDown,
+    NavLeft,
+    NavRight,
+    PageUp,
+    PageDown,
+    ToggleExpand,
+    SortPid,
+    SortName,
+    SortCpu,
+    SortMemory,
+    SortThreads,
+    SortUser,
+    KillProcess,
+    ToggleTree,
+    SortGpu,
+    SortType,
+    SortWorkingSet,
+    SortPrivateBytes,
+    SortDisplayName,
+    SortStatus,
+    SortStartType,
+    CycleFilter,
+    StartService,
+    StopService,
+    RestartService,
+    ShowSmart,
+    DeleteEntry,
+    Export,
+    FindDuplicates,
+    ToggleTreemap,
+    OpenFolder,
+    OpenTerminal,
+    TabMenu,
+    ExportProcesses,
+    CopySelection,
+    TogglePin,
+    ToggleAllInterfaces,
+    CycleAddressFamily,
+    RefreshFaster,
+    RefreshSlower,
+    TogglePause,
+    MoveTabLeft,
+    MoveTabRight,
+    SetDefaultTab,
+    CopyMarkdown,
+    ExportReport,
+    ShowDetails,
+    ToggleErrors,
+    ToggleAdapter,
+    FlushDns,
+    ToggleSplit,
+    CycleSplitTab,
+    JumpToPid,
+    ToggleLogs,
+}
+
+impl KeyAction {
+    pub fn all() -> &'static [KeyAction] {
+        &[
+            KeyAction::Quit,
+            KeyAction::OpenHistory,
+            KeyAction::ToggleHelp,
+            KeyAction::NextTab,
+            KeyAction::PrevTab,
+            KeyAction::ToggleCompact,
+            KeyAction::NavUp,
+            KeyAction::NavDown,
+            KeyAction::NavLeft,
+            KeyAction::NavRight,
+            KeyAction::PageUp,
+            KeyAction::PageDown,
+            KeyAction::ToggleExpand,
+            KeyAction::SortPid,
+            KeyAction::SortName,
+            KeyAction::SortCpu,
+            KeyAction::SortMemory,
+            KeyAction::SortThreads,
+            KeyAction::SortUser,
+            KeyAction::KillProcess,
+            KeyAction::ToggleTree,
+            KeyAction::SortGpu,
+            KeyAction::SortType,
+            KeyAction::SortWorkingSet,
+            KeyAction::SortPrivateBytes,
+            KeyAction::SortDisplayName,
+            KeyAction::SortStatus,
+            KeyAction::SortStartType,
+            KeyAction::CycleFilter,
+            KeyAction::StartService,
+            KeyAction::StopService,
+            KeyAction::RestartService,
+            KeyAction::ShowSmart,
+            KeyAction::DeleteEntry,
+            KeyAction::Export,
+            KeyAction::FindDuplicates,
+            KeyAction::ToggleTreemap,
+            KeyAction::OpenFolder,
+            KeyAction::OpenTerminal,
+            KeyAction::TabMenu,
+            KeyAction::ExportProcesses,
+            KeyAction::CopySelection,
+            KeyAction::TogglePin,
+            KeyAction::ToggleAllInterfaces,
+            KeyAction::CycleAddressFamily,
+            KeyAction::RefreshFaster,
+            KeyAction::RefreshSlower,
+            KeyAction::TogglePause,
+            KeyAction::MoveTabLeft,
+            KeyAction::MoveTabRight,
+            KeyAction::SetDefaultTab,
+            KeyAction::CopyMarkdown,
+            KeyAction::ExportReport,
+            KeyAction::ShowDetails,
+            KeyAction::ToggleErrors,
+            KeyAction::ToggleAdapter,
+            KeyAction::FlushDns,
+            KeyAction::ToggleSplit,
+            KeyAction::CycleSplitTab,
+            KeyAction::JumpToPid,
+            KeyAction::ToggleLogs,
+        ]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KeyAction::Quit => "quit",
+            KeyAction::OpenHistory => "open_history",
+            KeyAction::ToggleHelp => "toggle_help",
+            KeyAction::NextTab => "next_tab",
+            KeyAction::PrevTab => "prev_tab",
+            KeyAction::ToggleCompact => "toggle_compact",
+            KeyAction::NavUp => "nav_up",
+            KeyAction::NavDown => "nav_down",
+            KeyAction::NavLeft => "nav_left",
+            KeyAction::NavRight => "nav_right",
+            KeyAction::PageUp => "page_up",
+            KeyAction::PageDown => "page_down",
+            KeyAction::ToggleExpand => "toggle_expand",
+            KeyAction::SortPid => "sort_pid",
+            KeyAction::SortName => "sort_name",
+            KeyAction::SortCpu => "sort_cpu",
+            KeyAction::SortMemory => "sort_memory",
+            KeyAction::SortThreads => "sort_threads",
+            KeyAction::SortUser => "sort_user",
+            KeyAction::KillProcess => "kill_process",
+            KeyAction::ToggleTree => "toggle_tree",
+            KeyAction::SortGpu => "sort_gpu",
+            KeyAction::SortType => "sort_type",
+            KeyAction::SortWorkingSet => "sort_working_set",
+            KeyAction::SortPrivateBytes => "sort_private_bytes",
+            KeyAction::SortDisplayName => "sort_display_name",
+            KeyAction::SortStatus => "sort_status",
+            KeyAction::SortStartType => "sort_start_type",
+            KeyAction::CycleFilter => "cycle_filter",
+            KeyAction::StartService => "start_service",
+            KeyAction::StopService => "stop_service",
+            KeyAction::RestartService => "restart_service",
+            KeyAction::ShowSmart => "show_smart",
+            KeyAction::DeleteEntry => "delete_entry",
+            KeyAction::Export => "export",
+            KeyAction::FindDuplicates => "find_duplicates",
+            KeyAction::ToggleTreemap => "toggle_treemap",
+            KeyAction::OpenFolder => "open_folder",
+            KeyAction::OpenTerminal => "open_terminal",
+            KeyAction::TabMenu => "tab_menu",
+            KeyAction::ExportProcesses => "export_processes",
+            KeyAction::CopySelection => "copy_selection",
+            KeyAction::TogglePin => "toggle_pin",
+            KeyAction::ToggleAllInterfaces => "toggle_all_interfaces",
+            KeyAction::CycleAddressFamily => "cycle_address_family",
+            KeyAction::RefreshFaster => "refresh_faster",
+            KeyAction::RefreshSlower => "refresh_slower",
+            KeyAction::TogglePause => "toggle_pause",
+            KeyAction::MoveTabLeft => "move_tab_left",
+            KeyAction::MoveTabRight => "move_tab_right",
+            KeyAction::SetDefaultTab => "set_default_tab",
+            KeyAction::CopyMarkdown => "copy_markdown",
+            KeyAction::ExportReport => "export_report",
+            KeyAction::ShowDetails => "show_details",
+            KeyAction::ToggleErrors => "toggle_errors",
+            KeyAction::ToggleAdapter => "toggle_adapter",
+            KeyAction::FlushDns => "flush_dns",
+            KeyAction::ToggleSplit => "toggle_split",
+            KeyAction::CycleSplitTab => "cycle_split_tab",
+            KeyAction::JumpToPid => "jump_to_pid",
+            KeyAction::ToggleLogs => "toggle_logs",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        let s = s.trim().to_lowercase();
+        Self::all()
+            .iter()
+            .copied()
+            .find(|action| action.as_str() == s)
+    }
+
+    fn default_binding(&self) -> &'static str {
+        match self {
+            KeyAction::Quit => "ctrl+c",
+            KeyAction::OpenHistory => "ctrl+f",
+            KeyAction::ToggleHelp => "F1",
+            KeyAction::NextTab => "tab",
+            KeyAction::PrevTab => "shift+tab",
+            KeyAction::ToggleCompact => "F2",
+            KeyAction::NavUp => "up",
+            KeyAction::NavDown => "down",
+            KeyAction::NavLeft => "left",
+            KeyAction::NavRight => "right",
+            KeyAction::PageUp => "pageup",
+            KeyAction::PageDown => "pagedown",
+            KeyAction::ToggleExpand => "space",
+            KeyAction::SortPid => "p",
+            KeyAction::SortName => "n",
+            KeyAction::SortCpu => "c",
+            KeyAction::SortMemory => "m",
+            KeyAction::SortThreads => "t",
+            KeyAction::SortUser => "u",
+            KeyAction::KillProcess => "k",
+            KeyAction::ToggleTree => "shift+t",
+            KeyAction::SortGpu => "g",
+            KeyAction::SortType => "t",
+            KeyAction::SortWorkingSet => "w",
+            KeyAction::SortPrivateBytes => "b",
+            KeyAction::SortDisplayName => "d",
+            KeyAction::SortStatus => "s",
+            KeyAction::SortStartType => "t",
+            KeyAction::CycleFilter => "f",
+            KeyAction::StartService => "shift+s",
+            KeyAction::StopService => "shift+x",
+            KeyAction::RestartService => "shift+r",
+            KeyAction::ShowSmart => "s",
+            KeyAction::DeleteEntry => "d",
+            KeyAction::Export => "s",
+            KeyAction::FindDuplicates => "f",
+            KeyAction::ToggleTreemap => "m",
+            KeyAction::OpenFolder => "o",
+            KeyAction::OpenTerminal => "t",
+            KeyAction::TabMenu => "F3",
+            KeyAction::ExportProcesses => "e",
+            KeyAction::CopySelection => "y",
+            KeyAction::TogglePin => "*",
+            KeyAction::ToggleAllInterfaces => "a",
+            KeyAction::CycleAddressFamily => "i",
+            KeyAction::RefreshFaster => "+",
+            KeyAction::RefreshSlower => "-",
+            KeyAction::TogglePause => "F4",
+            KeyAction::MoveTabLeft => "ctrl+left",
+            KeyAction::MoveTabRight => "ctrl+right",
+            KeyAction::SetDefaultTab => "ctrl+d",
+            KeyAction::CopyMarkdown => "ctrl+y",
+            KeyAction::ExportReport => "F5",
+            KeyAction::ShowDetails => "enter",
+            KeyAction::ToggleErrors => "F6",
+            KeyAction::ToggleAdapter => "shift+d",
+            KeyAction::FlushDns => "shift+f",
+            KeyAction::ToggleSplit => "F7",
+            KeyAction::CycleSplitTab => "ctrl+n",
+            KeyAction::JumpToPid => "ctrl+g",
+            KeyAction::ToggleLogs => "F8",
+        }
+    }
+}
+
+/// A single key plus the modifiers that must be held with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyCombo {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyCombo {
+    /// Parse strings like `"ctrl+f"`, `"F2"`, `"shift+tab"` or `"j"`.
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        if input.is_empty() {
+            return None;
+        }
+
+        // A bare "+" is a valid key, so split on the last separator only
+        let (prefix, key) = match input.rfind('+') {
+            Some(idx) if idx + 1 < input.len() => (&input[..idx], &input[idx + 1..]),
+            Some(idx) if idx > 0 => (&input[..idx - 1], "+"),
+            _ => ("", input),
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        for part in prefix.split('+').map(str::trim).filter(|p| !p.is_empty()) {
+            match part.to_lowercase().as_str() {
+                "ctrl" | "control" => modifiers |= KeyModifiers::CONTROL,
+                "alt" => modifiers |= KeyModifiers::ALT,
+                "shift" => modifiers |= KeyModifiers::SHIFT,
+                _ => return None,
+            }
+        }
+
+        let lower = key.to_lowercase();
+        let code = match lower.as_str() {
+            "tab" if modifiers.contains(KeyModifiers::SHIFT) => {
+                modifiers -= KeyModifiers::SHIFT;
+                KeyCode::BackTab
+            }
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "space" => KeyCode::Char(' '),
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "insert" | "ins" => KeyCode::Insert,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" | "pgup" => KeyCode::PageUp,
+            "pagedown" | "pgdn" => KeyCode::PageDown,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            _ if lower.len() > 1 && lower.starts_with('f') => {
+                let n: u8 = lower[1..].parse().ok()?;
+                if !(1..=24).contains(&n) {
+                    return None;
+                }
+                KeyCode::F(n)
+            }
+            _ => {
+                let mut chars = key.chars();
+                let c = chars.next()?;
+                if chars.next().is_some() {
+                    return None;
+                }
+                // Shifted letters arrive from the terminal as uppercase chars
+                if modifiers.contains(KeyModifiers::SHIFT) {
+                    modifiers -= KeyModifiers::SHIFT;
+                    KeyCode::Char(c.to_ascii_uppercase())
+                } else if !modifiers.is_empty() {
+                    // "Ctrl+F" is reported as Char('f') with CONTROL
+                    KeyCode::Char(c.to_ascii_lowercase())
+                } else {
+                    KeyCode::Char(c)
+                }
+            }
+        };
+
+        Some(Self { code, modifiers })
+    }
+
+    /// Short display form for help text, e.g. `Ctrl+F`, `Shift+Tab`, `↑`.
+    pub fn label(&self) -> String {
+        let key = match self.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::F(n) => format!("F{}", n),
+            KeyCode::Tab => "Tab".to_string(),
+            KeyCode::BackTab => "Shift+Tab".to_string(),
+            KeyCode::Enter => "Enter".to_string(),
+            KeyCode::Esc => "Esc".to_string(),
+            KeyCode::Backspace => "Backspace".to_string(),
+            KeyCode::Delete => "Del".to_string(),
+            KeyCode::Insert => "Ins".to_string(),
+            KeyCode::Home => "Home".to_string(),
+            KeyCode::End => "End".to_string(),
+            KeyCode::PageUp => "PgUp".to_string(),
+            KeyCode::PageDown => "PgDn".to_string(),
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            other => format!("{:?}", other),
+        };
+
+        let mut label = String::new();
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            label.push_str("Ctrl+");
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            label.push_str("Alt+");
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            label.push_str("Shift+");
+        }
+        label.push_str(&key);
+        label
+    }
+
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        if self.code != key.code {
+            return false;
+        }
+
+        // Shift is already encoded in the char / BackTab itself and terminals
+        // disagree on whether they also report the modifier, so ignore it there.
+        let relevant = match key.code {
+            KeyCode::Char(_) | KeyCode::BackTab => KeyModifiers::CONTROL | KeyModifiers::ALT,
+            _ => KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT,
+        };
+
+        (key.modifiers & relevant) == (self.modifiers & relevant)
+    }
+}
+
+/// Resolved key bindings, built once from the config at startup.
+pub struct Keybindings {
+    actions: HashMap<KeyAction, KeyCombo>,
+    tabs: Vec<(TabType, KeyCombo)>,
+}
+
+impl Keybindings {
+    pub fn from_config(keybindings: &KeybindingsConfig, hotkeys: &HotkeysConfig) -> Self {
+        for name in keybindings.bindings.keys() {
+            if KeyAction::from_str(name).is_none() {
+                log::warn!("Unknown keybinding action '{}' ignored", name);
+            }
+        }
+
+        let mut actions = HashMap::new();
+        for &action in KeyAction::all() {
+            let default =
+                KeyCombo::parse(action.default_binding()).expect("default keybinding must parse");
+
+            let combo = match keybindings.bindings.get(action.as_str()) {
+                Some(raw) => KeyCombo::parse(raw).unwrap_or_else(|| {
+                    log::warn!(
+                        "Invalid keybinding '{}' for '{}', using default '{}'",
+                        raw,
+                        action.as_str(),
+                        action.default_binding()
+                    );
+                    default
+                }),
+                None => default,
+            };
+            actions.insert(action, combo);
+        }
+
+        let tab_hotkeys = [
+            (TabType::Overview, hotkeys.overview.as_str(), '`'),
+            (TabType::Cpu, hotkeys.cpu.as_str(), '1'),
+            (TabType::Gpu, hotkeys.gpu.as_str(), '2'),
+            (TabType::Ram, hotkeys.ram.as_str(), '3'),
+            (TabType::Disk, hotkeys.disk.as_str(), '4'),
+            (TabType::Network, hotkeys.network.as_str(), '5'),
+            (TabType::Ollama, hotkeys.ollama.as_str(), '6'),
+            (TabType::Processes, hotkeys.processes.as_str(), '7'),
+            (TabType::Services, hotkeys.services.as_str(), '8'),
+            (TabType::DiskAnalyzer, hotkeys.disk_analyzer.as_str(), '9'),
+            (TabType::Settings, hotkeys.settings.as_str(), '0'),
+        ];
+
+        let tabs = tab_hotkeys
+            .iter()
+            .map(|(tab, raw, fallback)| {
+                let combo = KeyCombo::parse(raw).unwrap_or_else(|| {
+                    log::warn!(
+                        "Invalid hotkey '{}' for tab '{}', using default '{}'",
+                        raw,
+                        tab.as_str(),
+                        fallback
+                    );
+                    KeyCombo {
+                        code: KeyCode::Char(*fallback),
+                        modifiers: KeyModifiers::NONE,
+                    }
+                });
+                (*tab, combo)
+            })
+            .collect();
+
+        Self { actions, tabs }
+    }
+
+    pub fn is(&self, action: KeyAction, key: &KeyEvent) -> bool {
+        self.actions
+            .get(&action)
+            .map(|combo| combo.matches(key))
+            .unwrap_or(false)
+    }
+
+    /// Return the first of `candidates` bound to `key`. Tabs pass only the
+    /// actions they handle, so the same key can mean different things per tab.
+    pub fn lookup(&self, key: &KeyEvent, candidates: &[KeyAction]) -> Option<KeyAction> {
+        candidates
+            .iter()
+            .copied()
+            .find(|action| self.is(*action, key))
+    }
+
+    pub fn tab_for(&self, key: &KeyEvent) -> Option<TabType> {
+        self.tabs
+            .iter()
+            .find(|(_, combo)| combo.matches(key))
+            .map(|(tab, _)| *tab)
+    }
+
+    pub fn label(&self, action: KeyAction) -> String {
+        self.actions
+            .get(&action)
+            .map(KeyCombo::label)
+            .unwrap_or_default()
+    }
+
+    /// Keys that work on every tab, with their current bindings.
+    pub fn global_help(&self) -> Vec<HelpEntry> {
+        let mut entries: Vec<HelpEntry> = [
+            (KeyAction::ToggleHelp, "Show / hide this help"),
+            (KeyAction::Quit, "Quit"),
+            (KeyAction::NextTab, "Next tab"),
+            (KeyAction::PrevTab, "Previous tab"),
+            (KeyAction::ToggleCompact, "Toggle compact mode"),
+            (KeyAction::OpenHistory, "Command history"),
+            (KeyAction::TabMenu, "Tab menu (or right-click)"),
+            (KeyAction::RefreshFaster, "Refresh this tab faster"),
+            (KeyAction::RefreshSlower, "Refresh this tab slower"),
+            (KeyAction::TogglePause, "Pause / resume all data refreshes"),
+            (KeyAction::MoveTabLeft, "Move tab left"),
+            (KeyAction::MoveTabRight, "Move tab right"),
+            (KeyAction::SetDefaultTab, "Open on this tab at startup"),
+            (KeyAction::CopyMarkdown, "Copy the shown table as Markdown"),
+            (KeyAction::ExportReport, "Write a diagnostic report"),
+            (KeyAction::ToggleErrors, "Monitor status and errors"),
+            (KeyAction::ToggleLogs, "Recent log messages"),
+            (
+                KeyAction::ToggleSplit,
+                "Split view: show a second tab alongside",
+            ),
+            (
+                KeyAction::CycleSplitTab,
+                "Split view: change the other pane's tab",
+            ),
+            (KeyAction::JumpToPid, "Jump to a PID in the Processes tab"),
+        ]
+        .iter()
+        .map(|(action, description)| HelpEntry {
+            keys: self.label(*action),
+            description,
+        })
+        .collect();
+
+        for (tab, combo) in &self.tabs {
+            entries.push(HelpEntry {
+                keys: combo.label(),
+                description: tab.as_str(),
+            });
+        }
+
+        entries
+    }
+
+    /// Keys handled by `tab`, with their current bindings.
+    pub fn tab_help(&self, tab: TabType) -> Vec<HelpEntry> {
+        if tab == TabType::Ollama {
+            // The Ollama tab handles raw keys and is not remappable
+            return OLLAMA_HELP
+                .iter()
+                .map(|(keys, description)| HelpEntry {
+                    keys: keys.to_string(),
+                    description,
+                })
+                .collect();
+        }
+
+        let mut entries: Vec<HelpEntry> = tab_actions(tab)
+            .iter()
+            .map(|(action, description)| HelpEntry {
+                keys: self.label(*action),
+                description,
+            })
+            .collect();
+
+        if tab == TabType::Processes {
+            entries.push(HelpEntry {
+                keys: "/".to_string(),
+                description: "Filter by name or PID (Enter apply, Esc clear)",
+            });
+        }
+
+        if tab == TabType::Services {
+            entries.push(HelpEntry {
+                keys: "/".to_string(),
+                description: "Search by name or display name (Enter apply, Esc clear)",
+            });
+        }
+
+        if tab == TabType::DiskAnalyzer {
+            entries.push(HelpEntry {
+                keys: "/".to_string(),
+                description: "Search folders by name or path (Enter apply, Esc clear)",
+            });
+        }
+
+        entries
+    }
+}
+
+/// One row of the help overlay.
+pub struct HelpEntry {
+    pub keys: String,
+    pub description: &'static str,
+}
+
+fn tab_actions(tab: TabType) -> &'static [(KeyAction, &'static str)] {
+    match tab {
+        TabType::Cpu => &[(
+            KeyAction::ToggleExpand,
+            "Show / hide cache and feature details",
+        )],
+        TabType::Gpu => &[
+            (KeyAction::NavUp, "Previous process"),
+            (KeyAction::NavDown, "Next process"),
+            (KeyAction::PageUp, "Page up"),
+            (KeyAction::PageDown, "Page down"),
+            (KeyAction::NavLeft, "Previous GPU"),
+            (KeyAction::NavRight, "Next GPU"),
+            (KeyAction::ToggleExpand, "Collapse / expand GPU"),
+            (KeyAction::SortPid, "Sort by PID"),
+            (KeyAction::SortName, "Sort by name"),
+            (KeyAction::SortGpu, "Sort by GPU usage"),
+            (KeyAction::SortMemory, "Sort by memory"),
+            (KeyAction::SortType, "Sort by type"),
+        ],
+        TabType::Ram => &[
+            (KeyAction::NavUp, "Previous process"),
+            (KeyAction::NavDown, "Next process"),
+            (KeyAction::PageUp, "Page up"),
+            (KeyAction::PageDown, "Page down"),
+            (KeyAction::NavLeft, "Switch panel"),
+            (KeyAction::NavRight, "Switch panel"),
+            (KeyAction::SortPid, "Sort by PID"),
+            (KeyAction::SortName, "Sort by name"),
+            (KeyAction::SortWorkingSet, "Sort by working set"),
+            (KeyAction::SortPrivateBytes, "Sort by private bytes"),
+        ],
+        TabType::Processes => &[
+            (KeyAction::NavUp, "Previous process"),
+            (KeyAction::NavDown, "Next process"),
+            (KeyAction::PageUp, "Page up"),
+            (KeyAction::PageDown, "Page down"),
+            (KeyAction::SortPid, "Sort by PID"),
+            (KeyAction::SortName, "Sort by name"),
+            (KeyAction::SortCpu, "Sort by CPU"),
+            (KeyAction::SortMemory, "Sort by memory"),
+            (KeyAction::SortThreads, "Sort by threads"),
+            (KeyAction::SortUser, "Sort by user"),
+            (KeyAction::KillProcess, "Kill selected process"),
+            (KeyAction::ToggleTree, "Toggle tree view"),
+            (
+                KeyAction::ToggleExpand,
+                "Collapse/expand subtree (tree view)",
+            ),
+            (
+                KeyAction::ExportProcesses,
+                "Export shown processes to JSON Lines",
+            ),
+            (KeyAction::CopySelection, "Copy name and PID"),
+            (KeyAction::TogglePin, "Pin / unpin process to the top"),
+            (KeyAction::ShowDetails, "Show process details"),
+        ],
+        TabType::Services => &[
+            (KeyAction::NavUp, "Previous service"),
+            (KeyAction::NavDown, "Next service"),
+            (KeyAction::PageUp, "Page up"),
+            (KeyAction::PageDown, "Page down"),
+            (KeyAction::NavLeft, "Switch panel"),
+            (KeyAction::NavRight, "Switch panel"),
+            (KeyAction::SortName, "Sort by name"),
+            (KeyAction::SortDisplayName, "Sort by display name"),
+            (KeyAction::SortStatus, "Sort by status"),
+            (KeyAction::SortStartType, "Sort by start type"),
+            (KeyAction::CycleFilter, "Cycle status filter"),
+            (KeyAction::StartService, "Start service"),
+            (KeyAction::StopService, "Stop service"),
+            (KeyAction::RestartService, "Restart service"),
+            (KeyAction::CopySelection, "Copy service name"),
+            (KeyAction::TogglePin, "Pin / unpin service to the top"),
+        ],
+        TabType::Disk => &[
+            (KeyAction::NavUp, "Previous disk / scroll SMART"),
+            (KeyAction::NavDown, "Next disk / scroll SMART"),
+            (KeyAction::ShowSmart, "Show / hide SMART attributes"),
+        ],
+        TabType::DiskAnalyzer => &[
+            (KeyAction::NavUp, "Previous folder"),
+            (KeyAction::NavDown, "Next folder"),
+            (KeyAction::NavLeft, "Previous drive"),
+            (KeyAction::NavRight, "Next drive"),
+            (
+                KeyAction::DeleteEntry,
+                "Delete folder (Recycle Bin / trash)",
+            ),
+            (KeyAction::Export, "Export drive to CSV/JSON"),
+            (KeyAction::FindDuplicates, "Find duplicate files in folder"),
+            (KeyAction::ToggleTreemap, "Toggle list / treemap view"),
+            (KeyAction::OpenFolder, "Open folder in the file manager"),
+            (KeyAction::OpenTerminal, "Open a terminal in the folder"),
+            (KeyAction::CopySelection, "Copy folder path"),
+        ],
+        TabType::Network => &[
+            (
+                KeyAction::NavLeft,
+                "Previous panel (Interfaces/Connections/Consumers)",
+            ),
+            (KeyAction::NavRight, "Next panel"),
+            (KeyAction::NavUp, "Select previous row / interface"),
+            (KeyAction::NavDown, "Select next row / interface"),
+            (KeyAction::PageUp, "Page up"),
+            (KeyAction::PageDown, "Page down"),
+            (
+                KeyAction::CycleFilter,
+                "Cycle state filter (Established/Listen/All)",
+            ),
+            (
+                KeyAction::ToggleAllInterfaces,
+                "Show filtered / all interfaces",
+            ),
+            (
+                KeyAction::CycleAddressFamily,
+                "Cycle IP version filter (All/IPv4/IPv6)",
+            ),
+            (
+                KeyAction::CopySelection,
+                "Copy address / remote endpoint / process",
+            ),
+            (
+                KeyAction::ToggleAdapter,
+                "Disable / enable selected adapter",
+            ),
+            (KeyAction::FlushDns, "Flush DNS cache"),
+        ],
+        _ => &[],
+    }
+}
+
+const OLLAMA_HELP: &[(&str, &str)] = &[
+    ("↑/↓", "Navigate"),
+    ("←/→", "Switch panel"),
+    ("v", "Toggle models / running view"),
+    ("r", "Chat with selected model"),
+    ("s/u", "Stop / unload running model"),
+    ("d", "Delete model or chat log"),
+    ("p", "Pull a model"),
+    ("c", "Run an ollama command"),
+    ("a", "Show additions"),
+    ("n/m/t/g", "Sort by name/params/time/messages"),
+    ("Esc", "Back"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn parses_modifiers_and_named_keys() {
+        let combo = KeyCombo::parse("Ctrl+F").unwrap();
+        assert!(combo.matches(&key(KeyCode::Char('f'), KeyModifiers::CONTROL)));
+        assert!(!combo.matches(&key(KeyCode::Char('f'), KeyModifiers::NONE)));
+
+        let combo = KeyCombo::parse("shift+k").unwrap();
+        assert!(combo.matches(&key(KeyCode::Char('K'), KeyModifiers::SHIFT)));
+        assert!(!combo.matches(&key(KeyCode::Char('k'), KeyModifiers::NONE)));
+
+        assert_eq!(KeyCombo::parse("F2").unwrap().code, KeyCode::F(2));
+        assert_eq!(KeyCombo::parse("shift+tab").unwrap().code, KeyCode::BackTab);
+        assert_eq!(KeyCombo::parse("+").unwrap().code, KeyCode::Char('+'));
+        assert_eq!(KeyCombo::parse("ctrl++").unwrap().code, KeyCode::Char('+'));
+        assert!(KeyCombo::parse("hyper+x").is_none());
+        assert!(KeyCombo::parse("F99").is_none());
+        assert!(KeyCombo::parse("").is_none());
+    }
+
+    #[test]
+    fn invalid_binding_keeps_default() {
+        let mut config = KeybindingsConfig::default();
+        config
+            .bindings
+            .insert("nav_down".to_string(), "j".to_string());
+        config
+            .bindings
+            .insert("nav_up".to_string(), "not a key".to_string());
+        let hotkeys: HotkeysConfig = toml::from_str(
+            r#"
+            cpu = "1"
+            gpu = "2"
+            ram = "3"
+            disk = "4"
+            network = "5"
+            ollama = "6"
+            processes = "7"
+            services = "8"
+            disk_analyzer = "9"
+            settings = "bogus"
+            "#,
+        )
+        .unwrap();
+
+        let bindings = Keybindings::from_config(&config, &hotkeys);
+        let j = key(KeyCode::Char('j'), KeyModifiers::NONE);
+        assert!(bindings.is(KeyAction::NavDown, &j));
+        assert!(!bindings.is(KeyAction::NavDown, &key(KeyCode::Down, KeyModifiers::NONE)));
+        assert!(bindings.is(KeyAction::NavUp, &key(KeyCode::Up, KeyModifiers::NONE)));
+        assert_eq!(
+            bindings.tab_for(&key(KeyCode::Char('0'), KeyModifiers::NONE)),
+            Some(TabType::Settings)
+        );
+        assert_eq!(
+            bindings.lookup(
+                &key(KeyCode::Char('t'), KeyModifiers::NONE),
+                &[KeyAction::SortGpu, KeyAction::SortType]
+            ),
+            Some(KeyAction::SortType)
+        );
+    }
+}
@@ -1,7 +1,11 @@
+pub mod alerts;
 pub mod state;
 pub mod config;
+pub mod keybindings;
+pub mod settings;
 pub mod tabs;
 pub mod monitors_task;
+pub mod notifications;
 
 pub use state::AppState;
 pub use config::{Config, ConfigManager};
@@ -12,6 +16,31 @@ use crossterm::event::Event as CrosstermEvent;
 use std::sync::Arc;
 
 use std::env;
+use std::path::PathBuf;
+
+/// `config.toml` in the working directory if present, otherwise next to the
+/// executable.
+pub fn resolve_config_path() -> Result<PathBuf> {
+    let exe_config_path = {
+        let mut path = env::current_exe()?;
+        path.set_file_name("config.toml");
+        path
+    };
+
+    let config_path = match env::current_dir() {
+        Ok(cwd) => {
+            let candidate = cwd.join("config.toml");
+            if candidate.exists() {
+                candidate
+            } else {
+                exe_config_path
+            }
+        }
+        Err(_) => exe_config_path,
+    };
+
+    Ok(config_path)
+}
 
 pub struct App {
     pub state: AppState,
@@ -20,29 +49,15 @@ pub struct App {
 }
 
 impl App {
-    pub async fn new() -> Result<Self> {
-        let exe_config_path = {
-            let mut path = env::current_exe()?;
-            path.set_file_name("config.toml");
-            path
-        };
-
-        let config_path = match env::current_dir() {
-            Ok(cwd) => {
-                let candidate = cwd.join("config.toml");
-                if candidate.exists() {
-                    candidate
-                } else {
-                    exe_config_path.clone()
-                }
-            }
-            Err(_) => exe_config_path.clone(),
-        };
+    /// `read_only` comes from `--read-only` and adds to `general.read_only`.
+    pub async fn new(read_only: bool) -> Result<Self> {
+        let config_path = resolve_config_path()?;
 
-        let config = Config::load_or_default(&config_path)?;
+        let (config, load_error) = Config::load_or_default(&config_path)?;
 
-        // Create config manager with hot reload
-        let config_manager = ConfigManager::new(config.clone(), config_path);
+        // Create config manager with hot reload; AppState shares its config
+        let config_manager = ConfigManager::new(config, config_path);
+        config_manager.set_load_error(load_error.as_ref());
 
         // Start watching for config changes
         if let Err(e) = config_manager.clone().watch() {
@@ -51,7 +66,7 @@ impl App {
             log::info!("Config hot reload enabled");
         }
 
-        let state = AppState::new(config).await?;
+        let state = AppState::new(Arc::clone(&config_manager), read_only).await?;
 
         Ok(Self {
             state,
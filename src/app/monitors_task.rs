@@ -1,9 +1,14 @@
+use chrono::{DateTime, Local};
 use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::Notify;
 use tokio::time::sleep;
 
-use crate::app::Config;
+use crate::app::{Config, TabType};
+use crate::integrations::remote::SshTarget;
 use crate::integrations::{OllamaClient, OllamaData, PowerShellExecutor};
 use crate::monitors::*;
 
@@ -13,11 +18,64 @@ struct PsSettings {
     timeout_seconds: u64,
     cache_ttl_seconds: u64,
     use_cache: bool,
+    persistent_session: bool,
+    max_concurrent: usize,
+    remote: Option<SshTarget>,
 }
 
-fn refresh_duration(refresh_interval_ms: u64) -> Duration {
+/// Whether a monitor is collecting data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonitorStatus {
+    Running,
+    /// `enabled = false` in the live config; the task idles until re-enabled.
+    Disabled,
+}
+
+/// When each monitor last stored fresh data, for the staleness indicator.
+pub type UpdateTimes = Arc<RwLock<HashMap<TabType, DateTime<Local>>>>;
+
+/// How often boot time is re-read; uptime is derived from it in between.
+const SYSTEM_INFO_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Multiplier for every monitor's refresh interval: 1 normally, the idle
+/// factor while nobody is using the UI.
+#[derive(Debug, Default)]
+pub struct RefreshScale {
+    factor: AtomicU32,
+    /// Wakes sleeping monitors when the factor drops, so input restores the
+    /// fast rate at once instead of after one long idle sleep.
+    lowered: Notify,
+}
+
+impl RefreshScale {
+    pub fn get(&self) -> u32 {
+        self.factor.load(Ordering::Relaxed).max(1)
+    }
+
+    /// Store a new factor and return the previous one.
+    pub fn set(&self, factor: u32) -> u32 {
+        let factor = factor.max(1);
+        let previous = self.factor.swap(factor, Ordering::Relaxed).max(1);
+        if factor < previous {
+            self.lowered.notify_waiters();
+        }
+        previous
+    }
+
+    /// Wait one scaled refresh interval, or until the factor drops.
+    async fn sleep(&self, refresh_interval_ms: u64) {
+        // Registered before reading the factor so a drop in between still wakes us
+        let lowered = self.lowered.notified();
+        tokio::select! {
+            _ = sleep(refresh_duration(refresh_interval_ms, self.get())) => {}
+            _ = lowered => {}
+        }
+    }
+}
+
+fn refresh_duration(refresh_interval_ms: u64, scale: u32) -> Duration {
     let interval_ms = if refresh_interval_ms == 0 { 1000 } else { refresh_interval_ms };
-    Duration::from_millis(interval_ms.max(100))
+    Duration::from_millis(interval_ms.max(100)) * scale.max(1)
 }
 
 fn effective_cache_ttl_seconds(cache_ttl_seconds: u64, refresh_interval_ms: u64) -> u64 {
@@ -45,6 +103,9 @@ fn build_ps_settings(config: &Config, refresh_interval_ms: u64) -> PsSettings {
         timeout_seconds: config.powershell.timeout_seconds,
         cache_ttl_seconds: effective_cache_ttl,
         use_cache: effective_use_cache,
+        persistent_session: config.powershell.persistent_session,
+        max_concurrent: config.powershell.max_concurrent,
+        remote: config.remote.target(),
     }
 }
 
@@ -55,6 +116,11 @@ fn update_monitor_error(
     new_error: Option<String>,
 ) {
     if &new_error == last_error {
+        // UI actions (e.g. service control) may have reported their own
+        // error; a successful refresh clears it.
+        if new_error.is_none() && error_store.read().is_some() {
+            *error_store.write() = None;
+        }
         return;
     }
 
@@ -88,6 +154,10 @@ pub fn spawn_monitor_tasks(
     service_error: Arc<RwLock<Option<String>>>,
     ollama_data: Arc<RwLock<Option<OllamaData>>>,
     ollama_error: Arc<RwLock<Option<String>>>,
+    system_info: Arc<RwLock<Option<SystemInfo>>>,
+    updated: UpdateTimes,
+    paused: Arc<AtomicBool>,
+    refresh_scale: Arc<RefreshScale>,
 ) {
     let config_snapshot = config.read().clone();
     let ps_executable = config_snapshot.powershell.executable.clone();
@@ -119,7 +189,10 @@ pub fn spawn_monitor_tasks(
     // CPU monitor task
     {
         let config = Arc::clone(&config);
+        let paused = Arc::clone(&paused);
+        let refresh_scale = Arc::clone(&refresh_scale);
         let cpu_data = Arc::clone(&cpu_data);
+        let updated = Arc::clone(&updated);
         let cpu_error = Arc::clone(&cpu_error);
         let ps_available = powershell_ready || cfg!(target_os = "linux");
         let unavailable_reason = ps_unavailable_reason.clone();
@@ -127,10 +200,18 @@ pub fn spawn_monitor_tasks(
             let mut monitor: Option<CpuMonitor> = None;
             let mut last_settings: Option<PsSettings> = None;
             let mut last_cache_ttl: Option<u64> = None;
+            let mut usage_history = std::collections::VecDeque::new();
             let mut last_error: Option<String> = None;
 
             loop {
-                let (enabled, refresh_interval_ms, settings, cache_ttl_config, use_cache_config) = {
+                let (
+                    enabled,
+                    refresh_interval_ms,
+                    settings,
+                    cache_ttl_config,
+                    use_cache_config,
+                    history_len,
+                ) = {
                     let cfg = config.read();
                     (
                         cfg.monitors.cpu.enabled,
@@ -138,10 +219,17 @@ pub fn spawn_monitor_tasks(
                         build_ps_settings(&cfg, cfg.monitors.cpu.refresh_interval_ms),
                         cfg.powershell.cache_ttl_seconds,
                         cfg.powershell.use_cache,
+                        cfg.general.history_len(),
                     )
                 };
 
+                if paused.load(Ordering::Relaxed) {
+                    refresh_scale.sleep(refresh_interval_ms).await;
+                    continue;
+                }
+
                 if !enabled {
+                    usage_history.clear();
                     *cpu_data.write() = None;
                     update_monitor_error(
                         "CPU",
@@ -149,7 +237,7 @@ pub fn spawn_monitor_tasks(
                         &cpu_error,
                         Some("CPU monitor disabled in config".to_string()),
                     );
-                    sleep(refresh_duration(refresh_interval_ms)).await;
+                    refresh_scale.sleep(refresh_interval_ms).await;
                     continue;
                 }
 
@@ -158,7 +246,7 @@ pub fn spawn_monitor_tasks(
                         .clone()
                         .unwrap_or_else(|| "PowerShell is required for CPU monitor".to_string());
                     update_monitor_error("CPU", &mut last_error, &cpu_error, Some(message));
-                    sleep(refresh_duration(refresh_interval_ms)).await;
+                    refresh_scale.sleep(refresh_interval_ms).await;
                     continue;
                 }
 
@@ -178,7 +266,10 @@ pub fn spawn_monitor_tasks(
                         settings.timeout_seconds,
                         settings.cache_ttl_seconds,
                         settings.use_cache,
-                    );
+                    )
+                    .with_persistent_session(settings.persistent_session)
+                    .with_max_concurrent(settings.max_concurrent)
+                    .with_remote(settings.remote.clone());
                     match CpuMonitor::new(ps) {
                         Ok(m) => {
                             monitor = Some(m);
@@ -191,7 +282,7 @@ pub fn spawn_monitor_tasks(
                                 &cpu_error,
                                 Some(e.to_string()),
                             );
-                            sleep(refresh_duration(refresh_interval_ms)).await;
+                            refresh_scale.sleep(refresh_interval_ms).await;
                             continue;
                         }
                     }
@@ -199,8 +290,16 @@ pub fn spawn_monitor_tasks(
 
                 if let Some(ref mut monitor) = monitor {
                     match monitor.collect_data().await {
-                        Ok(data) => {
+                        Ok(mut data) => {
+                            usage_history.push_back(data.overall_usage);
+                            while usage_history.len() > history_len {
+                                usage_history.pop_front();
+                            }
+                            data.usage_history = usage_history.clone();
+
                             *cpu_data.write() = Some(data);
+
+                            updated.write().insert(TabType::Cpu, Local::now());
                             update_monitor_error("CPU", &mut last_error, &cpu_error, None);
                         }
                         Err(e) => {
@@ -214,7 +313,7 @@ pub fn spawn_monitor_tasks(
                     }
                 }
 
-                sleep(refresh_duration(refresh_interval_ms)).await;
+                refresh_scale.sleep(refresh_interval_ms).await;
             }
         });
     }
@@ -222,18 +321,30 @@ pub fn spawn_monitor_tasks(
     // GPU monitor task
     {
         let config = Arc::clone(&config);
+        let paused = Arc::clone(&paused);
+        let refresh_scale = Arc::clone(&refresh_scale);
         let gpu_data = Arc::clone(&gpu_data);
+        let updated = Arc::clone(&updated);
         let gpu_error = Arc::clone(&gpu_error);
         let ps_available = powershell_ready || cfg!(target_os = "linux");
         let unavailable_reason = ps_unavailable_reason.clone();
         tokio::spawn(async move {
             let mut monitor: Option<GpuMonitor> = None;
             let mut last_settings: Option<PsSettings> = None;
+            let mut last_use_nvml: Option<bool> = None;
             let mut last_cache_ttl: Option<u64> = None;
             let mut last_error: Option<String> = None;
 
             loop {
-                let (enabled, refresh_interval_ms, settings, cache_ttl_config, use_cache_config) = {
+                let (
+                    enabled,
+                    refresh_interval_ms,
+                    settings,
+                    cache_ttl_config,
+                    use_cache_config,
+                    use_nvml,
+                    sources,
+                ) = {
                     let cfg = config.read();
                     (
                         cfg.monitors.gpu.enabled,
@@ -241,9 +352,16 @@ pub fn spawn_monitor_tasks(
                         build_ps_settings(&cfg, cfg.monitors.gpu.refresh_interval_ms),
                         cfg.powershell.cache_ttl_seconds,
                         cfg.powershell.use_cache,
+                        cfg.monitors.gpu.use_nvml,
+                        cfg.monitors.gpu.sources(),
                     )
                 };
 
+                if paused.load(Ordering::Relaxed) {
+                    refresh_scale.sleep(refresh_interval_ms).await;
+                    continue;
+                }
+
                 if !enabled {
                     *gpu_data.write() = None;
                     update_monitor_error(
@@ -252,7 +370,7 @@ pub fn spawn_monitor_tasks(
                         &gpu_error,
                         Some("GPU monitor disabled in config".to_string()),
                     );
-                    sleep(refresh_duration(refresh_interval_ms)).await;
+                    refresh_scale.sleep(refresh_interval_ms).await;
                     continue;
                 }
 
@@ -261,11 +379,12 @@ pub fn spawn_monitor_tasks(
                         .clone()
                         .unwrap_or_else(|| "PowerShell is required for GPU monitor".to_string());
                     update_monitor_error("GPU", &mut last_error, &gpu_error, Some(message));
-                    sleep(refresh_duration(refresh_interval_ms)).await;
+                    refresh_scale.sleep(refresh_interval_ms).await;
                     continue;
                 }
 
-                if last_settings.as_ref() != Some(&settings) {
+                // Rebuilding re-initializes NVML, so only do it when needed
+                if last_settings.as_ref() != Some(&settings) || last_use_nvml != Some(use_nvml) {
                     if use_cache_config && settings.cache_ttl_seconds < cache_ttl_config {
                         if last_cache_ttl != Some(settings.cache_ttl_seconds) {
                             log::info!(
@@ -281,11 +400,15 @@ pub fn spawn_monitor_tasks(
                         settings.timeout_seconds,
                         settings.cache_ttl_seconds,
                         settings.use_cache,
-                    );
-                    match GpuMonitor::new(ps) {
+                    )
+                    .with_persistent_session(settings.persistent_session)
+                    .with_max_concurrent(settings.max_concurrent)
+                    .with_remote(settings.remote.clone());
+                    match GpuMonitor::new(ps, use_nvml) {
                         Ok(m) => {
                             monitor = Some(m);
                             last_settings = Some(settings);
+                            last_use_nvml = Some(use_nvml);
                         }
                         Err(e) => {
                             update_monitor_error(
@@ -294,16 +417,17 @@ pub fn spawn_monitor_tasks(
                                 &gpu_error,
                                 Some(e.to_string()),
                             );
-                            sleep(refresh_duration(refresh_interval_ms)).await;
+                            refresh_scale.sleep(refresh_interval_ms).await;
                             continue;
                         }
                     }
                 }
 
                 if let Some(ref mut monitor) = monitor {
-                    match monitor.collect_data().await {
+                    match monitor.collect_data(&sources).await {
                         Ok(data) => {
                             *gpu_data.write() = Some(data);
+                            updated.write().insert(TabType::Gpu, Local::now());
                             update_monitor_error("GPU", &mut last_error, &gpu_error, None);
                         }
                         Err(e) => {
@@ -317,7 +441,7 @@ pub fn spawn_monitor_tasks(
                     }
                 }
 
-                sleep(refresh_duration(refresh_interval_ms)).await;
+                refresh_scale.sleep(refresh_interval_ms).await;
             }
         });
     }
@@ -325,7 +449,10 @@ pub fn spawn_monitor_tasks(
     // RAM monitor task
     {
         let config = Arc::clone(&config);
+        let paused = Arc::clone(&paused);
+        let refresh_scale = Arc::clone(&refresh_scale);
         let ram_data = Arc::clone(&ram_data);
+        let updated = Arc::clone(&updated);
         let ram_error = Arc::clone(&ram_error);
         let ps_available = powershell_ready || cfg!(target_os = "linux");
         let unavailable_reason = ps_unavailable_reason.clone();
@@ -333,10 +460,20 @@ pub fn spawn_monitor_tasks(
             let mut monitor: Option<RamMonitor> = None;
             let mut last_settings: Option<PsSettings> = None;
             let mut last_cache_ttl: Option<u64> = None;
+            let mut usage_history = std::collections::VecDeque::new();
+            let mut commit_history = std::collections::VecDeque::new();
+            let mut pagefile_history = std::collections::VecDeque::new();
             let mut last_error: Option<String> = None;
 
             loop {
-                let (enabled, refresh_interval_ms, settings, cache_ttl_config, use_cache_config) = {
+                let (
+                    enabled,
+                    refresh_interval_ms,
+                    settings,
+                    cache_ttl_config,
+                    use_cache_config,
+                    history_len,
+                ) = {
                     let cfg = config.read();
                     (
                         cfg.monitors.ram.enabled,
@@ -344,10 +481,19 @@ pub fn spawn_monitor_tasks(
                         build_ps_settings(&cfg, cfg.monitors.ram.refresh_interval_ms),
                         cfg.powershell.cache_ttl_seconds,
                         cfg.powershell.use_cache,
+                        cfg.general.history_len(),
                     )
                 };
 
+                if paused.load(Ordering::Relaxed) {
+                    refresh_scale.sleep(refresh_interval_ms).await;
+                    continue;
+                }
+
                 if !enabled {
+                    usage_history.clear();
+                    commit_history.clear();
+                    pagefile_history.clear();
                     *ram_data.write() = None;
                     update_monitor_error(
                         "RAM",
@@ -355,7 +501,7 @@ pub fn spawn_monitor_tasks(
                         &ram_error,
                         Some("RAM monitor disabled in config".to_string()),
                     );
-                    sleep(refresh_duration(refresh_interval_ms)).await;
+                    refresh_scale.sleep(refresh_interval_ms).await;
                     continue;
                 }
 
@@ -364,7 +510,7 @@ pub fn spawn_monitor_tasks(
                         .clone()
                         .unwrap_or_else(|| "PowerShell is required for RAM monitor".to_string());
                     update_monitor_error("RAM", &mut last_error, &ram_error, Some(message));
-                    sleep(refresh_duration(refresh_interval_ms)).await;
+                    refresh_scale.sleep(refresh_interval_ms).await;
                     continue;
                 }
 
@@ -384,7 +530,10 @@ pub fn spawn_monitor_tasks(
                         settings.timeout_seconds,
                         settings.cache_ttl_seconds,
                         settings.use_cache,
-                    );
+                    )
+                    .with_persistent_session(settings.persistent_session)
+                    .with_max_concurrent(settings.max_concurrent)
+                    .with_remote(settings.remote.clone());
                     match RamMonitor::new(ps) {
                         Ok(m) => {
                             monitor = Some(m);
@@ -397,7 +546,7 @@ pub fn spawn_monitor_tasks(
                                 &ram_error,
                                 Some(e.to_string()),
                             );
-                            sleep(refresh_duration(refresh_interval_ms)).await;
+                            refresh_scale.sleep(refresh_interval_ms).await;
                             continue;
                         }
                     }
@@ -405,8 +554,36 @@ pub fn spawn_monitor_tasks(
 
                 if let Some(ref mut monitor) = monitor {
                     match monitor.collect_data().await {
-                        Ok(data) => {
+                        Ok(mut data) => {
+                            let usage = if data.total > 0 {
+                                data.used as f64 / data.total as f64 * 100.0
+                            } else {
+                                0.0
+                            };
+                            let pagefile = if data.total_pagefile_size > 0 {
+                                data.total_pagefile_used as f64
+                                    / data.total_pagefile_size as f64
+                                    * 100.0
+                            } else {
+                                0.0
+                            };
+                            for (history, value) in [
+                                (&mut usage_history, usage),
+                                (&mut commit_history, data.commit_percent),
+                                (&mut pagefile_history, pagefile),
+                            ] {
+                                history.push_back(value);
+                                while history.len() > history_len {
+                                    history.pop_front();
+                                }
+                            }
+                            data.usage_history = usage_history.clone();
+                            data.commit_history = commit_history.clone();
+                            data.pagefile_history = pagefile_history.clone();
+
                             *ram_data.write() = Some(data);
+
+                            updated.write().insert(TabType::Ram, Local::now());
                             update_monitor_error("RAM", &mut last_error, &ram_error, None);
                         }
                         Err(e) => {
@@ -420,7 +597,7 @@ pub fn spawn_monitor_tasks(
                     }
                 }
 
-                sleep(refresh_duration(refresh_interval_ms)).await;
+                refresh_scale.sleep(refresh_interval_ms).await;
             }
         });
     }
@@ -428,7 +605,10 @@ pub fn spawn_monitor_tasks(
     // Disk monitor task
     {
         let config = Arc::clone(&config);
+        let paused = Arc::clone(&paused);
+        let refresh_scale = Arc::clone(&refresh_scale);
         let disk_data = Arc::clone(&disk_data);
+        let updated = Arc::clone(&updated);
         let disk_error = Arc::clone(&disk_error);
         let ps_available = powershell_ready || cfg!(target_os = "linux");
         let unavailable_reason = ps_unavailable_reason.clone();
@@ -450,6 +630,11 @@ pub fn spawn_monitor_tasks(
                     )
                 };
 
+                if paused.load(Ordering::Relaxed) {
+                    refresh_scale.sleep(refresh_interval_ms).await;
+                    continue;
+                }
+
                 if !enabled {
                     *disk_data.write() = None;
                     update_monitor_error(
@@ -458,7 +643,7 @@ pub fn spawn_monitor_tasks(
                         &disk_error,
                         Some("Disk monitor disabled in config".to_string()),
                     );
-                    sleep(refresh_duration(refresh_interval_ms)).await;
+                    refresh_scale.sleep(refresh_interval_ms).await;
                     continue;
                 }
 
@@ -467,7 +652,7 @@ pub fn spawn_monitor_tasks(
                         .clone()
                         .unwrap_or_else(|| "PowerShell is required for disk monitor".to_string());
                     update_monitor_error("Disk", &mut last_error, &disk_error, Some(message));
-                    sleep(refresh_duration(refresh_interval_ms)).await;
+                    refresh_scale.sleep(refresh_interval_ms).await;
                     continue;
                 }
 
@@ -487,7 +672,10 @@ pub fn spawn_monitor_tasks(
                         settings.timeout_seconds,
                         settings.cache_ttl_seconds,
                         settings.use_cache,
-                    );
+                    )
+                    .with_persistent_session(settings.persistent_session)
+                    .with_max_concurrent(settings.max_concurrent)
+                    .with_remote(settings.remote.clone());
                     match DiskMonitor::new(ps) {
                         Ok(m) => {
                             monitor = Some(m);
@@ -500,16 +688,18 @@ pub fn spawn_monitor_tasks(
                                 &disk_error,
                                 Some(e.to_string()),
                             );
-                            sleep(refresh_duration(refresh_interval_ms)).await;
+                            refresh_scale.sleep(refresh_interval_ms).await;
                             continue;
                         }
                     }
                 }
 
                 if let Some(ref mut monitor) = monitor {
+                    monitor.set_history_len(config.read().general.history_len());
                     match monitor.collect_data().await {
                         Ok(data) => {
                             *disk_data.write() = Some(data);
+                            updated.write().insert(TabType::Disk, Local::now());
                             update_monitor_error("Disk", &mut last_error, &disk_error, None);
                         }
                         Err(e) => {
@@ -523,7 +713,7 @@ pub fn spawn_monitor_tasks(
                     }
                 }
 
-                sleep(refresh_duration(refresh_interval_ms)).await;
+                refresh_scale.sleep(refresh_interval_ms).await;
             }
         });
     }
@@ -531,7 +721,10 @@ pub fn spawn_monitor_tasks(
     // Disk analyzer monitor task
     {
         let config = Arc::clone(&config);
+        let paused = Arc::clone(&paused);
+        let refresh_scale = Arc::clone(&refresh_scale);
         let disk_analyzer_data = Arc::clone(&disk_analyzer_data);
+        let updated = Arc::clone(&updated);
         let disk_analyzer_error = Arc::clone(&disk_analyzer_error);
         let ps_available = powershell_ready || cfg!(target_os = "linux");
         let unavailable_reason = ps_unavailable_reason.clone();
@@ -563,6 +756,11 @@ pub fn spawn_monitor_tasks(
                     )
                 };
 
+                if paused.load(Ordering::Relaxed) {
+                    refresh_scale.sleep(refresh_interval_ms).await;
+                    continue;
+                }
+
                 if !enabled {
                     *disk_analyzer_data.write() = None;
                     update_monitor_error(
@@ -571,7 +769,7 @@ pub fn spawn_monitor_tasks(
                         &disk_analyzer_error,
                         Some("Everything integration disabled in config".to_string()),
                     );
-                    sleep(refresh_duration(refresh_interval_ms)).await;
+                    refresh_scale.sleep(refresh_interval_ms).await;
                     continue;
                 }
 
@@ -585,7 +783,7 @@ pub fn spawn_monitor_tasks(
                         &disk_analyzer_error,
                         Some(message),
                     );
-                    sleep(refresh_duration(refresh_interval_ms)).await;
+                    refresh_scale.sleep(refresh_interval_ms).await;
                     continue;
                 }
 
@@ -606,7 +804,10 @@ pub fn spawn_monitor_tasks(
                         settings.timeout_seconds,
                         settings.cache_ttl_seconds,
                         settings.use_cache,
-                    );
+                    )
+                    .with_persistent_session(settings.persistent_session)
+                    .with_max_concurrent(settings.max_concurrent)
+                    .with_remote(settings.remote.clone());
                     match DiskAnalyzerMonitor::new(
                         ps,
                         es_executable.clone(),
@@ -624,7 +825,7 @@ pub fn spawn_monitor_tasks(
                                 &disk_analyzer_error,
                                 Some(e.to_string()),
                             );
-                            sleep(refresh_duration(refresh_interval_ms)).await;
+                            refresh_scale.sleep(refresh_interval_ms).await;
                             continue;
                         }
                     }
@@ -634,6 +835,7 @@ pub fn spawn_monitor_tasks(
                     match monitor.collect_data().await {
                         Ok(data) => {
                             *disk_analyzer_data.write() = Some(data);
+                            updated.write().insert(TabType::DiskAnalyzer, Local::now());
                             update_monitor_error(
                                 "Disk Analyzer",
                                 &mut last_error,
@@ -652,7 +854,7 @@ pub fn spawn_monitor_tasks(
                     }
                 }
 
-                sleep(refresh_duration(refresh_interval_ms)).await;
+                refresh_scale.sleep(refresh_interval_ms).await;
             }
         });
     }
@@ -660,7 +862,10 @@ pub fn spawn_monitor_tasks(
     // Network monitor task
     {
         let config = Arc::clone(&config);
+        let paused = Arc::clone(&paused);
+        let refresh_scale = Arc::clone(&refresh_scale);
         let network_data = Arc::clone(&network_data);
+        let updated = Arc::clone(&updated);
         let network_error = Arc::clone(&network_error);
         let ps_available = powershell_ready || cfg!(target_os = "linux");
         let unavailable_reason = ps_unavailable_reason.clone();
@@ -668,7 +873,6 @@ pub fn spawn_monitor_tasks(
             let mut monitor: Option<NetworkMonitor> = None;
             let mut last_settings: Option<PsSettings> = None;
             let mut last_cache_ttl: Option<u64> = None;
-            let mut traffic_history = std::collections::VecDeque::with_capacity(60);
             let mut last_error: Option<String> = None;
 
             loop {
@@ -683,8 +887,15 @@ pub fn spawn_monitor_tasks(
                     )
                 };
 
+                if paused.load(Ordering::Relaxed) {
+                    refresh_scale.sleep(refresh_interval_ms).await;
+                    continue;
+                }
+
                 if !enabled {
-                    traffic_history.clear();
+                    if let Some(monitor) = monitor.as_mut() {
+                        monitor.clear_traffic_history();
+                    }
                     *network_data.write() = None;
                     update_monitor_error(
                         "Network",
@@ -692,7 +903,7 @@ pub fn spawn_monitor_tasks(
                         &network_error,
                         Some("Network monitor disabled in config".to_string()),
                     );
-                    sleep(refresh_duration(refresh_interval_ms)).await;
+                    refresh_scale.sleep(refresh_interval_ms).await;
                     continue;
                 }
 
@@ -701,7 +912,7 @@ pub fn spawn_monitor_tasks(
                         .clone()
                         .unwrap_or_else(|| "PowerShell is required for network monitor".to_string());
                     update_monitor_error("Network", &mut last_error, &network_error, Some(message));
-                    sleep(refresh_duration(refresh_interval_ms)).await;
+                    refresh_scale.sleep(refresh_interval_ms).await;
                     continue;
                 }
 
@@ -721,9 +932,16 @@ pub fn spawn_monitor_tasks(
                         settings.timeout_seconds,
                         settings.cache_ttl_seconds,
                         settings.use_cache,
-                    );
+                    )
+                    .with_persistent_session(settings.persistent_session)
+                    .with_max_concurrent(settings.max_concurrent)
+                    .with_remote(settings.remote.clone());
                     match NetworkMonitor::new(ps) {
-                        Ok(m) => {
+                        Ok(mut m) => {
+                            // Keep the graphs across settings changes
+                            if let Some(previous) = monitor.take() {
+                                m.take_traffic_history(previous);
+                            }
                             monitor = Some(m);
                             last_settings = Some(settings);
                         }
@@ -734,27 +952,24 @@ pub fn spawn_monitor_tasks(
                                 &network_error,
                                 Some(e.to_string()),
                             );
-                            sleep(refresh_duration(refresh_interval_ms)).await;
+                            refresh_scale.sleep(refresh_interval_ms).await;
                             continue;
                         }
                     }
                 }
 
                 if let Some(monitor) = monitor.as_mut() {
-                    if let Ok(mut data) = monitor.collect_data().await {
-                        if !data.traffic_history.is_empty() {
-                            for sample in data.traffic_history.iter() {
-                                traffic_history.push_back(sample.clone());
-                            }
-                        }
-
-                        while traffic_history.len() > 60 {
-                            traffic_history.pop_front();
-                        }
-
-                        data.traffic_history = traffic_history.clone();
-
+                    {
+                        let config = config.read();
+                        monitor.set_ping_targets(&config.monitors.network.ping_targets);
+                        monitor.set_interface_filter(&config.monitors.network.interface_filter);
+                        monitor.set_show_connections(config.monitors.network.show_connections);
+                        monitor.set_history_len(config.general.history_len());
+                    }
+                    if let Ok(data) = monitor.collect_data().await {
                         *network_data.write() = Some(data);
+
+                        updated.write().insert(TabType::Network, Local::now());
                         update_monitor_error("Network", &mut last_error, &network_error, None);
                     } else {
                         update_monitor_error(
@@ -766,7 +981,7 @@ pub fn spawn_monitor_tasks(
                     }
                 }
 
-                sleep(refresh_duration(refresh_interval_ms)).await;
+                refresh_scale.sleep(refresh_interval_ms).await;
             }
         });
     }
@@ -774,7 +989,10 @@ pub fn spawn_monitor_tasks(
     // Process monitor task
     {
         let config = Arc::clone(&config);
+        let paused = Arc::clone(&paused);
+        let refresh_scale = Arc::clone(&refresh_scale);
         let process_data = Arc::clone(&process_data);
+        let updated = Arc::clone(&updated);
         let process_error = Arc::clone(&process_error);
         let ps_available = powershell_ready || cfg!(target_os = "linux");
         let unavailable_reason = ps_unavailable_reason.clone();
@@ -796,6 +1014,11 @@ pub fn spawn_monitor_tasks(
                     )
                 };
 
+                if paused.load(Ordering::Relaxed) {
+                    refresh_scale.sleep(refresh_interval_ms).await;
+                    continue;
+                }
+
                 if !enabled {
                     *process_data.write() = None;
                     update_monitor_error(
@@ -804,7 +1027,7 @@ pub fn spawn_monitor_tasks(
                         &process_error,
                         Some("Process monitor disabled in config".to_string()),
                     );
-                    sleep(refresh_duration(refresh_interval_ms)).await;
+                    refresh_scale.sleep(refresh_interval_ms).await;
                     continue;
                 }
 
@@ -813,7 +1036,7 @@ pub fn spawn_monitor_tasks(
                         .clone()
                         .unwrap_or_else(|| "PowerShell is required for process monitor".to_string());
                     update_monitor_error("Process", &mut last_error, &process_error, Some(message));
-                    sleep(refresh_duration(refresh_interval_ms)).await;
+                    refresh_scale.sleep(refresh_interval_ms).await;
                     continue;
                 }
 
@@ -833,7 +1056,10 @@ pub fn spawn_monitor_tasks(
                         settings.timeout_seconds,
                         settings.cache_ttl_seconds,
                         settings.use_cache,
-                    );
+                    )
+                    .with_persistent_session(settings.persistent_session)
+                    .with_max_concurrent(settings.max_concurrent)
+                    .with_remote(settings.remote.clone());
                     match ProcessMonitor::new(ps) {
                         Ok(m) => {
                             monitor = Some(m);
@@ -846,7 +1072,7 @@ pub fn spawn_monitor_tasks(
                                 &process_error,
                                 Some(e.to_string()),
                             );
-                            sleep(refresh_duration(refresh_interval_ms)).await;
+                            refresh_scale.sleep(refresh_interval_ms).await;
                             continue;
                         }
                     }
@@ -856,6 +1082,7 @@ pub fn spawn_monitor_tasks(
                     match monitor.collect_data().await {
                         Ok(data) => {
                             *process_data.write() = Some(data);
+                            updated.write().insert(TabType::Processes, Local::now());
                             update_monitor_error("Process", &mut last_error, &process_error, None);
                         }
                         Err(e) => {
@@ -869,7 +1096,7 @@ pub fn spawn_monitor_tasks(
                     }
                 }
 
-                sleep(refresh_duration(refresh_interval_ms)).await;
+                refresh_scale.sleep(refresh_interval_ms).await;
             }
         });
     }
@@ -877,7 +1104,10 @@ pub fn spawn_monitor_tasks(
     // Service monitor task
     {
         let config = Arc::clone(&config);
+        let paused = Arc::clone(&paused);
+        let refresh_scale = Arc::clone(&refresh_scale);
         let service_data = Arc::clone(&service_data);
+        let updated = Arc::clone(&updated);
         let service_error = Arc::clone(&service_error);
         let ps_available = powershell_ready || cfg!(target_os = "linux");
         let unavailable_reason = ps_unavailable_reason.clone();
@@ -899,6 +1129,11 @@ pub fn spawn_monitor_tasks(
                     )
                 };
 
+                if paused.load(Ordering::Relaxed) {
+                    refresh_scale.sleep(refresh_interval_ms).await;
+                    continue;
+                }
+
                 if !enabled {
                     *service_data.write() = None;
                     update_monitor_error(
@@ -907,7 +1142,7 @@ pub fn spawn_monitor_tasks(
                         &service_error,
                         Some("Service monitor disabled in config".to_string()),
                     );
-                    sleep(refresh_duration(refresh_interval_ms)).await;
+                    refresh_scale.sleep(refresh_interval_ms).await;
                     continue;
                 }
 
@@ -916,7 +1151,7 @@ pub fn spawn_monitor_tasks(
                         .clone()
                         .unwrap_or_else(|| "PowerShell is required for service monitor".to_string());
                     update_monitor_error("Service", &mut last_error, &service_error, Some(message));
-                    sleep(refresh_duration(refresh_interval_ms)).await;
+                    refresh_scale.sleep(refresh_interval_ms).await;
                     continue;
                 }
 
@@ -936,7 +1171,10 @@ pub fn spawn_monitor_tasks(
                         settings.timeout_seconds,
                         settings.cache_ttl_seconds,
                         settings.use_cache,
-                    );
+                    )
+                    .with_persistent_session(settings.persistent_session)
+                    .with_max_concurrent(settings.max_concurrent)
+                    .with_remote(settings.remote.clone());
                     match ServiceMonitor::new(ps) {
                         Ok(m) => {
                             monitor = Some(m);
@@ -949,7 +1187,7 @@ pub fn spawn_monitor_tasks(
                                 &service_error,
                                 Some(e.to_string()),
                             );
-                            sleep(refresh_duration(refresh_interval_ms)).await;
+                            refresh_scale.sleep(refresh_interval_ms).await;
                             continue;
                         }
                     }
@@ -959,6 +1197,7 @@ pub fn spawn_monitor_tasks(
                     match monitor.collect_data().await {
                         Ok(data) => {
                             *service_data.write() = Some(data);
+                            updated.write().insert(TabType::Services, Local::now());
                             update_monitor_error("Service", &mut last_error, &service_error, None);
                         }
                         Err(e) => {
@@ -972,7 +1211,7 @@ pub fn spawn_monitor_tasks(
                     }
                 }
 
-                sleep(refresh_duration(refresh_interval_ms)).await;
+                refresh_scale.sleep(refresh_interval_ms).await;
             }
         });
     }
@@ -980,7 +1219,10 @@ pub fn spawn_monitor_tasks(
     // Ollama monitor task
     {
         let config = Arc::clone(&config);
+        let paused = Arc::clone(&paused);
+        let refresh_scale = Arc::clone(&refresh_scale);
         let ollama_data = Arc::clone(&ollama_data);
+        let updated = Arc::clone(&updated);
         let ollama_error = Arc::clone(&ollama_error);
         tokio::spawn(async move {
             let mut client: Option<OllamaClient> = None;
@@ -994,6 +1236,11 @@ pub fn spawn_monitor_tasks(
                     )
                 };
 
+                if paused.load(Ordering::Relaxed) {
+                    refresh_scale.sleep(refresh_interval_ms).await;
+                    continue;
+                }
+
                 if !enabled {
                     client = None;
                     *ollama_data.write() = None;
@@ -1003,7 +1250,7 @@ pub fn spawn_monitor_tasks(
                         &ollama_error,
                         Some("Ollama integration disabled in config".to_string()),
                     );
-                    sleep(refresh_duration(refresh_interval_ms)).await;
+                    refresh_scale.sleep(refresh_interval_ms).await;
                     continue;
                 }
 
@@ -1017,7 +1264,7 @@ pub fn spawn_monitor_tasks(
                                 &ollama_error,
                                 Some(e.to_string()),
                             );
-                            sleep(refresh_duration(refresh_interval_ms)).await;
+                            refresh_scale.sleep(refresh_interval_ms).await;
                             continue;
                         }
                     }
@@ -1027,6 +1274,7 @@ pub fn spawn_monitor_tasks(
                     match client.collect_data().await {
                         Ok(data) => {
                             *ollama_data.write() = Some(data);
+                            updated.write().insert(TabType::Ollama, Local::now());
                             update_monitor_error("Ollama", &mut last_error, &ollama_error, None);
                         }
                         Err(e) => {
@@ -1039,7 +1287,53 @@ pub fn spawn_monitor_tasks(
                         }
                     }
                 }
-                sleep(refresh_duration(refresh_interval_ms)).await;
+                refresh_scale.sleep(refresh_interval_ms).await;
+            }
+        });
+    }
+
+    // Boot time for the header's uptime; it only moves on reboot, so this
+    // stays on a slow fixed interval and belongs to no tab
+    if powershell_ready || cfg!(target_os = "linux") {
+        let config = Arc::clone(&config);
+        let paused = Arc::clone(&paused);
+        tokio::spawn(async move {
+            let mut monitor: Option<SystemInfoMonitor> = None;
+            let mut last_settings: Option<PsSettings> = None;
+            loop {
+                let settings = {
+                    let cfg = config.read();
+                    build_ps_settings(&cfg, SYSTEM_INFO_INTERVAL.as_millis() as u64)
+                };
+
+                if paused.load(Ordering::Relaxed) {
+                    sleep(SYSTEM_INFO_INTERVAL).await;
+                    continue;
+                }
+
+                if last_settings.as_ref() != Some(&settings) {
+                    let ps = PowerShellExecutor::new(
+                        settings.executable.clone(),
+                        settings.timeout_seconds,
+                        settings.cache_ttl_seconds,
+                        settings.use_cache,
+                    )
+                    .with_max_concurrent(settings.max_concurrent)
+                    .with_remote(settings.remote.clone());
+                    monitor = SystemInfoMonitor::new(ps).ok();
+                    last_settings = Some(settings);
+                }
+
+                if let Some(monitor) = monitor.as_ref() {
+                    match monitor.collect_data().await {
+                        Ok(info) => *system_info.write() = Some(info),
+                        Err(e) => {
+                            log::debug!("System info unavailable: {}", e);
+                            *system_info.write() = None;
+                        }
+                    }
+                }
+                sleep(SYSTEM_INFO_INTERVAL).await;
             }
         });
     }
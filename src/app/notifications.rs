@@ -0,0 +1,78 @@
+use std::collections::VecDeque;
+use std::sync::mpsc::{Receiver, Sender};
+use std::time::{Duration, Instant};
+
+/// How long a toast stays on screen.
+pub const NOTIFICATION_TTL: Duration = Duration::from_secs(5);
+/// Toasts shown at once; older ones are dropped first.
+pub const MAX_NOTIFICATIONS: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub text: String,
+    pub level: NotificationLevel,
+    pub created_at: Instant,
+}
+
+impl Notification {
+    pub fn new(level: NotificationLevel, text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            level,
+            created_at: Instant::now(),
+        }
+    }
+
+    pub fn info(text: impl Into<String>) -> Self {
+        Self::new(NotificationLevel::Info, text)
+    }
+
+    pub fn error(text: impl Into<String>) -> Self {
+        Self::new(NotificationLevel::Error, text)
+    }
+}
+
+/// Sending half handed to spawned tasks and the config watcher thread.
+pub type NotificationSender = Sender<Notification>;
+
+/// Move queued toasts from background senders into `queue`, then drop expired
+/// ones and any beyond `MAX_NOTIFICATIONS`.
+pub fn drain(queue: &mut VecDeque<Notification>, rx: &Receiver<Notification>, now: Instant) {
+    queue.extend(rx.try_iter());
+    queue.retain(|n| now.saturating_duration_since(n.created_at) < NOTIFICATION_TTL);
+    while queue.len() > MAX_NOTIFICATIONS {
+        queue.pop_front();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::channel;
+
+    #[test]
+    fn drain_expires_and_caps_toasts() {
+        let (tx, rx) = channel();
+        let now = Instant::now();
+        let mut queue = VecDeque::new();
+        queue.push_back(Notification {
+            created_at: now - NOTIFICATION_TTL,
+            ..Notification::info("stale")
+        });
+        for i in 0..MAX_NOTIFICATIONS + 1 {
+            tx.send(Notification::info(format!("toast {i}"))).unwrap();
+        }
+
+        drain(&mut queue, &rx, now);
+
+        assert_eq!(queue.len(), MAX_NOTIFICATIONS);
+        assert_eq!(queue.front().unwrap().text, "toast 1");
+    }
+}
@@ -0,0 +1,373 @@
+use super::config::{MAX_HISTORY_SAMPLES, MIN_HISTORY_SAMPLES};
+use super::Config;
+
+/// How a setting is edited and validated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    /// Integer in `min..=max`; Left/Right move by `step`, Enter types a value
+    Number { min: i64, max: i64, step: i64 },
+    /// One of a fixed set of strings, cycled with Left/Right/Enter
+    Choice(&'static [&'static str]),
+    /// Whether a tab id is listed in `tabs.enabled`
+    TabEnabled(&'static str),
+}
+
+/// One editable row of the Settings tab, stored at `path` in `config.toml`.
+pub struct SettingField {
+    pub label: &'static str,
+    pub path: &'static [&'static str],
+    pub kind: FieldKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SettingValue {
+    Number(i64),
+    Text(String),
+    Toggle(bool),
+}
+
+impl SettingValue {
+    pub fn display(&self) -> String {
+        match self {
+            SettingValue::Number(value) => value.to_string(),
+            SettingValue::Text(value) => value.clone(),
+            SettingValue::Toggle(true) => "on".to_string(),
+            SettingValue::Toggle(false) => "off".to_string(),
+        }
+    }
+}
+
+const REFRESH: FieldKind = FieldKind::Number {
+    min: 100,
+    max: 60_000,
+    step: 250,
+};
+
+pub const FIELDS: &[SettingField] = &[
+    SettingField {
+        label: "CPU refresh (ms)",
+        path: &["monitors", "cpu", "refresh_interval_ms"],
+        kind: REFRESH,
+    },
+    SettingField {
+        label: "GPU refresh (ms)",
+        path: &["monitors", "gpu", "refresh_interval_ms"],
+        kind: REFRESH,
+    },
+    SettingField {
+        label: "RAM refresh (ms)",
+        path: &["monitors", "ram", "refresh_interval_ms"],
+        kind: REFRESH,
+    },
+    SettingField {
+        label: "Disk refresh (ms)",
+        path: &["monitors", "disk", "refresh_interval_ms"],
+        kind: REFRESH,
+    },
+    SettingField {
+        label: "Network refresh (ms)",
+        path: &["monitors", "network", "refresh_interval_ms"],
+        kind: REFRESH,
+    },
+    SettingField {
+        label: "Processes refresh (ms)",
+        path: &["monitors", "processes", "refresh_interval_ms"],
+        kind: REFRESH,
+    },
+    SettingField {
+        label: "Services refresh (ms)",
+        path: &["monitors", "services", "refresh_interval_ms"],
+        kind: REFRESH,
+    },
+    SettingField {
+        label: "Ollama refresh (ms)",
+        path: &["integrations", "ollama", "refresh_interval_ms"],
+        kind: REFRESH,
+    },
+    SettingField {
+        label: "Disk analyzer refresh (ms)",
+        path: &["integrations", "everything", "refresh_interval_ms"],
+        kind: REFRESH,
+    },
+    SettingField {
+        label: "Theme preset",
+        path: &["theme", "preset"],
+        kind: FieldKind::Choice(&["dark", "light", "nord"]),
+    },
+    SettingField {
+        label: "Byte units",
+        path: &["general", "byte_units"],
+        kind: FieldKind::Choice(&["jedec", "binary", "decimal"]),
+    },
+    SettingField {
+        label: "Decimal places",
+        path: &["general", "decimal_places"],
+        kind: FieldKind::Number {
+            min: 0,
+            max: 4,
+            step: 1,
+        },
+    },
+    SettingField {
+        label: "Network units",
+        path: &["general", "network_units"],
+        kind: FieldKind::Choice(&["bits", "bytes"]),
+    },
+    SettingField {
+        label: "Graph history (samples)",
+        path: &["general", "history_samples"],
+        kind: FieldKind::Number {
+            min: MIN_HISTORY_SAMPLES as i64,
+            max: MAX_HISTORY_SAMPLES as i64,
+            step: 30,
+        },
+    },
+    SettingField {
+        label: "Idle after (s, 0 = never)",
+        path: &["general", "idle_after_seconds"],
+        kind: FieldKind::Number {
+            min: 0,
+            max: 3600,
+            step: 60,
+        },
+    },
+    SettingField {
+        label: "PowerShell timeout (s)",
+        path: &["powershell", "timeout_seconds"],
+        kind: FieldKind::Number {
+            min: 1,
+            max: 600,
+            step: 5,
+        },
+    },
+    SettingField {
+        label: "Overview tab",
+        path: &["tabs", "enabled"],
+        kind: FieldKind::TabEnabled("overview"),
+    },
+    SettingField {
+        label: "CPU tab",
+        path: &["tabs", "enabled"],
+        kind: FieldKind::TabEnabled("cpu"),
+    },
+    SettingField {
+        label: "GPU tab",
+        path: &["tabs", "enabled"],
+        kind: FieldKind::TabEnabled("gpu"),
+    },
+    SettingField {
+        label: "RAM tab",
+        path: &["tabs", "enabled"],
+        kind: FieldKind::TabEnabled("ram"),
+    },
+    SettingField {
+        label: "Disk tab",
+        path: &["tabs", "enabled"],
+        kind: FieldKind::TabEnabled("disk"),
+    },
+    SettingField {
+        label: "Disk Analyzer tab",
+        path: &["tabs", "enabled"],
+        kind: FieldKind::TabEnabled("disk_analyzer"),
+    },
+    SettingField {
+        label: "Network tab",
+        path: &["tabs", "enabled"],
+        kind: FieldKind::TabEnabled("network"),
+    },
+    SettingField {
+        label: "Ollama tab",
+        path: &["tabs", "enabled"],
+        kind: FieldKind::TabEnabled("ollama"),
+    },
+    SettingField {
+        label: "Processes tab",
+        path: &["tabs", "enabled"],
+        kind: FieldKind::TabEnabled("processes"),
+    },
+    SettingField {
+        label: "Services tab",
+        path: &["tabs", "enabled"],
+        kind: FieldKind::TabEnabled("services"),
+    },
+];
+
+impl SettingField {
+    pub fn value(&self, config: &Config) -> SettingValue {
+        if let FieldKind::TabEnabled(tab) = self.kind {
+            return SettingValue::Toggle(
+                config
+                    .tabs
+                    .enabled
+                    .iter()
+                    .any(|t| t.eq_ignore_ascii_case(tab)),
+            );
+        }
+
+        let mut value = toml::Value::try_from(config).ok();
+        for key in self.path {
+            value = value.and_then(|v| v.get(key).cloned());
+        }
+        match value {
+            Some(toml::Value::Integer(number)) => SettingValue::Number(number),
+            Some(toml::Value::String(text)) => SettingValue::Text(text),
+            Some(toml::Value::Boolean(flag)) => SettingValue::Toggle(flag),
+            _ => SettingValue::Text(String::new()),
+        }
+    }
+
+    /// The value after one Left (`forward == false`) or Right press.
+    pub fn step(&self, current: &SettingValue, forward: bool) -> SettingValue {
+        match (self.kind, current) {
+            (FieldKind::Number { min, max, step }, SettingValue::Number(value)) => {
+                let next = if forward { value + step } else { value - step };
+                SettingValue::Number(next.clamp(min, max))
+            }
+            (FieldKind::Choice(options), SettingValue::Text(value)) => {
+                let index = options.iter().position(|o| o == value).unwrap_or(0);
+                let next = if forward {
+                    (index + 1) % options.len()
+                } else {
+                    (index + options.len() - 1) % options.len()
+                };
+                SettingValue::Text(options[next].to_string())
+            }
+            (_, SettingValue::Toggle(flag)) => SettingValue::Toggle(!flag),
+            _ => current.clone(),
+        }
+    }
+
+    /// Validate text typed into a number field.
+    pub fn parse(&self, input: &str) -> Result<SettingValue, String> {
+        let FieldKind::Number { min, max, .. } = self.kind else {
+            return Err(format!("{} is not a number field", self.label));
+        };
+        let value: i64 = input
+            .trim()
+            .parse()
+            .map_err(|_| format!("'{}' is not a whole number", input.trim()))?;
+        if !(min..=max).contains(&value) {
+            return Err(format!("Must be between {} and {}", min, max));
+        }
+        Ok(SettingValue::Number(value))
+    }
+
+    /// The TOML value to store at `path`, or why `value` can't be applied.
+    pub fn to_toml(
+        &self,
+        value: &SettingValue,
+        config: &Config,
+    ) -> Result<toml_edit::Value, String> {
+        match (self.kind, value) {
+            (FieldKind::Number { min, max, .. }, SettingValue::Number(number)) => {
+                if !(min..=max).contains(number) {
+                    return Err(format!("Must be between {} and {}", min, max));
+                }
+                Ok((*number).into())
+            }
+            (FieldKind::Choice(options), SettingValue::Text(text)) => {
+                if !options.contains(&text.as_str()) {
+                    return Err(format!("Must be one of: {}", options.join(", ")));
+                }
+                Ok(text.as_str().into())
+            }
+            (FieldKind::TabEnabled(tab), SettingValue::Toggle(enabled)) => {
+                let mut tabs: Vec<String> = config
+                    .tabs
+                    .enabled
+                    .iter()
+                    .filter(|t| !t.eq_ignore_ascii_case(tab))
+                    .cloned()
+                    .collect();
+                if *enabled {
+                    tabs.push(tab.to_string());
+                } else if !tabs.iter().any(|t| t != "settings") {
+                    return Err("At least one monitor tab must stay enabled".to_string());
+                }
+                Ok(tabs
+                    .iter()
+                    .map(String::as_str)
+                    .collect::<toml_edit::Array>()
+                    .into())
+            }
+            _ => Err(format!("Invalid value for {}", self.label)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        toml::from_str(include_str!("../../config.toml")).unwrap()
+    }
+
+    #[test]
+    fn steps_and_validates_fields() {
+        let config = config();
+        let cpu_refresh = &FIELDS[0];
+        assert_eq!(cpu_refresh.value(&config), SettingValue::Number(1000));
+        assert_eq!(
+            cpu_refresh.step(&SettingValue::Number(200), false),
+            SettingValue::Number(100)
+        );
+        assert!(cpu_refresh.parse("50").is_err());
+        assert!(cpu_refresh.parse("abc").is_err());
+        assert_eq!(cpu_refresh.parse(" 1500 "), Ok(SettingValue::Number(1500)));
+
+        let theme = FIELDS
+            .iter()
+            .find(|f| f.path == ["theme", "preset"])
+            .unwrap();
+        assert_eq!(
+            theme.step(&SettingValue::Text("nord".to_string()), true),
+            SettingValue::Text("dark".to_string())
+        );
+
+        let gpu_tab = FIELDS
+            .iter()
+            .find(|f| f.kind == FieldKind::TabEnabled("gpu"))
+            .unwrap();
+        let enabled = gpu_tab
+            .to_toml(&SettingValue::Toggle(false), &config)
+            .unwrap();
+        let tabs: Vec<&str> = enabled
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|v| v.as_str())
+            .collect();
+        assert!(!tabs.contains(&"gpu"));
+        assert!(tabs.contains(&"cpu"));
+    }
+
+    #[test]
+    fn saving_changes_only_the_edited_value() {
+        let original = include_str!("../../config.toml");
+        let path =
+            std::env::temp_dir().join(format!("tui-plus-settings-{}.toml", std::process::id()));
+        std::fs::write(&path, original).unwrap();
+        let manager = super::super::ConfigManager::new(config(), path.clone());
+
+        manager
+            .set_value(&["powershell", "timeout_seconds"], 45.into())
+            .unwrap();
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(manager.get_config().read().powershell.timeout_seconds, 45);
+        let saved: Config = toml::from_str(&written).unwrap();
+        assert_eq!(saved.powershell.timeout_seconds, 45);
+        assert_eq!(saved.powershell.executable, config().powershell.executable);
+
+        // Every other line, comments included, is left as it was
+        assert_eq!(written.lines().count(), original.lines().count());
+        let changed = original
+            .lines()
+            .zip(written.lines())
+            .filter(|(before, after)| before != after)
+            .count();
+        assert_eq!(changed, 1);
+    }
+}
@@ -1,29 +1,66 @@
 use anyhow::Result;
 use chrono::Local;
 use crossterm::event::{
-    Event as CrosstermEvent, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent,
+    Event as CrosstermEvent, KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEvent,
     MouseEventKind,
 };
 use crossterm::terminal;
 use parking_lot::RwLock;
+use std::cell::Cell;
 use std::cmp::Ordering;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use std::sync::mpsc::{self, Receiver};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use super::{monitors_task, Config, TabManager, TabType};
+use super::alerts::{self, AlertMetrics, AlertState};
+use super::keybindings::{KeyAction, Keybindings};
+use super::monitors_task::{self, MonitorStatus, RefreshScale, UpdateTimes};
+use super::notifications::{self, Notification, NotificationLevel, NotificationSender};
+use super::settings::{self, FieldKind, SettingValue};
+use super::tabs::LAST_TAB_FILE;
+use super::{Config, ConfigManager, TabManager, TabType};
 use crate::integrations::{ChatLogMetadata, OllamaClient, OllamaData, PowerShellExecutor};
 use crate::integrations::ollama::{OllamaModel, RunningModel};
+use crate::integrations::remote::SshTarget;
+use crate::monitors::disk_analyzer::{
+    self, is_drive_root, Deletion, DuplicateGroup, DuplicateProgress, RootFolderInfo,
+};
+use crate::monitors::network::{AddressFamily, NetworkConnection, NetworkInterface};
+use crate::monitors::processes::{self, ProcessDetails, ProcessEntry};
+use crate::monitors::services::{self, ServiceEntry, ServiceStartType, ServiceStatus};
 use crate::monitors::{
-    CpuData, DiskAnalyzerData, DiskData, GpuData, NetworkData, ProcessData, RamData, ServiceData,
+    AnalyzedDrive, CpuData, DiskAnalyzerData, DiskData, GpuData, NetworkData, NetworkMonitor, ProcessData, ProcessMonitor,
+    RamData, ServiceData, ServiceMonitor, SystemInfo,
 };
-use crate::utils::command_history::CommandHistory;
+use crate::recorder::{RecordSources, Recorder};
+use crate::report;
+use crate::snapshot::Snapshot;
+use crate::ui::widgets::radial_menu::RadialMenu;
+use crate::utils::{clipboard, launcher};
+use crate::utils::log_buffer::{self, LogBuffer};
+use crate::utils::command_history::{CommandHistory, HISTORY_FILE};
+use crate::utils::format::FormatOptions;
+use crate::utils::is_elevated;
+use crate::utils::markdown::{self, ToMarkdown};
 use std::fs;
 
 pub struct AppState {
     pub config: Arc<RwLock<Config>>,
     pub tab_manager: TabManager,
+    /// Tab in the right-hand pane of the split view; `None` when not split
+    pub secondary_tab: Option<TabType>,
     pub compact_mode: bool,
+    pub keybindings: Keybindings,
+    /// Shared with the monitor tasks, which skip collecting while it is set
+    pub paused: Arc<AtomicBool>,
+    /// Shared with the monitor tasks; raised to `general.idle_factor` after
+    /// `general.idle_after_seconds` without input
+    refresh_scale: Arc<RefreshScale>,
+    last_input: Instant,
+    /// Set for the whole run by `--read-only` or `general.read_only`
+    pub read_only: bool,
 
     // Monitor data
     pub cpu_data: Arc<RwLock<Option<CpuData>>>,
@@ -46,11 +83,40 @@ pub struct AppState {
     // Ollama integration
     pub ollama_data: Arc<RwLock<Option<OllamaData>>>,
     pub ollama_error: Arc<RwLock<Option<String>>>,
+    /// Boot time for the header; `None` until read or when unavailable
+    pub system_info: Arc<RwLock<Option<SystemInfo>>>,
+    monitor_updates: UpdateTimes,
+    /// Running as administrator/root; checked once at startup
+    pub elevated: bool,
 
     // UI state
     pub command_menu_active: bool,
+    /// Radial tab switcher, open while `Some`
+    pub tab_menu: Option<RadialMenu>,
     pub command_history: CommandHistory,
     pub command_input: String,
+    /// Output of the last executed command and whether it succeeded.
+    pub command_output: Option<(String, bool)>,
+    pub command_output_scroll: usize,
+    pub help_visible: bool,
+    /// Monitor status and error console
+    pub errors_visible: bool,
+    /// Log viewer overlay, with how many records it is scrolled back from the newest
+    pub logs_visible: bool,
+    pub log_scroll: usize,
+    pub log_buffer: LogBuffer,
+    /// Digits typed into the jump-to-PID prompt, open while `Some`
+    pub pid_prompt: Option<String>,
+    /// Short-lived footer message such as "Copied!", with when it was set.
+    pub flash: Option<(String, Instant)>,
+    /// Recent toasts, oldest first
+    pub notifications: VecDeque<Notification>,
+    notification_tx: NotificationSender,
+    notification_rx: Receiver<Notification>,
+    pub alerts: AlertState,
+    #[cfg(feature = "metrics")]
+    _metrics_server: Option<crate::metrics::MetricsServer>,
+    pub recorder: Option<Recorder>,
     #[allow(dead_code)]
     pub selected_section: Option<String>,
     pub last_nav_input: Option<Instant>,
@@ -73,26 +139,324 @@ pub struct AppState {
     // Services UI state
     pub services_state: ServicesUIState,
 
+    // Disk UI state
+    pub cpu_state: CpuUIState,
+    pub disk_state: DiskUIState,
+
+    // Disk analyzer UI state
+    pub disk_analyzer_state: DiskAnalyzerUIState,
+
+    // Network UI state
+    pub network_state: NetworkUIState,
+    pub settings_state: SettingsUIState,
+    config_manager: Arc<ConfigManager>,
+    /// Config generation `keybindings` was built from
+    config_generation: u64,
+
     // Ollama UI state
     pub ollama_state: OllamaUIState,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub enum ProcessSortColumn {
+    Pid,
+    Name,
+    #[default]
+    Cpu,
+    Memory,
+    Threads,
+    User,
+}
+
+impl ProcessSortColumn {
+    /// Direction a column starts in when first selected: identifiers read
+    /// A-Z, usage columns put the heaviest processes on top.
+    fn default_ascending(self) -> bool {
+        matches!(self, Self::Pid | Self::Name | Self::User)
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "pid" => Some(Self::Pid),
+            "name" => Some(Self::Name),
+            "cpu" => Some(Self::Cpu),
+            "memory" | "mem" => Some(Self::Memory),
+            "threads" => Some(Self::Threads),
+            "user" => Some(Self::User),
+            _ => None,
+        }
+    }
+
+    pub fn config_name(self) -> &'static str {
+        match self {
+            Self::Pid => "pid",
+            Self::Name => "name",
+            Self::Cpu => "cpu",
+            Self::Memory => "memory",
+            Self::Threads => "threads",
+            Self::User => "user",
+        }
+    }
+
+    /// Column and direction from `monitors.processes.default_sort`; CPU
+    /// descending, whatever the direction, when the column is not recognized.
+    pub fn from_config(spec: &str) -> (Self, bool) {
+        let (name, ascending) = parse_sort_spec(spec);
+        match Self::from_name(name) {
+            Some(column) => (column, ascending.unwrap_or(column.default_ascending())),
+            None => (Self::Cpu, Self::Cpu.default_ascending()),
+        }
+    }
+}
+
+/// `"memory:desc"` -> column name and direction. No direction, or one that
+/// isn't asc/desc, leaves it to the column's default.
+fn parse_sort_spec(spec: &str) -> (&str, Option<bool>) {
+    let (name, direction) = spec.split_once(':').unwrap_or((spec, ""));
+    let ascending = match direction.trim().to_ascii_lowercase().as_str() {
+        "asc" | "ascending" => Some(true),
+        "desc" | "descending" => Some(false),
+        _ => None,
+    };
+    (name.trim(), ascending)
+}
+
+fn sort_spec(name: &str, ascending: bool) -> String {
+    format!("{}:{}", name, if ascending { "asc" } else { "desc" })
+}
+
+/// A column of the Processes table, as named in `monitors.processes.columns`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessColumn {
     Pid,
     Name,
     Cpu,
     Memory,
     Threads,
     User,
+    ParentPid,
+    Handles,
+    Started,
+    Command,
+}
+
+impl ProcessColumn {
+    pub const DEFAULT: [ProcessColumn; 6] = [
+        Self::Pid,
+        Self::Name,
+        Self::Cpu,
+        Self::Memory,
+        Self::Threads,
+        Self::User,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "pid" => Some(Self::Pid),
+            "name" => Some(Self::Name),
+            "cpu" => Some(Self::Cpu),
+            "memory" | "mem" => Some(Self::Memory),
+            "threads" => Some(Self::Threads),
+            "user" => Some(Self::User),
+            "ppid" | "parent" => Some(Self::ParentPid),
+            "handles" => Some(Self::Handles),
+            "started" | "start_time" => Some(Self::Started),
+            "command" | "command_line" => Some(Self::Command),
+            _ => None,
+        }
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Pid => "PID",
+            Self::Name => "Name",
+            Self::Cpu => "CPU%",
+            Self::Memory => "Memory",
+            Self::Threads => "Threads",
+            Self::User => "User",
+            Self::ParentPid => "PPID",
+            Self::Handles => "Handles",
+            Self::Started => "Started",
+            Self::Command => "Command",
+        }
+    }
+
+    /// The sort this column's header shows; extras can't be sorted on.
+    pub fn sort_column(self) -> Option<ProcessSortColumn> {
+        match self {
+            Self::Pid => Some(ProcessSortColumn::Pid),
+            Self::Name => Some(ProcessSortColumn::Name),
+            Self::Cpu => Some(ProcessSortColumn::Cpu),
+            Self::Memory => Some(ProcessSortColumn::Memory),
+            Self::Threads => Some(ProcessSortColumn::Threads),
+            Self::User => Some(ProcessSortColumn::User),
+            Self::ParentPid | Self::Handles | Self::Started | Self::Command => None,
+        }
+    }
+
+    /// Plain cell text for `row`. Collapsed tree parents show their subtree's
+    /// CPU and memory, matching the table.
+    pub(crate) fn cell_text(self, row: &ProcessRow, fmt: &FormatOptions) -> String {
+        let process = &row.process;
+        let (cpu, memory) = if row.collapsed {
+            (row.subtree_cpu, row.subtree_memory)
+        } else {
+            (process.cpu_usage, process.memory)
+        };
+        match self {
+            Self::Pid => process.pid.to_string(),
+            Self::Name => process.name.clone(),
+            Self::Cpu => format!("{:.1}", cpu),
+            Self::Memory => fmt.bytes(memory),
+            Self::Threads => process.threads.to_string(),
+            Self::User => process.user.clone(),
+            Self::ParentPid => process
+                .parent_pid
+                .map_or_else(|| "-".to_string(), |ppid| ppid.to_string()),
+            Self::Handles => process.handle_count.to_string(),
+            Self::Started => process.start_time.clone().unwrap_or_else(|| "-".to_string()),
+            Self::Command => process.command_line.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// The Processes table as shown: visible rows in the configured columns.
+pub(crate) struct ProcessTable<'a> {
+    pub rows: &'a [ProcessRow],
+    pub columns: &'a [ProcessColumn],
+    pub fmt: FormatOptions,
+}
+
+impl ToMarkdown for ProcessTable<'_> {
+    fn to_markdown(&self) -> String {
+        let headers: Vec<&str> = self.columns.iter().map(|column| column.title()).collect();
+        markdown::table(
+            &headers,
+            self.rows.iter().map(|row| {
+                self.columns
+                    .iter()
+                    .map(|column| column.cell_text(row, &self.fmt))
+                    .collect()
+            }),
+        )
+    }
+}
+
+/// One monitor's row in the error console.
+pub struct MonitorHealth {
+    pub tab: TabType,
+    pub status: MonitorStatus,
+    /// Why the monitor has no data, as its task last reported
+    pub error: Option<String>,
+    /// Time since it last stored data
+    pub age: Option<chrono::Duration>,
+}
+
+impl MonitorHealth {
+    pub fn state(&self) -> &'static str {
+        match (self.status, &self.error, self.age) {
+            (MonitorStatus::Disabled, _, _) => "disabled",
+            (_, Some(_), _) => "error",
+            (_, None, None) => "waiting",
+            (_, None, Some(_)) => "ok",
+        }
+    }
+
+    /// e.g. `12s ago`, or `never`
+    pub fn updated(&self) -> String {
+        match self.age.map(|age| age.num_seconds().max(0)) {
+            None => "never".to_string(),
+            Some(secs) if secs < 60 => format!("{}s ago", secs),
+            Some(secs) if secs < 3600 => format!("{}m ago", secs / 60),
+            Some(secs) => format!("{}h ago", secs / 3600),
+        }
+    }
+}
+
+impl ToMarkdown for [MonitorHealth] {
+    fn to_markdown(&self) -> String {
+        markdown::table(
+            &["Monitor", "Status", "Updated", "Last error"],
+            self.iter().map(|health| {
+                vec![
+                    health.tab.as_str().to_string(),
+                    health.state().to_string(),
+                    health.updated(),
+                    health.error.clone().unwrap_or_default(),
+                ]
+            }),
+        )
+    }
 }
 
+#[derive(Default)]
 pub struct ProcessesUIState {
     pub selected_index: usize,
     pub scroll_offset: usize,
     pub sort_column: ProcessSortColumn,
     pub sort_ascending: bool,
     pub filter: String,
+    pub filter_editing: bool,
+    pub tree_view: bool,
+    pub collapsed: HashSet<u32>,
+    pub pending_kill: Option<ProcessKillTarget>,
+    pub show_kill_confirm: bool,
+    pub action_status: Option<String>,
+    /// Table rows that fit on screen at the last render; 0 before the first.
+    pub last_visible_rows: Cell<usize>,
+    /// Pinned processes by PID and name, so a reused PID doesn't inherit the pin.
+    pub pinned: HashSet<(u32, String)>,
+    /// Detail popup for one process, open until Esc
+    pub detail: Option<ProcessDetailView>,
+}
+
+impl ProcessesUIState {
+    /// Select `pid` among `processes` and scroll it into view, clearing a
+    /// filter or expanding collapsed parents that hide it. False when no
+    /// such process is running.
+    pub fn select_pid(&mut self, processes: &[ProcessEntry], pid: u32) -> bool {
+        let Some(process) = processes.iter().find(|p| p.pid == pid) else {
+            return false;
+        };
+        if !visible_processes(processes, self).iter().any(|p| p.pid == pid) {
+            self.filter.clear();
+            self.filter_editing = false;
+        }
+        let mut parent = process.parent_pid;
+        for _ in 0..processes.len() {
+            let Some(ppid) = parent else {
+                break;
+            };
+            self.collapsed.remove(&ppid);
+            parent = processes
+                .iter()
+                .find(|p| p.pid == ppid)
+                .and_then(|p| p.parent_pid);
+        }
+
+        let Some(index) = process_rows(processes, self)
+            .iter()
+            .position(|row| row.process.pid == pid)
+        else {
+            return false;
+        };
+        self.selected_index = index;
+        self.scroll_offset = index;
+        true
+    }
+}
+
+pub struct ProcessDetailView {
+    /// The row as it was when the popup opened
+    pub process: ProcessEntry,
+    /// Filled in by the lookup task; `Err` holds the message to show
+    pub details: Arc<RwLock<Option<Result<ProcessDetails, String>>>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProcessKillTarget {
+    pub pid: u32,
+    pub name: String,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -103,6 +467,42 @@ pub enum ServiceSortColumn {
     StartType,
 }
 
+impl ServiceSortColumn {
+    /// Every service column is text, so each starts A-Z.
+    fn default_ascending(self) -> bool {
+        true
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "name" => Some(Self::Name),
+            "display_name" | "display" => Some(Self::DisplayName),
+            "status" => Some(Self::Status),
+            "start_type" | "startup" => Some(Self::StartType),
+            _ => None,
+        }
+    }
+
+    pub fn config_name(self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::DisplayName => "display_name",
+            Self::Status => "status",
+            Self::StartType => "start_type",
+        }
+    }
+
+    /// Column and direction from `monitors.services.default_sort`; name A-Z,
+    /// whatever the direction, when the column is not recognized.
+    pub fn from_config(spec: &str) -> (Self, bool) {
+        let (name, ascending) = parse_sort_spec(spec);
+        match Self::from_name(name) {
+            Some(column) => (column, ascending.unwrap_or(column.default_ascending())),
+            None => (Self::Name, Self::Name.default_ascending()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ServiceStatusFilter {
     All,
@@ -110,6 +510,57 @@ pub enum ServiceStatusFilter {
     Stopped,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionFilter {
+    Established,
+    Listen,
+    All,
+}
+
+impl ConnectionFilter {
+    pub fn from_config(value: &str) -> Self {
+        match value.to_lowercase().as_str() {
+            "listen" | "listening" => ConnectionFilter::Listen,
+            "all" => ConnectionFilter::All,
+            _ => ConnectionFilter::Established,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConnectionFilter::Established => "Established",
+            ConnectionFilter::Listen => "Listen",
+            ConnectionFilter::All => "All",
+        }
+    }
+}
+
+/// Which IP versions the connections table lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressFamilyFilter {
+    All,
+    IPv4,
+    IPv6,
+}
+
+impl AddressFamilyFilter {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AddressFamilyFilter::All => "IPv4+6",
+            AddressFamilyFilter::IPv4 => "IPv4",
+            AddressFamilyFilter::IPv6 => "IPv6",
+        }
+    }
+
+    fn matches(&self, family: AddressFamily) -> bool {
+        match self {
+            AddressFamilyFilter::All => true,
+            AddressFamilyFilter::IPv4 => family == AddressFamily::IPv4,
+            AddressFamilyFilter::IPv6 => family == AddressFamily::IPv6,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum GpuProcessSortColumn {
     Pid,
@@ -123,6 +574,8 @@ pub struct GpuUIState {
     pub selected_index: usize,
     pub sort_column: GpuProcessSortColumn,
     pub sort_ascending: bool,
+    pub selected_device: usize,
+    pub collapsed_devices: HashSet<usize>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -158,8 +611,159 @@ pub struct ServicesUIState {
     pub sort_column: ServiceSortColumn,
     pub sort_ascending: bool,
     pub status_filter: ServiceStatusFilter,
+    /// Substring matched against name and display name
+    pub filter: String,
+    pub filter_editing: bool,
     pub focused_panel: ServicesPanelFocus,
     pub details_scroll: usize,
+    /// Table rows that fit on screen at the last render; 0 before the first.
+    pub last_visible_rows: Cell<usize>,
+    /// Names of services kept at the top of the table.
+    pub pinned: HashSet<String>,
+}
+
+pub struct CpuUIState {
+    /// Cache sizes and feature flags panel
+    pub show_details: bool,
+}
+
+pub struct DiskUIState {
+    pub selected_disk: usize,
+    pub show_smart: bool,
+    pub smart_scroll: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct AnalyzerDeleteTarget {
+    pub drive: String,
+    pub name: String,
+    pub path: String,
+    pub size: u64,
+}
+
+pub struct DiskAnalyzerUIState {
+    pub selected_drive: usize,
+    pub selected_folder: usize,
+    pub search: String,
+    pub search_editing: bool,
+    pub pending_delete: Option<AnalyzerDeleteTarget>,
+    pub show_delete_confirm: bool,
+    /// Large deletes need a second confirmation; set until it is given
+    pub delete_needs_second_confirm: bool,
+    pub action_status: Option<String>,
+    /// Duplicate-file scan of a folder, shown in place of the drive panels
+    pub duplicates: Option<DuplicateScan>,
+    /// Draw the selected drive's folders as a treemap instead of a list
+    pub treemap: bool,
+    /// Subfolders of the selected treemap tile, drawn inside it
+    pub treemap_children: Option<FolderChildren>,
+    /// Delete running in the background; applied by `update_deletes`
+    pub deleting: Option<DeleteTask>,
+}
+
+pub struct DeleteTask {
+    pub job: DeleteJob,
+    /// Per-path results, filled in by the delete task when it finishes
+    pub results: Arc<RwLock<Option<Vec<(String, Result<Deletion, String>)>>>>,
+}
+
+pub enum DeleteJob {
+    Entry(AnalyzerDeleteTarget),
+    /// All but the first copy of `group`, which was at index `selected`
+    Duplicates {
+        group: DuplicateGroup,
+        selected: usize,
+        groups: Arc<RwLock<Option<Vec<DuplicateGroup>>>>,
+    },
+}
+
+pub struct FolderChildren {
+    pub path: String,
+    /// Filled in by the scan task when it finishes
+    pub folders: Arc<RwLock<Option<Vec<RootFolderInfo>>>>,
+    /// Set when the selection moves on; the scan stops early
+    pub cancelled: Arc<AtomicBool>,
+}
+
+pub struct DuplicateScan {
+    pub root: String,
+    pub progress: Arc<DuplicateProgress>,
+    /// Filled in by the scan task when it finishes
+    pub groups: Arc<RwLock<Option<Vec<DuplicateGroup>>>>,
+    pub selected_group: usize,
+    /// Asking to delete all but the first copy in the selected group
+    pub confirm_delete: bool,
+}
+
+pub struct SettingsUIState {
+    pub selected_index: usize,
+    /// Text typed into a number field, while editing
+    pub input: Option<String>,
+    /// Validation or save error for the selected field
+    pub error: Option<String>,
+    pub status: Option<String>,
+}
+
+/// Network panel that Up/Down act on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkView {
+    Interfaces,
+    Connections,
+    Consumers,
+}
+
+impl NetworkView {
+    fn next(self) -> Self {
+        match self {
+            NetworkView::Interfaces => NetworkView::Connections,
+            NetworkView::Connections => NetworkView::Consumers,
+            NetworkView::Consumers => NetworkView::Interfaces,
+        }
+    }
+
+    fn previous(self) -> Self {
+        match self {
+            NetworkView::Interfaces => NetworkView::Consumers,
+            NetworkView::Connections => NetworkView::Interfaces,
+            NetworkView::Consumers => NetworkView::Connections,
+        }
+    }
+}
+
+pub struct NetworkUIState {
+    pub view: NetworkView,
+    /// Selected row in the connections or consumers table
+    pub selected_index: usize,
+    pub scroll_offset: usize,
+    /// Interface shown in the details panel
+    pub selected_interface: usize,
+    pub connection_filter: ConnectionFilter,
+    pub family_filter: AddressFamilyFilter,
+    /// List interfaces hidden by `interface_filter` and graph their traffic
+    pub show_all_interfaces: bool,
+    /// Adapter or DNS change waiting for confirmation
+    pub pending_action: Option<NetworkAction>,
+}
+
+/// Remediation run from the Network tab once confirmed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetworkAction {
+    SetAdapterEnabled { name: String, enabled: bool },
+    FlushDns,
+}
+
+impl NetworkAction {
+    pub fn describe(&self) -> String {
+        match self {
+            NetworkAction::SetAdapterEnabled { name, enabled: true } => {
+                format!("Enable adapter {}", name)
+            }
+            NetworkAction::SetAdapterEnabled { name, enabled: false } => {
+                format!("Disable adapter {}", name)
+            }
+            NetworkAction::FlushDns => "Flush DNS cache".to_string(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -271,6 +875,85 @@ pub enum OllamaDeleteTarget {
 }
 
 impl AppState {
+    /// Follows the live config, so a hot-reloaded `enabled` flag shows at
+    /// once. Tabs without a monitor task (e.g. Settings) count as running.
+    pub fn monitor_status(&self, tab: TabType) -> MonitorStatus {
+        match self.config.read().monitor_enabled(tab) {
+            Some(false) => MonitorStatus::Disabled,
+            _ => MonitorStatus::Running,
+        }
+    }
+
+    /// Status, freshness and last error of every monitor, for the error
+    /// console.
+    pub fn monitor_health(&self) -> Vec<MonitorHealth> {
+        [
+            (TabType::Cpu, &self.cpu_error),
+            (TabType::Gpu, &self.gpu_error),
+            (TabType::Ram, &self.ram_error),
+            (TabType::Disk, &self.disk_error),
+            (TabType::DiskAnalyzer, &self.disk_analyzer_error),
+            (TabType::Network, &self.network_error),
+            (TabType::Ollama, &self.ollama_error),
+            (TabType::Processes, &self.process_error),
+            (TabType::Services, &self.service_error),
+        ]
+        .into_iter()
+        .map(|(tab, error)| MonitorHealth {
+            tab,
+            status: self.monitor_status(tab),
+            error: error.read().clone(),
+            age: self.monitor_age(tab),
+        })
+        .collect()
+    }
+
+    /// Time since the monitor behind `tab` last stored fresh data.
+    pub fn monitor_age(&self, tab: TabType) -> Option<chrono::Duration> {
+        self.monitor_updates
+            .read()
+            .get(&tab)
+            .map(|updated| Local::now() - *updated)
+    }
+
+    /// Re-evaluate alert thresholds against the latest monitor data.
+    pub fn update_alerts(&mut self) {
+        let config = self.config.read().alerts.clone();
+        let metrics = AlertMetrics::collect(
+            self.cpu_data.read().as_ref(),
+            self.ram_data.read().as_ref(),
+            self.gpu_data.read().as_ref(),
+            self.disk_data.read().as_ref(),
+        );
+
+        let active = alerts::evaluate(&config, &metrics);
+        let cooldown = Duration::from_secs(config.cooldown_seconds);
+        if self.alerts.update(active, cooldown) && config.bell {
+            print!("\x07");
+            let _ = std::io::Write::flush(&mut std::io::stdout());
+        }
+    }
+
+    pub fn push_notification(&mut self, level: NotificationLevel, text: impl Into<String>) {
+        self.notifications.push_back(Notification::new(level, text));
+    }
+
+    /// Pick up toasts sent by background tasks and expire old ones.
+    pub fn update_notifications(&mut self) {
+        notifications::drain(&mut self.notifications, &self.notification_rx, Instant::now());
+    }
+
+    /// Rebuild the key map after a hot reload or Settings edit changed the config.
+    pub fn update_config(&mut self) {
+        let generation = self.config_manager.generation();
+        if generation == self.config_generation {
+            return;
+        }
+        self.config_generation = generation;
+        let config = self.config.read();
+        self.keybindings = Keybindings::from_config(&config.keybindings, &config.hotkeys);
+    }
+
     fn update_terminal_size(&mut self, cols: u16, rows: u16) {
         self.terminal_size = (cols, rows);
         if self.ollama_state.input_mode == OllamaInputMode::Chat {
@@ -503,6 +1186,41 @@ impl AppState {
         }
     }
 
+    /// Same column flips the direction; a new column starts in its default.
+    fn toggle_process_sort(&mut self, column: ProcessSortColumn) {
+        let shown = self
+            .config
+            .read()
+            .monitors
+            .processes
+            .columns()
+            .iter()
+            .any(|c| c.sort_column() == Some(column));
+        if !shown {
+            self.flash = Some((
+                format!("{:?} column is hidden in config", column),
+                Instant::now(),
+            ));
+            return;
+        }
+
+        if self.processes_state.sort_column == column {
+            self.processes_state.sort_ascending = !self.processes_state.sort_ascending;
+        } else {
+            self.processes_state.sort_column = column;
+            self.processes_state.sort_ascending = column.default_ascending();
+        }
+    }
+
+    fn toggle_service_sort(&mut self, column: ServiceSortColumn) {
+        if self.services_state.sort_column == column {
+            self.services_state.sort_ascending = !self.services_state.sort_ascending;
+        } else {
+            self.services_state.sort_column = column;
+            self.services_state.sort_ascending = column.default_ascending();
+        }
+    }
+
     fn allow_widget_scroll(&mut self) -> bool {
         Self::allow_with_throttle(
             &mut self.last_widget_scroll_input,
@@ -989,6 +1707,9 @@ impl AppState {
     }
 
     async fn run_ollama_command(&mut self, command: String) {
+        if self.refuse_read_only("commands") {
+            return;
+        }
         let title = format!("Command: {}", command);
         let output = match OllamaClient::new(None) {
             Ok(client) => match client.execute_command(&command).await {
@@ -1011,12 +1732,33 @@ impl AppState {
         self.close_activity_additions();
     }
 
-    pub async fn new(config: Config) -> Result<Self> {
-        let tab_manager = TabManager::new(config.tabs.enabled.clone(), &config.tabs.default);
-
-        let command_history = CommandHistory::new(config.ui.command_history.max_entries);
+    pub async fn new(config_manager: Arc<ConfigManager>, read_only: bool) -> Result<Self> {
+        let config = config_manager.get_config().read().clone();
+        let read_only = read_only || config.general.read_only;
+        let mut tab_manager = TabManager::new(config.tabs.enabled.clone(), &config.tabs.default);
+        if config.tabs.restore_last {
+            tab_manager.restore_last(&config_manager.config_path().with_file_name(LAST_TAB_FILE));
+        }
 
-        let config = Arc::new(RwLock::new(config));
+        let history_path = config
+            .ui
+            .command_history
+            .persist
+            .then(|| config_manager.config_path().with_file_name(HISTORY_FILE));
+        let command_history =
+            CommandHistory::new(config.ui.command_history.max_entries, history_path);
+        let keybindings = Keybindings::from_config(&config.keybindings, &config.hotkeys);
+        let connection_filter =
+            ConnectionFilter::from_config(&config.monitors.network.connection_filter);
+        let (process_sort, process_sort_ascending) =
+            ProcessSortColumn::from_config(&config.monitors.processes.default_sort);
+        let (service_sort, service_sort_ascending) =
+            ServiceSortColumn::from_config(&config.monitors.services.default_sort);
+
+        // Shared with the config manager so hot reloads and Settings edits apply live
+        let config = config_manager.get_config();
+        let (notification_tx, notification_rx) = mpsc::channel();
+        config_manager.set_notifier(notification_tx.clone());
 
         let cpu_data = Arc::new(RwLock::new(None));
         let cpu_error = Arc::new(RwLock::new(None));
@@ -1037,8 +1779,12 @@ impl AppState {
 
         let ollama_data = Arc::new(RwLock::new(None));
         let ollama_error = Arc::new(RwLock::new(None));
+        let system_info = Arc::new(RwLock::new(None));
 
         // Start monitor tasks
+        let monitor_updates: UpdateTimes = Arc::new(RwLock::new(HashMap::new()));
+        let paused = Arc::new(AtomicBool::new(false));
+        let refresh_scale = Arc::new(RefreshScale::default());
         monitors_task::spawn_monitor_tasks(
             Arc::clone(&config),
             Arc::clone(&cpu_data),
@@ -1059,12 +1805,67 @@ impl AppState {
             Arc::clone(&service_error),
             Arc::clone(&ollama_data),
             Arc::clone(&ollama_error),
+            Arc::clone(&system_info),
+            Arc::clone(&monitor_updates),
+            Arc::clone(&paused),
+            Arc::clone(&refresh_scale),
         );
 
+        let metrics_config = config.read().metrics.clone();
+        #[cfg(feature = "metrics")]
+        let metrics_server = if metrics_config.enabled {
+            let sources = crate::metrics::MetricsSources {
+                cpu: Arc::clone(&cpu_data),
+                ram: Arc::clone(&ram_data),
+                gpu: Arc::clone(&gpu_data),
+                disk: Arc::clone(&disk_data),
+            };
+            match crate::metrics::MetricsServer::start(&metrics_config.bind_address, sources).await
+            {
+                Ok(server) => Some(server),
+                Err(e) => {
+                    log::warn!("Metrics endpoint disabled: {}", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        #[cfg(not(feature = "metrics"))]
+        if metrics_config.enabled {
+            log::warn!("metrics.enabled is set but this build lacks the `metrics` feature");
+        }
+
+        let recording_config = config.read().recording.clone();
+        let recorder = if recording_config.enabled {
+            let sources = RecordSources {
+                cpu: Arc::clone(&cpu_data),
+                ram: Arc::clone(&ram_data),
+                gpu: Arc::clone(&gpu_data),
+                disk: Arc::clone(&disk_data),
+                network: Arc::clone(&network_data),
+            };
+            match Recorder::start(&recording_config, sources) {
+                Ok(recorder) => Some(recorder),
+                Err(e) => {
+                    log::warn!("Metrics recording disabled: {}", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
         Ok(Self {
             config,
             tab_manager,
+            secondary_tab: None,
             compact_mode: false,
+            paused,
+            refresh_scale,
+            last_input: Instant::now(),
+            read_only,
+            keybindings,
 
             cpu_data,
             cpu_error,
@@ -1085,10 +1886,30 @@ impl AppState {
 
             ollama_data,
             ollama_error,
+            system_info,
+            monitor_updates,
+            elevated: is_elevated(),
 
             command_menu_active: false,
+            tab_menu: None,
             command_history,
             command_input: String::new(),
+            command_output: None,
+            command_output_scroll: 0,
+            help_visible: false,
+            errors_visible: false,
+            logs_visible: false,
+            log_scroll: 0,
+            log_buffer: log_buffer::shared(),
+            pid_prompt: None,
+            flash: None,
+            notifications: VecDeque::new(),
+            notification_tx,
+            notification_rx,
+            alerts: AlertState::default(),
+            #[cfg(feature = "metrics")]
+            _metrics_server: metrics_server,
+            recorder,
             selected_section: None,
             last_nav_input: None,
             last_horizontal_nav_input: None,
@@ -1102,6 +1923,8 @@ impl AppState {
                 selected_index: 0,
                 sort_column: GpuProcessSortColumn::Gpu,
                 sort_ascending: false,
+                selected_device: 0,
+                collapsed_devices: HashSet::new(),
             },
 
             ram_state: RamUIState {
@@ -1112,22 +1935,68 @@ impl AppState {
             },
 
             processes_state: ProcessesUIState {
-                selected_index: 0,
-                scroll_offset: 0,
-                sort_column: ProcessSortColumn::Cpu,
-                sort_ascending: false,
-                filter: String::new(),
+                sort_column: process_sort,
+                sort_ascending: process_sort_ascending,
+                ..Default::default()
             },
 
             services_state: ServicesUIState {
                 selected_index: 0,
                 scroll_offset: 0,
-                sort_column: ServiceSortColumn::Name,
-                sort_ascending: true,
+                sort_column: service_sort,
+                sort_ascending: service_sort_ascending,
                 status_filter: ServiceStatusFilter::All,
+                filter: String::new(),
+                filter_editing: false,
                 focused_panel: ServicesPanelFocus::Table,
                 details_scroll: 0,
+                last_visible_rows: Cell::new(0),
+                pinned: HashSet::new(),
+            },
+
+            cpu_state: CpuUIState {
+                show_details: false,
             },
+            disk_state: DiskUIState {
+                selected_disk: 0,
+                show_smart: false,
+                smart_scroll: 0,
+            },
+
+            disk_analyzer_state: DiskAnalyzerUIState {
+                selected_drive: 0,
+                selected_folder: 0,
+                search: String::new(),
+                search_editing: false,
+                pending_delete: None,
+                show_delete_confirm: false,
+                delete_needs_second_confirm: false,
+                action_status: None,
+                duplicates: None,
+                treemap: false,
+                treemap_children: None,
+                deleting: None,
+            },
+
+            network_state: NetworkUIState {
+                view: NetworkView::Connections,
+                selected_index: 0,
+                scroll_offset: 0,
+                selected_interface: 0,
+                connection_filter,
+                family_filter: AddressFamilyFilter::All,
+                show_all_interfaces: false,
+                pending_action: None,
+            },
+
+            settings_state: SettingsUIState {
+                selected_index: 0,
+                input: None,
+                error: None,
+                status: None,
+            },
+            config_generation: config_manager.generation(),
+            config_manager,
 
             ollama_state: OllamaUIState {
                 selected_model_index: 0,
@@ -1165,6 +2034,9 @@ impl AppState {
     }
 
     pub async fn handle_event(&mut self, event: CrosstermEvent) -> Result<bool> {
+        if matches!(event, CrosstermEvent::Key(_) | CrosstermEvent::Mouse(_)) {
+            self.mark_active();
+        }
         match event {
             CrosstermEvent::Key(key_event) => self.handle_key_event(key_event).await,
             CrosstermEvent::Mouse(mouse_event) => self.handle_mouse_event(mouse_event).await,
@@ -1179,27 +2051,166 @@ impl AppState {
     async fn handle_key_event(&mut self, key: KeyEvent) -> Result<bool> {
         let is_initial_press = matches!(key.kind, KeyEventKind::Press);
         // Handle Ctrl+C to quit
-        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+        if self.keybindings.is(KeyAction::Quit, &key) {
             return Ok(false);
         }
 
         // Handle Ctrl+F to open command history menu
-        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('f') {
+        if self.keybindings.is(KeyAction::OpenHistory, &key) {
             if is_initial_press {
                 self.command_menu_active = !self.command_menu_active;
             }
             return Ok(true);
         }
 
-        // If command menu is active, handle navigation
-        if self.command_menu_active {
-            match key.code {
-                KeyCode::Esc => {
-                    self.command_menu_active = false;
+        // Help overlay swallows every other key while open
+        if self.help_visible {
+            if is_initial_press
+                && (key.code == KeyCode::Esc || self.keybindings.is(KeyAction::ToggleHelp, &key))
+            {
+                self.help_visible = false;
+            }
+            return Ok(true);
+        }
+
+        if self.keybindings.is(KeyAction::ToggleHelp, &key) {
+            if is_initial_press {
+                self.help_visible = true;
+            }
+            return Ok(true);
+        }
+
+        // So does the error console, which can copy its contents
+        if self.errors_visible {
+            if is_initial_press {
+                if key.code == KeyCode::Esc || self.keybindings.is(KeyAction::ToggleErrors, &key) {
+                    self.errors_visible = false;
+                } else if self.keybindings.is(KeyAction::CopySelection, &key) {
+                    self.copy_monitor_health();
                 }
-                KeyCode::Enter if is_initial_press => {
-                    // First Enter: insert command into input
-                    if let Some(cmd) = self.command_history.get_selected() {
+            }
+            return Ok(true);
+        }
+
+        if self.keybindings.is(KeyAction::ToggleErrors, &key) {
+            if is_initial_press {
+                self.errors_visible = true;
+            }
+            return Ok(true);
+        }
+
+        // And the log viewer, which scrolls back from the newest record
+        if self.logs_visible {
+            let max_scroll = self.log_buffer.len().saturating_sub(1);
+            match key.code {
+                KeyCode::Esc if is_initial_press => self.logs_visible = false,
+                _ if is_initial_press && self.keybindings.is(KeyAction::ToggleLogs, &key) => {
+                    self.logs_visible = false;
+                }
+                KeyCode::Up => self.log_scroll = (self.log_scroll + 1).min(max_scroll),
+                KeyCode::Down => self.log_scroll = self.log_scroll.saturating_sub(1),
+                KeyCode::PageUp => self.log_scroll = (self.log_scroll + 10).min(max_scroll),
+                KeyCode::PageDown => self.log_scroll = self.log_scroll.saturating_sub(10),
+                KeyCode::End => self.log_scroll = 0,
+                _ => {}
+            }
+            return Ok(true);
+        }
+
+        if self.keybindings.is(KeyAction::ToggleLogs, &key) {
+            if is_initial_press {
+                self.logs_visible = true;
+                self.log_scroll = 0;
+            }
+            return Ok(true);
+        }
+
+        // Command output popup: scroll or dismiss
+        if let Some((output, _)) = self.command_output.as_ref() {
+            let max_scroll = output.lines().count().saturating_sub(1);
+            match key.code {
+                KeyCode::Esc | KeyCode::Enter if is_initial_press => {
+                    self.command_output = None;
+                    self.command_output_scroll = 0;
+                }
+                KeyCode::Up => {
+                    self.command_output_scroll = self.command_output_scroll.saturating_sub(1);
+                }
+                KeyCode::Down => {
+                    self.command_output_scroll = (self.command_output_scroll + 1).min(max_scroll);
+                }
+                KeyCode::PageUp => {
+                    self.command_output_scroll = self.command_output_scroll.saturating_sub(10);
+                }
+                KeyCode::PageDown => {
+                    self.command_output_scroll = (self.command_output_scroll + 10).min(max_scroll);
+                }
+                _ => {}
+            }
+            return Ok(true);
+        }
+
+        if let Some(menu) = self.tab_menu.as_mut() {
+            match key.code {
+                KeyCode::Esc => self.tab_menu = None,
+                KeyCode::Enter if is_initial_press => {
+                    if let Some(tab) = menu.selected() {
+                        self.tab_manager.select(tab);
+                    }
+                    self.tab_menu = None;
+                }
+                KeyCode::Right | KeyCode::Down | KeyCode::Tab if is_initial_press => menu.next(),
+                KeyCode::Left | KeyCode::Up | KeyCode::BackTab if is_initial_press => {
+                    menu.previous()
+                }
+                _ if is_initial_press && self.keybindings.is(KeyAction::TabMenu, &key) => {
+                    self.tab_menu = None;
+                }
+                _ => {}
+            }
+            return Ok(true);
+        }
+
+        if self.keybindings.is(KeyAction::TabMenu, &key) {
+            if is_initial_press {
+                self.open_tab_menu();
+            }
+            return Ok(true);
+        }
+
+        // Jump-to-PID prompt takes digits until Enter or Esc
+        if let Some(input) = self.pid_prompt.as_mut() {
+            match key.code {
+                KeyCode::Esc => self.pid_prompt = None,
+                KeyCode::Enter if is_initial_press => {
+                    let input = self.pid_prompt.take().unwrap_or_default();
+                    self.jump_to_pid(&input);
+                }
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Char(c) if c.is_ascii_digit() && input.len() < 10 => input.push(c),
+                _ => {}
+            }
+            return Ok(true);
+        }
+
+        if self.keybindings.is(KeyAction::JumpToPid, &key) {
+            if is_initial_press {
+                self.pid_prompt = Some(String::new());
+            }
+            return Ok(true);
+        }
+
+        // If command menu is active, handle navigation
+        if self.command_menu_active {
+            match key.code {
+                KeyCode::Esc => {
+                    self.command_menu_active = false;
+                }
+                KeyCode::Enter if is_initial_press => {
+                    // First Enter: insert command into input
+                    if let Some(cmd) = self.command_history.get_selected() {
                         self.command_input = cmd.clone();
                         self.command_menu_active = false;
                     }
@@ -1245,8 +2256,100 @@ impl AppState {
 
         // Handle tab-specific hotkeys first
         if self.tab_manager.current() == TabType::Processes {
-            match key.code {
-                KeyCode::Up => {
+            if self.processes_state.detail.is_some() {
+                if key.code == KeyCode::Esc
+                    || (is_initial_press
+                        && self.keybindings.lookup(&key, &[KeyAction::ShowDetails]).is_some())
+                {
+                    self.processes_state.detail = None;
+                }
+                return Ok(true);
+            }
+
+            if self.processes_state.show_kill_confirm {
+                match key.code {
+                    KeyCode::Char('y') | KeyCode::Enter if is_initial_press => {
+                        if let Some(target) = self.processes_state.pending_kill.take() {
+                            self.kill_process(target).await;
+                        }
+                        self.processes_state.show_kill_confirm = false;
+                    }
+                    KeyCode::Char('n') | KeyCode::Esc => {
+                        self.processes_state.pending_kill = None;
+                        self.processes_state.show_kill_confirm = false;
+                    }
+                    _ => {}
+                }
+                return Ok(true);
+            }
+
+            // Filter input: typed characters go to the filter, not to hotkeys
+            if self.processes_state.filter_editing {
+                match key.code {
+                    KeyCode::Enter if is_initial_press => {
+                        self.processes_state.filter_editing = false;
+                    }
+                    KeyCode::Esc => {
+                        self.processes_state.filter_editing = false;
+                        self.set_process_filter(String::new());
+                    }
+                    KeyCode::Backspace => {
+                        let mut filter = self.processes_state.filter.clone();
+                        filter.pop();
+                        self.set_process_filter(filter);
+                    }
+                    KeyCode::Char(c) => {
+                        let mut filter = self.processes_state.filter.clone();
+                        filter.push(c);
+                        self.set_process_filter(filter);
+                    }
+                    _ => {}
+                }
+                return Ok(true);
+            }
+
+            let action = self.keybindings.lookup(
+                &key,
+                &[
+                    KeyAction::NavUp,
+                    KeyAction::NavDown,
+                    KeyAction::PageUp,
+                    KeyAction::PageDown,
+                    KeyAction::SortPid,
+                    KeyAction::SortName,
+                    KeyAction::SortCpu,
+                    KeyAction::SortMemory,
+                    KeyAction::SortThreads,
+                    KeyAction::SortUser,
+                    KeyAction::KillProcess,
+                    KeyAction::ToggleTree,
+                    KeyAction::ToggleExpand,
+                    KeyAction::ExportProcesses,
+                    KeyAction::CopySelection,
+                    KeyAction::TogglePin,
+                    KeyAction::ShowDetails,
+                ],
+            );
+            match action {
+                Some(KeyAction::CopySelection) => {
+                    if is_initial_press {
+                        self.copy_selection();
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::ShowDetails) => {
+                    if is_initial_press {
+                        self.open_process_detail();
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::TogglePin) => {
+                    if is_initial_press {
+                        self.toggle_process_pin();
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::NavUp) => {
                     if !self.allow_nav() {
                         return Ok(true);
                     }
@@ -1260,100 +2363,145 @@ impl AppState {
                     }
                     return Ok(true);
                 }
-                KeyCode::Down => {
+                Some(KeyAction::NavDown) => {
                     if !self.allow_nav() {
                         return Ok(true);
                     }
-                    let process_count = self
-                        .process_data
-                        .read()
-                        .as_ref()
-                        .map(|d| d.processes.len())
-                        .unwrap_or(0);
+                    let process_count = self.visible_process_count();
                     if self.processes_state.selected_index + 1 < process_count {
                         self.processes_state.selected_index += 1;
                     }
                     return Ok(true);
                 }
-                KeyCode::PageUp => {
+                Some(KeyAction::PageUp) => {
                     if !self.allow_nav() {
                         return Ok(true);
                     }
-                    if self.processes_state.selected_index >= 10 {
-                        self.processes_state.selected_index -= 10;
-                    } else {
-                        self.processes_state.selected_index = 0;
-                    }
+                    let page = page_rows(&self.processes_state.last_visible_rows);
+                    self.processes_state.selected_index =
+                        self.processes_state.selected_index.saturating_sub(page);
                     self.processes_state.scroll_offset = self.processes_state.selected_index;
                     return Ok(true);
                 }
-                KeyCode::PageDown => {
+                Some(KeyAction::PageDown) => {
                     if !self.allow_nav() {
                         return Ok(true);
                     }
-                    let process_count = self
-                        .process_data
-                        .read()
-                        .as_ref()
-                        .map(|d| d.processes.len())
-                        .unwrap_or(0);
-                    if self.processes_state.selected_index + 10 < process_count {
-                        self.processes_state.selected_index += 10;
+                    let process_count = self.visible_process_count();
+                    let page = page_rows(&self.processes_state.last_visible_rows);
+                    if self.processes_state.selected_index + page < process_count {
+                        self.processes_state.selected_index += page;
                     } else if process_count > 0 {
                         self.processes_state.selected_index = process_count - 1;
                     }
                     return Ok(true);
                 }
-                KeyCode::Char('p') => {
+                Some(KeyAction::SortPid) => {
                     if !is_initial_press || !self.allow_sort_toggle() {
                         return Ok(true);
                     }
-                    self.processes_state.sort_column = ProcessSortColumn::Pid;
-                    self.processes_state.sort_ascending = !self.processes_state.sort_ascending;
+                    self.toggle_process_sort(ProcessSortColumn::Pid);
                     return Ok(true);
                 }
-                KeyCode::Char('n') => {
+                Some(KeyAction::SortName) => {
                     if !is_initial_press || !self.allow_sort_toggle() {
                         return Ok(true);
                     }
-                    self.processes_state.sort_column = ProcessSortColumn::Name;
-                    self.processes_state.sort_ascending = !self.processes_state.sort_ascending;
+                    self.toggle_process_sort(ProcessSortColumn::Name);
                     return Ok(true);
                 }
-                KeyCode::Char('c') => {
+                Some(KeyAction::SortCpu) => {
                     if !is_initial_press || !self.allow_sort_toggle() {
                         return Ok(true);
                     }
-                    self.processes_state.sort_column = ProcessSortColumn::Cpu;
-                    self.processes_state.sort_ascending = !self.processes_state.sort_ascending;
+                    self.toggle_process_sort(ProcessSortColumn::Cpu);
                     return Ok(true);
                 }
-                KeyCode::Char('m') => {
+                Some(KeyAction::SortMemory) => {
                     if !is_initial_press || !self.allow_sort_toggle() {
                         return Ok(true);
                     }
-                    self.processes_state.sort_column = ProcessSortColumn::Memory;
-                    self.processes_state.sort_ascending = !self.processes_state.sort_ascending;
+                    self.toggle_process_sort(ProcessSortColumn::Memory);
                     return Ok(true);
                 }
-                KeyCode::Char('t') => {
+                Some(KeyAction::SortThreads) => {
                     if !is_initial_press || !self.allow_sort_toggle() {
                         return Ok(true);
                     }
-                    self.processes_state.sort_column = ProcessSortColumn::Threads;
-                    self.processes_state.sort_ascending = !self.processes_state.sort_ascending;
+                    self.toggle_process_sort(ProcessSortColumn::Threads);
                     return Ok(true);
                 }
-                KeyCode::Char('u') => {
+                Some(KeyAction::SortUser) => {
                     if !is_initial_press || !self.allow_sort_toggle() {
                         return Ok(true);
                     }
-                    self.processes_state.sort_column = ProcessSortColumn::User;
-                    self.processes_state.sort_ascending = !self.processes_state.sort_ascending;
+                    self.toggle_process_sort(ProcessSortColumn::User);
+                    return Ok(true);
+                }
+                Some(KeyAction::KillProcess) => {
+                    if !is_initial_press || self.refuse_read_only("kill") {
+                        return Ok(true);
+                    }
+                    let selected = self.selected_process_row().map(|row| ProcessKillTarget {
+                        pid: row.process.pid,
+                        name: row.process.name.clone(),
+                    });
+                    let owner = self.selected_process_row().map(|row| row.process.user.clone());
+                    let local = self.config.read().remote.target().is_none();
+                    if let Some(target) = selected {
+                        if is_protected_pid(target.pid, local) {
+                            self.processes_state.action_status = Some(format!(
+                                "Refusing to kill protected process {} (PID {})",
+                                target.name, target.pid
+                            ));
+                        } else if !self.elevated
+                            && owner.as_deref().is_some_and(is_system_account)
+                        {
+                            self.processes_state.action_status = Some(format!(
+                                "Killing {} (PID {}) requires administrator",
+                                target.name, target.pid
+                            ));
+                        } else {
+                            self.processes_state.pending_kill = Some(target);
+                            self.processes_state.show_kill_confirm = true;
+                        }
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::ExportProcesses) => {
+                    if is_initial_press {
+                        self.export_processes();
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::ToggleTree) => {
+                    if !is_initial_press || !self.allow_view_toggle() {
+                        return Ok(true);
+                    }
+                    // Keep the same process selected across the layout change
+                    let selected_pid = self.selected_process_row().map(|row| row.process.pid);
+                    self.processes_state.tree_view = !self.processes_state.tree_view;
+                    self.select_process_row(selected_pid);
+                    return Ok(true);
+                }
+                Some(KeyAction::ToggleExpand) => {
+                    if !is_initial_press || !self.processes_state.tree_view {
+                        return Ok(true);
+                    }
+                    if let Some(row) = self.selected_process_row() {
+                        if row.has_children {
+                            let pid = row.process.pid;
+                            if !self.processes_state.collapsed.remove(&pid) {
+                                self.processes_state.collapsed.insert(pid);
+                            }
+                        }
+                    }
                     return Ok(true);
                 }
-                KeyCode::Char('/') => {
-                    // Enter filter mode (will be handled in UI)
+                None if key.code == KeyCode::Char('/') => {
+                    if is_initial_press {
+                        self.processes_state.filter_editing = true;
+                    }
                     return Ok(true);
                 }
                 _ => {}
@@ -1361,14 +2509,58 @@ impl AppState {
         }
 
         if self.tab_manager.current() == TabType::Gpu {
-            let process_count = self
+            let (process_count, device_count) = self
                 .gpu_data
                 .read()
                 .as_ref()
-                .map(|d| d.processes.len())
-                .unwrap_or(0);
-            match key.code {
-                KeyCode::Up => {
+                .map(|d| (d.processes.len(), d.devices.len()))
+                .unwrap_or((0, 0));
+            let action = self.keybindings.lookup(
+                &key,
+                &[
+                    KeyAction::NavLeft,
+                    KeyAction::NavRight,
+                    KeyAction::ToggleExpand,
+                    KeyAction::NavUp,
+                    KeyAction::NavDown,
+                    KeyAction::PageUp,
+                    KeyAction::PageDown,
+                    KeyAction::SortPid,
+                    KeyAction::SortName,
+                    KeyAction::SortGpu,
+                    KeyAction::SortMemory,
+                    KeyAction::SortType,
+                ],
+            );
+            match action {
+                Some(KeyAction::NavLeft) => {
+                    if !self.allow_horizontal_nav() {
+                        return Ok(true);
+                    }
+                    self.gpu_state.selected_device =
+                        self.gpu_state.selected_device.saturating_sub(1);
+                    return Ok(true);
+                }
+                Some(KeyAction::NavRight) => {
+                    if !self.allow_horizontal_nav() {
+                        return Ok(true);
+                    }
+                    if self.gpu_state.selected_device + 1 < device_count {
+                        self.gpu_state.selected_device += 1;
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::ToggleExpand) => {
+                    if !is_initial_press || !self.allow_view_toggle() {
+                        return Ok(true);
+                    }
+                    let device = self.gpu_state.selected_device;
+                    if !self.gpu_state.collapsed_devices.remove(&device) {
+                        self.gpu_state.collapsed_devices.insert(device);
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::NavUp) => {
                     if !self.allow_nav() {
                         return Ok(true);
                     }
@@ -1377,7 +2569,7 @@ impl AppState {
                     }
                     return Ok(true);
                 }
-                KeyCode::Down => {
+                Some(KeyAction::NavDown) => {
                     if !self.allow_nav() {
                         return Ok(true);
                     }
@@ -1386,7 +2578,7 @@ impl AppState {
                     }
                     return Ok(true);
                 }
-                KeyCode::PageUp => {
+                Some(KeyAction::PageUp) => {
                     if !self.allow_nav() {
                         return Ok(true);
                     }
@@ -1395,7 +2587,7 @@ impl AppState {
                         self.gpu_state.selected_index.saturating_sub(step);
                     return Ok(true);
                 }
-                KeyCode::PageDown => {
+                Some(KeyAction::PageDown) => {
                     if !self.allow_nav() {
                         return Ok(true);
                     }
@@ -1407,35 +2599,35 @@ impl AppState {
                     }
                     return Ok(true);
                 }
-                KeyCode::Char('p') => {
+                Some(KeyAction::SortPid) => {
                     if !is_initial_press || !self.allow_sort_toggle() {
                         return Ok(true);
                     }
                     self.toggle_gpu_sort(GpuProcessSortColumn::Pid);
                     return Ok(true);
                 }
-                KeyCode::Char('n') => {
+                Some(KeyAction::SortName) => {
                     if !is_initial_press || !self.allow_sort_toggle() {
                         return Ok(true);
                     }
                     self.toggle_gpu_sort(GpuProcessSortColumn::Name);
                     return Ok(true);
                 }
-                KeyCode::Char('g') => {
+                Some(KeyAction::SortGpu) => {
                     if !is_initial_press || !self.allow_sort_toggle() {
                         return Ok(true);
                     }
                     self.toggle_gpu_sort(GpuProcessSortColumn::Gpu);
                     return Ok(true);
                 }
-                KeyCode::Char('m') => {
+                Some(KeyAction::SortMemory) => {
                     if !is_initial_press || !self.allow_sort_toggle() {
                         return Ok(true);
                     }
                     self.toggle_gpu_sort(GpuProcessSortColumn::Memory);
                     return Ok(true);
                 }
-                KeyCode::Char('t') => {
+                Some(KeyAction::SortType) => {
                     if !is_initial_press || !self.allow_sort_toggle() {
                         return Ok(true);
                     }
@@ -1453,8 +2645,23 @@ impl AppState {
                 .as_ref()
                 .map(|d| d.top_processes.len())
                 .unwrap_or(0);
-            match key.code {
-                KeyCode::Left | KeyCode::Right => {
+            let action = self.keybindings.lookup(
+                &key,
+                &[
+                    KeyAction::NavLeft,
+                    KeyAction::NavRight,
+                    KeyAction::NavUp,
+                    KeyAction::NavDown,
+                    KeyAction::PageUp,
+                    KeyAction::PageDown,
+                    KeyAction::SortPid,
+                    KeyAction::SortName,
+                    KeyAction::SortWorkingSet,
+                    KeyAction::SortPrivateBytes,
+                ],
+            );
+            match action {
+                Some(KeyAction::NavLeft | KeyAction::NavRight) => {
                     if !self.allow_nav() {
                         return Ok(true);
                     }
@@ -1464,7 +2671,7 @@ impl AppState {
                     };
                     return Ok(true);
                 }
-                KeyCode::Up => {
+                Some(KeyAction::NavUp) => {
                     if !self.allow_nav() {
                         return Ok(true);
                     }
@@ -1475,7 +2682,7 @@ impl AppState {
                     }
                     return Ok(true);
                 }
-                KeyCode::Down => {
+                Some(KeyAction::NavDown) => {
                     if !self.allow_nav() {
                         return Ok(true);
                     }
@@ -1486,7 +2693,7 @@ impl AppState {
                     }
                     return Ok(true);
                 }
-                KeyCode::PageUp => {
+                Some(KeyAction::PageUp) => {
                     if !self.allow_nav() {
                         return Ok(true);
                     }
@@ -1497,7 +2704,7 @@ impl AppState {
                     }
                     return Ok(true);
                 }
-                KeyCode::PageDown => {
+                Some(KeyAction::PageDown) => {
                     if !self.allow_nav() {
                         return Ok(true);
                     }
@@ -1511,7 +2718,7 @@ impl AppState {
                     }
                     return Ok(true);
                 }
-                KeyCode::Char('p') => {
+                Some(KeyAction::SortPid) => {
                     if !is_initial_press || !self.allow_sort_toggle() {
                         return Ok(true);
                     }
@@ -1519,7 +2726,7 @@ impl AppState {
                     self.ram_state.sort_ascending = !self.ram_state.sort_ascending;
                     return Ok(true);
                 }
-                KeyCode::Char('n') => {
+                Some(KeyAction::SortName) => {
                     if !is_initial_press || !self.allow_sort_toggle() {
                         return Ok(true);
                     }
@@ -1527,7 +2734,7 @@ impl AppState {
                     self.ram_state.sort_ascending = !self.ram_state.sort_ascending;
                     return Ok(true);
                 }
-                KeyCode::Char('w') => {
+                Some(KeyAction::SortWorkingSet) => {
                     if !is_initial_press || !self.allow_sort_toggle() {
                         return Ok(true);
                     }
@@ -1535,7 +2742,7 @@ impl AppState {
                     self.ram_state.sort_ascending = !self.ram_state.sort_ascending;
                     return Ok(true);
                 }
-                KeyCode::Char('b') => {
+                Some(KeyAction::SortPrivateBytes) => {
                     if !is_initial_press || !self.allow_sort_toggle() {
                         return Ok(true);
                     }
@@ -1549,8 +2756,66 @@ impl AppState {
 
                 // Services tab hotkeys
         if self.tab_manager.current() == TabType::Services {
-            match key.code {
-                KeyCode::Left | KeyCode::Right => {
+            // Search input: typed characters go to the filter, not to hotkeys
+            if self.services_state.filter_editing {
+                match key.code {
+                    KeyCode::Enter if is_initial_press => {
+                        self.services_state.filter_editing = false;
+                    }
+                    KeyCode::Esc => {
+                        self.services_state.filter_editing = false;
+                        self.set_service_filter(String::new());
+                    }
+                    KeyCode::Backspace => {
+                        let mut filter = self.services_state.filter.clone();
+                        filter.pop();
+                        self.set_service_filter(filter);
+                    }
+                    KeyCode::Char(c) => {
+                        let mut filter = self.services_state.filter.clone();
+                        filter.push(c);
+                        self.set_service_filter(filter);
+                    }
+                    _ => {}
+                }
+                return Ok(true);
+            }
+
+            let action = self.keybindings.lookup(
+                &key,
+                &[
+                    KeyAction::NavLeft,
+                    KeyAction::NavRight,
+                    KeyAction::NavUp,
+                    KeyAction::NavDown,
+                    KeyAction::PageUp,
+                    KeyAction::PageDown,
+                    KeyAction::SortName,
+                    KeyAction::SortDisplayName,
+                    KeyAction::SortStatus,
+                    KeyAction::SortStartType,
+                    KeyAction::CycleFilter,
+                    KeyAction::StartService,
+                    KeyAction::StopService,
+                    KeyAction::RestartService,
+                    KeyAction::CopySelection,
+                    KeyAction::TogglePin,
+                ],
+            );
+            match action {
+                Some(KeyAction::CopySelection) => {
+                    if is_initial_press {
+                        self.copy_selection();
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::TogglePin) => {
+                    if is_initial_press {
+                        self.toggle_service_pin();
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::NavLeft | KeyAction::NavRight) => {
                     if !self.allow_nav() {
                         return Ok(true);
                     }
@@ -1567,7 +2832,7 @@ impl AppState {
                     }
                     return Ok(true);
                 }
-                KeyCode::Up => {
+                Some(KeyAction::NavUp) => {
                     if self.services_state.focused_panel == ServicesPanelFocus::Details {
                         if !self.allow_widget_scroll() {
                             return Ok(true);
@@ -1587,7 +2852,7 @@ impl AppState {
                     }
                     return Ok(true);
                 }
-                KeyCode::Down => {
+                Some(KeyAction::NavDown) => {
                     if self.services_state.focused_panel == ServicesPanelFocus::Details {
                         if !self.allow_widget_scroll() {
                             return Ok(true);
@@ -1598,18 +2863,13 @@ impl AppState {
                     if !self.allow_nav() {
                         return Ok(true);
                     }
-                    let service_count = self
-                        .service_data
-                        .read()
-                        .as_ref()
-                        .map(|d| d.services.len())
-                        .unwrap_or(0);
+                    let service_count = self.visible_service_count();
                     if self.services_state.selected_index + 1 < service_count {
                         self.services_state.selected_index += 1;
                     }
                     return Ok(true);
                 }
-                KeyCode::PageUp => {
+                Some(KeyAction::PageUp) => {
                     if self.services_state.focused_panel == ServicesPanelFocus::Details {
                         if !self.allow_widget_scroll() {
                             return Ok(true);
@@ -1621,15 +2881,13 @@ impl AppState {
                     if !self.allow_nav() {
                         return Ok(true);
                     }
-                    if self.services_state.selected_index >= 10 {
-                        self.services_state.selected_index -= 10;
-                    } else {
-                        self.services_state.selected_index = 0;
-                    }
+                    let page = page_rows(&self.services_state.last_visible_rows);
+                    self.services_state.selected_index =
+                        self.services_state.selected_index.saturating_sub(page);
                     self.services_state.scroll_offset = self.services_state.selected_index;
                     return Ok(true);
                 }
-                KeyCode::PageDown => {
+                Some(KeyAction::PageDown) => {
                     if self.services_state.focused_panel == ServicesPanelFocus::Details {
                         if !self.allow_widget_scroll() {
                             return Ok(true);
@@ -1640,100 +2898,617 @@ impl AppState {
                     if !self.allow_nav() {
                         return Ok(true);
                     }
-                    let service_count = self
-                        .service_data
-                        .read()
-                        .as_ref()
-                        .map(|d| d.services.len())
-                        .unwrap_or(0);
-                    if self.services_state.selected_index + 10 < service_count {
-                        self.services_state.selected_index += 10;
+                    let service_count = self.visible_service_count();
+                    let page = page_rows(&self.services_state.last_visible_rows);
+                    if self.services_state.selected_index + page < service_count {
+                        self.services_state.selected_index += page;
                     } else if service_count > 0 {
                         self.services_state.selected_index = service_count - 1;
                     }
                     return Ok(true);
                 }
-                KeyCode::Char('n') => {
+                Some(KeyAction::SortName) => {
                     if self.services_state.focused_panel != ServicesPanelFocus::Table
                         || !is_initial_press
                         || !self.allow_sort_toggle()
                     {
                         return Ok(true);
                     }
-                    self.services_state.sort_column = ServiceSortColumn::Name;
-                    self.services_state.sort_ascending = !self.services_state.sort_ascending;
+                    self.toggle_service_sort(ServiceSortColumn::Name);
                     return Ok(true);
                 }
-                KeyCode::Char('d') => {
+                Some(KeyAction::SortDisplayName) => {
                     if self.services_state.focused_panel != ServicesPanelFocus::Table
                         || !is_initial_press
                         || !self.allow_sort_toggle()
                     {
                         return Ok(true);
                     }
-                    self.services_state.sort_column = ServiceSortColumn::DisplayName;
-                    self.services_state.sort_ascending = !self.services_state.sort_ascending;
+                    self.toggle_service_sort(ServiceSortColumn::DisplayName);
                     return Ok(true);
                 }
-                KeyCode::Char('s') => {
+                Some(KeyAction::SortStatus) => {
                     if self.services_state.focused_panel != ServicesPanelFocus::Table
                         || !is_initial_press
                         || !self.allow_sort_toggle()
                     {
                         return Ok(true);
                     }
-                    self.services_state.sort_column = ServiceSortColumn::Status;
-                    self.services_state.sort_ascending = !self.services_state.sort_ascending;
+                    self.toggle_service_sort(ServiceSortColumn::Status);
                     return Ok(true);
                 }
-                KeyCode::Char('t') => {
+                Some(KeyAction::SortStartType) => {
                     if self.services_state.focused_panel != ServicesPanelFocus::Table
                         || !is_initial_press
                         || !self.allow_sort_toggle()
                     {
                         return Ok(true);
                     }
-                    self.services_state.sort_column = ServiceSortColumn::StartType;
-                    self.services_state.sort_ascending = !self.services_state.sort_ascending;
+                    self.toggle_service_sort(ServiceSortColumn::StartType);
                     return Ok(true);
                 }
-                KeyCode::Char('f') => {
+                Some(KeyAction::CycleFilter) => {
                     // Cycle through filter options
                     self.services_state.status_filter = match self.services_state.status_filter {
                         ServiceStatusFilter::All => ServiceStatusFilter::Running,
                         ServiceStatusFilter::Running => ServiceStatusFilter::Stopped,
                         ServiceStatusFilter::Stopped => ServiceStatusFilter::All,
                     };
+                    self.services_state.selected_index = 0;
+                    self.services_state.scroll_offset = 0;
+                    return Ok(true);
+                }
+                Some(
+                    action @ (KeyAction::StartService
+                    | KeyAction::StopService
+                    | KeyAction::RestartService),
+                ) => {
+                    if is_initial_press {
+                        self.control_selected_service(action);
+                    }
+                    return Ok(true);
+                }
+                None if key.code == KeyCode::Char('/') => {
+                    if is_initial_press {
+                        self.services_state.filter_editing = true;
+                    }
                     return Ok(true);
                 }
                 _ => {}
             }
         }
 
+        // CPU tab hotkeys
+        if self.tab_manager.current() == TabType::Cpu
+            && self.keybindings.lookup(&key, &[KeyAction::ToggleExpand]).is_some()
+        {
+            if is_initial_press {
+                self.cpu_state.show_details = !self.cpu_state.show_details;
+            }
+            return Ok(true);
+        }
 
-        // Ollama tab hotkeys
-        if self.tab_manager.current() == TabType::Ollama {
-            if self.ollama_state.show_delete_confirm {
-                match key.code {
-                    KeyCode::Char('y') | KeyCode::Enter => {
-                        if let Some(target) = self.ollama_state.pending_delete.clone() {
-                            match target {
-                                OllamaDeleteTarget::Model(model_name) => {
-                                    tokio::spawn(async move {
-                                        use crate::integrations::OllamaClient;
-                                        if let Ok(client) = OllamaClient::new(None) {
-                                            let _ = client.remove_model(&model_name).await;
-                                        }
-                                    });
-                                }
-                                OllamaDeleteTarget::ChatLog(entry) => {
-                                    let log_path = entry.path.clone();
-                                    let meta_path =
-                                        std::path::PathBuf::from(&log_path).with_extension("toml");
-                                    let _ = fs::remove_file(&log_path);
-                                    let _ = fs::remove_file(&meta_path);
-                                    if let Some(data) = self.ollama_data.write().as_mut() {
-                                        data.chat_logs
+        // Disk tab hotkeys
+        if self.tab_manager.current() == TabType::Disk {
+            let action = self.keybindings.lookup(
+                &key,
+                &[
+                    KeyAction::NavUp,
+                    KeyAction::NavDown,
+                    KeyAction::PageUp,
+                    KeyAction::PageDown,
+                    KeyAction::ShowSmart,
+                ],
+            );
+
+            if self.disk_state.show_smart {
+                let attribute_count = self
+                    .disk_data
+                    .read()
+                    .as_ref()
+                    .and_then(|d| d.physical_disks.get(self.disk_state.selected_disk))
+                    .map(|disk| disk.smart_attributes.len())
+                    .unwrap_or(0);
+                let max_scroll = attribute_count.saturating_sub(1);
+                let state = &mut self.disk_state;
+                match action {
+                    Some(KeyAction::ShowSmart) if is_initial_press => state.show_smart = false,
+                    Some(KeyAction::NavUp) => state.smart_scroll = state.smart_scroll.saturating_sub(1),
+                    Some(KeyAction::NavDown) => state.smart_scroll = (state.smart_scroll + 1).min(max_scroll),
+                    Some(KeyAction::PageUp) => state.smart_scroll = state.smart_scroll.saturating_sub(10),
+                    Some(KeyAction::PageDown) => state.smart_scroll = (state.smart_scroll + 10).min(max_scroll),
+                    _ if key.code == KeyCode::Esc => state.show_smart = false,
+                    _ => {}
+                }
+                return Ok(true);
+            }
+
+            match action {
+                Some(KeyAction::NavUp) => {
+                    if self.allow_nav() {
+                        self.disk_state.selected_disk = self.disk_state.selected_disk.saturating_sub(1);
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::NavDown) => {
+                    if !self.allow_nav() {
+                        return Ok(true);
+                    }
+                    let disk_count = self
+                        .disk_data
+                        .read()
+                        .as_ref()
+                        .map(|d| d.physical_disks.len())
+                        .unwrap_or(0);
+                    if self.disk_state.selected_disk + 1 < disk_count {
+                        self.disk_state.selected_disk += 1;
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::ShowSmart) => {
+                    if is_initial_press {
+                        self.disk_state.show_smart = true;
+                        self.disk_state.smart_scroll = 0;
+                    }
+                    return Ok(true);
+                }
+                _ => {}
+            }
+        }
+
+        // Disk analyzer tab hotkeys
+        if self.tab_manager.current() == TabType::DiskAnalyzer {
+            if self.disk_analyzer_state.show_delete_confirm {
+                match key.code {
+                    KeyCode::Char('y') | KeyCode::Enter if is_initial_press => {
+                        if self.disk_analyzer_state.delete_needs_second_confirm {
+                            self.disk_analyzer_state.delete_needs_second_confirm = false;
+                        } else {
+                            if let Some(target) = self.disk_analyzer_state.pending_delete.take() {
+                                self.delete_analyzer_entry(target);
+                            }
+                            self.disk_analyzer_state.show_delete_confirm = false;
+                        }
+                    }
+                    KeyCode::Char('n') | KeyCode::Esc => {
+                        self.disk_analyzer_state.pending_delete = None;
+                        self.disk_analyzer_state.show_delete_confirm = false;
+                        self.disk_analyzer_state.delete_needs_second_confirm = false;
+                    }
+                    _ => {}
+                }
+                return Ok(true);
+            }
+
+            if let Some(scan) = self.disk_analyzer_state.duplicates.as_mut() {
+                if scan.confirm_delete {
+                    match key.code {
+                        KeyCode::Char('y') | KeyCode::Enter if is_initial_press => {
+                            scan.confirm_delete = false;
+                            self.delete_duplicate_copies();
+                        }
+                        KeyCode::Char('n') | KeyCode::Esc => scan.confirm_delete = false,
+                        _ => {}
+                    }
+                    return Ok(true);
+                }
+
+                let group_count = scan.groups.read().as_ref().map_or(0, Vec::len);
+                let action = self.keybindings.lookup(
+                    &key,
+                    &[
+                        KeyAction::NavUp,
+                        KeyAction::NavDown,
+                        KeyAction::DeleteEntry,
+                        KeyAction::OpenFolder,
+                        KeyAction::OpenTerminal,
+                    ],
+                );
+                let selected_group = scan.selected_group;
+                let next = match action {
+                    Some(KeyAction::NavUp) if self.allow_nav() => selected_group.saturating_sub(1),
+                    Some(KeyAction::NavDown) if self.allow_nav() => {
+                        (selected_group + 1).min(group_count.saturating_sub(1))
+                    }
+                    Some(KeyAction::DeleteEntry)
+                        if is_initial_press
+                            && group_count > 0
+                            && !self.refuse_read_only("delete") =>
+                    {
+                        if let Some(scan) = self.disk_analyzer_state.duplicates.as_mut() {
+                            scan.confirm_delete = true;
+                        }
+                        selected_group
+                    }
+                    Some(KeyAction::OpenFolder | KeyAction::OpenTerminal) => {
+                        if is_initial_press {
+                            self.open_analyzer_selection(action == Some(KeyAction::OpenTerminal));
+                        }
+                        return Ok(true);
+                    }
+                    _ if key.code == KeyCode::Esc => {
+                        self.close_duplicate_scan();
+                        return Ok(true);
+                    }
+                    _ => selected_group,
+                };
+                if let Some(scan) = self.disk_analyzer_state.duplicates.as_mut() {
+                    scan.selected_group = next;
+                }
+                return Ok(true);
+            }
+
+            // Search input: typed characters go to the query, not to hotkeys
+            if self.disk_analyzer_state.search_editing {
+                match key.code {
+                    KeyCode::Enter if is_initial_press => {
+                        self.disk_analyzer_state.search_editing = false;
+                    }
+                    KeyCode::Esc => {
+                        self.disk_analyzer_state.search_editing = false;
+                        self.set_analyzer_search(String::new());
+                    }
+                    KeyCode::Backspace => {
+                        let mut search = self.disk_analyzer_state.search.clone();
+                        search.pop();
+                        self.set_analyzer_search(search);
+                    }
+                    KeyCode::Char(c) => {
+                        let mut search = self.disk_analyzer_state.search.clone();
+                        search.push(c);
+                        self.set_analyzer_search(search);
+                    }
+                    _ => {}
+                }
+                return Ok(true);
+            }
+
+            let action = self.keybindings.lookup(
+                &key,
+                &[
+                    KeyAction::NavUp,
+                    KeyAction::NavDown,
+                    KeyAction::NavLeft,
+                    KeyAction::NavRight,
+                    KeyAction::DeleteEntry,
+                    KeyAction::Export,
+                    KeyAction::FindDuplicates,
+                    KeyAction::ToggleTreemap,
+                    KeyAction::OpenFolder,
+                    KeyAction::OpenTerminal,
+                    KeyAction::CopySelection,
+                ],
+            );
+            let (drive_count, folder_count) = {
+                let data = self.disk_analyzer_data.read();
+                let drives = data.as_ref().map(|d| d.drives.as_slice()).unwrap_or_default();
+                (
+                    drives.len(),
+                    drives
+                        .get(self.disk_analyzer_state.selected_drive)
+                        .map(|d| visible_folders(d, &self.disk_analyzer_state.search).len())
+                        .unwrap_or(0),
+                )
+            };
+            match action {
+                Some(KeyAction::NavUp) => {
+                    if self.allow_nav() {
+                        let state = &mut self.disk_analyzer_state;
+                        state.selected_folder = state.selected_folder.saturating_sub(1);
+                        self.sync_treemap_children();
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::NavDown) => {
+                    if self.allow_nav() {
+                        let state = &mut self.disk_analyzer_state;
+                        if state.selected_folder + 1 < folder_count {
+                            state.selected_folder += 1;
+                        }
+                        self.sync_treemap_children();
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::NavLeft) => {
+                    let state = &mut self.disk_analyzer_state;
+                    if is_initial_press && state.selected_drive > 0 {
+                        state.selected_drive -= 1;
+                        state.selected_folder = 0;
+                        self.sync_treemap_children();
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::NavRight) => {
+                    let state = &mut self.disk_analyzer_state;
+                    if is_initial_press && state.selected_drive + 1 < drive_count {
+                        state.selected_drive += 1;
+                        state.selected_folder = 0;
+                        self.sync_treemap_children();
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::DeleteEntry) => {
+                    if is_initial_press {
+                        self.request_analyzer_delete();
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::CopySelection) => {
+                    if is_initial_press {
+                        self.copy_selection();
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::Export) => {
+                    if is_initial_press {
+                        self.export_analyzer_drive();
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::FindDuplicates) => {
+                    if is_initial_press {
+                        self.start_duplicate_scan();
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::OpenFolder | KeyAction::OpenTerminal) => {
+                    if is_initial_press {
+                        self.open_analyzer_selection(action == Some(KeyAction::OpenTerminal));
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::ToggleTreemap) => {
+                    if is_initial_press {
+                        let state = &mut self.disk_analyzer_state;
+                        state.treemap = !state.treemap;
+                        self.sync_treemap_children();
+                    }
+                    return Ok(true);
+                }
+                None if key.code == KeyCode::Char('/') => {
+                    if is_initial_press {
+                        self.disk_analyzer_state.search_editing = true;
+                    }
+                    return Ok(true);
+                }
+                _ => {}
+            }
+        }
+
+        // Network tab hotkeys
+        if self.tab_manager.current() == TabType::Network {
+            if self.network_state.pending_action.is_some() {
+                match key.code {
+                    KeyCode::Char('y') | KeyCode::Enter if is_initial_press => {
+                        if let Some(action) = self.network_state.pending_action.take() {
+                            self.run_network_action(action);
+                        }
+                    }
+                    KeyCode::Char('n') | KeyCode::Esc => {
+                        self.network_state.pending_action = None;
+                    }
+                    _ => {}
+                }
+                return Ok(true);
+            }
+
+            let action = self.keybindings.lookup(
+                &key,
+                &[
+                    KeyAction::NavUp,
+                    KeyAction::NavDown,
+                    KeyAction::NavLeft,
+                    KeyAction::NavRight,
+                    KeyAction::PageUp,
+                    KeyAction::PageDown,
+                    KeyAction::CycleFilter,
+                    KeyAction::CopySelection,
+                    KeyAction::ToggleAllInterfaces,
+                    KeyAction::CycleAddressFamily,
+                    KeyAction::ToggleAdapter,
+                    KeyAction::FlushDns,
+                ],
+            );
+            match action {
+                Some(KeyAction::ToggleAdapter | KeyAction::FlushDns) => {
+                    if is_initial_press {
+                        self.request_network_action(action == Some(KeyAction::FlushDns));
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::CopySelection) => {
+                    if is_initial_press {
+                        self.copy_selection();
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::ToggleAllInterfaces) => {
+                    if is_initial_press {
+                        let state = &mut self.network_state;
+                        state.show_all_interfaces = !state.show_all_interfaces;
+                        state.selected_interface = 0;
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::NavLeft | KeyAction::NavRight) => {
+                    if self.allow_horizontal_nav() {
+                        let state = &mut self.network_state;
+                        state.view = if action == Some(KeyAction::NavLeft) {
+                            state.view.previous()
+                        } else {
+                            state.view.next()
+                        };
+                        state.selected_index = 0;
+                        state.scroll_offset = 0;
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::NavUp) => {
+                    if self.allow_nav() {
+                        self.move_network_selection(-1);
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::NavDown) => {
+                    if self.allow_nav() {
+                        self.move_network_selection(1);
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::PageUp) => {
+                    if self.allow_nav() {
+                        self.move_network_selection(-10);
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::PageDown) => {
+                    if self.allow_nav() {
+                        self.move_network_selection(10);
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::CycleFilter) => {
+                    if is_initial_press {
+                        self.network_state.connection_filter =
+                            match self.network_state.connection_filter {
+                                ConnectionFilter::Established => ConnectionFilter::Listen,
+                                ConnectionFilter::Listen => ConnectionFilter::All,
+                                ConnectionFilter::All => ConnectionFilter::Established,
+                            };
+                        if self.network_state.view == NetworkView::Connections {
+                            self.network_state.selected_index = 0;
+                        }
+                        self.network_state.scroll_offset = 0;
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::CycleAddressFamily) => {
+                    if is_initial_press {
+                        let state = &mut self.network_state;
+                        state.family_filter = match state.family_filter {
+                            AddressFamilyFilter::All => AddressFamilyFilter::IPv4,
+                            AddressFamilyFilter::IPv4 => AddressFamilyFilter::IPv6,
+                            AddressFamilyFilter::IPv6 => AddressFamilyFilter::All,
+                        };
+                        if state.view == NetworkView::Connections {
+                            state.selected_index = 0;
+                        }
+                        state.scroll_offset = 0;
+                    }
+                    return Ok(true);
+                }
+                _ => {}
+            }
+        }
+
+        if self.tab_manager.current() == TabType::Settings {
+            // Number input: typed characters go to the field, not to hotkeys
+            if let Some(input) = self.settings_state.input.as_mut() {
+                match key.code {
+                    KeyCode::Enter if is_initial_press => {
+                        let field = &settings::FIELDS[self.settings_state.selected_index];
+                        match field.parse(input) {
+                            Ok(value) => {
+                                self.settings_state.input = None;
+                                self.apply_setting(value);
+                            }
+                            Err(message) => self.settings_state.error = Some(message),
+                        }
+                    }
+                    KeyCode::Esc => {
+                        self.settings_state.input = None;
+                        self.settings_state.error = None;
+                    }
+                    KeyCode::Backspace => {
+                        input.pop();
+                    }
+                    KeyCode::Char(c) if input.len() < 12 => input.push(c),
+                    _ => {}
+                }
+                return Ok(true);
+            }
+
+            let action = self.keybindings.lookup(
+                &key,
+                &[
+                    KeyAction::NavUp,
+                    KeyAction::NavDown,
+                    KeyAction::NavLeft,
+                    KeyAction::NavRight,
+                ],
+            );
+            match action {
+                Some(KeyAction::NavUp | KeyAction::NavDown) => {
+                    if self.allow_nav() {
+                        let state = &mut self.settings_state;
+                        state.selected_index = if action == Some(KeyAction::NavUp) {
+                            state.selected_index.saturating_sub(1)
+                        } else {
+                            (state.selected_index + 1).min(settings::FIELDS.len() - 1)
+                        };
+                        state.error = None;
+                    }
+                    return Ok(true);
+                }
+                Some(KeyAction::NavLeft | KeyAction::NavRight) => {
+                    if self.allow_horizontal_nav() {
+                        let field = &settings::FIELDS[self.settings_state.selected_index];
+                        let current = field.value(&self.config.read());
+                        let value = field.step(&current, action == Some(KeyAction::NavRight));
+                        self.apply_setting(value);
+                    }
+                    return Ok(true);
+                }
+                _ => {}
+            }
+
+            if key.code == KeyCode::Enter && is_initial_press {
+                let field = &settings::FIELDS[self.settings_state.selected_index];
+                let current = field.value(&self.config.read());
+                if let (FieldKind::Number { .. }, SettingValue::Number(number)) = (field.kind, &current) {
+                    self.settings_state.input = Some(number.to_string());
+                    self.settings_state.error = None;
+                } else {
+                    let value = field.step(&current, true);
+                    self.apply_setting(value);
+                }
+                return Ok(true);
+            }
+        }
+
+        // Ollama tab hotkeys
+        if self.tab_manager.current() == TabType::Ollama {
+            if self.ollama_state.show_delete_confirm {
+                match key.code {
+                    KeyCode::Char('y') | KeyCode::Enter => {
+                        let target = self.ollama_state.pending_delete.clone();
+                        if let Some(target) = target.filter(|_| !self.refuse_read_only("delete")) {
+                            match target {
+                                OllamaDeleteTarget::Model(model_name) => {
+                                    let notifier = self.notification_tx.clone();
+                                    tokio::spawn(async move {
+                                        use crate::integrations::OllamaClient;
+                                        let result = match OllamaClient::new(None) {
+                                            Ok(client) => client.remove_model(&model_name).await,
+                                            Err(e) => Err(e),
+                                        };
+                                        let _ = notifier.send(match result {
+                                            Ok(()) => Notification::info(format!("Removed {}", model_name)),
+                                            Err(e) => Notification::error(format!(
+                                                "Failed to remove {}: {}",
+                                                model_name, e
+                                            )),
+                                        });
+                                    });
+                                }
+                                OllamaDeleteTarget::ChatLog(entry) => {
+                                    let log_path = entry.path.clone();
+                                    let meta_path =
+                                        std::path::PathBuf::from(&log_path).with_extension("toml");
+                                    let _ = fs::remove_file(&log_path);
+                                    let _ = fs::remove_file(&meta_path);
+                                    if let Some(data) = self.ollama_data.write().as_mut() {
+                                        data.chat_logs
                                             .retain(|item| item.path != entry.path);
                                     }
                                 }
@@ -1786,11 +3561,24 @@ impl AppState {
                         OllamaInputMode::Pull => {
                             let model_name = self.ollama_state.input_buffer.trim().to_string();
                             if !model_name.is_empty() {
+                                self.push_notification(
+                                    NotificationLevel::Info,
+                                    format!("Pulling {}...", model_name),
+                                );
+                                let notifier = self.notification_tx.clone();
                                 tokio::spawn(async move {
                                     use crate::integrations::OllamaClient;
-                                    if let Ok(client) = OllamaClient::new(None) {
-                                        let _ = client.pull_model(&model_name).await;
-                                    }
+                                    let result = match OllamaClient::new(None) {
+                                        Ok(client) => client.pull_model(&model_name).await,
+                                        Err(e) => Err(e),
+                                    };
+                                    let _ = notifier.send(match result {
+                                        Ok(_) => Notification::info(format!("Pulled {}", model_name)),
+                                        Err(e) => Notification::error(format!(
+                                            "Failed to pull {}: {}",
+                                            model_name, e
+                                        )),
+                                    });
                                 });
                             }
                             self.ollama_state.input_buffer.clear();
@@ -2350,6 +4138,9 @@ impl AppState {
                     return Ok(true);
                 }
                 KeyCode::Char('s') | KeyCode::Char('u') => {
+                    if self.refuse_read_only("stopping models") {
+                        return Ok(true);
+                    }
                     let model_name = self.selected_running_model_name();
                     if let Some(model_name) = model_name {
                         if self.ollama_state.active_chat_model.as_deref()
@@ -2365,17 +4156,26 @@ impl AppState {
                         {
                             self.ollama_state.paused_chats.remove(pos);
                         }
+                        let notifier = self.notification_tx.clone();
                         tokio::spawn(async move {
                             use crate::integrations::OllamaClient;
-                            if let Ok(client) = OllamaClient::new(None) {
-                                let _ = client.stop_model(&model_name).await;
-                            }
+                            let result = match OllamaClient::new(None) {
+                                Ok(client) => client.stop_model(&model_name).await,
+                                Err(e) => Err(e),
+                            };
+                            let _ = notifier.send(match result {
+                                Ok(()) => Notification::info(format!("Stopped {}", model_name)),
+                                Err(e) => Notification::error(format!(
+                                    "Failed to stop {}: {}",
+                                    model_name, e
+                                )),
+                            });
                         });
                     }
                     return Ok(true);
                 }
                 KeyCode::Char('d') => {
-                    if !is_initial_press {
+                    if !is_initial_press || self.refuse_read_only("delete") {
                         return Ok(true);
                     }
                     if self.ollama_state.focused_panel == OllamaPanelFocus::Activity
@@ -2416,7 +4216,7 @@ impl AppState {
                     return Ok(true);
                 }
                 KeyCode::Char('p') => {
-                    if !is_initial_press {
+                    if !is_initial_press || self.refuse_read_only("pulling models") {
                         return Ok(true);
                     }
                     self.ollama_state.input_mode = OllamaInputMode::Pull;
@@ -2425,7 +4225,7 @@ impl AppState {
                     return Ok(true);
                 }
                 KeyCode::Char('c') => {
-                    if !is_initial_press {
+                    if !is_initial_press || self.refuse_read_only("commands") {
                         return Ok(true);
                     }
                     self.ollama_state.input_mode = OllamaInputMode::Command;
@@ -2440,40 +4240,96 @@ impl AppState {
             }
         }
 
-
         // Handle global hotkeys
-        match key.code {
-            KeyCode::F(2) => {
+        if let Some(tab) = self.keybindings.tab_for(&key) {
+            self.tab_manager.select(tab);
+            return Ok(true);
+        }
+
+        let action = self.keybindings.lookup(
+            &key,
+            &[
+                KeyAction::ToggleCompact,
+                KeyAction::NextTab,
+                KeyAction::PrevTab,
+                KeyAction::NavUp,
+                KeyAction::NavDown,
+                KeyAction::RefreshFaster,
+                KeyAction::RefreshSlower,
+                KeyAction::TogglePause,
+                KeyAction::MoveTabLeft,
+                KeyAction::MoveTabRight,
+                KeyAction::SetDefaultTab,
+                KeyAction::CopyMarkdown,
+                KeyAction::ExportReport,
+                KeyAction::ToggleSplit,
+                KeyAction::CycleSplitTab,
+            ],
+        );
+        match action {
+            Some(KeyAction::ToggleSplit) if is_initial_press => {
+                self.toggle_split();
+            }
+            Some(KeyAction::CycleSplitTab) if is_initial_press => {
+                if let Some(shown) = self.split_tab() {
+                    self.secondary_tab = self.tab_manager.next_other(shown);
+                }
+            }
+            Some(KeyAction::CopyMarkdown) if is_initial_press => {
+                self.copy_markdown();
+            }
+            Some(KeyAction::ExportReport) if is_initial_press => {
+                self.export_report();
+            }
+            Some(KeyAction::MoveTabLeft) if is_initial_press => {
+                self.move_current_tab(-1);
+            }
+            Some(KeyAction::MoveTabRight) if is_initial_press => {
+                self.move_current_tab(1);
+            }
+            Some(KeyAction::SetDefaultTab) if is_initial_press => {
+                let tab = self.tab_manager.current();
+                let message = match self
+                    .config_manager
+                    .set_value(&["tabs", "default"], tab.config_name().into())
+                {
+                    Ok(()) => format!("{} opens at startup", tab.as_str()),
+                    Err(e) => format!("Failed to save default tab: {}", e),
+                };
+                self.flash = Some((message, Instant::now()));
+            }
+            Some(KeyAction::TogglePause) if is_initial_press => {
+                // fetch_xor flips the flag and returns the old value
+                let was_paused = self.paused.fetch_xor(true, AtomicOrdering::Relaxed);
+                log::info!("Data refresh {}", if was_paused { "resumed" } else { "paused" });
+            }
+            Some(KeyAction::RefreshFaster) if is_initial_press => {
+                self.adjust_refresh_interval(true);
+            }
+            Some(KeyAction::RefreshSlower) if is_initial_press => {
+                self.adjust_refresh_interval(false);
+            }
+            Some(KeyAction::ToggleCompact) => {
                 self.compact_mode = !self.compact_mode;
                 if self.compact_mode {
                     self.services_state.focused_panel = ServicesPanelFocus::Table;
                     self.services_state.details_scroll = 0;
                 }
             }
-            KeyCode::Tab if is_initial_press => {
+            Some(KeyAction::NextTab) if is_initial_press => {
                 self.tab_manager.next();
             }
-            KeyCode::BackTab if is_initial_press => {
+            Some(KeyAction::PrevTab) if is_initial_press => {
                 self.tab_manager.previous();
             }
-            KeyCode::Char('1') => self.tab_manager.select(TabType::Cpu),
-            KeyCode::Char('2') => self.tab_manager.select(TabType::Gpu),
-            KeyCode::Char('3') => self.tab_manager.select(TabType::Ram),
-            KeyCode::Char('4') => self.tab_manager.select(TabType::Disk),
-            KeyCode::Char('5') => self.tab_manager.select(TabType::Network),
-            KeyCode::Char('6') => self.tab_manager.select(TabType::Ollama),
-            KeyCode::Char('7') => self.tab_manager.select(TabType::Processes),
-            KeyCode::Char('8') => self.tab_manager.select(TabType::Services),
-            KeyCode::Char('9') => self.tab_manager.select(TabType::DiskAnalyzer),
-            KeyCode::Char('0') => self.tab_manager.select(TabType::Settings),
-            KeyCode::Up if is_initial_press => {
+            Some(KeyAction::NavUp) if is_initial_press => {
                 // Navigate command history with arrow keys (only when not on Processes tab)
                 self.command_history.previous();
                 if let Some(cmd) = self.command_history.get_selected() {
                     self.command_input = cmd.clone();
                 }
             }
-            KeyCode::Down if is_initial_press => {
+            Some(KeyAction::NavDown) if is_initial_press => {
                 self.command_history.next();
                 if let Some(cmd) = self.command_history.get_selected() {
                     self.command_input = cmd.clone();
@@ -2486,7 +4342,27 @@ impl AppState {
     }
 
     async fn handle_mouse_event(&mut self, mouse: MouseEvent) -> Result<bool> {
+        if self.help_visible || self.errors_visible || self.logs_visible {
+            return Ok(true);
+        }
+
+        if let Some(menu) = self.tab_menu.as_ref() {
+            if let MouseEventKind::Down(_) = mouse.kind {
+                let (cols, rows) = self.terminal_size;
+                let screen = ratatui::layout::Rect::new(0, 0, cols, rows);
+                // A click outside the ring (or in its centre) just closes it
+                if let Some(tab) = menu.hit_test(screen, mouse.column, mouse.row) {
+                    self.tab_manager.select(tab);
+                }
+                self.tab_menu = None;
+            }
+            return Ok(true);
+        }
+
         match mouse.kind {
+            MouseEventKind::Down(MouseButton::Right) if !self.command_menu_active => {
+                self.open_tab_menu();
+            }
             MouseEventKind::Down(_) => {
                 // Handle mouse clicks for radial menu
                 if self.command_menu_active {
@@ -2494,19 +4370,1299 @@ impl AppState {
                         .handle_mouse_click(mouse.column, mouse.row);
                 }
             }
+            MouseEventKind::ScrollUp if self.command_output.is_some() => {
+                self.command_output_scroll = self.command_output_scroll.saturating_sub(1);
+            }
+            MouseEventKind::ScrollDown if self.command_output.is_some() => {
+                let max_scroll = self
+                    .command_output
+                    .as_ref()
+                    .map(|(output, _)| output.lines().count().saturating_sub(1))
+                    .unwrap_or(0);
+                self.command_output_scroll = (self.command_output_scroll + 1).min(max_scroll);
+            }
+            MouseEventKind::ScrollUp if !self.command_menu_active => {
+                self.scroll_table_by_wheel(false);
+            }
+            MouseEventKind::ScrollDown if !self.command_menu_active => {
+                self.scroll_table_by_wheel(true);
+            }
             _ => {}
         }
 
         Ok(true)
     }
 
-    async fn execute_command(&mut self) -> Result<()> {
-        if self.command_input.is_empty() {
-            return Ok(());
-        }
+    /// Write the selected Settings field to config.toml and apply it.
+    fn apply_setting(&mut self, value: SettingValue) {
+        let field = &settings::FIELDS[self.settings_state.selected_index];
+        // Release the read lock before set_value takes the write lock
+        let toml_value = field.to_toml(&value, &self.config.read());
+        let result = toml_value.and_then(|toml_value| {
+            self.config_manager
+                .set_value(field.path, toml_value)
+                .map_err(|e| format!("{:#}", e))
+        });
 
-        // Add to history
-        self.command_history.add(self.command_input.clone());
+        match result {
+            Ok(()) => {
+                self.settings_state.error = None;
+                self.settings_state.status =
+                    Some(format!("Saved {} = {}", field.label, value.display()));
+                if let FieldKind::TabEnabled(_) = field.kind {
+                    self.rebuild_tabs();
+                }
+            }
+            Err(message) => {
+                log::warn!("Failed to save setting {}: {}", field.label, message);
+                self.settings_state.error = Some(message);
+            }
+        }
+    }
+
+    fn toggle_split(&mut self) {
+        if self.secondary_tab.take().is_some() {
+            return;
+        }
+        self.secondary_tab = self.tab_manager.next_other(self.tab_manager.current());
+        if self.secondary_tab.is_none() {
+            self.flash = Some(("Split view needs a second tab".to_string(), Instant::now()));
+        } else if self.terminal_size.0 < crate::ui::MIN_SPLIT_WIDTH {
+            self.flash = Some((
+                format!(
+                    "Split view shows at {}+ columns",
+                    crate::ui::MIN_SPLIT_WIDTH
+                ),
+                Instant::now(),
+            ));
+        }
+    }
+
+    /// Tab for the split view's second pane: the chosen one, or the next tab
+    /// when the focused pane has moved onto it or it left the bar.
+    pub fn split_tab(&self) -> Option<TabType> {
+        let secondary = self.secondary_tab?;
+        if secondary != self.tab_manager.current() && self.tab_manager.tabs.contains(&secondary) {
+            Some(secondary)
+        } else {
+            self.tab_manager.next_other(secondary)
+        }
+    }
+
+    /// Move the current tab along the bar and save the new order.
+    fn move_current_tab(&mut self, offset: isize) {
+        if !self.tab_manager.move_current(offset) {
+            return;
+        }
+        let order: toml_edit::Array = self.tab_manager.config_names().into_iter().collect();
+        if let Err(e) = self.config_manager.set_value(&["tabs", "enabled"], order.into()) {
+            log::warn!("Failed to save tab order: {}", e);
+            self.flash = Some((format!("Failed to save tab order: {}", e), Instant::now()));
+        }
+    }
+
+    /// Remember the current tab for the next launch when `tabs.restore_last`
+    /// is set.
+    pub fn save_last_tab(&self) {
+        if !self.config.read().tabs.restore_last {
+            return;
+        }
+        let path = self.config_manager.config_path().with_file_name(LAST_TAB_FILE);
+        if let Err(e) = self.tab_manager.save_last(&path) {
+            log::warn!("Failed to save last tab to {}: {}", path.display(), e);
+        }
+    }
+
+    /// Write the sorts in effect back to `default_sort` so the next launch
+    /// opens with them. Done once at exit: saving on every sort change would
+    /// rewrite config.toml, and trigger a reload, per keypress.
+    pub fn save_default_sorts(&self) {
+        let (processes, services) = {
+            let config = self.config.read();
+            (
+                ProcessSortColumn::from_config(&config.monitors.processes.default_sort),
+                ServiceSortColumn::from_config(&config.monitors.services.default_sort),
+            )
+        };
+
+        let process_sort = (self.processes_state.sort_column, self.processes_state.sort_ascending);
+        if process_sort != processes {
+            let spec = sort_spec(process_sort.0.config_name(), process_sort.1);
+            self.save_default_sort("processes", spec);
+        }
+        let service_sort = (self.services_state.sort_column, self.services_state.sort_ascending);
+        if service_sort != services {
+            let spec = sort_spec(service_sort.0.config_name(), service_sort.1);
+            self.save_default_sort("services", spec);
+        }
+    }
+
+    fn save_default_sort(&self, monitor: &str, spec: String) {
+        let path = ["monitors", monitor, "default_sort"];
+        if let Err(e) = self.config_manager.write_value(&path, spec.into()) {
+            log::warn!("Failed to save {} sort: {}", monitor, e);
+        }
+    }
+
+    /// Rebuild the tab bar from `tabs.enabled`, staying on the current tab.
+    fn rebuild_tabs(&mut self) {
+        let current = self.tab_manager.current();
+        let (enabled, default) = {
+            let config = self.config.read();
+            (config.tabs.enabled.clone(), config.tabs.default.clone())
+        };
+        self.tab_manager = TabManager::new(enabled, &default);
+        self.tab_manager.select(current);
+    }
+
+    fn open_tab_menu(&mut self) {
+        self.command_menu_active = false;
+        self.tab_menu = Some(RadialMenu::new(
+            self.tab_manager.tabs.clone(),
+            self.tab_manager.current(),
+        ));
+    }
+
+    fn set_process_filter(&mut self, filter: String) {
+        if filter != self.processes_state.filter {
+            self.processes_state.filter = filter;
+            self.processes_state.selected_index = 0;
+            self.processes_state.scroll_offset = 0;
+        }
+    }
+
+    fn set_service_filter(&mut self, filter: String) {
+        if filter != self.services_state.filter {
+            self.services_state.filter = filter;
+            self.services_state.selected_index = 0;
+            self.services_state.scroll_offset = 0;
+        }
+    }
+
+    fn visible_process_count(&self) -> usize {
+        self.process_data
+            .read()
+            .as_ref()
+            .map(|d| process_rows(&d.processes, &self.processes_state).len())
+            .unwrap_or(0)
+    }
+
+    fn visible_service_count(&self) -> usize {
+        self.service_data
+            .read()
+            .as_ref()
+            .map(|d| visible_services(&d.services, &self.services_state).len())
+            .unwrap_or(0)
+    }
+
+    fn selected_process_row(&self) -> Option<ProcessRow> {
+        let data = self.process_data.read();
+        let mut rows = process_rows(&data.as_ref()?.processes, &self.processes_state);
+        let index = self
+            .processes_state
+            .selected_index
+            .min(rows.len().saturating_sub(1));
+        (index < rows.len()).then(|| rows.swap_remove(index))
+    }
+
+    /// Pin or unpin the selected process, keeping it selected as it moves.
+    fn toggle_process_pin(&mut self) {
+        let Some(row) = self.selected_process_row() else {
+            return;
+        };
+        let key = (row.process.pid, row.process.name);
+        if !self.processes_state.pinned.remove(&key) {
+            self.processes_state.pinned.insert(key);
+        }
+        self.select_process_row(Some(row.process.pid));
+    }
+
+    /// Pin or unpin the selected service, keeping it selected as it moves.
+    fn toggle_service_pin(&mut self) {
+        let name = {
+            let data = self.service_data.read();
+            let Some(data) = data.as_ref() else {
+                return;
+            };
+            let services = visible_services(&data.services, &self.services_state);
+            match services.get(self.services_state.selected_index) {
+                Some(service) => service.name.clone(),
+                None => return,
+            }
+        };
+        if !self.services_state.pinned.remove(&name) {
+            self.services_state.pinned.insert(name.clone());
+        }
+
+        let index = {
+            let data = self.service_data.read();
+            data.as_ref().and_then(|d| {
+                visible_services(&d.services, &self.services_state)
+                    .iter()
+                    .position(|s| s.name == name)
+            })
+        };
+        self.services_state.selected_index = index.unwrap_or(0);
+    }
+
+    /// Switch to the Processes tab with `input`'s PID selected.
+    fn jump_to_pid(&mut self, input: &str) {
+        let Ok(pid) = input.trim().parse::<u32>() else {
+            if !input.trim().is_empty() {
+                self.push_notification(NotificationLevel::Warn, format!("Not a PID: {}", input));
+            }
+            return;
+        };
+        self.tab_manager.select(TabType::Processes);
+        if self.tab_manager.current() != TabType::Processes {
+            self.push_notification(NotificationLevel::Warn, "The Processes tab is not enabled");
+            return;
+        }
+
+        let found = {
+            let data = self.process_data.read();
+            data.as_ref()
+                .is_some_and(|d| self.processes_state.select_pid(&d.processes, pid))
+        };
+        if !found {
+            self.push_notification(NotificationLevel::Warn, format!("No process with PID {}", pid));
+        }
+    }
+
+    fn select_process_row(&mut self, pid: Option<u32>) {
+        let index = pid.and_then(|pid| {
+            let data = self.process_data.read();
+            process_rows(&data.as_ref()?.processes, &self.processes_state)
+                .iter()
+                .position(|row| row.process.pid == pid)
+        });
+        self.processes_state.selected_index = index.unwrap_or(0);
+        self.processes_state.scroll_offset = 0;
+    }
+
+    /// Move the selection in the focused network panel, stopping at its last row.
+    fn move_network_selection(&mut self, delta: isize) {
+        let count = {
+            let data = self.network_data.read();
+            data.as_ref().map_or(0, |d| match self.network_state.view {
+                NetworkView::Interfaces => {
+                    visible_interfaces(&d.interfaces, self.network_state.show_all_interfaces)
+                        .len()
+                }
+                NetworkView::Connections => {
+                    visible_connections(
+                        &d.connections,
+                        self.network_state.connection_filter,
+                        self.network_state.family_filter,
+                    )
+                    .len()
+                }
+                NetworkView::Consumers => d.bandwidth_consumers.len(),
+            })
+        };
+        let state = &mut self.network_state;
+        let last = count.saturating_sub(1);
+        if state.view == NetworkView::Interfaces {
+            state.selected_interface = state.selected_interface.saturating_add_signed(delta).min(last);
+            return;
+        }
+        state.selected_index = state.selected_index.saturating_add_signed(delta).min(last);
+        if state.selected_index < state.scroll_offset {
+            state.scroll_offset = state.selected_index;
+        }
+    }
+
+    /// Move the table selection one row per wheel notch, keeping it in view
+    /// the same way keyboard navigation does.
+    fn scroll_table_by_wheel(&mut self, down: bool) {
+        match self.tab_manager.current() {
+            TabType::Network => self.move_network_selection(if down { 1 } else { -1 }),
+            TabType::Processes => {
+                let process_count = self.visible_process_count();
+                let state = &mut self.processes_state;
+                if down {
+                    if state.selected_index + 1 < process_count {
+                        state.selected_index += 1;
+                    }
+                } else {
+                    state.selected_index = state.selected_index.saturating_sub(1);
+                }
+                if state.selected_index < state.scroll_offset {
+                    state.scroll_offset = state.selected_index;
+                }
+            }
+            TabType::Services => {
+                if self.services_state.focused_panel == ServicesPanelFocus::Details {
+                    self.services_state.details_scroll = if down {
+                        self.services_state.details_scroll + 1
+                    } else {
+                        self.services_state.details_scroll.saturating_sub(1)
+                    };
+                    return;
+                }
+                let service_count = self.visible_service_count();
+                let state = &mut self.services_state;
+                if down {
+                    if state.selected_index + 1 < service_count {
+                        state.selected_index += 1;
+                    }
+                } else {
+                    state.selected_index = state.selected_index.saturating_sub(1);
+                }
+                if state.selected_index < state.scroll_offset {
+                    state.scroll_offset = state.selected_index;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Runs Start/Stop/Restart-Service for the selected row in the background.
+    /// The row shows a pending status until the next refresh replaces it.
+    fn control_selected_service(&mut self, action: KeyAction) {
+        if self.refuse_read_only("service control") {
+            return;
+        }
+        let selected = self.service_data.read().as_ref().and_then(|data| {
+            let services = visible_services(&data.services, &self.services_state);
+            let index = self
+                .services_state
+                .selected_index
+                .min(services.len().saturating_sub(1));
+            services.get(index).cloned()
+        });
+        let Some(service) = selected else {
+            return;
+        };
+        if !self.elevated {
+            *self.service_error.write() = Some(format!(
+                "Controlling '{}' requires administrator",
+                service.display_name
+            ));
+            return;
+        }
+
+        if matches!(action, KeyAction::StopService | KeyAction::RestartService)
+            && !service.can_stop
+        {
+            *self.service_error.write() = Some(format!(
+                "Service '{}' cannot be stopped",
+                service.display_name
+            ));
+            return;
+        }
+
+        let pending = match action {
+            KeyAction::StartService => ServiceStatus::StartPending,
+            _ => ServiceStatus::StopPending,
+        };
+        if let Some(data) = self.service_data.write().as_mut() {
+            if let Some(entry) = data.services.iter_mut().find(|s| s.name == service.name) {
+                entry.status = pending;
+            }
+        }
+
+        let ps = {
+            let config = self.config.read();
+            PowerShellExecutor::new(
+                config.powershell.executable.clone(),
+                config.powershell.timeout_seconds,
+                0,
+                false,
+            )
+            .with_remote(config.remote.target())
+        };
+        let service_error = Arc::clone(&self.service_error);
+
+        tokio::spawn(async move {
+            let result = match ServiceMonitor::new(ps) {
+                Ok(monitor) => match action {
+                    KeyAction::StartService => monitor.start_service(&service.name).await,
+                    KeyAction::StopService => monitor.stop_service(&service.name).await,
+                    _ => monitor.restart_service(&service.name).await,
+                },
+                Err(e) => Err(e),
+            };
+
+            if let Err(e) = result {
+                let message = e.to_string();
+                log::error!("Service control failed for {}: {}", service.name, message);
+                *service_error.write() = Some(if services::is_access_denied(&message) {
+                    format!(
+                        "Access denied controlling '{}'. Run TUI+ as Administrator.",
+                        service.display_name
+                    )
+                } else {
+                    format!("Failed to control '{}': {}", service.display_name, message)
+                });
+            }
+        });
+    }
+
+    /// Ask to confirm flushing DNS, or toggling the selected adapter.
+    fn request_network_action(&mut self, flush_dns: bool) {
+        if self.refuse_read_only("network changes") {
+            return;
+        }
+        let action = if flush_dns {
+            Some(NetworkAction::FlushDns)
+        } else {
+            self.network_data.read().as_ref().and_then(|data| {
+                let state = &self.network_state;
+                let interfaces = visible_interfaces(&data.interfaces, state.show_all_interfaces);
+                let index = state.selected_interface.min(interfaces.len().saturating_sub(1));
+                interfaces.get(index).map(|iface| NetworkAction::SetAdapterEnabled {
+                    name: iface.name.clone(),
+                    enabled: !iface.status.eq_ignore_ascii_case("up"),
+                })
+            })
+        };
+        let Some(action) = action else {
+            return;
+        };
+        if !self.elevated {
+            self.push_notification(
+                NotificationLevel::Warn,
+                format!("{} requires administrator", action.describe()),
+            );
+            return;
+        }
+        self.network_state.pending_action = Some(action);
+    }
+
+    /// Runs a confirmed adapter or DNS change in the background. The adapter
+    /// shows a pending status until the next refresh replaces it.
+    fn run_network_action(&mut self, action: NetworkAction) {
+        if self.refuse_read_only("network changes") {
+            return;
+        }
+        if let NetworkAction::SetAdapterEnabled { name, enabled } = &action {
+            if let Some(data) = self.network_data.write().as_mut() {
+                if let Some(iface) = data.interfaces.iter_mut().find(|i| &i.name == name) {
+                    iface.status = if *enabled { "Enabling" } else { "Disabling" }.to_string();
+                }
+            }
+        }
+
+        let ps = {
+            let config = self.config.read();
+            PowerShellExecutor::new(
+                config.powershell.executable.clone(),
+                config.powershell.timeout_seconds,
+                0,
+                false,
+            )
+            .with_remote(config.remote.target())
+        };
+        let notifier = self.notification_tx.clone();
+
+        tokio::spawn(async move {
+            let result = match NetworkMonitor::new(ps) {
+                Ok(monitor) => match &action {
+                    NetworkAction::SetAdapterEnabled { name, enabled } => {
+                        monitor.set_adapter_enabled(name, *enabled).await
+                    }
+                    NetworkAction::FlushDns => monitor.flush_dns().await,
+                },
+                Err(e) => Err(e),
+            };
+
+            let _ = notifier.send(match result {
+                Ok(()) => Notification::info(format!("{}: done", action.describe())),
+                Err(e) => {
+                    log::error!("{} failed: {}", action.describe(), e);
+                    Notification::error(format!("{} failed: {}", action.describe(), e))
+                }
+            });
+        });
+    }
+
+    /// Copy a text form of the selected row on the current tab.
+    fn copy_selection(&mut self) {
+        let text = match self.tab_manager.current() {
+            TabType::Processes => self
+                .selected_process_row()
+                .map(|row| format!("{} (PID {})", row.process.name, row.process.pid)),
+            TabType::Services => self.service_data.read().as_ref().and_then(|data| {
+                visible_services(&data.services, &self.services_state)
+                    .get(self.services_state.selected_index)
+                    .map(|service| service.name.clone())
+            }),
+            TabType::Network => self.network_data.read().as_ref().and_then(|data| {
+                let state = &self.network_state;
+                match state.view {
+                    NetworkView::Interfaces => {
+                        visible_interfaces(&data.interfaces, state.show_all_interfaces)
+                            .get(state.selected_interface)
+                            .map(|iface| format!("{} {}", iface.name, iface.ipv4_address))
+                    }
+                    NetworkView::Connections => {
+                        visible_connections(
+                            &data.connections,
+                            state.connection_filter,
+                            state.family_filter,
+                        )
+                        .get(state.selected_index)
+                        .map(|conn| conn.remote_endpoint())
+                    }
+                    NetworkView::Consumers => data
+                        .bandwidth_consumers
+                        .get(state.selected_index)
+                        .map(|c| format!("{} (PID {})", c.process_name, c.pid)),
+                }
+            }),
+            TabType::DiskAnalyzer => self.disk_analyzer_data.read().as_ref().and_then(|data| {
+                let drive = data.drives.get(self.disk_analyzer_state.selected_drive)?;
+                visible_folders(drive, &self.disk_analyzer_state.search)
+                    .get(self.disk_analyzer_state.selected_folder)
+                    .map(|folder| folder.path.clone())
+            }),
+            _ => None,
+        };
+        let Some(text) = text else {
+            return;
+        };
+
+        let message = match clipboard::copy_text(&text) {
+            Ok(()) => format!("Copied! {}", text),
+            Err(e) => {
+                log::warn!("Clipboard unavailable, not copying '{}': {}", text, e);
+                "Clipboard unavailable (see log)".to_string()
+            }
+        };
+        self.flash = Some((message, Instant::now()));
+    }
+
+    /// Copy the current tab's table, as displayed, as a Markdown table.
+    fn copy_markdown(&mut self) {
+        let table = match self.tab_manager.current() {
+            TabType::Processes => self.process_data.read().as_ref().map(|data| {
+                let rows = process_rows(&data.processes, &self.processes_state);
+                let config = self.config.read();
+                let table = ProcessTable {
+                    rows: &rows,
+                    columns: &config.monitors.processes.columns(),
+                    fmt: FormatOptions::from_config(&config),
+                };
+                (rows.len(), table.to_markdown())
+            }),
+            TabType::Services => self.service_data.read().as_ref().map(|data| {
+                let services = visible_services(&data.services, &self.services_state);
+                (services.len(), services.to_markdown())
+            }),
+            TabType::Network if self.network_state.view == NetworkView::Connections => {
+                self.network_data.read().as_ref().map(|data| {
+                    let connections = visible_connections(
+                        &data.connections,
+                        self.network_state.connection_filter,
+                        self.network_state.family_filter,
+                    );
+                    (connections.len(), connections.to_markdown())
+                })
+            }
+            TabType::Disk => self
+                .disk_data
+                .read()
+                .as_ref()
+                .map(|data| (data.logical_drives.len(), data.logical_drives.to_markdown())),
+            _ => None,
+        };
+        let Some((count, markdown)) = table else {
+            self.flash = Some(("No table to copy on this tab".to_string(), Instant::now()));
+            return;
+        };
+
+        match clipboard::copy_text(&markdown) {
+            Ok(()) => self.push_notification(
+                NotificationLevel::Info,
+                format!("Copied! {} rows as a Markdown table", count),
+            ),
+            Err(e) => {
+                log::warn!("Clipboard unavailable, not copying Markdown table: {}", e);
+                self.push_notification(NotificationLevel::Warn, "Clipboard unavailable (see log)");
+            }
+        }
+    }
+
+    /// Copy the error console as a Markdown table.
+    fn copy_monitor_health(&mut self) {
+        let markdown = self.monitor_health().to_markdown();
+        match clipboard::copy_text(&markdown) {
+            Ok(()) => self.push_notification(
+                NotificationLevel::Info,
+                "Copied! Monitor status as a Markdown table",
+            ),
+            Err(e) => {
+                log::warn!("Clipboard unavailable, not copying monitor status: {}", e);
+                self.push_notification(NotificationLevel::Warn, "Clipboard unavailable (see log)");
+            }
+        }
+    }
+
+    /// Write a diagnostic report from the data already on screen, in the
+    /// working directory.
+    fn export_report(&mut self) {
+        let config = self.config.read().clone();
+        let monitors = &config.monitors;
+        let mut errors = BTreeMap::new();
+        for (name, enabled, error) in [
+            ("cpu", monitors.cpu.enabled, &self.cpu_error),
+            ("gpu", monitors.gpu.enabled, &self.gpu_error),
+            ("ram", monitors.ram.enabled, &self.ram_error),
+            ("disk", monitors.disk.enabled, &self.disk_error),
+            ("network", monitors.network.enabled, &self.network_error),
+            ("processes", monitors.processes.enabled, &self.process_error),
+            ("services", monitors.services.enabled, &self.service_error),
+        ] {
+            if let Some(error) = error.read().clone().filter(|_| enabled) {
+                errors.insert(name, error);
+            }
+        }
+
+        let snapshot = Snapshot {
+            timestamp: Local::now().to_rfc3339(),
+            cpu: self.cpu_data.read().clone(),
+            gpu: self.gpu_data.read().clone(),
+            ram: self.ram_data.read().clone(),
+            disk: self.disk_data.read().clone(),
+            network: self.network_data.read().clone(),
+            processes: self.process_data.read().clone(),
+            services: self.service_data.read().clone(),
+            system: self.system_info.read().clone(),
+            errors,
+        };
+        let path = std::path::PathBuf::from(report::default_file_name());
+        match report::write(&path, &snapshot, &config) {
+            Ok(()) => {
+                log::info!("Wrote diagnostic report to {}", path.display());
+                self.push_notification(
+                    NotificationLevel::Info,
+                    format!("Report written to {}", path.display()),
+                );
+            }
+            Err(e) => {
+                log::error!("Failed to write diagnostic report: {}", e);
+                self.push_notification(NotificationLevel::Error, format!("Report failed: {}", e));
+            }
+        }
+    }
+
+    /// Step the current tab's refresh interval. Monitor tasks re-read the
+    /// interval from the shared config on every pass, so this applies live.
+    fn adjust_refresh_interval(&mut self, faster: bool) {
+        let tab = self.tab_manager.current();
+        let (interval, persist) = {
+            let mut config = self.config.write();
+            let persist = config.general.persist_refresh_hotkeys;
+            let Some(interval) = config.refresh_interval_mut(tab) else {
+                return;
+            };
+            *interval = next_refresh_interval(*interval, faster);
+            (*interval, persist)
+        };
+
+        let mut message = format!("{} refresh: {} ms", tab.as_str(), interval);
+        if persist {
+            if let Some(path) = Config::refresh_interval_path(tab) {
+                let value = toml_edit::Value::from(interval as i64);
+                match self.config_manager.set_value(path, value) {
+                    Ok(()) => message.push_str(" (saved)"),
+                    Err(e) => {
+                        log::warn!("Failed to save refresh interval: {:#}", e);
+                        message.push_str(" (not saved, see log)");
+                    }
+                }
+            }
+        }
+        self.flash = Some((message, Instant::now()));
+    }
+
+    /// In read-only mode, flash why `action` did nothing and return true.
+    fn refuse_read_only(&mut self, action: &str) -> bool {
+        if self.read_only {
+            self.flash = Some((
+                format!("Read-only mode: {} disabled", action),
+                Instant::now(),
+            ));
+        }
+        self.read_only
+    }
+
+    /// Slow every monitor down once there has been no input for
+    /// `general.idle_after_seconds`.
+    pub fn update_idle(&mut self) {
+        let (idle_after, factor) = {
+            let config = self.config.read();
+            (config.general.idle_after_seconds, config.general.idle_factor.max(1))
+        };
+        let idle = idle_after > 0 && self.last_input.elapsed() >= Duration::from_secs(idle_after);
+        let scale = if idle { factor } else { 1 };
+        if self.refresh_scale.set(scale) != scale && idle {
+            log::info!("No input for {}s, refreshing {}x slower", idle_after, factor);
+        }
+    }
+
+    /// Back to the configured refresh rates on any key or mouse input.
+    fn mark_active(&mut self) {
+        self.last_input = Instant::now();
+        if self.refresh_scale.set(1) > 1 {
+            log::info!("Input received, leaving idle mode");
+        }
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.refresh_scale.get() > 1
+    }
+
+    /// Current multiplier on every monitor's refresh interval.
+    pub fn refresh_scale(&self) -> u32 {
+        self.refresh_scale.get()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(AtomicOrdering::Relaxed)
+    }
+
+    /// The flash message while it is still fresh.
+    pub fn flash_message(&self) -> Option<&str> {
+        self.flash
+            .as_ref()
+            .filter(|(_, at)| at.elapsed() < Duration::from_secs(3))
+            .map(|(message, _)| message.as_str())
+    }
+
+    /// Why config.toml was rejected, while the file on disk stays broken.
+    pub fn config_error(&self) -> Option<String> {
+        self.config_manager.load_error()
+    }
+
+    /// Save the rows the Processes table currently shows, in the same order.
+    fn export_processes(&mut self) {
+        let rows = match self.process_data.read().as_ref() {
+            Some(data) => process_rows(&data.processes, &self.processes_state),
+            None => return,
+        };
+
+        let export_dir = std::path::Path::new(".");
+        let status = match processes::export_processes_jsonl(
+            rows.iter().map(|row| &row.process),
+            export_dir,
+        ) {
+            Ok(path) => {
+                log::info!("Exported {} processes to {}", rows.len(), path.display());
+                format!("Exported {} processes to {}", rows.len(), path.display())
+            }
+            Err(e) => {
+                log::error!("Failed to export processes: {}", e);
+                format!("Export failed: {}", e)
+            }
+        };
+        self.processes_state.action_status = Some(status);
+    }
+
+    /// Open the detail popup for the selected process and look up the
+    /// fields the regular refresh doesn't collect in the background.
+    fn open_process_detail(&mut self) {
+        let Some(row) = self.selected_process_row() else {
+            return;
+        };
+        let ps = {
+            let config = self.config.read();
+            PowerShellExecutor::new(
+                config.powershell.executable.clone(),
+                config.powershell.timeout_seconds,
+                0,
+                false,
+            )
+            .with_remote(config.remote.target())
+        };
+
+        let details = Arc::new(RwLock::new(None));
+        let (pid, task_details) = (row.process.pid, Arc::clone(&details));
+        tokio::spawn(async move {
+            let result = match ProcessMonitor::new(ps) {
+                Ok(monitor) => monitor.get_details(pid).await,
+                Err(e) => Err(e),
+            };
+            if let Err(e) = &result {
+                log::warn!("Failed to read details for PID {}: {}", pid, e);
+            }
+            *task_details.write() = Some(result.map_err(|e| e.to_string()));
+        });
+
+        self.processes_state.detail = Some(ProcessDetailView {
+            process: row.process,
+            details,
+        });
+    }
+
+    async fn kill_process(&mut self, target: ProcessKillTarget) {
+        if self.refuse_read_only("kill") {
+            return;
+        }
+        let ps = {
+            let config = self.config.read();
+            PowerShellExecutor::new(
+                config.powershell.executable.clone(),
+                config.powershell.timeout_seconds,
+                0,
+                false,
+            )
+            .with_remote(config.remote.target())
+        };
+
+        let result = match ProcessMonitor::new(ps) {
+            Ok(monitor) => monitor.kill_process(target.pid).await,
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(()) => {
+                log::info!("Killed process {} (PID {})", target.name, target.pid);
+                self.processes_state.action_status =
+                    Some(format!("Killed {} (PID {})", target.name, target.pid));
+                if let Some(data) = self.process_data.write().as_mut() {
+                    data.processes.retain(|p| p.pid != target.pid);
+                }
+            }
+            Err(e) => {
+                log::error!("Failed to kill PID {}: {}", target.pid, e);
+                let message = e.to_string();
+                // Reported next to the table, like export failures, so the
+                // process list stays visible
+                self.processes_state.action_status =
+                    Some(if !self.elevated && services::is_access_denied(&message) {
+                        format!(
+                            "Killing {} (PID {}) requires administrator",
+                            target.name, target.pid
+                        )
+                    } else {
+                        format!("Failed to kill {} (PID {}): {}", target.name, target.pid, e)
+                    });
+            }
+        }
+    }
+
+    /// Open the delete confirmation for the selected analyzer folder.
+    fn request_analyzer_delete(&mut self) {
+        if self.refuse_read_only("delete") {
+            return;
+        }
+        let target = {
+            let data = self.disk_analyzer_data.read();
+            data.as_ref()
+                .and_then(|d| d.drives.get(self.disk_analyzer_state.selected_drive))
+                .and_then(|drive| {
+                    visible_folders(drive, &self.disk_analyzer_state.search)
+                        .get(self.disk_analyzer_state.selected_folder)
+                        .map(|folder| AnalyzerDeleteTarget {
+                            drive: drive.letter.clone(),
+                            name: folder.name.clone(),
+                            path: folder.path.clone(),
+                            size: folder.size,
+                        })
+                })
+        };
+        let Some(target) = target else {
+            return;
+        };
+
+        let state = &mut self.disk_analyzer_state;
+        let is_mount_root = target.path.trim_end_matches(['\\', '/'])
+            == target.drive.trim_end_matches(['\\', '/']);
+        if is_drive_root(&target.path) || is_mount_root {
+            state.action_status = Some(format!("Refusing to delete drive root {}", target.path));
+            return;
+        }
+
+        let threshold_mb = self.config.read().integrations.everything.delete_double_confirm_mb;
+        state.delete_needs_second_confirm =
+            threshold_mb > 0 && target.size > threshold_mb.saturating_mul(1024 * 1024);
+        state.pending_delete = Some(target);
+        state.show_delete_confirm = true;
+    }
+
+    fn set_analyzer_search(&mut self, search: String) {
+        if search != self.disk_analyzer_state.search {
+            self.disk_analyzer_state.search = search;
+            self.disk_analyzer_state.selected_folder = 0;
+            self.sync_treemap_children();
+        }
+    }
+
+    /// Size the selected folder's subfolders for the treemap, unless that
+    /// folder is already scanned. Only local folders can be drilled into.
+    /// A scan still running for a previous selection is cancelled.
+    fn sync_treemap_children(&mut self) {
+        let local = self.config.read().remote.target().is_none();
+        let path = if self.disk_analyzer_state.treemap && local {
+            let data = self.disk_analyzer_data.read();
+            data.as_ref()
+                .and_then(|d| d.drives.get(self.disk_analyzer_state.selected_drive))
+                .and_then(|drive| {
+                    visible_folders(drive, &self.disk_analyzer_state.search)
+                        .get(self.disk_analyzer_state.selected_folder)
+                        .map(|folder| folder.path.clone())
+                })
+        } else {
+            None
+        };
+        let current = self.disk_analyzer_state.treemap_children.as_ref();
+        if current.map(|children| &children.path) == path.as_ref() {
+            return;
+        }
+        if let Some(stale) = self.disk_analyzer_state.treemap_children.take() {
+            stale.cancelled.store(true, AtomicOrdering::Relaxed);
+        }
+        let Some(path) = path else {
+            return;
+        };
+
+        let timeout = Duration::from_secs(self.config.read().powershell.timeout_seconds.max(1));
+        let folders = Arc::new(RwLock::new(None));
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let task_path = path.clone();
+        let (task_folders, task_cancelled) = (Arc::clone(&folders), Arc::clone(&cancelled));
+        tokio::task::spawn_blocking(move || {
+            let found = disk_analyzer::scan_subfolders(&task_path, timeout, &task_cancelled);
+            *task_folders.write() = Some(found);
+        });
+        self.disk_analyzer_state.treemap_children = Some(FolderChildren {
+            path,
+            folders,
+            cancelled,
+        });
+    }
+
+    fn export_analyzer_drive(&mut self) {
+        let (format, max_results) = {
+            let config = self.config.read();
+            (
+                config.integrations.everything.export_format.clone(),
+                config.integrations.everything.max_depth,
+            )
+        };
+        let data = self.disk_analyzer_data.read();
+        let Some(drive) = data
+            .as_ref()
+            .and_then(|d| d.drives.get(self.disk_analyzer_state.selected_drive))
+        else {
+            return;
+        };
+
+        let export_dir = std::path::Path::new(".");
+        let status = match disk_analyzer::export_drive(drive, &format, max_results, export_dir) {
+            Ok((path, truncated)) => {
+                log::info!("Exported drive {} to {}", drive.letter, path.display());
+                let note = if truncated {
+                    format!(" (top {} folders only)", max_results)
+                } else {
+                    String::new()
+                };
+                format!("Exported to {}{}", path.display(), note)
+            }
+            Err(e) => {
+                log::error!("Failed to export drive {}: {}", drive.letter, e);
+                format!("Export failed: {}", e)
+            }
+        };
+        drop(data);
+        self.disk_analyzer_state.action_status = Some(status);
+    }
+
+    /// Hash the selected folder's files on a blocking thread and show
+    /// groups of identical ones as they're found.
+    fn start_duplicate_scan(&mut self) {
+        let (min_size, max_results, remote) = {
+            let config = self.config.read();
+            let everything = &config.integrations.everything;
+            (
+                everything.duplicate_min_size_mb.saturating_mul(1024 * 1024),
+                everything.max_depth,
+                config.remote.target(),
+            )
+        };
+        if let Some(target) = remote {
+            self.disk_analyzer_state.action_status = Some(format!(
+                "Duplicate scan only reads local files, not {}",
+                target.destination()
+            ));
+            return;
+        }
+        let root = {
+            let data = self.disk_analyzer_data.read();
+            data.as_ref()
+                .and_then(|d| d.drives.get(self.disk_analyzer_state.selected_drive))
+                .and_then(|drive| {
+                    visible_folders(drive, &self.disk_analyzer_state.search)
+                        .get(self.disk_analyzer_state.selected_folder)
+                        .map(|folder| folder.path.clone())
+                })
+        };
+        let Some(root) = root else {
+            return;
+        };
+
+        let progress = Arc::new(DuplicateProgress::default());
+        let groups = Arc::new(RwLock::new(None));
+        let (task_root, task_progress, task_groups) =
+            (root.clone(), Arc::clone(&progress), Arc::clone(&groups));
+        tokio::task::spawn_blocking(move || {
+            let found = disk_analyzer::find_duplicates(
+                std::path::Path::new(&task_root),
+                min_size,
+                max_results,
+                &task_progress,
+            );
+            *task_groups.write() = Some(found);
+        });
+
+        self.disk_analyzer_state.action_status = None;
+        self.disk_analyzer_state.duplicates = Some(DuplicateScan {
+            root,
+            progress,
+            groups,
+            selected_group: 0,
+            confirm_delete: false,
+        });
+    }
+
+    /// Show the selected folder (or the kept copy of the selected duplicate
+    /// group) in the file manager, or open a terminal there.
+    fn open_analyzer_selection(&mut self, terminal: bool) {
+        let remote = self.config.read().remote.target();
+        if let Some(target) = remote {
+            self.push_notification(
+                NotificationLevel::Warn,
+                format!("Can't open folders on {} from here", target.destination()),
+            );
+            return;
+        }
+        let path = match self.disk_analyzer_state.duplicates.as_ref() {
+            Some(scan) => scan.groups.read().as_ref().and_then(|groups| {
+                groups
+                    .get(scan.selected_group)
+                    .and_then(|group| group.paths.first().cloned())
+            }),
+            None => {
+                let data = self.disk_analyzer_data.read();
+                data.as_ref()
+                    .and_then(|d| d.drives.get(self.disk_analyzer_state.selected_drive))
+                    .and_then(|drive| {
+                        visible_folders(drive, &self.disk_analyzer_state.search)
+                            .get(self.disk_analyzer_state.selected_folder)
+                            .map(|folder| folder.path.clone())
+                    })
+            }
+        };
+        let Some(path) = path else {
+            return;
+        };
+
+        let result = if terminal {
+            launcher::open_terminal(std::path::Path::new(&path))
+        } else {
+            launcher::open_in_file_manager(std::path::Path::new(&path))
+        };
+        match result {
+            Ok(()) => self.push_notification(NotificationLevel::Info, format!("Opened {}", path)),
+            Err(e) => {
+                log::warn!("Failed to open {}: {:#}", path, e);
+                self.push_notification(
+                    NotificationLevel::Error,
+                    format!("Could not open {}: {}", path, e),
+                );
+            }
+        }
+    }
+
+    fn close_duplicate_scan(&mut self) {
+        if let Some(scan) = self.disk_analyzer_state.duplicates.take() {
+            scan.progress.cancelled.store(true, AtomicOrdering::Relaxed);
+            self.disk_analyzer_state.action_status = None;
+        }
+    }
+
+    /// Delete every copy in the selected group except the first.
+    fn delete_duplicate_copies(&mut self) {
+        if self.refuse_read_only("delete") || self.refuse_concurrent_delete() {
+            return;
+        }
+        let Some(scan) = self.disk_analyzer_state.duplicates.as_ref() else {
+            return;
+        };
+        let selected = scan.selected_group;
+        let Some(group) = scan
+            .groups
+            .read()
+            .as_ref()
+            .and_then(|groups| groups.get(selected).cloned())
+        else {
+            return;
+        };
+        let paths = group.paths.iter().skip(1).cloned().collect();
+        self.disk_analyzer_state.action_status = Some(format!(
+            "Deleting {} duplicate{}...",
+            group.paths.len() - 1,
+            if group.paths.len() == 2 { "" } else { "s" }
+        ));
+        let groups = Arc::clone(&scan.groups);
+        self.spawn_delete(
+            DeleteJob::Duplicates {
+                group,
+                selected,
+                groups,
+            },
+            paths,
+            None,
+        );
+    }
+
+    fn delete_analyzer_entry(&mut self, target: AnalyzerDeleteTarget) {
+        if self.refuse_read_only("delete") || self.refuse_concurrent_delete() {
+            return;
+        }
+        let remote = self.config.read().remote.target();
+        self.disk_analyzer_state.action_status = Some(format!("Deleting {}...", target.path));
+        let paths = vec![target.path.clone()];
+        self.spawn_delete(DeleteJob::Entry(target), paths, remote);
+    }
+
+    fn refuse_concurrent_delete(&mut self) -> bool {
+        let busy = self.disk_analyzer_state.deleting.is_some();
+        if busy {
+            self.flash = Some((
+                "Wait for the current delete to finish".to_string(),
+                Instant::now(),
+            ));
+        }
+        busy
+    }
+
+    /// Delete `paths` one by one in the background. Recycling a large folder
+    /// can take minutes, so the command timeout doesn't apply.
+    fn spawn_delete(&mut self, job: DeleteJob, paths: Vec<String>, remote: Option<SshTarget>) {
+        let ps = {
+            let config = self.config.read();
+            PowerShellExecutor::new(
+                config.powershell.executable.clone(),
+                config.powershell.timeout_seconds,
+                0,
+                false,
+            )
+            .without_timeout()
+            .with_remote(remote)
+        };
+        let results = Arc::new(RwLock::new(None));
+        let task_results = Arc::clone(&results);
+        tokio::spawn(async move {
+            let mut outcome = Vec::with_capacity(paths.len());
+            for path in paths {
+                let result = disk_analyzer::delete_entry(&ps, &path).await;
+                match &result {
+                    Ok(_) => log::info!("Deleted {}", path),
+                    Err(e) => log::error!("Failed to delete {}: {}", path, e),
+                }
+                outcome.push((path, result.map_err(|e| e.to_string())));
+            }
+            *task_results.write() = Some(outcome);
+        });
+        self.disk_analyzer_state.deleting = Some(DeleteTask { job, results });
+    }
+
+    /// Apply a finished background delete to the folder tree or duplicate
+    /// groups and report how it went.
+    pub fn update_deletes(&mut self) {
+        let Some(task) = self.disk_analyzer_state.deleting.as_ref() else {
+            return;
+        };
+        let Some(results) = task.results.write().take() else {
+            return;
+        };
+        let Some(task) = self.disk_analyzer_state.deleting.take() else {
+            return;
+        };
+        let fmt = FormatOptions::from_config(&self.config.read());
+
+        match task.job {
+            DeleteJob::Entry(target) => {
+                let Some((_, result)) = results.into_iter().next() else {
+                    return;
+                };
+                match result {
+                    Ok(deletion) => {
+                        self.disk_analyzer_state.action_status = Some(format!(
+                            "Deleted {} ({})",
+                            target.path,
+                            fmt.bytes(target.size)
+                        ));
+                        if let Some(data) = self.disk_analyzer_data.write().as_mut() {
+                            if let Some(drive) =
+                                data.drives.iter_mut().find(|d| d.letter == target.drive)
+                            {
+                                drive.remove_folder(&target.path, deletion == Deletion::Permanent);
+                                let folder_count =
+                                    visible_folders(drive, &self.disk_analyzer_state.search).len();
+                                let state = &mut self.disk_analyzer_state;
+                                state.selected_folder =
+                                    state.selected_folder.min(folder_count.saturating_sub(1));
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        self.disk_analyzer_state.action_status =
+                            Some(format!("Failed to delete {}: {}", target.path, e));
+                    }
+                }
+            }
+            DeleteJob::Duplicates {
+                group,
+                selected,
+                groups,
+            } => {
+                let mut deleted = Vec::new();
+                let mut failure = None;
+                for (path, result) in results {
+                    match result {
+                        Ok(_) => deleted.push(path),
+                        Err(e) => failure = Some(format!("Failed to delete {}: {}", path, e)),
+                    }
+                }
+
+                if let Some(list) = groups.write().as_mut() {
+                    if let Some(entry) = list.get_mut(selected) {
+                        entry.paths.retain(|path| !deleted.contains(path));
+                        if entry.paths.len() < 2 {
+                            list.remove(selected);
+                        }
+                    }
+                    // Only clamp the selection of the scan the delete came from
+                    if let Some(scan) = self.disk_analyzer_state.duplicates.as_mut() {
+                        if Arc::ptr_eq(&scan.groups, &groups) {
+                            scan.selected_group =
+                                scan.selected_group.min(list.len().saturating_sub(1));
+                        }
+                    }
+                }
+                self.disk_analyzer_state.action_status = Some(failure.unwrap_or_else(|| {
+                    format!(
+                        "Deleted {} duplicate{} ({})",
+                        deleted.len(),
+                        if deleted.len() == 1 { "" } else { "s" },
+                        fmt.bytes(group.size.saturating_mul(deleted.len() as u64))
+                    )
+                }));
+            }
+        }
+    }
+
+    async fn execute_command(&mut self) -> Result<()> {
+        if self.command_input.is_empty() || self.refuse_read_only("commands") {
+            return Ok(());
+        }
+
+        // Add to history
+        self.command_history.add(self.command_input.clone());
 
         // Execute PowerShell command
         let ps = PowerShellExecutor::new(
@@ -2514,14 +5670,18 @@ impl AppState {
             self.config.read().powershell.timeout_seconds,
             self.config.read().powershell.cache_ttl_seconds,
             self.config.read().powershell.use_cache,
-        );
+        )
+        .with_remote(self.config.read().remote.target());
 
-        match ps.execute(&self.command_input).await {
+        self.command_output_scroll = 0;
+        match ps.execute_uncached(&self.command_input).await {
             Ok(output) => {
                 log::info!("Command output: {}", output);
+                self.command_output = Some((output, true));
             }
             Err(e) => {
                 log::error!("Command failed: {}", e);
+                self.command_output = Some((e.to_string(), false));
             }
         }
 
@@ -2529,6 +5689,375 @@ impl AppState {
     }
 }
 
+/// PageUp/PageDown step: one screenful of rows, or 10 before the table has
+/// been rendered.
+fn page_rows(last_visible_rows: &Cell<usize>) -> usize {
+    match last_visible_rows.get() {
+        0 => 10,
+        rows => rows,
+    }
+}
+
+/// PIDs that must never be killed from the UI: the idle/system processes and,
+/// when the process list is `local`, ourselves.
+fn is_protected_pid(pid: u32, local: bool) -> bool {
+    pid == 0 || pid == 4 || (local && pid == std::process::id())
+}
+
+/// Accounts whose processes only an elevated user can kill.
+fn is_system_account(user: &str) -> bool {
+    let name = user.rsplit('\\').next().unwrap_or(user);
+    ["SYSTEM", "LOCAL SERVICE", "NETWORK SERVICE", "root"]
+        .iter()
+        .any(|account| name.eq_ignore_ascii_case(account))
+}
+
+/// Processes in the order the table shows them (filtered, then sorted, with
+/// pinned processes first), so a `selected_index` maps to the same row in
+/// state and UI.
+pub(crate) fn visible_processes(
+    processes: &[ProcessEntry],
+    ui_state: &ProcessesUIState,
+) -> Vec<ProcessEntry> {
+    let mut processes = processes.to_vec();
+
+    if !ui_state.filter.is_empty() {
+        let filter = ui_state.filter.to_lowercase();
+        processes.retain(|p| {
+            p.name.to_lowercase().contains(&filter) || p.pid.to_string().contains(&filter)
+        });
+    }
+
+    sort_processes(&mut processes, ui_state.sort_column, ui_state.sort_ascending);
+    if ui_state.pinned.is_empty() {
+        return processes;
+    }
+    // Pinned processes go first, each group keeping the sort order
+    let (mut pinned, rest): (Vec<_>, Vec<_>) = processes
+        .into_iter()
+        .partition(|p| is_pinned(&ui_state.pinned, p));
+    pinned.extend(rest);
+    pinned
+}
+
+fn is_pinned(pinned: &HashSet<(u32, String)>, process: &ProcessEntry) -> bool {
+    !pinned.is_empty() && pinned.contains(&(process.pid, process.name.clone()))
+}
+
+/// A row of the Processes table; in tree view it carries the drawing prefix
+/// and the totals of the subtree rooted at the process.
+#[derive(Debug, Clone)]
+pub(crate) struct ProcessRow {
+    pub process: ProcessEntry,
+    pub pinned: bool,
+    pub prefix: String,
+    pub has_children: bool,
+    pub collapsed: bool,
+    pub subtree_cpu: f32,
+    pub subtree_memory: u64,
+}
+
+/// Rows in the order the table shows them: the flat sorted list, or in tree
+/// view the same processes nested under their parents (siblings keep the
+/// sort order with pinned ones first, children of collapsed parents are
+/// hidden).
+pub(crate) fn process_rows(
+    processes: &[ProcessEntry],
+    ui_state: &ProcessesUIState,
+) -> Vec<ProcessRow> {
+    let processes = visible_processes(processes, ui_state);
+
+    if !ui_state.tree_view {
+        return processes
+            .into_iter()
+            .map(|process| ProcessRow {
+                pinned: is_pinned(&ui_state.pinned, &process),
+                subtree_cpu: process.cpu_usage,
+                subtree_memory: process.memory,
+                process,
+                prefix: String::new(),
+                has_children: false,
+                collapsed: false,
+            })
+            .collect();
+    }
+
+    let index_by_pid: HashMap<u32, usize> = processes
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (p.pid, i))
+        .collect();
+
+    // Processes whose parent is gone (or filtered out) become roots
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); processes.len()];
+    let mut roots = Vec::new();
+    for (i, process) in processes.iter().enumerate() {
+        match process.parent_pid.and_then(|ppid| index_by_pid.get(&ppid)) {
+            Some(&parent) if parent != i => children[parent].push(i),
+            _ => roots.push(i),
+        }
+    }
+
+    let mut tree = ProcessTree {
+        processes: &processes,
+        children,
+        collapsed: &ui_state.collapsed,
+        pinned: &ui_state.pinned,
+        visited: vec![false; processes.len()],
+    };
+    let mut rows = Vec::with_capacity(processes.len());
+    for &root in &roots {
+        tree.push_subtree(root, "", None, &mut rows);
+    }
+
+    // PID reuse can leave parent links forming a cycle with no root; show
+    // those processes at the top level rather than dropping them
+    for i in 0..processes.len() {
+        if !tree.visited[i] {
+            tree.push_subtree(i, "", None, &mut rows);
+        }
+    }
+
+    rows
+}
+
+struct ProcessTree<'a> {
+    processes: &'a [ProcessEntry],
+    children: Vec<Vec<usize>>,
+    collapsed: &'a HashSet<u32>,
+    pinned: &'a HashSet<(u32, String)>,
+    visited: Vec<bool>,
+}
+
+impl ProcessTree<'_> {
+    /// Append `index` and (unless collapsed) its descendants to `rows`,
+    /// returning the subtree's total CPU and memory. `last_sibling` is
+    /// `None` for roots, which are drawn without a connector.
+    fn push_subtree(
+        &mut self,
+        index: usize,
+        indent: &str,
+        last_sibling: Option<bool>,
+        rows: &mut Vec<ProcessRow>,
+    ) -> (f32, u64) {
+        self.visited[index] = true;
+        let process = &self.processes[index];
+        let kids: Vec<usize> = self.children[index]
+            .iter()
+            .copied()
+            .filter(|&child| !self.visited[child])
+            .collect();
+        let is_collapsed = !kids.is_empty() && self.collapsed.contains(&process.pid);
+
+        let (prefix, child_indent) = match last_sibling {
+            Some(true) => (format!("{}└─ ", indent), format!("{}   ", indent)),
+            Some(false) => (format!("{}├─ ", indent), format!("{}│  ", indent)),
+            None => (String::new(), String::new()),
+        };
+
+        let row_index = rows.len();
+        rows.push(ProcessRow {
+            process: process.clone(),
+            pinned: is_pinned(self.pinned, process),
+            prefix,
+            has_children: !kids.is_empty(),
+            collapsed: is_collapsed,
+            subtree_cpu: process.cpu_usage,
+            subtree_memory: process.memory,
+        });
+
+        let mut cpu = process.cpu_usage;
+        let mut memory = process.memory;
+        // Collapsed subtrees are still walked for their totals, just not shown
+        let mut hidden = Vec::new();
+        for (n, &child) in kids.iter().enumerate() {
+            if self.visited[child] {
+                continue;
+            }
+            let target = if is_collapsed { &mut hidden } else { &mut *rows };
+            let (child_cpu, child_memory) =
+                self.push_subtree(child, &child_indent, Some(n + 1 == kids.len()), target);
+            cpu += child_cpu;
+            memory += child_memory;
+        }
+
+        rows[row_index].subtree_cpu = cpu;
+        rows[row_index].subtree_memory = memory;
+        (cpu, memory)
+    }
+}
+
+/// Analyzer folders whose name or path contains `search` (case-insensitive).
+pub(crate) fn visible_folders<'a>(drive: &'a AnalyzedDrive, search: &str) -> Vec<&'a RootFolderInfo> {
+    let search = search.to_lowercase();
+    drive
+        .root_folders
+        .iter()
+        .filter(|folder| {
+            search.is_empty()
+                || folder.name.to_lowercase().contains(&search)
+                || folder.path.to_lowercase().contains(&search)
+        })
+        .collect()
+}
+
+/// Refresh intervals the `+`/`-` hotkeys step through, in milliseconds.
+const REFRESH_STEPS_MS: &[u64] = &[100, 250, 500, 1000, 2000, 5000, 10_000, 30_000, 60_000];
+
+/// The next step faster (shorter) or slower (longer) than `current`, which
+/// need not be one of the steps itself.
+fn next_refresh_interval(current: u64, faster: bool) -> u64 {
+    if faster {
+        REFRESH_STEPS_MS
+            .iter()
+            .rev()
+            .copied()
+            .find(|&step| step < current)
+            .unwrap_or(REFRESH_STEPS_MS[0])
+    } else {
+        REFRESH_STEPS_MS
+            .iter()
+            .copied()
+            .find(|&step| step > current)
+            .unwrap_or(REFRESH_STEPS_MS[REFRESH_STEPS_MS.len() - 1])
+    }
+}
+
+/// Interfaces kept by `interface_filter`, or all of them in the All view.
+pub(crate) fn visible_interfaces(
+    interfaces: &[NetworkInterface],
+    show_all: bool,
+) -> Vec<&NetworkInterface> {
+    interfaces
+        .iter()
+        .filter(|iface| show_all || !iface.hidden)
+        .collect()
+}
+
+/// Connections matching both filters; UDP endpoints count as listening.
+pub(crate) fn visible_connections(
+    connections: &[NetworkConnection],
+    filter: ConnectionFilter,
+    family: AddressFamilyFilter,
+) -> Vec<&NetworkConnection> {
+    connections
+        .iter()
+        .filter(|conn| family.matches(conn.address_family))
+        .filter(|conn| {
+            let is_udp = conn.protocol.eq_ignore_ascii_case("UDP");
+            match filter {
+                ConnectionFilter::Established => {
+                    !is_udp && conn.state.eq_ignore_ascii_case("Established")
+                }
+                ConnectionFilter::Listen => is_udp || conn.state.eq_ignore_ascii_case("Listen"),
+                ConnectionFilter::All => true,
+            }
+        })
+        .collect()
+}
+
+/// Services in the order the table shows them (search and status filter,
+/// then sort, then pinned services moved to the top).
+pub(crate) fn visible_services(
+    services: &[ServiceEntry],
+    ui_state: &ServicesUIState,
+) -> Vec<ServiceEntry> {
+    let search = ui_state.filter.to_lowercase();
+    let mut services: Vec<ServiceEntry> = services
+        .iter()
+        .filter(|s| {
+            search.is_empty()
+                || s.name.to_lowercase().contains(&search)
+                || s.display_name.to_lowercase().contains(&search)
+        })
+        .cloned()
+        .collect();
+
+    match ui_state.status_filter {
+        ServiceStatusFilter::Running => {
+            services.retain(|s| s.status == ServiceStatus::Running);
+        }
+        ServiceStatusFilter::Stopped => {
+            services.retain(|s| s.status == ServiceStatus::Stopped);
+        }
+        ServiceStatusFilter::All => {}
+    }
+
+    sort_services(&mut services, ui_state.sort_column, ui_state.sort_ascending);
+    if !ui_state.pinned.is_empty() {
+        // Stable, so each group keeps the sort order
+        services.sort_by_key(|s| !ui_state.pinned.contains(&s.name));
+    }
+    services
+}
+
+fn sort_services(services: &mut [ServiceEntry], column: ServiceSortColumn, ascending: bool) {
+    services.sort_by(|a, b| {
+        let cmp = match column {
+            ServiceSortColumn::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            ServiceSortColumn::DisplayName => a
+                .display_name
+                .to_lowercase()
+                .cmp(&b.display_name.to_lowercase()),
+            ServiceSortColumn::Status => {
+                // Sort by status priority: Running > Paused > Starting/Stopping > Stopped
+                let priority = |status: ServiceStatus| match status {
+                    ServiceStatus::Running => 0,
+                    ServiceStatus::Paused => 1,
+                    ServiceStatus::StartPending | ServiceStatus::ContinuePending => 2,
+                    ServiceStatus::StopPending | ServiceStatus::PausePending => 3,
+                    ServiceStatus::Stopped => 4,
+                    ServiceStatus::Unknown => 5,
+                };
+                priority(a.status).cmp(&priority(b.status))
+            }
+            ServiceSortColumn::StartType => {
+                // Sort by start type priority: Automatic > Auto (Delayed) > Manual > Disabled
+                let priority = |start_type: ServiceStartType| match start_type {
+                    ServiceStartType::Automatic => 0,
+                    ServiceStartType::AutomaticDelayedStart => 1,
+                    ServiceStartType::Manual => 2,
+                    ServiceStartType::Disabled => 3,
+                    ServiceStartType::Unknown => 4,
+                };
+                priority(a.start_type).cmp(&priority(b.start_type))
+            }
+        };
+
+        if ascending {
+            cmp
+        } else {
+            cmp.reverse()
+        }
+    });
+}
+
+pub(crate) fn sort_processes(
+    processes: &mut [ProcessEntry],
+    column: ProcessSortColumn,
+    ascending: bool,
+) {
+    processes.sort_by(|a, b| {
+        let cmp = match column {
+            ProcessSortColumn::Pid => a.pid.cmp(&b.pid),
+            ProcessSortColumn::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            ProcessSortColumn::Cpu => a
+                .cpu_usage
+                .partial_cmp(&b.cpu_usage)
+                .unwrap_or(Ordering::Equal),
+            ProcessSortColumn::Memory => a.memory.cmp(&b.memory),
+            ProcessSortColumn::Threads => a.threads.cmp(&b.threads),
+            ProcessSortColumn::User => a.user.to_lowercase().cmp(&b.user.to_lowercase()),
+        };
+
+        if ascending {
+            cmp
+        } else {
+            cmp.reverse()
+        }
+    });
+}
+
 pub(crate) fn sort_ollama_models(
     models: &mut Vec<OllamaModel>,
     column: OllamaModelSortColumn,
@@ -2639,9 +6168,251 @@ fn params_sort_key(unit: Option<char>, value: Option<f64>) -> (u8, f64) {
     (rank, val)
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(pid: u32, parent_pid: Option<u32>, memory: u64) -> ProcessEntry {
+        ProcessEntry {
+            pid,
+            parent_pid,
+            name: format!("p{}", pid),
+            cpu_usage: 1.0,
+            memory,
+            threads: 1,
+            user: String::new(),
+            command_line: None,
+            start_time: None,
+            handle_count: 0,
+            io_read_bytes: 0,
+            io_write_bytes: 0,
+        }
+    }
 
+    fn ui_state() -> ProcessesUIState {
+        ProcessesUIState {
+            sort_column: ProcessSortColumn::Pid,
+            sort_ascending: true,
+            tree_view: true,
+            ..Default::default()
+        }
+    }
 
+    #[test]
+    fn tree_rows_nest_children_and_aggregate_collapsed() {
+        let processes = vec![
+            process(1, None, 10),
+            process(2, Some(1), 20),
+            process(3, Some(2), 30),
+            process(4, Some(1), 40),
+            // Parent missing from the list: shown as a root
+            process(5, Some(99), 50),
+        ];
+        let mut state = ui_state();
+
+        let rows = process_rows(&processes, &state);
+        let layout: Vec<(u32, &str)> = rows
+            .iter()
+            .map(|r| (r.process.pid, r.prefix.as_str()))
+            .collect();
+        assert_eq!(
+            layout,
+            vec![(1, ""), (2, "├─ "), (3, "│  └─ "), (4, "└─ "), (5, "")]
+        );
+        assert_eq!(rows[0].subtree_memory, 100);
+
+        state.collapsed.insert(2);
+        let rows = process_rows(&processes, &state);
+        let pids: Vec<u32> = rows.iter().map(|r| r.process.pid).collect();
+        assert_eq!(pids, vec![1, 2, 4, 5]);
+        assert!(rows[1].collapsed);
+        assert_eq!(rows[1].subtree_memory, 50);
+        assert_eq!(rows[1].subtree_cpu, 2.0);
+    }
 
+    #[test]
+    fn tree_rows_keep_processes_in_parent_cycles() {
+        let processes = vec![process(1, Some(2), 1), process(2, Some(1), 1)];
+        let rows = process_rows(&processes, &ui_state());
+        assert_eq!(rows.len(), 2);
+    }
 
+    #[test]
+    fn select_pid_reveals_hidden_processes() {
+        let processes = vec![process(1, None, 10), process(2, Some(1), 20), process(3, Some(2), 30)];
+        let mut state = ui_state();
+        state.collapsed.insert(1);
+        state.filter = "p1".to_string();
+
+        assert!(state.select_pid(&processes, 3));
+        assert_eq!(state.selected_index, 2);
+        assert!(state.filter.is_empty());
+        assert!(state.collapsed.is_empty());
+        assert!(!state.select_pid(&processes, 42));
+    }
+
+    #[test]
+    fn pinned_processes_stay_on_top() {
+        let processes = vec![process(1, None, 10), process(2, None, 20), process(3, None, 30)];
+        let mut state = ui_state();
+        state.tree_view = false;
+        state.pinned.insert((3, "p3".to_string()));
+        // Same PID under another name is a different process
+        state.pinned.insert((2, "other".to_string()));
+
+        let rows = process_rows(&processes, &state);
+        let pids: Vec<u32> = rows.iter().map(|r| r.process.pid).collect();
+        assert_eq!(pids, vec![3, 1, 2]);
+        assert!(rows[0].pinned);
+        assert!(!rows[2].pinned);
+
+        state.pinned.clear();
+        let pids: Vec<u32> = process_rows(&processes, &state)
+            .iter()
+            .map(|r| r.process.pid)
+            .collect();
+        assert_eq!(pids, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn service_search_composes_with_status_filter() {
+        let service = |name: &str, display_name: &str, status: ServiceStatus| ServiceEntry {
+            name: name.to_string(),
+            display_name: display_name.to_string(),
+            status,
+            ..Default::default()
+        };
+        let services = vec![
+            service("Spooler", "Print Spooler", ServiceStatus::Running),
+            service("wuauserv", "Windows Update", ServiceStatus::Stopped),
+            service("PrintNotify", "Printer Extensions", ServiceStatus::Stopped),
+        ];
+        let mut state = ServicesUIState {
+            selected_index: 0,
+            scroll_offset: 0,
+            sort_column: ServiceSortColumn::Name,
+            sort_ascending: true,
+            status_filter: ServiceStatusFilter::All,
+            filter: "print".to_string(),
+            filter_editing: false,
+            focused_panel: ServicesPanelFocus::Table,
+            details_scroll: 0,
+            last_visible_rows: Cell::new(0),
+            pinned: HashSet::new(),
+        };
+        let names = |state: &ServicesUIState| -> Vec<String> {
+            visible_services(&services, state)
+                .into_iter()
+                .map(|s| s.name)
+                .collect()
+        };
 
+        assert_eq!(names(&state), vec!["PrintNotify", "Spooler"]);
+        state.status_filter = ServiceStatusFilter::Stopped;
+        assert_eq!(names(&state), vec!["PrintNotify"]);
+        state.filter = "UPDATE".to_string();
+        assert_eq!(names(&state), vec!["wuauserv"]);
+    }
+
+    #[test]
+    fn refresh_hotkeys_step_between_presets() {
+        assert_eq!(next_refresh_interval(1000, true), 500);
+        assert_eq!(next_refresh_interval(1000, false), 2000);
+        assert_eq!(next_refresh_interval(1500, true), 1000);
+        assert_eq!(next_refresh_interval(1500, false), 2000);
+        assert_eq!(next_refresh_interval(100, true), 100);
+        assert_eq!(next_refresh_interval(60_000, false), 60_000);
+    }
+
+    #[test]
+    fn system_accounts_need_elevation() {
+        assert!(is_system_account("NT AUTHORITY\\SYSTEM"));
+        assert!(is_system_account("NT AUTHORITY\\Network Service"));
+        assert!(is_system_account("root"));
+        assert!(!is_system_account("DESKTOP\\alice"));
+        assert!(!is_system_account("USER"));
+    }
+
+    #[test]
+    fn analyzer_search_matches_name_or_path_case_insensitively() {
+        let folder = |name: &str, path: &str| RootFolderInfo::new(name, path, 1);
+        let drive = AnalyzedDrive {
+            letter: "C:".to_string(),
+            name: String::new(),
+            total: 10,
+            used: 3,
+            free: 7,
+            root_folders: vec![
+                folder("Users", "C:\\Users"),
+                folder("Games", "C:\\Games"),
+                folder("Program Files", "C:\\Program Files"),
+            ],
+            error: None,
+        };
 
+        let names = |search: &str| -> Vec<String> {
+            visible_folders(&drive, search)
+                .iter()
+                .map(|f| f.name.clone())
+                .collect()
+        };
+        assert_eq!(names("").len(), 3);
+        assert_eq!(names("GAM"), vec!["Games"]);
+        assert_eq!(names("c:\\prog"), vec!["Program Files"]);
+    }
+
+    #[test]
+    fn monitor_health_state_prefers_disabled_then_error() {
+        let health = |status, error: Option<&str>, age| MonitorHealth {
+            tab: TabType::Cpu,
+            status,
+            error: error.map(str::to_string),
+            age,
+        };
+        let seconds = Some(chrono::Duration::seconds(75));
+        assert_eq!(health(MonitorStatus::Disabled, Some("x"), None).state(), "disabled");
+        assert_eq!(health(MonitorStatus::Running, Some("x"), seconds).state(), "error");
+        assert_eq!(health(MonitorStatus::Running, None, None).state(), "waiting");
+        assert_eq!(health(MonitorStatus::Running, None, seconds).state(), "ok");
+        assert_eq!(health(MonitorStatus::Running, None, seconds).updated(), "1m ago");
+        assert_eq!(health(MonitorStatus::Running, None, None).updated(), "never");
+    }
+
+    #[test]
+    fn default_sort_parses_column_and_direction() {
+        assert_eq!(
+            ProcessSortColumn::from_config("memory:desc"),
+            (ProcessSortColumn::Memory, false)
+        );
+        assert_eq!(
+            ProcessSortColumn::from_config(" Name : ASC "),
+            (ProcessSortColumn::Name, true)
+        );
+        // A missing or unknown direction uses the column's own default
+        assert_eq!(
+            ProcessSortColumn::from_config("threads"),
+            (ProcessSortColumn::Threads, false)
+        );
+        assert_eq!(
+            ProcessSortColumn::from_config("bogus:asc"),
+            (ProcessSortColumn::Cpu, false)
+        );
+        assert_eq!(
+            ServiceSortColumn::from_config("bogus:desc"),
+            (ServiceSortColumn::Name, true)
+        );
+        assert_eq!(
+            ServiceSortColumn::from_config("start_type:desc"),
+            (ServiceSortColumn::StartType, false)
+        );
+        assert_eq!(sort_spec("status", true), "status:asc");
+    }
+
+    #[test]
+    fn own_pid_is_only_protected_locally() {
+        let own = std::process::id();
+        assert!(is_protected_pid(own, true));
+        assert!(!is_protected_pid(own, false));
+        assert!(is_protected_pid(4, false));
+    }
+}
@@ -1,7 +1,14 @@
 use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// File remembering the tab open at exit for `tabs.restore_last`, stored
+/// beside config.toml.
+pub const LAST_TAB_FILE: &str = "last_tab.txt";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TabType {
+    Overview,
     Cpu,
     Gpu,
     Ram,
@@ -15,8 +22,9 @@ pub enum TabType {
 }
 
 impl TabType {
-    pub fn as_str(&self) -> &str {
+    pub fn as_str(&self) -> &'static str {
         match self {
+            TabType::Overview => "Overview",
             TabType::Cpu => "CPU",
             TabType::Gpu => "GPU",
             TabType::Ram => "RAM",
@@ -30,8 +38,26 @@ impl TabType {
         }
     }
 
+    /// Name used for this tab in `tabs.enabled` and `tabs.default`.
+    pub fn config_name(&self) -> &'static str {
+        match self {
+            TabType::Overview => "overview",
+            TabType::Cpu => "cpu",
+            TabType::Gpu => "gpu",
+            TabType::Ram => "ram",
+            TabType::Disk => "disk",
+            TabType::Network => "network",
+            TabType::Ollama => "ollama",
+            TabType::Processes => "processes",
+            TabType::Services => "services",
+            TabType::DiskAnalyzer => "disk_analyzer",
+            TabType::Settings => "settings",
+        }
+    }
+
     pub fn from_str(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
+            "overview" => Some(TabType::Overview),
             "cpu" => Some(TabType::Cpu),
             "gpu" => Some(TabType::Gpu),
             "ram" => Some(TabType::Ram),
@@ -49,6 +75,7 @@ impl TabType {
     #[allow(dead_code)]
     pub fn all() -> Vec<TabType> {
         vec![
+            TabType::Overview,
             TabType::Cpu,
             TabType::Gpu,
             TabType::Ram,
@@ -75,9 +102,8 @@ impl TabManager {
             .filter_map(|s| TabType::from_str(s))
             .collect();
 
-        let current_index = tabs
-            .iter()
-            .position(|t| t.as_str().to_lowercase() == default_tab.to_lowercase())
+        let current_index = TabType::from_str(default_tab)
+            .and_then(|default| tabs.iter().position(|&t| t == default))
             .unwrap_or(0);
 
         Self {
@@ -107,4 +133,97 @@ impl TabManager {
             self.current_index = index;
         }
     }
+
+    /// Swap the current tab with its left (`-1`) or right (`1`) neighbour,
+    /// staying on it. Returns false at either end of the bar.
+    pub fn move_current(&mut self, offset: isize) -> bool {
+        let Some(target) = self.current_index.checked_add_signed(offset) else {
+            return false;
+        };
+        if target >= self.tabs.len() {
+            return false;
+        }
+        self.tabs.swap(self.current_index, target);
+        self.current_index = target;
+        true
+    }
+
+    /// The tab after `tab` in bar order, skipping the current one; `None`
+    /// when the bar has no other tab.
+    pub fn next_other(&self, tab: TabType) -> Option<TabType> {
+        let start = self.tabs.iter().position(|&t| t == tab).unwrap_or(self.current_index);
+        (1..=self.tabs.len())
+            .map(|offset| self.tabs[(start + offset) % self.tabs.len()])
+            .find(|&t| t != self.current())
+    }
+
+    /// Select the tab saved in `path` by `save_last`. Keeps the current tab
+    /// when the file is missing or names a tab that isn't enabled.
+    pub fn restore_last(&mut self, path: &Path) {
+        let saved = fs::read_to_string(path).ok();
+        if let Some(tab) = saved.as_deref().and_then(|name| TabType::from_str(name.trim())) {
+            self.select(tab);
+        }
+    }
+
+    /// Write the current tab to `path` for `restore_last`.
+    pub fn save_last(&self, path: &Path) -> std::io::Result<()> {
+        fs::write(path, self.current().config_name())
+    }
+
+    /// Config names of the tabs in bar order, as stored in `tabs.enabled`.
+    pub fn config_names(&self) -> Vec<&'static str> {
+        self.tabs.iter().map(TabType::config_name).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moving_a_tab_keeps_it_selected() {
+        let enabled = ["overview", "cpu", "disk_analyzer"].map(String::from).to_vec();
+        let mut manager = TabManager::new(enabled, "disk_analyzer");
+        assert_eq!(manager.current(), TabType::DiskAnalyzer);
+
+        assert!(!manager.move_current(1));
+        assert!(manager.move_current(-1));
+        assert!(manager.move_current(-1));
+        assert!(!manager.move_current(-1));
+
+        assert_eq!(manager.current(), TabType::DiskAnalyzer);
+        assert_eq!(manager.config_names(), ["disk_analyzer", "overview", "cpu"]);
+    }
+
+    #[test]
+    fn next_other_skips_the_current_tab() {
+        let enabled = ["overview", "cpu", "gpu"].map(String::from).to_vec();
+        let manager = TabManager::new(enabled, "cpu");
+        assert_eq!(manager.next_other(TabType::Cpu), Some(TabType::Gpu));
+        assert_eq!(manager.next_other(TabType::Gpu), Some(TabType::Overview));
+        assert_eq!(manager.next_other(TabType::Overview), Some(TabType::Gpu));
+
+        let single = TabManager::new(vec!["cpu".to_string()], "cpu");
+        assert_eq!(single.next_other(TabType::Cpu), None);
+    }
+
+    #[test]
+    fn restoring_falls_back_to_the_default_tab() {
+        let path = std::env::temp_dir().join(format!("tui-plus-last-tab-{}.txt", std::process::id()));
+        let enabled = ["overview", "cpu", "gpu"].map(String::from).to_vec();
+
+        let mut manager = TabManager::new(enabled.clone(), "cpu");
+        manager.select(TabType::Gpu);
+        manager.save_last(&path).unwrap();
+        let mut restored = TabManager::new(enabled.clone(), "cpu");
+        restored.restore_last(&path);
+        assert_eq!(restored.current(), TabType::Gpu);
+
+        fs::write(&path, "disk").unwrap();
+        let mut restored = TabManager::new(enabled, "cpu");
+        restored.restore_last(&path);
+        fs::remove_file(&path).ok();
+        assert_eq!(restored.current(), TabType::Cpu);
+    }
 }
@@ -1,6 +1,6 @@
 use crossterm::event::{Event, EventStream};
 use futures::StreamExt;
-use tokio::time::{interval, Duration, Interval};
+use tokio::time::{interval, Duration, Interval, MissedTickBehavior};
 
 pub enum AppEvent {
     Input(Event),
@@ -10,23 +10,37 @@ pub enum AppEvent {
 pub struct EventHandler {
     event_stream: EventStream,
     tick_interval: Interval,
+    input_closed: bool,
 }
 
 impl EventHandler {
     pub fn new(tick_rate_ms: u64) -> Self {
+        let mut tick_interval = interval(Duration::from_millis(tick_rate_ms));
+        // A slow frame should not be followed by a burst of catch-up redraws
+        tick_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
         Self {
             event_stream: EventStream::new(),
-            tick_interval: interval(Duration::from_millis(tick_rate_ms)),
+            tick_interval,
+            input_closed: false,
         }
     }
 
     pub async fn next(&mut self) -> AppEvent {
         tokio::select! {
             _ = self.tick_interval.tick() => AppEvent::Tick,
-            event = self.event_stream.next() => {
+            event = self.event_stream.next(), if !self.input_closed => {
                 match event {
                     Some(Ok(evt)) => AppEvent::Input(evt),
-                    _ => AppEvent::Tick, // Fallback to tick on error or None
+                    Some(Err(e)) => {
+                        log::warn!("Terminal event error: {}", e);
+                        AppEvent::Tick
+                    }
+                    None => {
+                        // Stream ended; keep ticking instead of spinning on it
+                        self.input_closed = true;
+                        AppEvent::Tick
+                    }
                 }
             }
         }
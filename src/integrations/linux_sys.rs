@@ -1,14 +1,150 @@
 #![allow(dead_code)]
 
 use anyhow::{Context, Result};
+use std::collections::HashMap;
 use std::fs;
-use std::process::Command;
+use std::process::{Command, ExitStatus};
 
-pub struct LinuxSysMonitor;
+use super::remote::SshTarget;
+
+/// Reads `/proc` and `/sys` on the monitored host. Every method blocks, on
+/// file I/O or on ssh, so async callers go through `blocking`.
+#[derive(Clone)]
+pub struct LinuxSysMonitor {
+    remote: Option<SshTarget>,
+}
 
 impl LinuxSysMonitor {
     pub fn new() -> Self {
-        Self
+        Self { remote: None }
+    }
+
+    /// Read /proc, /sys and run commands on `remote` over SSH instead.
+    pub fn with_remote(mut self, remote: Option<SshTarget>) -> Self {
+        self.remote = remote;
+        self
+    }
+
+    /// Run `f` on tokio's blocking pool so a slow read or SSH round trip
+    /// doesn't stall the runtime's worker threads.
+    pub async fn blocking<T, F>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&LinuxSysMonitor) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let sys = self.clone();
+        tokio::task::spawn_blocking(move || f(&sys))
+            .await
+            .context("Linux sysfs task failed")
+    }
+
+    /// `program args`, local or wrapped in ssh.
+    fn command(&self, program: &str, args: &[&str]) -> Result<Command> {
+        match &self.remote {
+            Some(target) => {
+                target.check_connection()?;
+                Ok(target.command(program, args))
+            }
+            None => {
+                let mut command = Command::new(program);
+                command.args(args);
+                Ok(command)
+            }
+        }
+    }
+
+    fn record_status(&self, status: &ExitStatus) {
+        if let Some(target) = &self.remote {
+            target.record_status(status);
+        }
+    }
+
+    /// Contents of `path` on the monitored host.
+    pub fn read(&self, path: &str) -> Result<String> {
+        match &self.remote {
+            Some(target) => target.output("cat", &[path]),
+            None => Ok(fs::read_to_string(path)?),
+        }
+    }
+
+    /// Contents of each of `paths`, `None` where unreadable. Remote hosts
+    /// answer all of them in one SSH round trip.
+    pub fn read_many(&self, paths: &[String]) -> Vec<Option<String>> {
+        if paths.is_empty() {
+            return Vec::new();
+        }
+        match &self.remote {
+            Some(target) => {
+                let mut args = vec!["-c", REMOTE_READ_SCRIPT, "sh"];
+                args.extend(paths.iter().map(String::as_str));
+                match target.output("sh", &args) {
+                    Ok(output) => parse_remote_reads(&output, paths.len()),
+                    Err(e) => {
+                        log::debug!("Batched read failed: {}", e);
+                        vec![None; paths.len()]
+                    }
+                }
+            }
+            None => paths.iter().map(|path| fs::read_to_string(path).ok()).collect(),
+        }
+    }
+
+    /// stdout of `program args` on the monitored host.
+    pub fn output(&self, program: &str, args: &[&str]) -> Result<String> {
+        match &self.remote {
+            Some(target) => target.output(program, args),
+            None => {
+                let output = Command::new(program).args(args).output()?;
+                Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+            }
+        }
+    }
+
+    /// Entry names in each of `paths`, empty where one can't be listed.
+    /// Remote hosts answer all of them in one SSH round trip.
+    fn list_dirs(&self, paths: &[String]) -> Vec<Vec<String>> {
+        if paths.is_empty() {
+            return Vec::new();
+        }
+        match &self.remote {
+            Some(target) => {
+                let mut args = vec!["-c", REMOTE_LIST_SCRIPT, "sh"];
+                args.extend(paths.iter().map(String::as_str));
+                let output = target.output("sh", &args).unwrap_or_else(|e| {
+                    log::debug!("Batched listing failed: {}", e);
+                    String::new()
+                });
+                parse_remote_reads(&output, paths.len())
+                    .into_iter()
+                    .map(|listing| {
+                        listing
+                            .unwrap_or_default()
+                            .lines()
+                            .map(str::to_string)
+                            .collect()
+                    })
+                    .collect()
+            }
+            None => paths.iter().map(|path| self.list_dir(path)).collect(),
+        }
+    }
+
+    /// Entry names in `path`; empty when it can't be listed.
+    fn list_dir(&self, path: &str) -> Vec<String> {
+        match &self.remote {
+            Some(target) => target
+                .output("ls", &["-1", path])
+                .map(|listing| listing.lines().map(str::to_string).collect())
+                .unwrap_or_default(),
+            None => fs::read_dir(path)
+                .map(|entries| {
+                    entries
+                        .flatten()
+                        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
     }
 
     // CPU functions
@@ -29,7 +165,7 @@ impl LinuxSysMonitor {
     }
 
     pub fn get_cpu_info(&self) -> Result<CpuInfo> {
-        let content = fs::read_to_string("/proc/cpuinfo")?;
+        let content = self.read("/proc/cpuinfo")?;
         let mut name = String::from("Unknown CPU");
         let mut core_count = 0;
         let mut mhz = 0.0;
@@ -57,38 +193,213 @@ impl LinuxSysMonitor {
         })
     }
 
+    /// Feature flags of the first CPU in `/proc/cpuinfo` (`flags` on x86,
+    /// `Features` on ARM).
+    pub fn get_cpu_flags(&self) -> Vec<String> {
+        let Ok(content) = self.read("/proc/cpuinfo") else {
+            return Vec::new();
+        };
+        content
+            .lines()
+            .find(|line| line.starts_with("flags") || line.starts_with("Features"))
+            .and_then(|line| line.split_once(':'))
+            .map(|(_, flags)| flags.split_whitespace().map(str::to_string).collect())
+            .unwrap_or_default()
+    }
+
+    /// Package-level RAPL energy counters from `/sys/class/powercap`, one
+    /// per socket. Empty without RAPL, or when `energy_uj` is root-only.
+    pub fn get_rapl_zones(&self) -> Vec<RaplZone> {
+        let base = "/sys/class/powercap";
+        let zones = self
+            .list_dir(base)
+            .into_iter()
+            // "intel-rapl:N" is a package; "intel-rapl:N:M" are its core/uncore/dram parts
+            .filter(|zone| {
+                zone.strip_prefix("intel-rapl:")
+                    .is_some_and(|index| !index.contains(':'))
+            })
+            .collect::<Vec<_>>();
+        const FILES: [&str; 3] = ["energy_uj", "max_energy_range_uj", "constraint_0_power_limit_uw"];
+        let paths: Vec<String> = zones
+            .iter()
+            .flat_map(|zone| FILES.map(|file| format!("{}/{}/{}", base, zone, file)))
+            .collect();
+        let values: Vec<Option<u64>> = self
+            .read_many(&paths)
+            .into_iter()
+            .map(|value| value.and_then(|value| value.trim().parse().ok()))
+            .collect();
+
+        zones
+            .into_iter()
+            .zip(values.chunks(FILES.len()))
+            .filter_map(|(zone, values)| {
+                Some(RaplZone {
+                    energy_uj: values[0]?,
+                    max_energy_uj: values[1].unwrap_or(u64::MAX),
+                    power_limit_uw: values[2],
+                    name: zone,
+                })
+            })
+            .collect()
+    }
+
+    /// Caches of cpu0 from sysfs. Unreadable entries are left out.
+    pub fn get_cpu_caches(&self) -> Vec<CpuCache> {
+        let base = "/sys/devices/system/cpu/cpu0/cache";
+        const FILES: [&str; 4] = ["level", "type", "size", "shared_cpu_list"];
+        let paths: Vec<String> = self
+            .list_dir(base)
+            .into_iter()
+            .filter(|name| name.starts_with("index"))
+            .flat_map(|index| FILES.map(|file| format!("{}/{}/{}", base, index, file)))
+            .collect();
+        let values: Vec<Option<String>> = self
+            .read_many(&paths)
+            .into_iter()
+            .map(|value| value.map(|value| value.trim().to_string()))
+            .collect();
+
+        let mut caches: Vec<CpuCache> = values
+            .chunks(FILES.len())
+            .filter_map(|values| {
+                Some(CpuCache {
+                    level: values[0].as_ref()?.parse().ok()?,
+                    kind: values[1].clone()?,
+                    size: parse_cache_size(values[2].as_ref()?)?,
+                    shared_by: values[3].as_deref().map_or(1, count_cpu_list),
+                })
+            })
+            .collect();
+        caches.sort_by_key(|cache| cache.level);
+        caches
+    }
+
+    /// Current clock of each of the first `count` CPUs in MHz. cpufreq is
+    /// exact; `/proc/cpuinfo` is the fallback where no cpufreq driver is loaded.
+    pub fn get_core_frequencies(&self, count: usize) -> Vec<Option<f32>> {
+        let cpuinfo_mhz: Vec<f32> = self
+            .read("/proc/cpuinfo")
+            .map(|content| {
+                content
+                    .lines()
+                    .filter(|line| line.starts_with("cpu MHz"))
+                    .filter_map(|line| line.split(':').nth(1)?.trim().parse().ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let paths: Vec<String> = (0..count)
+            .map(|cpu| format!("/sys/devices/system/cpu/cpu{}/cpufreq/scaling_cur_freq", cpu))
+            .collect();
+        self.read_many(&paths)
+            .into_iter()
+            .enumerate()
+            .map(|(cpu, khz)| {
+                khz.and_then(|khz| khz.trim().parse::<f32>().ok())
+                    .map(|khz| khz / 1000.0)
+                    .or_else(|| cpuinfo_mhz.get(cpu).copied())
+            })
+            .collect()
+    }
+
     pub fn get_core_usage(&self) -> Result<Vec<f32>> {
-        // Simplified: return overall usage for each core
-        // Full implementation would track each core separately
-        let usage = self.get_cpu_usage()?;
-        let info = self.get_cpu_info()?;
-        Ok(vec![usage; info.core_count])
+        let first: HashMap<usize, CpuStat> = self.read_core_stats()?.into_iter().collect();
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        let second = self.read_core_stats()?;
+
+        // Match on the cpuN label so a core going offline between samples
+        // doesn't shift every later core onto the wrong baseline.
+        let usage = second
+            .iter()
+            .map(|(core_id, stat2)| match first.get(core_id) {
+                Some(stat1) => {
+                    let total_diff = stat2.total().saturating_sub(stat1.total());
+                    let idle_diff = stat2.idle.saturating_sub(stat1.idle);
+                    if total_diff == 0 {
+                        0.0
+                    } else {
+                        (100.0 * (1.0 - (idle_diff as f64 / total_diff as f64))) as f32
+                    }
+                }
+                None => 0.0,
+            })
+            .collect();
+
+        Ok(usage)
+    }
+
+    /// CPU temperature in °C from /sys/class/hwmon, or `None` when no CPU
+    /// sensor is exposed (e.g. in VMs).
+    pub fn get_cpu_temperature(&self) -> Option<f32> {
+        // Walking hwmon costs a round trip per sensor file over SSH
+        if self.remote.is_some() {
+            return None;
+        }
+        let mut readings = Vec::new();
+
+        for entry in fs::read_dir("/sys/class/hwmon").ok()?.flatten() {
+            let dir = entry.path();
+            let chip = fs::read_to_string(dir.join("name")).unwrap_or_default();
+            let Ok(files) = fs::read_dir(&dir) else {
+                continue;
+            };
+
+            for file in files.flatten() {
+                let file_name = file.file_name().to_string_lossy().into_owned();
+                let Some(sensor) = file_name
+                    .strip_prefix("temp")
+                    .and_then(|rest| rest.strip_suffix("_input"))
+                else {
+                    continue;
+                };
+                let Some(millidegrees) = fs::read_to_string(file.path())
+                    .ok()
+                    .and_then(|value| value.trim().parse::<f32>().ok())
+                else {
+                    continue;
+                };
+                let label = fs::read_to_string(dir.join(format!("temp{}_label", sensor)))
+                    .unwrap_or_default();
+                readings.push(TempReading {
+                    chip: chip.trim().to_string(),
+                    label: label.trim().to_string(),
+                    celsius: millidegrees / 1000.0,
+                });
+            }
+        }
+
+        pick_cpu_temperature(&readings)
     }
 
     fn read_cpu_stat(&self) -> Result<CpuStat> {
-        let content = fs::read_to_string("/proc/stat")?;
+        let content = self.read("/proc/stat")?;
         let line = content.lines().next().context("Empty /proc/stat")?;
 
-        let values: Vec<u64> = line
-            .split_whitespace()
-            .skip(1)
-            .filter_map(|s| s.parse().ok())
+        Ok(CpuStat::from_line(line))
+    }
+
+    /// Per-core counters from the `cpuN` lines of /proc/stat, sorted by N.
+    fn read_core_stats(&self) -> Result<Vec<(usize, CpuStat)>> {
+        let content = self.read("/proc/stat")?;
+
+        let mut stats: Vec<(usize, CpuStat)> = content
+            .lines()
+            .filter_map(|line| {
+                let label = line.split_whitespace().next()?;
+                let core_id = label.strip_prefix("cpu")?.parse::<usize>().ok()?;
+                Some((core_id, CpuStat::from_line(line)))
+            })
             .collect();
+        stats.sort_by_key(|(core_id, _)| *core_id);
 
-        Ok(CpuStat {
-            user: *values.get(0).unwrap_or(&0),
-            nice: *values.get(1).unwrap_or(&0),
-            system: *values.get(2).unwrap_or(&0),
-            idle: *values.get(3).unwrap_or(&0),
-            iowait: *values.get(4).unwrap_or(&0),
-            irq: *values.get(5).unwrap_or(&0),
-            softirq: *values.get(6).unwrap_or(&0),
-        })
+        Ok(stats)
     }
 
     // Memory functions
     pub fn get_memory_info(&self) -> Result<MemoryInfo> {
-        let content = fs::read_to_string("/proc/meminfo")?;
+        let content = self.read("/proc/meminfo")?;
         let mut total = 0;
         let mut available = 0;
         let mut free = 0;
@@ -96,6 +407,8 @@ impl LinuxSysMonitor {
         let mut cached = 0;
         let mut swap_total = 0;
         let mut swap_free = 0;
+        let mut committed = 0;
+        let mut commit_limit = 0;
 
         for line in content.lines() {
             let parts: Vec<&str> = line.split_whitespace().collect();
@@ -113,11 +426,13 @@ impl LinuxSysMonitor {
                 "Cached:" => cached = value * 1024,
                 "SwapTotal:" => swap_total = value * 1024,
                 "SwapFree:" => swap_free = value * 1024,
+                "Committed_AS:" => committed = value * 1024,
+                "CommitLimit:" => commit_limit = value * 1024,
                 _ => {}
             }
         }
 
-        let used = total - available;
+        let used = total.saturating_sub(available);
 
         Ok(MemoryInfo {
             total,
@@ -127,15 +442,18 @@ impl LinuxSysMonitor {
             buffers,
             cached,
             swap_total,
-            swap_used: swap_total - swap_free,
+            swap_used: swap_total.saturating_sub(swap_free),
+            committed,
+            commit_limit,
         })
     }
 
     // Disk functions
     pub fn get_disk_info(&self) -> Result<Vec<DiskInfo>> {
-        let output = Command::new("df")
-            .args(&["-B1", "-T"])  // Block size 1 byte, show type
+        let output = self
+            .command("df", &["-B1", "-T"])?  // Block size 1 byte, show type
             .output()?;
+        self.record_status(&output.status);
 
         let stdout = String::from_utf8_lossy(&output.stdout);
         let mut disks = Vec::new();
@@ -171,9 +489,107 @@ impl LinuxSysMonitor {
         Ok(disks)
     }
 
+    /// Cumulative I/O counters for whole block devices (no partitions,
+    /// loop or RAM disks).
+    pub fn get_disk_stats(&self) -> Result<Vec<DiskStatCounters>> {
+        let content = self
+            .read("/proc/diskstats")
+            .context("Failed to read /proc/diskstats")?;
+        let block_devices = self.list_dir("/sys/block");
+        Ok(parse_diskstats(&content)
+            .into_iter()
+            .filter(|dev| !dev.name.starts_with("loop") && !dev.name.starts_with("ram"))
+            .filter(|dev| block_devices.contains(&dev.name))
+            .collect())
+    }
+
+    /// Model, media and size of each of the block devices `names`, from sysfs.
+    pub fn get_block_device_info(&self, names: &[String]) -> Vec<BlockDeviceInfo> {
+        const FILES: [&str; 3] = ["device/model", "queue/rotational", "size"];
+        let paths: Vec<String> = names
+            .iter()
+            .flat_map(|name| FILES.map(|file| format!("/sys/block/{}/{}", name, file)))
+            .collect();
+        let values: Vec<Option<String>> = self
+            .read_many(&paths)
+            .into_iter()
+            .map(|value| value.map(|value| value.trim().to_string()))
+            .collect();
+
+        values
+            .chunks(FILES.len())
+            .map(|values| BlockDeviceInfo {
+                model: values[0].clone().filter(|model| !model.is_empty()),
+                rotational: values[1].as_deref().map(|value| value == "1"),
+                // Always counted in 512-byte sectors, whatever the device's block size
+                size: values[2]
+                    .as_ref()
+                    .and_then(|value| value.parse::<u64>().ok())
+                    .map(|sectors| sectors * 512)
+                    .unwrap_or(0),
+            })
+            .collect()
+    }
+
+    /// Partitions of each of the whole block devices `names`, in
+    /// `/proc/partitions` order.
+    pub fn get_partitions(&self, names: &[String]) -> Vec<Vec<BlockPartition>> {
+        let content = self.read("/proc/partitions").unwrap_or_default();
+        // Longest match, so sdaa1 belongs to sdaa rather than sda
+        let candidates: Vec<(usize, String, u64)> = parse_proc_partitions(&content)
+            .into_iter()
+            .filter_map(|(entry, size)| {
+                let owner = names
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, name)| entry.starts_with(name.as_str()) && entry != **name)
+                    .max_by_key(|(_, name)| name.len())?
+                    .0;
+                Some((owner, entry, size))
+            })
+            .collect();
+
+        // Only partitions have a PARTN in their uevent
+        let mut paths: Vec<String> = candidates
+            .iter()
+            .map(|(_, entry, _)| format!("/sys/class/block/{}/uevent", entry))
+            .collect();
+        paths.push("/proc/swaps".to_string());
+        let mut contents = self.read_many(&paths);
+        let swaps = swap_devices(&contents.pop().flatten().unwrap_or_default());
+
+        let found: Vec<(usize, String, u64, u32, Option<String>)> = candidates
+            .into_iter()
+            .zip(contents)
+            .filter_map(|((owner, name, size), uevent)| {
+                let (number, label) = parse_partition_uevent(&uevent?)?;
+                Some((owner, name, size, number, label))
+            })
+            .collect();
+        // LVM, LUKS and RAID members are held by the device built on them
+        let holder_paths: Vec<String> = found
+            .iter()
+            .map(|(_, name, ..)| format!("/sys/class/block/{}/holders", name))
+            .collect();
+        let holders = self.list_dirs(&holder_paths);
+
+        let mut partitions = vec![Vec::new(); names.len()];
+        for ((owner, name, size, number, label), holders) in found.into_iter().zip(holders) {
+            let in_use = !holders.is_empty() || swaps.contains(&format!("/dev/{}", name));
+            partitions[owner].push(BlockPartition {
+                name,
+                number,
+                size,
+                label,
+                in_use,
+            });
+        }
+        partitions
+    }
+
     // Network functions
     pub fn get_network_stats(&self) -> Result<Vec<NetworkInterface>> {
-        let content = fs::read_to_string("/proc/net/dev")?;
+        let content = self.read("/proc/net/dev")?;
         let mut interfaces = Vec::new();
 
         for line in content.lines().skip(2) {  // Skip first 2 header lines
@@ -200,14 +616,43 @@ impl LinuxSysMonitor {
                 rx_packets,
                 tx_bytes,
                 tx_packets,
+                operstate: String::new(),
             });
         }
 
+        let paths: Vec<String> = interfaces
+            .iter()
+            .map(|iface| format!("/sys/class/net/{}/operstate", iface.name))
+            .collect();
+        for (iface, state) in interfaces.iter_mut().zip(self.read_many(&paths)) {
+            iface.operstate = state.map_or_else(|| "unknown".to_string(), |s| s.trim().to_string());
+        }
+
         Ok(interfaces)
     }
 
     // Process functions
+    pub fn kill_process(&self, pid: u32) -> Result<()> {
+        let output = self
+            .command("kill", &["-KILL", &pid.to_string()])?
+            .output()
+            .context("Failed to run kill")?;
+        self.record_status(&output.status);
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("{}", stderr.trim());
+        }
+
+        Ok(())
+    }
+
     pub fn get_processes(&self) -> Result<Vec<ProcessInfo>> {
+        if let Some(target) = &self.remote {
+            let output = target.output("sh", &["-c", REMOTE_PROCESSES_SCRIPT])?;
+            return Ok(parse_remote_processes(&output));
+        }
+
         let mut processes = Vec::new();
 
         if let Ok(entries) = fs::read_dir("/proc") {
@@ -228,49 +673,230 @@ impl LinuxSysMonitor {
     }
 
     fn get_process_info(&self, pid: u32) -> Result<ProcessInfo> {
-        let stat_path = format!("/proc/{}/stat", pid);
-        let cmdline_path = format!("/proc/{}/cmdline", pid);
-
-        let stat = fs::read_to_string(&stat_path)?;
-        let parts: Vec<&str> = stat.split_whitespace().collect();
-
-        // Extract name from stat (it's in parentheses)
-        let name = if let Some(start) = stat.find('(') {
-            if let Some(end) = stat.find(')') {
-                stat[start + 1..end].to_string()
-            } else {
-                String::from("unknown")
-            }
-        } else {
-            String::from("unknown")
-        };
-
-        // Read cmdline
-        let cmdline = fs::read_to_string(&cmdline_path)
+        let stat = fs::read_to_string(format!("/proc/{}/stat", pid))?;
+        let statm = fs::read_to_string(format!("/proc/{}/statm", pid)).ok();
+        let cmdline = fs::read_to_string(format!("/proc/{}/cmdline", pid))
             .ok()
             .map(|s| s.replace('\0', " ").trim().to_string());
 
-        // Parse values
-        let threads = parts.get(19).and_then(|s| s.parse().ok()).unwrap_or(1);
+        Ok(parse_process(pid, &stat, statm.as_deref(), cmdline))
+    }
 
-        // Read memory from statm
-        let statm_path = format!("/proc/{}/statm", pid);
-        let memory = if let Ok(statm) = fs::read_to_string(&statm_path) {
-            let pages: Vec<u64> = statm.split_whitespace()
-                .filter_map(|s| s.parse().ok())
-                .collect();
-            pages.get(1).unwrap_or(&0) * 4096  // RSS in pages * page size (4096)
-        } else {
-            0
+    /// Facts about one process that are too costly to gather for all of
+    /// them on every refresh; read when its detail popup opens.
+    pub fn get_process_details(&self, pid: u32) -> Result<ProcessDetailsInfo> {
+        let paths = [
+            format!("/proc/{}/status", pid),
+            format!("/proc/{}/cmdline", pid),
+            format!("/proc/{}/stat", pid),
+            "/proc/stat".to_string(),
+        ];
+        let [status, cmdline, stat, proc_stat]: [Option<String>; 4] = self
+            .read_many(&paths)
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Short read of /proc/{}", pid))?;
+        let status = status.with_context(|| format!("Process {} has exited", pid))?;
+        let mut details = parse_process_status(&status);
+
+        details.command_line = cmdline
+            .map(|cmdline| cmdline.replace('\0', " ").trim().to_string())
+            .filter(|cmdline| !cmdline.is_empty());
+
+        let exe = format!("/proc/{}/exe", pid);
+        details.executable = match &self.remote {
+            Some(target) => target
+                .output("readlink", &[&exe])
+                .ok()
+                .map(|path| path.trim().to_string()),
+            None => fs::read_link(&exe).ok().map(|path| path.display().to_string()),
+        }
+        .filter(|path| !path.is_empty());
+
+        let start_ticks = stat.map(|stat| parse_process(pid, &stat, None, None).start_ticks);
+        let boot_time = proc_stat.and_then(|stat| parse_boot_time(&stat));
+        details.started_at = start_ticks
+            .zip(boot_time)
+            .map(|(ticks, boot)| boot + (ticks as f64 / USER_HZ) as u64);
+
+        // Unreadable for other users' processes, which is not "no files"
+        let fds = self.list_dir(&format!("/proc/{}/fd", pid));
+        details.open_files = (!fds.is_empty()).then_some(fds.len() as u32);
+
+        Ok(details)
+    }
+}
+
+/// See `LinuxSysMonitor::get_process_details`. Sizes are in bytes.
+#[derive(Debug, Default)]
+pub struct ProcessDetailsInfo {
+    pub executable: Option<String>,
+    pub command_line: Option<String>,
+    pub parent_pid: Option<u32>,
+    pub threads: Option<u32>,
+    pub open_files: Option<u32>,
+    /// VmRSS
+    pub resident: Option<u64>,
+    /// RssAnon: resident memory not backed by a file
+    pub private: Option<u64>,
+    /// Unix time the process started
+    pub started_at: Option<u64>,
+}
+
+/// Boot time as Unix seconds, from the `btime` line of `/proc/stat`.
+fn parse_boot_time(stat: &str) -> Option<u64> {
+    stat.lines()
+        .find_map(|line| line.strip_prefix("btime "))
+        .and_then(|value| value.trim().parse().ok())
+}
+
+/// PPid, Threads, VmRSS and RssAnon from `/proc/<pid>/status`.
+fn parse_process_status(status: &str) -> ProcessDetailsInfo {
+    let mut details = ProcessDetailsInfo::default();
+    for line in status.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
         };
+        let number = value.split_whitespace().next().and_then(|n| n.parse::<u64>().ok());
+        match key {
+            "PPid" => details.parent_pid = number.map(|n| n as u32).filter(|&n| n != 0),
+            "Threads" => details.threads = number.map(|n| n as u32),
+            "VmRSS" => details.resident = number.map(|kb| kb * 1024),
+            "RssAnon" => details.private = number.map(|kb| kb * 1024),
+            _ => {}
+        }
+    }
+    details
+}
 
-        Ok(ProcessInfo {
-            pid,
-            name,
-            cmdline,
-            threads,
-            memory,
+/// Prints each process as a `\x1e<pid>` line followed by its stat, statm and
+/// space-separated cmdline, so the whole table comes back in one SSH call.
+const REMOTE_PROCESSES_SCRIPT: &str = r#"for d in /proc/[0-9]*; do
+printf '\036%s\n' "${d#/proc/}"
+cat "$d/stat" "$d/statm" 2>/dev/null
+tr '\0' ' ' < "$d/cmdline" 2>/dev/null
+echo
+done"#;
+
+fn parse_remote_processes(output: &str) -> Vec<ProcessInfo> {
+    output
+        .split('\u{1e}')
+        .filter_map(|block| {
+            let mut lines = block.lines();
+            let pid = lines.next()?.trim().parse::<u32>().ok()?;
+            let stat = lines.next().filter(|line| line.contains(')'))?;
+            let statm = lines.next();
+            let cmdline = lines.next().map(|line| line.trim().to_string());
+            Some(parse_process(pid, stat, statm, cmdline))
         })
+        .collect()
+}
+
+/// Prints each file named in the arguments followed by `\x1e`, or `\x15`
+/// in its place when it can't be read.
+const REMOTE_READ_SCRIPT: &str = r#"for f; do cat "$f" 2>/dev/null || printf '\025'; printf '\036'; done"#;
+
+/// Lists each directory named in the arguments followed by `\x1e`.
+const REMOTE_LIST_SCRIPT: &str = r#"for d; do ls -1 "$d" 2>/dev/null; printf '\036'; done"#;
+
+fn parse_remote_reads(output: &str, count: usize) -> Vec<Option<String>> {
+    let mut contents: Vec<Option<String>> = output
+        .split('\u{1e}')
+        .take(count)
+        .map(|content| (content != "\u{15}").then(|| content.to_string()))
+        .collect();
+    contents.resize(count, None);
+    contents
+}
+
+/// Name and size in bytes of each row of `/proc/partitions`, which counts
+/// 1 KiB blocks.
+fn parse_proc_partitions(content: &str) -> Vec<(String, u64)> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            let [_, _, blocks, name] = parts[..] else {
+                return None;
+            };
+            Some((name.to_string(), blocks.parse::<u64>().ok()? * 1024))
+        })
+        .collect()
+}
+
+/// Number and GPT name from a partition's uevent; `None` for whole disks.
+fn parse_partition_uevent(content: &str) -> Option<(u32, Option<String>)> {
+    let field = |key: &str| {
+        content
+            .lines()
+            .find_map(|line| line.strip_prefix(key)?.strip_prefix('='))
+    };
+    let number = field("PARTN")?.trim().parse().ok()?;
+    let label = field("PARTNAME")
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string);
+    Some((number, label))
+}
+
+/// Devices listed in `/proc/swaps`.
+fn swap_devices(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| line.split_whitespace().next())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Page size assumed for `statm`, which counts pages
+const PAGE_SIZE: u64 = 4096;
+
+/// Clock ticks per second in `/proc/<pid>/stat`; the kernel fixes this at
+/// 100 for userspace on every mainstream architecture, remote hosts included.
+const USER_HZ: f64 = 100.0;
+
+fn parse_process(pid: u32, stat: &str, statm: Option<&str>, cmdline: Option<String>) -> ProcessInfo {
+    // Extract name from stat (it's in parentheses)
+    let name = if let Some(start) = stat.find('(') {
+        if let Some(end) = stat.rfind(')') {
+            stat[start + 1..end].to_string()
+        } else {
+            String::from("unknown")
+        }
+    } else {
+        String::from("unknown")
+    };
+
+    // Fields after the name, starting at state (the name itself may contain spaces)
+    let after_name: Vec<&str> = stat
+        .rfind(')')
+        .map(|end| stat[end + 1..].split_whitespace().collect())
+        .unwrap_or_default();
+    let field = |index: usize| after_name.get(index).and_then(|s| s.parse::<u64>().ok());
+
+    let parent_pid = field(1).map(|ppid| ppid as u32).filter(|&ppid| ppid != 0);
+    let threads = field(17).map_or(1, |threads| threads as usize);
+    // utime + stime and starttime, in clock ticks
+    let cpu_ticks = field(11).unwrap_or(0) + field(12).unwrap_or(0);
+    let start_ticks = field(19).unwrap_or(0);
+
+    // Resident and shared pages from statm
+    let pages: Vec<u64> = statm
+        .map(|statm| statm.split_whitespace().filter_map(|s| s.parse().ok()).collect())
+        .unwrap_or_default();
+    let resident = pages.get(1).copied().unwrap_or(0);
+    let shared = pages.get(2).copied().unwrap_or(0);
+
+    ProcessInfo {
+        pid,
+        parent_pid,
+        name,
+        cmdline,
+        threads,
+        memory: resident * PAGE_SIZE,
+        private_memory: resident.saturating_sub(shared) * PAGE_SIZE,
+        cpu_seconds: cpu_ticks as f64 / USER_HZ,
+        start_ticks,
     }
 }
 
@@ -286,11 +912,62 @@ struct CpuStat {
 }
 
 impl CpuStat {
+    fn from_line(line: &str) -> Self {
+        let values: Vec<u64> = line
+            .split_whitespace()
+            .skip(1)
+            .filter_map(|s| s.parse().ok())
+            .collect();
+
+        Self {
+            user: *values.first().unwrap_or(&0),
+            nice: *values.get(1).unwrap_or(&0),
+            system: *values.get(2).unwrap_or(&0),
+            idle: *values.get(3).unwrap_or(&0),
+            iowait: *values.get(4).unwrap_or(&0),
+            irq: *values.get(5).unwrap_or(&0),
+            softirq: *values.get(6).unwrap_or(&0),
+        }
+    }
+
     fn total(&self) -> u64 {
         self.user + self.nice + self.system + self.idle + self.iowait + self.irq + self.softirq
     }
 }
 
+#[derive(Debug)]
+struct TempReading {
+    chip: String,
+    label: String,
+    celsius: f32,
+}
+
+/// Package/Tctl sensors win over per-core ones (hottest core); unlabeled
+/// readings only count when they come from a CPU driver.
+fn pick_cpu_temperature(readings: &[TempReading]) -> Option<f32> {
+    const CPU_CHIPS: &[&str] = &["coretemp", "k10temp", "zenpower", "cpu_thermal"];
+
+    let rank = |reading: &TempReading| {
+        let label = reading.label.to_lowercase();
+        if label.contains("package") || label.contains("tctl") {
+            Some(0)
+        } else if label.contains("core") {
+            Some(1)
+        } else if CPU_CHIPS.contains(&reading.chip.as_str()) {
+            Some(2)
+        } else {
+            None
+        }
+    };
+
+    readings
+        .iter()
+        .filter(|reading| reading.celsius > 0.0)
+        .filter_map(|reading| rank(reading).map(|rank| (rank, reading.celsius)))
+        .min_by(|a, b| a.0.cmp(&b.0).then(b.1.total_cmp(&a.1)))
+        .map(|(_, celsius)| celsius)
+}
+
 #[derive(Debug)]
 pub struct CpuInfo {
     pub name: String,
@@ -298,6 +975,58 @@ pub struct CpuInfo {
     pub frequency_mhz: f32,
 }
 
+/// A RAPL package zone, e.g. `/sys/class/powercap/intel-rapl:0`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RaplZone {
+    pub name: String,
+    /// Cumulative energy in microjoules
+    pub energy_uj: u64,
+    /// `energy_uj` wraps back to zero past this
+    pub max_energy_uj: u64,
+    /// Long-term (PL1) limit in microwatts, close to the TDP
+    pub power_limit_uw: Option<u64>,
+}
+
+/// One cache from `/sys/devices/system/cpu/cpu0/cache/index*`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CpuCache {
+    pub level: u8,
+    /// "Data", "Instruction" or "Unified"
+    pub kind: String,
+    /// Bytes per instance
+    pub size: u64,
+    /// Logical CPUs sharing one instance
+    pub shared_by: usize,
+}
+
+/// Sysfs cache sizes look like "32K" or "16384K".
+fn parse_cache_size(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let (digits, multiplier) = match value.chars().last()? {
+        'K' => (&value[..value.len() - 1], 1024),
+        'M' => (&value[..value.len() - 1], 1024 * 1024),
+        'G' => (&value[..value.len() - 1], 1024 * 1024 * 1024),
+        _ => (value, 1),
+    };
+    digits.parse::<u64>().ok().map(|n| n * multiplier)
+}
+
+/// Number of CPUs in a list like "0-3,8-11" or "0,4".
+fn count_cpu_list(list: &str) -> usize {
+    list.trim()
+        .split(',')
+        .filter(|part| !part.is_empty())
+        .map(|part| match part.split_once('-') {
+            Some((start, end)) => match (start.parse::<usize>(), end.parse::<usize>()) {
+                (Ok(start), Ok(end)) if end >= start => end - start + 1,
+                _ => 1,
+            },
+            None => 1,
+        })
+        .sum::<usize>()
+        .max(1)
+}
+
 #[derive(Debug)]
 pub struct MemoryInfo {
     pub total: u64,
@@ -308,6 +1037,8 @@ pub struct MemoryInfo {
     pub cached: u64,
     pub swap_total: u64,
     pub swap_used: u64,
+    pub committed: u64,
+    pub commit_limit: u64,
 }
 
 #[derive(Debug)]
@@ -320,6 +1051,63 @@ pub struct DiskInfo {
     pub fs_type: String,
 }
 
+/// One `/proc/diskstats` row; sectors are always 512 bytes.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DiskStatCounters {
+    pub name: String,
+    pub reads_completed: u64,
+    pub sectors_read: u64,
+    pub read_time_ms: u64,
+    pub writes_completed: u64,
+    pub sectors_written: u64,
+    pub write_time_ms: u64,
+    pub in_flight: u64,
+    pub io_time_ms: u64,
+}
+
+#[derive(Debug)]
+pub struct BlockDeviceInfo {
+    pub model: Option<String>,
+    /// `None` when the kernel doesn't report it
+    pub rotational: Option<bool>,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct BlockPartition {
+    pub name: String,
+    pub number: u32,
+    pub size: u64,
+    /// GPT partition name, e.g. "EFI System Partition"
+    pub label: Option<String>,
+    /// Backs another block device (LVM, LUKS, RAID) or is active swap
+    pub in_use: bool,
+}
+
+fn parse_diskstats(content: &str) -> Vec<DiskStatCounters> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            if parts.len() < 14 {
+                return None;
+            }
+            let field = |i: usize| parts[i].parse::<u64>().unwrap_or(0);
+            Some(DiskStatCounters {
+                name: parts[2].to_string(),
+                reads_completed: field(3),
+                sectors_read: field(5),
+                read_time_ms: field(6),
+                writes_completed: field(7),
+                sectors_written: field(9),
+                write_time_ms: field(10),
+                in_flight: field(11),
+                io_time_ms: field(12),
+            })
+        })
+        .collect()
+}
+
 #[derive(Debug)]
 pub struct NetworkInterface {
     pub name: String,
@@ -327,13 +1115,177 @@ pub struct NetworkInterface {
     pub rx_packets: u64,
     pub tx_bytes: u64,
     pub tx_packets: u64,
+    /// `up`, `down`, `unknown`, ... from /sys/class/net
+    pub operstate: String,
 }
 
 #[derive(Debug)]
 pub struct ProcessInfo {
     pub pid: u32,
+    pub parent_pid: Option<u32>,
     pub name: String,
     pub cmdline: Option<String>,
     pub threads: usize,
+    /// Resident set size in bytes
     pub memory: u64,
+    /// Resident bytes not shared with other processes
+    pub private_memory: u64,
+    /// Cumulative user + system CPU time
+    pub cpu_seconds: f64,
+    /// Start time in clock ticks after boot; tells a reused PID apart
+    pub start_ticks: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reading(chip: &str, label: &str, celsius: f32) -> TempReading {
+        TempReading {
+            chip: chip.to_string(),
+            label: label.to_string(),
+            celsius,
+        }
+    }
+
+    #[test]
+    fn parses_process_status_fields() {
+        let status = "Name:\tbash\nPPid:\t1\nThreads:\t4\nVmRSS:\t    2048 kB\nRssAnon:\t     512 kB\n";
+        let details = parse_process_status(status);
+        assert_eq!(details.parent_pid, Some(1));
+        assert_eq!(details.threads, Some(4));
+        assert_eq!(details.resident, Some(2048 * 1024));
+        assert_eq!(details.private, Some(512 * 1024));
+
+        // Kernel threads have PPid 0 and no memory lines
+        let details = parse_process_status("PPid:\t0\nThreads:\t1\n");
+        assert_eq!(details.parent_pid, None);
+        assert_eq!(details.resident, None);
+    }
+
+    #[test]
+    fn parses_cache_sizes_and_cpu_lists() {
+        assert_eq!(parse_cache_size("48K"), Some(48 * 1024));
+        assert_eq!(parse_cache_size("32M"), Some(32 * 1024 * 1024));
+        assert_eq!(parse_cache_size("bogus"), None);
+        assert_eq!(count_cpu_list("0-3,8-11"), 8);
+        assert_eq!(count_cpu_list("0,16\n"), 2);
+        assert_eq!(count_cpu_list(""), 1);
+    }
+
+    #[test]
+    fn parses_remote_process_listing() {
+        let output = "\u{1e}42\n\
+42 (sshd) S 1 42 42 0 -1 4194560 0 0 0 0 0 0 0 0 20 0 3 0 100 0\n\
+2000 500 0 0 0 0 0\n\
+/usr/sbin/sshd -D \n\
+\u{1e}43\n\
+\n";
+        let processes = parse_remote_processes(output);
+
+        assert_eq!(processes.len(), 1);
+        assert_eq!(processes[0].pid, 42);
+        assert_eq!(processes[0].name, "sshd");
+        assert_eq!(processes[0].parent_pid, Some(1));
+        assert_eq!(processes[0].threads, 3);
+        assert_eq!(processes[0].cmdline.as_deref(), Some("/usr/sbin/sshd -D"));
+    }
+
+    #[test]
+    fn parses_process_cpu_time_and_private_memory() {
+        // A name with spaces and parentheses must not shift the fields after it
+        let stat = "7 (Web Content (x)) S 1 7 7 0 -1 0 0 0 0 0 250 50 0 0 20 0 12 0 9000 0";
+        let process = parse_process(7, stat, Some("5000 300 100 0 0 0 0"), None);
+
+        assert_eq!(process.name, "Web Content (x)");
+        assert_eq!(process.threads, 12);
+        assert!((process.cpu_seconds - 3.0).abs() < 1e-9);
+        assert_eq!(process.start_ticks, 9000);
+        assert_eq!(process.memory, 300 * 4096);
+        assert_eq!(process.private_memory, 200 * 4096);
+    }
+
+    #[test]
+    fn splits_batched_remote_reads() {
+        // A readable file, an unreadable one and an empty one
+        let output = "schedutil\n\u{1e}\u{15}\u{1e}\u{1e}";
+        assert_eq!(
+            parse_remote_reads(output, 3),
+            vec![Some("schedutil\n".to_string()), None, Some(String::new())]
+        );
+    }
+
+    #[test]
+    fn parses_proc_partitions_rows() {
+        let content = "major minor  #blocks  name\n\n 259        0  500107608 nvme0n1\n 259        1     524288 nvme0n1p1\n";
+        assert_eq!(
+            parse_proc_partitions(content),
+            vec![
+                ("nvme0n1".to_string(), 500107608 * 1024),
+                ("nvme0n1p1".to_string(), 524288 * 1024),
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_partition_uevents_and_swaps() {
+        let uevent = "MAJOR=8\nMINOR=1\nDEVNAME=sda1\nDEVTYPE=partition\nPARTN=1\nPARTNAME=EFI System Partition\n";
+        assert_eq!(
+            parse_partition_uevent(uevent),
+            Some((1, Some("EFI System Partition".to_string())))
+        );
+        assert_eq!(parse_partition_uevent("PARTN=3\n"), Some((3, None)));
+        assert_eq!(parse_partition_uevent("MAJOR=8\nDEVTYPE=disk\n"), None);
+
+        let swaps = "Filename\tType\tSize\tUsed\tPriority\n/dev/sda2 partition 8388604 0 -2\n";
+        assert_eq!(swap_devices(swaps), vec!["/dev/sda2"]);
+    }
+
+    #[test]
+    fn reads_boot_time_from_proc_stat() {
+        let stat = "cpu  10 0 5 100 0 0 0 0 0 0\nctxt 123\nbtime 1700000000\nprocesses 42\n";
+        assert_eq!(parse_boot_time(stat), Some(1_700_000_000));
+        assert_eq!(parse_boot_time("cpu  10 0 5 100\n"), None);
+    }
+
+    #[test]
+    fn parses_diskstats_rows() {
+        let content = "\
+ 259       0 nvme0n1 5000 10 400000 2500 3000 20 200000 1500 2 3000 4000 0 0 0 0
+   7       0 loop0 1 0 2 0 0 0 0 0 0 0 0
+";
+        let stats = parse_diskstats(content);
+        assert_eq!(stats.len(), 2);
+        assert_eq!(
+            stats[0],
+            DiskStatCounters {
+                name: "nvme0n1".to_string(),
+                reads_completed: 5000,
+                sectors_read: 400000,
+                read_time_ms: 2500,
+                writes_completed: 3000,
+                sectors_written: 200000,
+                write_time_ms: 1500,
+                in_flight: 2,
+                io_time_ms: 3000,
+            }
+        );
+    }
+
+    #[test]
+    fn prefers_package_then_hottest_core() {
+        let readings = vec![
+            reading("nvme", "Composite", 70.0),
+            reading("coretemp", "Core 0", 48.0),
+            reading("coretemp", "Core 1", 52.0),
+        ];
+        assert_eq!(pick_cpu_temperature(&readings), Some(52.0));
+
+        let mut with_package = readings;
+        with_package.push(reading("coretemp", "Package id 0", 55.0));
+        assert_eq!(pick_cpu_temperature(&with_package), Some(55.0));
+
+        assert_eq!(pick_cpu_temperature(&[reading("nvme", "Composite", 70.0)]), None);
+        assert_eq!(pick_cpu_temperature(&[reading("k10temp", "", 61.5)]), Some(61.5));
+    }
 }
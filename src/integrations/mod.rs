@@ -1,7 +1,8 @@
 pub mod powershell;
 pub mod ollama;
 pub mod linux_sys;
+pub mod remote;
 
 pub use powershell::PowerShellExecutor;
 pub use ollama::{ChatLogMetadata, OllamaClient, OllamaData};
-pub use linux_sys::LinuxSysMonitor;
+pub use linux_sys::{DiskStatCounters, LinuxSysMonitor};
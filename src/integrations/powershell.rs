@@ -1,18 +1,93 @@
 use anyhow::{Context, Result};
 use base64::Engine;
 use parking_lot::RwLock;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::process::{Command as StdCommand, Stdio};
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
-use tokio::io::AsyncReadExt;
-use tokio::process::Command as TokioCommand;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::process::{Child, ChildStdin, ChildStdout, Command as TokioCommand};
+use tokio::sync::{Mutex as AsyncMutex, Semaphore};
 use tokio::time::timeout;
 
+use super::remote::SshTarget;
+
 const MAX_OUTPUT_BYTES: usize = 1024 * 1024;
 const MAX_LOG_CHARS: usize = 4096;
-const PS_ENCODING_PREFIX: &str =
-    "[Console]::OutputEncoding = [System.Text.Encoding]::UTF8\n$OutputEncoding = [System.Text.Encoding]::UTF8\n";
+/// Run before every script: UTF-8 output, and the invariant culture so
+/// number formatting and `[double]::TryParse` don't follow the user's locale
+/// (`2,5` instead of `2.5` on German or French Windows).
+const PS_SCRIPT_PREFIX: &str = "[Console]::OutputEncoding = [System.Text.Encoding]::UTF8\n\
+    $OutputEncoding = [System.Text.Encoding]::UTF8\n\
+    [System.Threading.Thread]::CurrentThread.CurrentCulture = [System.Globalization.CultureInfo]::InvariantCulture\n\
+    [System.Threading.Thread]::CurrentThread.CurrentUICulture = [System.Globalization.CultureInfo]::InvariantCulture\n";
+
+/// Process-wide limiter shared by every executor built with
+/// `with_max_concurrent`, keyed by its permit count.
+static CONCURRENCY_LIMITER: parking_lot::Mutex<Option<(usize, Arc<Semaphore>)>> =
+    parking_lot::const_mutex(None);
+
+/// Persistent sessions shared by executors built with
+/// `with_persistent_session`, one per shell and host. Executors hold the
+/// strong refs and the map only weak ones, so a session ends with the last
+/// executor using it.
+static SHARED_SESSIONS: parking_lot::Mutex<Option<SessionMap>> = parking_lot::const_mutex(None);
+
+type SessionKey = (String, Option<SshTarget>);
+type SharedSession = Arc<AsyncMutex<Option<PersistentSession>>>;
+type SessionMap = HashMap<SessionKey, Weak<AsyncMutex<Option<PersistentSession>>>>;
+
+/// Executable names that mean "whatever PowerShell is installed".
+const DEFAULT_EXECUTABLES: &[&str] = &["powershell.exe", "powershell"];
+
+/// Shell picked for a default `executable`, probed once per process.
+static AUTO_EXECUTABLE: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+
+/// Prefer PowerShell 7 (`pwsh`) when the configured executable is the
+/// default and `pwsh` is on PATH; any other configured path is used as is.
+pub fn resolve_executable(configured: &str) -> String {
+    if !DEFAULT_EXECUTABLES
+        .iter()
+        .any(|name| configured.eq_ignore_ascii_case(name))
+    {
+        return configured.to_string();
+    }
+
+    AUTO_EXECUTABLE
+        .get_or_init(|| {
+            let path = std::env::var_os("PATH").unwrap_or_default();
+            let chosen = if find_on_path(&path, "pwsh").is_some() {
+                "pwsh".to_string()
+            } else {
+                configured.to_string()
+            };
+            log::info!("Using PowerShell executable '{}'", chosen);
+            chosen
+        })
+        .clone()
+}
+
+fn find_on_path(path: &std::ffi::OsStr, program: &str) -> Option<std::path::PathBuf> {
+    let names: &[String] = &[program.to_string(), format!("{}.exe", program)];
+    std::env::split_paths(path)
+        .flat_map(|dir| names.iter().map(move |name| dir.join(name)))
+        .find(|candidate| candidate.is_file())
+}
+
+fn shared_limiter(max_concurrent: usize) -> Arc<Semaphore> {
+    let mut limiter = CONCURRENCY_LIMITER.lock();
+    match limiter.as_ref() {
+        Some((max, semaphore)) if *max == max_concurrent => Arc::clone(semaphore),
+        _ => {
+            // Commands holding permits of a replaced limiter finish unaffected
+            let semaphore = Arc::new(Semaphore::new(max_concurrent));
+            *limiter = Some((max_concurrent, Arc::clone(&semaphore)));
+            semaphore
+        }
+    }
+}
 
 struct LimitedOutput {
     bytes: Vec<u8>,
@@ -87,6 +162,135 @@ where
     Ok(LimitedOutput { bytes: buf, truncated })
 }
 
+fn shared_session(executable: &str, remote: Option<&SshTarget>) -> SharedSession {
+    let key = (executable.to_string(), remote.cloned());
+    let mut sessions = SHARED_SESSIONS.lock();
+    session_for(sessions.get_or_insert_with(HashMap::new), key)
+}
+
+fn session_for(sessions: &mut SessionMap, key: SessionKey) -> SharedSession {
+    if let Some(session) = sessions.get(&key).and_then(Weak::upgrade) {
+        return session;
+    }
+    sessions.retain(|_, session| session.strong_count() > 0);
+    let session = Arc::new(AsyncMutex::new(None));
+    sessions.insert(key, Arc::downgrade(&session));
+    session
+}
+
+/// A long-lived `powershell -Command -` child. Each script is sent as a single
+/// line and its output is read back until a per-call sentinel line.
+struct PersistentSession {
+    _child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl PersistentSession {
+    async fn spawn(executable: &str, remote: Option<&SshTarget>) -> Result<Self> {
+        let mut child = shell_command(
+            executable,
+            &["-NoProfile", "-NonInteractive", "-NoExit", "-Command", "-"],
+            remote,
+        )
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .kill_on_drop(true)
+            .spawn()
+            .context("Failed to spawn persistent PowerShell session")?;
+
+        let stdin = child
+            .stdin
+            .take()
+            .context("Failed to capture PowerShell session stdin")?;
+        let stdout = child
+            .stdout
+            .take()
+            .context("Failed to capture PowerShell session stdout")?;
+
+        let mut session = Self {
+            _child: child,
+            stdin,
+            stdout: BufReader::new(stdout),
+        };
+        session
+            .write_line(&PS_SCRIPT_PREFIX.replace('\n', "; "))
+            .await?;
+
+        Ok(session)
+    }
+
+    async fn write_line(&mut self, line: &str) -> Result<()> {
+        self.stdin
+            .write_all(line.as_bytes())
+            .await
+            .context("Failed to write to PowerShell session")?;
+        self.stdin
+            .write_all(b"\n")
+            .await
+            .context("Failed to write to PowerShell session")?;
+        self.stdin
+            .flush()
+            .await
+            .context("Failed to flush PowerShell session")?;
+        Ok(())
+    }
+
+    /// Outer `Err` means the pipe is broken and the session must be discarded;
+    /// inner `Err` carries a terminating error thrown by the script itself.
+    async fn run(&mut self, command: &str) -> Result<std::result::Result<String, String>> {
+        let stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_else(|_| Duration::from_secs(0))
+            .as_nanos();
+        let sentinel = format!("__TUI_PS_END_{}__", stamp);
+
+        // `-Command -` executes stdin line by line, so multi-line scripts are
+        // shipped base64-encoded and rebuilt into a script block.
+        let line = format!(
+            "try {{ & ([ScriptBlock]::Create([Text.Encoding]::Unicode.GetString([Convert]::FromBase64String('{encoded}')))) | Out-String -Stream -Width 4096 | ForEach-Object {{ [Console]::Out.WriteLine($_) }}; [Console]::Out.WriteLine('{sentinel}:OK') }} catch {{ [Console]::Out.WriteLine('{sentinel}:ERR:' + ($_.Exception.Message -replace \"`r?`n\", ' ')) }}",
+            encoded = encode_powershell_command(command),
+            sentinel = sentinel,
+        );
+        self.write_line(&line).await?;
+
+        let mut output = Vec::new();
+        let mut truncated = false;
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+            let n = self
+                .stdout
+                .read_until(b'\n', &mut buf)
+                .await
+                .context("Failed to read PowerShell session output")?;
+            if n == 0 {
+                anyhow::bail!("PowerShell session closed unexpectedly");
+            }
+
+            let text = String::from_utf8_lossy(&buf);
+            let trimmed = text.trim_end_matches(['\r', '\n']);
+            if let Some(status) = trimmed.strip_prefix(sentinel.as_str()) {
+                if truncated {
+                    log::warn!("PowerShell stdout truncated to {} bytes", MAX_OUTPUT_BYTES);
+                }
+                let stdout = decode_output(&output);
+                return Ok(match status.strip_prefix(":ERR:") {
+                    Some(message) => Err(message.trim().to_string()),
+                    None => Ok(stdout),
+                });
+            }
+
+            if output.len() + buf.len() <= MAX_OUTPUT_BYTES {
+                output.extend_from_slice(&buf);
+            } else {
+                truncated = true;
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 struct CacheEntry {
     value: String,
@@ -96,9 +300,27 @@ struct CacheEntry {
 pub struct PowerShellExecutor {
     executable: String,
     timeout: Duration,
-    cache: Arc<RwLock<HashMap<String, CacheEntry>>>,
+    /// Keyed by `script_hash` of the command text
+    cache: Arc<RwLock<HashMap<u64, CacheEntry>>>,
     cache_ttl: Duration,
     cache_enabled: bool,
+    /// Set by `with_persistent_session`; kept here so the session outlives
+    /// each call
+    session: Option<SharedSession>,
+    limiter: Option<Arc<Semaphore>>,
+    remote: Option<SshTarget>,
+}
+
+/// `executable args`, run locally or on `remote` over SSH.
+fn shell_command(executable: &str, args: &[&str], remote: Option<&SshTarget>) -> TokioCommand {
+    match remote {
+        Some(target) => target.tokio_command(executable, args),
+        None => {
+            let mut command = TokioCommand::new(executable);
+            command.args(args);
+            command
+        }
+    }
 }
 
 impl PowerShellExecutor {
@@ -111,41 +333,148 @@ impl PowerShellExecutor {
         use_cache: bool,
     ) -> Self {
         Self {
-            executable,
+            executable: resolve_executable(&executable),
             timeout: Duration::from_secs(timeout_seconds),
             cache: Arc::new(RwLock::new(HashMap::new())),
             cache_ttl: Duration::from_secs(cache_ttl_seconds),
             cache_enabled: use_cache && cache_ttl_seconds > 0,
+            session: None,
+            limiter: None,
+            remote: None,
         }
     }
 
-    pub async fn execute(&self, command: &str) -> Result<String> {
-        let cache_key = command.to_string();
-        // Check cache
-        if self.cache_enabled {
-            let cache = self.cache.read();
-            if let Some(entry) = cache.get(&cache_key) {
-                if entry.timestamp.elapsed() < self.cache_ttl {
-                    return Ok(entry.value.clone());
+    /// Route commands through one long-lived PowerShell process instead of
+    /// spawning a new one per call. All executors with the same shell and
+    /// host share that process; it is started lazily and replaced if its
+    /// pipes break.
+    pub fn with_persistent_session(mut self, enabled: bool) -> Self {
+        self.session = enabled.then(|| shared_session(&self.executable, self.remote.as_ref()));
+        self
+    }
+
+    /// Let commands run as long as they need, for actions like recycling a
+    /// large folder that must not be killed halfway through.
+    pub fn without_timeout(mut self) -> Self {
+        self.timeout = Duration::MAX;
+        self
+    }
+
+    /// Wait for one of `max_concurrent` process-wide slots before running each
+    /// command, so monitors starting together don't launch a burst of
+    /// PowerShell processes. 0 leaves the executor unlimited.
+    pub fn with_max_concurrent(mut self, max_concurrent: usize) -> Self {
+        self.limiter = (max_concurrent > 0).then(|| shared_limiter(max_concurrent));
+        self
+    }
+
+    /// Run every command on `remote` over SSH instead of on this machine.
+    /// Connection failures put the host into a shared reconnect backoff.
+    pub fn with_remote(mut self, remote: Option<SshTarget>) -> Self {
+        self.remote = remote;
+        if self.session.is_some() {
+            self.session = Some(shared_session(&self.executable, self.remote.as_ref()));
+        }
+        self
+    }
+
+    pub fn remote(&self) -> Option<&SshTarget> {
+        self.remote.as_ref()
+    }
+
+    /// Returns `None` when the session is unusable so the caller can fall
+    /// back to spawning a process.
+    async fn execute_in_session(&self, command: &str) -> Option<Result<String>> {
+        let session = self.session.as_ref()?;
+        // Held for the whole call so concurrent callers can't interleave output
+        let mut guard = session.lock().await;
+
+        if guard.is_none() {
+            match PersistentSession::spawn(&self.executable, self.remote.as_ref()).await {
+                Ok(new_session) => *guard = Some(new_session),
+                Err(e) => {
+                    log::warn!("PowerShell session unavailable, spawning per call: {}", e);
+                    return None;
                 }
             }
         }
 
-        let command = format!("{}{}", PS_ENCODING_PREFIX, command);
+        let active = guard.as_mut()?;
+        match timeout(self.timeout, active.run(command)).await {
+            Ok(Ok(Ok(stdout))) => Some(Ok(stdout)),
+            Ok(Ok(Err(message))) => Some(Err(anyhow::anyhow!(
+                "PowerShell command failed: {}",
+                message
+            ))),
+            Ok(Err(e)) => {
+                log::warn!("PowerShell session failed, falling back: {}", e);
+                *guard = None;
+                None
+            }
+            Err(_) => {
+                // Output of the timed-out script would leak into the next call
+                *guard = None;
+                Some(Err(anyhow::anyhow!(
+                    "PowerShell command timed out after {}s",
+                    self.timeout.as_secs()
+                )))
+            }
+        }
+    }
+
+    /// Runs `command`, returning the stored output if the same script ran
+    /// within `cache_ttl_seconds`. Use `execute_uncached` for commands with
+    /// side effects.
+    pub async fn execute(&self, command: &str) -> Result<String> {
+        let cache_key = script_hash(command);
+        if let Some(value) = self.cached(cache_key) {
+            return Ok(value);
+        }
+
+        let stdout = self.execute_uncached(command).await?;
+        self.store_cache(cache_key, &stdout);
+        Ok(stdout)
+    }
+
+    /// Always runs `command` and leaves the cache untouched.
+    pub async fn execute_uncached(&self, command: &str) -> Result<String> {
+        // Dropped on every return path, including errors and timeouts
+        let _permit = match &self.limiter {
+            Some(limiter) => Some(
+                limiter
+                    .acquire()
+                    .await
+                    .context("PowerShell concurrency limiter closed")?,
+            ),
+            None => None,
+        };
+
+        let command = format!("{}{}", PS_SCRIPT_PREFIX, command);
 
         log::debug!(
             "Executing PowerShell command: {}",
             sanitize_for_log(&command)
         );
 
+        if let Some(target) = &self.remote {
+            target.check_connection()?;
+        }
+
+        if let Some(result) = self.execute_in_session(&command).await {
+            return result.map(strip_bom);
+        }
+
         let encoded_command = encode_powershell_command(&command);
-        let mut child = TokioCommand::new(&self.executable)
-            .args(&[
+        let mut child = shell_command(
+            &self.executable,
+            &[
                 "-NoProfile",
                 "-NonInteractive",
                 "-EncodedCommand",
                 &encoded_command,
-            ])
+            ],
+            self.remote.as_ref(),
+        )
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()
@@ -191,6 +520,10 @@ impl PowerShellExecutor {
             log::warn!("PowerShell stderr truncated to {} bytes", MAX_OUTPUT_BYTES);
         }
 
+        if let Some(target) = &self.remote {
+            target.record_status(&status);
+        }
+
         let stdout_text = decode_output(&stdout.bytes);
         let stderr_text = decode_output(&stderr.bytes);
 
@@ -214,21 +547,39 @@ impl PowerShellExecutor {
             anyhow::bail!("PowerShell command failed (exit {}): {}", code, message);
         }
 
-        let stdout = stdout_text;
+        Ok(strip_bom(stdout_text))
+    }
+
+    fn cached(&self, cache_key: u64) -> Option<String> {
+        if !self.cache_enabled {
+            return None;
+        }
+        let mut cache = self.cache.write();
+        match cache.get(&cache_key) {
+            Some(entry) if entry.timestamp.elapsed() < self.cache_ttl => Some(entry.value.clone()),
+            Some(_) => {
+                cache.remove(&cache_key);
+                None
+            }
+            None => None,
+        }
+    }
 
-        // Update cache
+    fn store_cache(&self, cache_key: u64, stdout: &str) {
         if self.cache_enabled {
             let mut cache = self.cache.write();
+            // Expired entries are only dropped here and on lookup, so scripts
+            // that stop being polled don't pin their output forever
+            let ttl = self.cache_ttl;
+            cache.retain(|_, entry| entry.timestamp.elapsed() < ttl);
             cache.insert(
                 cache_key,
                 CacheEntry {
-                    value: stdout.clone(),
+                    value: stdout.to_string(),
                     timestamp: Instant::now(),
                 },
             );
         }
-
-        Ok(stdout)
     }
 
     pub async fn execute_batch(&self, commands: &[&str]) -> Result<Vec<String>> {
@@ -244,7 +595,7 @@ impl PowerShellExecutor {
         let escaped_separator = separator.replace('\'', "''");
 
         let mut script = String::new();
-        script.push_str(PS_ENCODING_PREFIX);
+        script.push_str(PS_SCRIPT_PREFIX);
         script.push_str("$ErrorActionPreference = 'Continue'\n");
         script.push_str("$ProgressPreference = 'SilentlyContinue'\n");
         script.push_str("$WarningPreference = 'SilentlyContinue'\n");
@@ -266,6 +617,7 @@ impl PowerShellExecutor {
     }
 
     pub fn check_environment(executable: &str) -> PowerShellEnvironmentStatus {
+        let executable = &resolve_executable(executable);
         let version_check = StdCommand::new(executable)
             .args([
                 "-NoProfile",
@@ -325,15 +677,33 @@ impl Clone for PowerShellExecutor {
             cache: Arc::clone(&self.cache),
             cache_ttl: self.cache_ttl,
             cache_enabled: self.cache_enabled,
+            session: self.session.clone(),
+            limiter: self.limiter.clone(),
+            remote: self.remote.clone(),
         }
     }
 }
 
+fn script_hash(script: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    script.hash(&mut hasher);
+    hasher.finish()
+}
+
 pub struct PowerShellEnvironmentStatus {
     pub available: bool,
     pub missing_modules: Vec<String>,
 }
 
+/// Some hosts start `ConvertTo-Json` output with a UTF-8 BOM, which
+/// `serde_json::from_str` rejects.
+fn strip_bom(text: String) -> String {
+    match text.strip_prefix('\u{feff}') {
+        Some(rest) => rest.to_string(),
+        None => text,
+    }
+}
+
 fn decode_output(bytes: &[u8]) -> String {
     if bytes.is_empty() {
         return String::new();
@@ -422,7 +792,12 @@ fn encoding_for_codepage(codepage: u32) -> Option<&'static encoding_rs::Encoding
 
 #[cfg(test)]
 mod tests {
-    use super::split_batch_output;
+    use super::{
+        find_on_path, resolve_executable, script_hash, session_for, shared_limiter,
+        split_batch_output, strip_bom, CacheEntry, PowerShellExecutor, SessionMap,
+    };
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
 
     #[test]
     fn split_batch_output_ok() {
@@ -442,4 +817,105 @@ mod tests {
             "unexpected error: {err}"
         );
     }
+
+    #[test]
+    fn cache_returns_fresh_entries_and_evicts_stale_ones() {
+        let ps = PowerShellExecutor::new("pwsh".to_string(), 5, 60, true);
+        let fresh = script_hash("Get-Date");
+        let stale = script_hash("Get-Process");
+        assert_ne!(fresh, stale);
+
+        ps.store_cache(fresh, "now");
+        ps.cache.write().insert(
+            stale,
+            CacheEntry {
+                value: "old".to_string(),
+                timestamp: Instant::now() - Duration::from_secs(120),
+            },
+        );
+
+        assert_eq!(ps.cached(fresh).as_deref(), Some("now"));
+        assert_eq!(ps.cached(stale), None);
+        assert!(!ps.cache.read().contains_key(&stale));
+
+        let disabled = PowerShellExecutor::new("pwsh".to_string(), 5, 0, true);
+        disabled.store_cache(fresh, "now");
+        assert_eq!(disabled.cached(fresh), None);
+    }
+
+    #[test]
+    fn finds_programs_on_path() {
+        let dir = std::env::temp_dir().join(format!("tui-plus-path-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("pwsh.exe"), "").unwrap();
+        let path = std::env::join_paths([dir.clone()]).unwrap();
+
+        let found = find_on_path(&path, "pwsh");
+        let missing = find_on_path(&path, "powershell");
+        std::fs::remove_dir_all(&dir).ok();
+        assert_eq!(found, Some(dir.join("pwsh.exe")));
+        assert_eq!(missing, None);
+        assert_eq!(resolve_executable("C:\\custom\\pwsh.exe"), "C:\\custom\\pwsh.exe");
+    }
+
+    #[test]
+    fn strips_a_leading_bom_only() {
+        assert_eq!(strip_bom("\u{feff}[1,2]".to_string()), "[1,2]");
+        assert_eq!(strip_bom("[1]\u{feff}".to_string()), "[1]\u{feff}");
+    }
+
+    #[test]
+    fn shared_limiter_is_reused_per_size() {
+        let a = shared_limiter(3);
+        let b = shared_limiter(3);
+        assert!(Arc::ptr_eq(&a, &b));
+        assert_eq!(a.available_permits(), 3);
+
+        let c = shared_limiter(1);
+        assert!(!Arc::ptr_eq(&a, &c));
+        assert_eq!(c.available_permits(), 1);
+    }
+
+    #[test]
+    fn shared_session_is_reused_per_shell_and_host() {
+        let mut sessions = SessionMap::new();
+        let key = |shell: &str| (shell.to_string(), None);
+        let a = session_for(&mut sessions, key("pwsh"));
+        let b = session_for(&mut sessions, key("pwsh"));
+        assert!(Arc::ptr_eq(&a, &b));
+
+        // A second shell gets its own session without replacing the first
+        let c = session_for(&mut sessions, key("powershell.exe"));
+        assert!(!Arc::ptr_eq(&a, &c));
+        assert!(Arc::ptr_eq(&a, &session_for(&mut sessions, key("pwsh"))));
+    }
+
+    /// Stands in for PowerShell: answers each script line with its own PID
+    /// followed by the call's sentinel.
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn persistent_session_survives_between_calls() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join(format!("tui-plus-session-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let shell = dir.join("fake-pwsh");
+        std::fs::write(
+            &shell,
+            "#!/bin/sh\nwhile read -r line; do\n  s=$(printf '%s' \"$line\" | grep -o '__TUI_PS_END_[0-9]*__' | head -n 1)\n  [ -n \"$s\" ] && echo $$ && echo \"$s:OK\"\ndone\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&shell, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let executor = PowerShellExecutor::new(shell.display().to_string(), 5, 0, false)
+            .with_persistent_session(true);
+        let first = executor.execute_in_session("first").await;
+        let second = executor.clone().execute_in_session("second").await;
+        std::fs::remove_dir_all(&dir).ok();
+
+        let first = first.expect("session available").unwrap();
+        let second = second.expect("session available").unwrap();
+        assert!(!first.trim().is_empty());
+        assert_eq!(first, second);
+    }
 }
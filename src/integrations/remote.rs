@@ -0,0 +1,194 @@
+//! Runs monitor commands on another host through the system `ssh` client.
+
+use anyhow::{Context, Result};
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::process::{Command as StdCommand, ExitStatus};
+use std::time::{Duration, Instant};
+use tokio::process::Command as TokioCommand;
+
+/// `ssh` exits with 255 when the connection itself failed, as opposed to the
+/// remote command failing.
+const SSH_CONNECTION_FAILED: i32 = 255;
+const MIN_BACKOFF: Duration = Duration::from_secs(2);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Connection failures per destination, shared by every monitor so one
+/// outage pauses them all until the next retry.
+static CONNECTIONS: Mutex<Option<HashMap<String, Backoff>>> = parking_lot::const_mutex(None);
+
+#[derive(Debug, Clone, Copy)]
+struct Backoff {
+    retry_at: Instant,
+    delay: Duration,
+}
+
+/// Host that commands are sent to instead of running locally.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SshTarget {
+    pub host: String,
+    pub user: Option<String>,
+    pub key_path: Option<String>,
+    pub port: Option<u16>,
+}
+
+impl SshTarget {
+    /// `user@host`, or just the host when no user is set.
+    pub fn destination(&self) -> String {
+        match &self.user {
+            Some(user) => format!("{}@{}", user, self.host),
+            None => self.host.clone(),
+        }
+    }
+
+    /// Arguments to `ssh` that run `program args` on the host. The remote
+    /// side goes through a shell, so every word is quoted for it.
+    fn ssh_args(&self, program: &str, args: &[&str]) -> Vec<String> {
+        let mut ssh_args = vec![
+            "-o".to_string(),
+            "BatchMode=yes".to_string(),
+            "-o".to_string(),
+            "ConnectTimeout=5".to_string(),
+        ];
+        // Reuse one connection for the many short commands monitors send
+        #[cfg(unix)]
+        ssh_args.extend(
+            [
+                "-o",
+                "ControlMaster=auto",
+                "-o",
+                "ControlPath=~/.ssh/tui-plus-%C",
+                "-o",
+                "ControlPersist=60",
+            ]
+            .map(String::from),
+        );
+        if let Some(key_path) = &self.key_path {
+            ssh_args.push("-i".to_string());
+            ssh_args.push(key_path.clone());
+        }
+        if let Some(port) = self.port {
+            ssh_args.push("-p".to_string());
+            ssh_args.push(port.to_string());
+        }
+        ssh_args.push(self.destination());
+        ssh_args.push("--".to_string());
+        ssh_args.push(shell_quote(program));
+        ssh_args.extend(args.iter().map(|arg| shell_quote(arg)));
+        ssh_args
+    }
+
+    pub fn command(&self, program: &str, args: &[&str]) -> StdCommand {
+        let mut command = StdCommand::new("ssh");
+        command.args(self.ssh_args(program, args));
+        command
+    }
+
+    pub fn tokio_command(&self, program: &str, args: &[&str]) -> TokioCommand {
+        let mut command = TokioCommand::new("ssh");
+        command.args(self.ssh_args(program, args));
+        command
+    }
+
+    /// Fails fast while the host is in reconnect backoff.
+    pub fn check_connection(&self) -> Result<()> {
+        let connections = CONNECTIONS.lock();
+        if let Some(backoff) = connections.as_ref().and_then(|c| c.get(&self.destination())) {
+            let now = Instant::now();
+            if now < backoff.retry_at {
+                anyhow::bail!(
+                    "Connection to {} lost, retrying in {}s",
+                    self.destination(),
+                    (backoff.retry_at - now).as_secs() + 1
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Start or extend the backoff after a connection failure, or clear it
+    /// once a command gets through.
+    pub fn record_status(&self, status: &ExitStatus) {
+        let mut connections = CONNECTIONS.lock();
+        let connections = connections.get_or_insert_with(HashMap::new);
+        if status.code() == Some(SSH_CONNECTION_FAILED) {
+            let delay = connections
+                .get(&self.destination())
+                .map_or(MIN_BACKOFF, |backoff| (backoff.delay * 2).min(MAX_BACKOFF));
+            log::warn!(
+                "SSH connection to {} failed, retrying in {}s",
+                self.destination(),
+                delay.as_secs()
+            );
+            connections.insert(
+                self.destination(),
+                Backoff {
+                    retry_at: Instant::now() + delay,
+                    delay,
+                },
+            );
+        } else if connections.remove(&self.destination()).is_some() {
+            log::info!("SSH connection to {} restored", self.destination());
+        }
+    }
+
+    /// Whether the last command reached the host.
+    pub fn is_connected(&self) -> bool {
+        CONNECTIONS
+            .lock()
+            .as_ref()
+            .is_none_or(|connections| !connections.contains_key(&self.destination()))
+    }
+
+    /// Run `program args` on the host and return its stdout.
+    pub fn output(&self, program: &str, args: &[&str]) -> Result<String> {
+        self.check_connection()?;
+        let output = self
+            .command(program, args)
+            .output()
+            .context("Failed to run ssh")?;
+        self.record_status(&output.status);
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("{} on {}: {}", program, self.destination(), stderr.trim());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+/// Single-quote `word` for a POSIX shell unless it only has safe characters.
+fn shell_quote(word: &str) -> String {
+    let safe = !word.is_empty()
+        && word
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./=:,+@%".contains(c));
+    if safe {
+        word.to_string()
+    } else {
+        format!("'{}'", word.replace('\'', "'\\''"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quotes_remote_arguments() {
+        let target = SshTarget {
+            host: "server".to_string(),
+            user: Some("admin".to_string()),
+            key_path: None,
+            port: Some(2222),
+        };
+        let args = target.ssh_args("cat", &["/proc/stat", "it's here"]);
+
+        assert_eq!(target.destination(), "admin@server");
+        assert!(args.windows(2).any(|pair| pair == ["-p", "2222"]));
+        assert_eq!(
+            args[args.len() - 5..],
+            ["admin@server", "--", "cat", "/proc/stat", "'it'\\''s here'"]
+        );
+    }
+}
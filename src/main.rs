@@ -20,6 +20,11 @@ mod monitors;
 mod integrations;
 mod events;
 mod utils;
+mod snapshot;
+mod report;
+mod recorder;
+#[cfg(feature = "metrics")]
+mod metrics;
 
 use app::App;
 use events::{EventHandler, AppEvent};
@@ -30,8 +35,18 @@ async fn main() -> Result<()> {
 
     set_console_utf8();
 
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "--json") {
+        return run_json_snapshot(&args).await;
+    }
+    if let Some(idx) = args.iter().position(|arg| arg == "--report") {
+        return run_report(&args, idx).await;
+    }
+
+    let read_only = args.iter().any(|arg| arg == "--read-only");
+
     // Setup terminal with proper error handling
-    if let Err(e) = setup_terminal().await {
+    if let Err(e) = setup_terminal(read_only).await {
         eprintln!("Failed to setup terminal: {}", e);
         return Err(e);
     }
@@ -39,6 +54,57 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// `--json [--once] [--timeout <seconds>]`: print one snapshot and exit.
+async fn run_json_snapshot(args: &[String]) -> Result<()> {
+    let (config, limit) = load_headless_config(args)?;
+    let snapshot = snapshot::collect(&config, limit).await;
+    writeln!(io::stdout(), "{}", serde_json::to_string_pretty(&snapshot)?)?;
+
+    if snapshot.all_failed() {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+/// `--report [<path>] [--timeout <seconds>]`: write a diagnostic report and
+/// exit. Markdown by default, JSON when the path ends in `.json`.
+async fn run_report(args: &[String], idx: usize) -> Result<()> {
+    let path = args
+        .get(idx + 1)
+        .filter(|arg| !arg.starts_with("--"))
+        .cloned()
+        .unwrap_or_else(report::default_file_name);
+    let (config, limit) = load_headless_config(args)?;
+
+    let snapshot = snapshot::collect(&config, limit).await;
+    report::write(Path::new(&path), &snapshot, &config)?;
+    writeln!(io::stdout(), "Wrote report to {}", path)?;
+    for (monitor, error) in &snapshot.errors {
+        eprintln!("Warning: {} monitor failed: {}", monitor, error);
+    }
+
+    Ok(())
+}
+
+/// Config plus per-monitor time limit for the headless modes.
+fn load_headless_config(args: &[String]) -> Result<(app::Config, std::time::Duration)> {
+    let (config, load_error) = app::Config::load_or_default(app::resolve_config_path()?)?;
+    if let Some(e) = load_error {
+        eprintln!("Warning: {}; using default config", e);
+    }
+
+    let limit = match args.iter().position(|arg| arg == "--timeout") {
+        Some(idx) => args
+            .get(idx + 1)
+            .and_then(|value| value.parse::<u64>().ok())
+            .ok_or_else(|| anyhow::anyhow!("--timeout expects a number of seconds"))?,
+        None => config.powershell.timeout_seconds,
+    };
+
+    Ok((config, std::time::Duration::from_secs(limit.max(1))))
+}
+
 fn init_logging() {
     let mut builder = env_logger::Builder::from_env(
         env_logger::Env::default().default_filter_or("info"),
@@ -67,7 +133,7 @@ fn init_logging() {
         }
     }
 
-    builder.init();
+    utils::log_buffer::install(builder.build());
 }
 
 #[cfg(windows)]
@@ -87,7 +153,7 @@ fn set_console_utf8() {
 #[cfg(not(windows))]
 fn set_console_utf8() {}
 
-async fn setup_terminal() -> Result<()> {
+async fn setup_terminal(read_only: bool) -> Result<()> {
     enable_raw_mode()?;
 
     let mut stdout = io::stdout();
@@ -100,11 +166,11 @@ async fn setup_terminal() -> Result<()> {
     terminal.clear()?;
 
     // Create app
-    let app = match App::new().await {
+    let app = match App::new(read_only).await {
         Ok(app) => app,
         Err(e) => {
             // Cleanup terminal before returning error
-            cleanup_terminal(&mut terminal)?;
+            cleanup_terminal(&mut terminal, None)?;
             return Err(e);
         }
     };
@@ -118,15 +184,24 @@ async fn setup_terminal() -> Result<()> {
     let event_handler = EventHandler::new(tick_rate_ms.max(50)); // At least 20fps
 
     // Run the application
-    let res = run_app(&mut terminal, app_state, event_handler).await;
+    let res = run_app(&mut terminal, Arc::clone(&app_state), event_handler).await;
 
     // Always cleanup terminal
-    cleanup_terminal(&mut terminal)?;
+    let app = app_state.lock().await;
+    app.state.save_last_tab();
+    app.state.save_default_sorts();
+    cleanup_terminal(&mut terminal, app.state.recorder.as_ref())?;
 
     res
 }
 
-fn cleanup_terminal(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
+fn cleanup_terminal(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    recorder: Option<&recorder::Recorder>,
+) -> Result<()> {
+    if let Some(recorder) = recorder {
+        recorder.flush();
+    }
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
@@ -176,7 +251,15 @@ async fn run_app(
                 let mut app = app_state.lock().await;
                 app.handle_event(crossterm_event).await?
             }
-            AppEvent::Tick => true,
+            AppEvent::Tick => {
+                let mut app = app_state.lock().await;
+                app.state.update_alerts();
+                app.state.update_notifications();
+                app.state.update_deletes();
+                app.state.update_config();
+                app.state.update_idle();
+                true
+            }
         };
 
         if !should_continue {
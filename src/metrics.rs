@@ -0,0 +1,197 @@
+//! Optional Prometheus exporter. Serves `/metrics` in the text exposition
+//! format from the same shared monitor data the UI renders.
+
+use anyhow::{Context, Result};
+use parking_lot::RwLock;
+use std::fmt::Write as _;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::oneshot;
+
+use crate::monitors::{CpuData, DiskData, GpuData, RamData};
+
+/// Handles to the monitor data the exporter reads on each scrape.
+#[derive(Clone)]
+pub struct MetricsSources {
+    pub cpu: Arc<RwLock<Option<CpuData>>>,
+    pub ram: Arc<RwLock<Option<RamData>>>,
+    pub gpu: Arc<RwLock<Option<GpuData>>>,
+    pub disk: Arc<RwLock<Option<DiskData>>>,
+}
+
+/// Keeps the exporter running; dropping it stops the server.
+pub struct MetricsServer {
+    _shutdown: oneshot::Sender<()>,
+}
+
+impl MetricsServer {
+    pub async fn start(bind_address: &str, sources: MetricsSources) -> Result<Self> {
+        let listener = TcpListener::bind(bind_address)
+            .await
+            .with_context(|| format!("Failed to bind metrics endpoint on {}", bind_address))?;
+        log::info!(
+            "Serving Prometheus metrics on http://{}/metrics",
+            bind_address
+        );
+
+        let (shutdown_tx, mut shutdown_rx) = oneshot::channel::<()>();
+
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = &mut shutdown_rx => break,
+                    accepted = listener.accept() => match accepted {
+                        Ok((stream, _)) => {
+                            let sources = sources.clone();
+                            tokio::spawn(async move {
+                                if let Err(e) = handle_connection(stream, &sources).await {
+                                    log::debug!("Metrics request failed: {}", e);
+                                }
+                            });
+                        }
+                        Err(e) => log::warn!("Metrics endpoint accept failed: {}", e),
+                    },
+                }
+            }
+            log::info!("Metrics endpoint stopped");
+        });
+
+        Ok(Self {
+            _shutdown: shutdown_tx,
+        })
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream, sources: &MetricsSources) -> Result<()> {
+    let mut buf = [0u8; 1024];
+    let read = stream.read(&mut buf).await?;
+    let request = String::from_utf8_lossy(&buf[..read]);
+    let path = request
+        .lines()
+        .next()
+        .and_then(|line| {
+            let mut parts = line.split_whitespace();
+            match (parts.next(), parts.next()) {
+                (Some("GET"), Some(path)) => Some(path),
+                _ => None,
+            }
+        })
+        .unwrap_or("");
+
+    let response = if path == "/metrics" {
+        let body = render_metrics(sources);
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        )
+    } else {
+        "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string()
+    };
+
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+fn render_metrics(sources: &MetricsSources) -> String {
+    let mut out = String::new();
+
+    if let Some(cpu) = sources.cpu.read().as_ref() {
+        gauge(&mut out, "cpu_usage_percent", "Overall CPU usage.");
+        sample(&mut out, "cpu_usage_percent", "", cpu.overall_usage as f64);
+        if let Some(temp) = cpu.temperature {
+            gauge(
+                &mut out,
+                "cpu_temperature_celsius",
+                "CPU package temperature.",
+            );
+            sample(&mut out, "cpu_temperature_celsius", "", temp as f64);
+        }
+    }
+
+    if let Some(ram) = sources.ram.read().as_ref() {
+        gauge(&mut out, "ram_used_bytes", "Physical memory in use.");
+        sample(&mut out, "ram_used_bytes", "", ram.used as f64);
+        gauge(&mut out, "ram_total_bytes", "Installed physical memory.");
+        sample(&mut out, "ram_total_bytes", "", ram.total as f64);
+    }
+
+    if let Some(gpu) = sources.gpu.read().as_ref() {
+        gauge(&mut out, "gpu_temperature_celsius", "GPU temperature.");
+        for device in &gpu.devices {
+            let labels = format!("gpu=\"{}\"", device.gpu_index);
+            sample(
+                &mut out,
+                "gpu_temperature_celsius",
+                &labels,
+                device.temperature as f64,
+            );
+        }
+        gauge(&mut out, "gpu_utilization_percent", "GPU utilization.");
+        for device in &gpu.devices {
+            let labels = format!("gpu=\"{}\"", device.gpu_index);
+            sample(
+                &mut out,
+                "gpu_utilization_percent",
+                &labels,
+                device.utilization as f64,
+            );
+        }
+        gauge(&mut out, "gpu_memory_used_bytes", "GPU memory in use.");
+        for device in &gpu.devices {
+            let labels = format!("gpu=\"{}\"", device.gpu_index);
+            sample(
+                &mut out,
+                "gpu_memory_used_bytes",
+                &labels,
+                device.memory_used as f64,
+            );
+        }
+    }
+
+    if let Some(disk) = sources.disk.read().as_ref() {
+        // Monitor reports MB/s
+        const MB: f64 = 1024.0 * 1024.0;
+        gauge(&mut out, "disk_read_bytes_per_sec", "Disk read throughput.");
+        for stat in &disk.io_stats {
+            let labels = format!("disk=\"{}\"", stat.disk_number);
+            sample(
+                &mut out,
+                "disk_read_bytes_per_sec",
+                &labels,
+                stat.read_speed * MB,
+            );
+        }
+        gauge(
+            &mut out,
+            "disk_write_bytes_per_sec",
+            "Disk write throughput.",
+        );
+        for stat in &disk.io_stats {
+            let labels = format!("disk=\"{}\"", stat.disk_number);
+            sample(
+                &mut out,
+                "disk_write_bytes_per_sec",
+                &labels,
+                stat.write_speed * MB,
+            );
+        }
+    }
+
+    out
+}
+
+fn gauge(out: &mut String, name: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} gauge", name);
+}
+
+fn sample(out: &mut String, name: &str, labels: &str, value: f64) {
+    if labels.is_empty() {
+        let _ = writeln!(out, "{} {}", name, value);
+    } else {
+        let _ = writeln!(out, "{}{{{}}} {}", name, labels, value);
+    }
+}
@@ -1,6 +1,11 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use crate::integrations::{PowerShellExecutor, LinuxSysMonitor};
+use parking_lot::Mutex;
+use std::collections::{HashMap, VecDeque};
+use std::time::Instant;
+use tokio::sync::OnceCell;
+use crate::integrations::{linux_sys, PowerShellExecutor, LinuxSysMonitor};
+use crate::monitors::processes::cpu_percent;
 use crate::utils::parse_json_array;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,12 +19,44 @@ pub struct CpuData {
     pub power: PowerInfo,
     pub temperature: Option<f32>,
     pub top_processes: Vec<ProcessInfo>,
+    /// Recent overall usage samples, oldest first. Filled in by the monitor task.
+    pub usage_history: VecDeque<f32>,
+    #[serde(default)]
+    pub details: CpuDetails,
 }
 
+/// Cache sizes and notable instruction set extensions. Values the platform
+/// doesn't report are `None` rather than zero.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CpuDetails {
+    /// Bytes across the whole package; L1 counts data and instruction caches
+    pub l1_cache: Option<u64>,
+    pub l2_cache: Option<u64>,
+    pub l3_cache: Option<u64>,
+    /// e.g. "AVX2", "AVX-512", "VT-x"
+    pub features: Vec<String>,
+}
+
+/// `/proc/cpuinfo` flags worth showing, in display order.
+#[allow(dead_code)]
+const LINUX_FEATURE_FLAGS: &[(&str, &str)] = &[
+    ("sse4_2", "SSE4.2"),
+    ("avx", "AVX"),
+    ("avx2", "AVX2"),
+    ("avx512f", "AVX-512"),
+    ("aes", "AES-NI"),
+    ("sha_ni", "SHA"),
+    ("vmx", "VT-x"),
+    ("svm", "AMD-V"),
+];
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CoreUsage {
     pub core_id: usize,
     pub usage: f32,
+    /// Live clock in MHz, when the platform reports it per core
+    #[serde(default)]
+    pub frequency_mhz: Option<f32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,6 +71,9 @@ pub struct FrequencyInfo {
 pub struct PowerInfo {
     pub current_power: f32,   // Watts
     pub max_power: f32,       // Watts (TDP)
+    /// Read from RAPL or a hardware sensor; otherwise estimated from load
+    #[serde(default)]
+    pub measured: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -45,10 +85,51 @@ pub struct ProcessInfo {
     pub memory: u64,  // Bytes
 }
 
+/// Everything one Linux refresh reads from the host, gathered in a single
+/// blocking task.
+#[allow(dead_code)]
+struct LinuxCpuSample {
+    cpu_info: linux_sys::CpuInfo,
+    overall_usage: f32,
+    core_usage_values: Vec<f32>,
+    core_frequencies: Vec<Option<f32>>,
+    temperature: Option<f32>,
+    rapl_zones: Vec<linux_sys::RaplZone>,
+    processes: Result<Vec<linux_sys::ProcessInfo>>,
+}
+
+impl LinuxCpuSample {
+    #[allow(dead_code)]
+    fn read(sys: &LinuxSysMonitor) -> Result<Self> {
+        let core_usage_values = sys.get_core_usage()?;
+        Ok(Self {
+            cpu_info: sys.get_cpu_info()?,
+            overall_usage: sys.get_cpu_usage()?,
+            core_frequencies: sys.get_core_frequencies(core_usage_values.len()),
+            core_usage_values,
+            temperature: sys.get_cpu_temperature(),
+            rapl_zones: sys.get_rapl_zones(),
+            processes: sys.get_processes(),
+        })
+    }
+}
+
 pub struct CpuMonitor {
     ps: PowerShellExecutor,
+    // Name, core counts and TDP don't change while running
+    static_info: OnceCell<CpuInfo>,
+    details: OnceCell<CpuDetails>,
     #[allow(dead_code)]
     linux_sys: LinuxSysMonitor,
+    /// Cumulative CPU seconds per PID with its start ticks; Linux works out
+    /// per-process usage from their growth since `last_process_sample`
+    #[allow(dead_code)]
+    last_process_times: Mutex<HashMap<u32, (u64, f64)>>,
+    #[allow(dead_code)]
+    last_process_sample: Mutex<Option<Instant>>,
+    /// Previous RAPL reading; Linux power is the energy used since then
+    #[allow(dead_code)]
+    last_energy: Mutex<Option<(Instant, Vec<linux_sys::RaplZone>)>>,
 }
 
 const CPU_INFO_SCRIPT: &str = r#"
@@ -78,6 +159,30 @@ const CPU_INFO_SCRIPT: &str = r#"
     }
 "#;
 
+// Win32_CacheMemory level 3 is the primary (L1) cache. The PF_* numbers are
+// SSE4.2 (38), AVX (39), AVX2 (40) and AVX-512F (41).
+#[allow(dead_code)]
+const CPU_DETAILS_SCRIPT: &str = r#"
+    if (-not ('TuiPlus.CpuFeatures' -as [type])) {
+        Add-Type -Namespace TuiPlus -Name CpuFeatures -MemberDefinition '[DllImport("kernel32.dll")] public static extern bool IsProcessorFeaturePresent(uint feature);'
+    }
+    $cpu = Get-CimInstance Win32_Processor -ErrorAction Stop | Select-Object -First 1
+    $l1 = (Get-CimInstance Win32_CacheMemory -ErrorAction SilentlyContinue |
+        Where-Object { $_.Level -eq 3 } |
+        Measure-Object -Property InstalledSize -Sum).Sum
+    [PSCustomObject]@{
+        Manufacturer = $cpu.Manufacturer
+        L1CacheKB = $l1
+        L2CacheKB = $cpu.L2CacheSize
+        L3CacheKB = $cpu.L3CacheSize
+        Virtualization = [bool]$cpu.VMMonitorModeExtensions
+        Sse42 = [TuiPlus.CpuFeatures]::IsProcessorFeaturePresent(38)
+        Avx = [TuiPlus.CpuFeatures]::IsProcessorFeaturePresent(39)
+        Avx2 = [TuiPlus.CpuFeatures]::IsProcessorFeaturePresent(40)
+        Avx512 = [TuiPlus.CpuFeatures]::IsProcessorFeaturePresent(41)
+    } | ConvertTo-Json -Compress
+"#;
+
 const CORE_USAGE_SCRIPT: &str = r#"
     try {
         $cores = Get-CimInstance Win32_PerfFormattedData_PerfOS_Processor -ErrorAction Stop |
@@ -94,6 +199,24 @@ const CORE_USAGE_SCRIPT: &str = r#"
     }
 "#;
 
+// Performance is relative to the base clock, so it goes above 100 when boosting
+#[allow(dead_code)]
+const CORE_FREQUENCY_SCRIPT: &str = r#"
+    try {
+        $cores = Get-CimInstance Win32_PerfFormattedData_Counters_ProcessorInformation -ErrorAction Stop |
+            Where-Object { $_.Name -notlike '*_Total' }
+        $result = foreach ($core in $cores) {
+            [PSCustomObject]@{
+                Core = $core.Name
+                Performance = [double]$core.PercentProcessorPerformance
+            }
+        }
+        $result | ConvertTo-Json
+    } catch {
+        "[]"
+    }
+"#;
+
 const OVERALL_USAGE_SCRIPT: &str = r#"
     try {
         $total = Get-CimInstance Win32_PerfFormattedData_PerfOS_Processor -ErrorAction Stop |
@@ -176,11 +299,38 @@ const TEMPERATURE_SCRIPT: &str = r#"
     }
 "#;
 
+// LibreHardwareMonitor (or its predecessor) publishes its sensors over WMI
+// while it runs; without it Windows has no CPU power reading.
+#[allow(dead_code)]
+const POWER_SCRIPT: &str = r#"
+    try {
+        $sensor = $null
+        foreach ($ns in @("root/LibreHardwareMonitor", "root/OpenHardwareMonitor")) {
+            $sensor = Get-CimInstance -Namespace $ns -ClassName Sensor -ErrorAction SilentlyContinue |
+                Where-Object { $_.SensorType -eq "Power" -and $_.Name -eq "CPU Package" } |
+                Select-Object -First 1
+            if ($sensor) { break }
+        }
+        if ($sensor) {
+            [math]::Round($sensor.Value, 1)
+        } else {
+            ""
+        }
+    } catch {
+        ""
+    }
+"#;
+
 impl CpuMonitor {
     pub fn new(ps: PowerShellExecutor) -> Result<Self> {
         Ok(Self {
+            linux_sys: LinuxSysMonitor::new().with_remote(ps.remote().cloned()),
             ps,
-            linux_sys: LinuxSysMonitor::new(),
+            static_info: OnceCell::new(),
+            details: OnceCell::new(),
+            last_process_times: Mutex::new(HashMap::new()),
+            last_process_sample: Mutex::new(None),
+            last_energy: Mutex::new(None),
         })
     }
 
@@ -199,23 +349,39 @@ impl CpuMonitor {
 
     #[allow(dead_code)]
     async fn collect_data_linux(&self) -> Result<CpuData> {
-        let cpu_info = self.linux_sys.get_cpu_info()?;
-        let overall_usage = self.linux_sys.get_cpu_usage()?;
-        let core_usage_values = self.linux_sys.get_core_usage()?;
+        let LinuxCpuSample {
+            cpu_info,
+            overall_usage,
+            core_usage_values,
+            core_frequencies,
+            temperature,
+            rapl_zones,
+            processes,
+        } = self.linux_sys.blocking(LinuxCpuSample::read).await??;
 
         let core_usage: Vec<CoreUsage> = core_usage_values
             .iter()
+            .zip(core_frequencies)
             .enumerate()
-            .map(|(i, &usage)| CoreUsage {
+            .map(|(i, (&usage, frequency_mhz))| CoreUsage {
                 core_id: i,
                 usage,
+                frequency_mhz,
             })
             .collect();
 
+        let live_mhz: Vec<f32> = core_usage.iter().filter_map(|c| c.frequency_mhz).collect();
+        let avg_mhz = if live_mhz.is_empty() {
+            cpu_info.frequency_mhz
+        } else {
+            live_mhz.iter().sum::<f32>() / live_mhz.len() as f32
+        };
+        let max_mhz = live_mhz.iter().copied().fold(cpu_info.frequency_mhz, f32::max);
+
         let frequency = FrequencyInfo {
             base_clock: cpu_info.frequency_mhz / 1000.0,
-            avg_frequency: cpu_info.frequency_mhz / 1000.0,
-            max_frequency: cpu_info.frequency_mhz / 1000.0,
+            avg_frequency: avg_mhz / 1000.0,
+            max_frequency: max_mhz / 1000.0,
             boost_active: false,
         };
 
@@ -226,37 +392,172 @@ impl CpuMonitor {
             thread_count: cpu_info.core_count,
             core_usage,
             frequency,
-            power: PowerInfo {
-                current_power: (overall_usage / 100.0) * 65.0,  // Assume 65W TDP
-                max_power: 65.0,
-            },
-            temperature: Some(50.0),  // Placeholder
-            top_processes: Vec::new(),  // Will implement later
+            power: self.get_power_info_linux(rapl_zones, overall_usage),
+            temperature,
+            top_processes: self.top_processes_linux(processes, core_usage_values.len()),
+            usage_history: VecDeque::new(),
+            details: self.get_details_linux(cpu_info.core_count).await,
         })
     }
 
+    /// Package power from RAPL energy used since the previous refresh. The
+    /// first refresh, or a host without readable RAPL counters, falls back to
+    /// scaling an assumed 65W TDP by load.
+    #[allow(dead_code)]
+    fn get_power_info_linux(&self, zones: Vec<linux_sys::RaplZone>, overall_usage: f32) -> PowerInfo {
+        let limit_uw: u64 = zones.iter().filter_map(|zone| zone.power_limit_uw).sum();
+        let max_power = if limit_uw > 0 { limit_uw as f32 / 1_000_000.0 } else { 65.0 };
+
+        let now = Instant::now();
+        let previous = if zones.is_empty() {
+            self.last_energy.lock().take()
+        } else {
+            self.last_energy.lock().replace((now, zones.clone()))
+        };
+        let measured = previous.and_then(|(at, previous)| {
+            rapl_watts(&previous, &zones, now.duration_since(at).as_secs_f64())
+        });
+
+        match measured {
+            Some(watts) => PowerInfo {
+                current_power: watts,
+                max_power,
+                measured: true,
+            },
+            None => PowerInfo {
+                current_power: (overall_usage / 100.0) * max_power,
+                max_power,
+                measured: false,
+            },
+        }
+    }
+
+    /// The five busiest processes since the previous refresh, sized like the
+    /// Windows list. The first refresh has no baseline and lists nothing.
+    #[allow(dead_code)]
+    fn top_processes_linux(
+        &self,
+        processes: Result<Vec<linux_sys::ProcessInfo>>,
+        cpu_count: usize,
+    ) -> Vec<ProcessInfo> {
+        let processes = match processes {
+            Ok(processes) => processes,
+            Err(e) => {
+                log::debug!("Failed to list processes: {}", e);
+                return Vec::new();
+            }
+        };
+
+        let now = Instant::now();
+        let mut last_sample = self.last_process_sample.lock();
+        let mut last_times = self.last_process_times.lock();
+        let time_delta = last_sample.map_or(0.0, |t| now.duration_since(t).as_secs_f64());
+
+        let top = rank_by_cpu(&processes, &last_times, time_delta, cpu_count.max(1) as f64);
+
+        *last_sample = Some(now);
+        *last_times = processes
+            .iter()
+            .map(|p| (p.pid, (p.start_ticks, p.cpu_seconds)))
+            .collect();
+        top
+    }
+
+    #[allow(dead_code)]
+    async fn get_details_linux(&self, thread_count: usize) -> CpuDetails {
+        self.details
+            .get_or_init(|| async {
+                let mut details = CpuDetails::default();
+                let (caches, flags) = self
+                    .linux_sys
+                    .blocking(|sys| (sys.get_cpu_caches(), sys.get_cpu_flags()))
+                    .await
+                    .unwrap_or_default();
+                for cache in caches {
+                    let slot = match cache.level {
+                        1 => &mut details.l1_cache,
+                        2 => &mut details.l2_cache,
+                        3 => &mut details.l3_cache,
+                        _ => continue,
+                    };
+                    let instances = (thread_count / cache.shared_by.max(1)).max(1) as u64;
+                    *slot = Some(slot.unwrap_or(0) + cache.size * instances);
+                }
+
+                details.features = LINUX_FEATURE_FLAGS
+                    .iter()
+                    .filter(|(flag, _)| flags.iter().any(|f| f == flag))
+                    .map(|(_, label)| label.to_string())
+                    .collect();
+                details
+            })
+            .await
+            .clone()
+    }
+
+    /// Cached after the first successful query; a failure is retried next time.
+    #[cfg(not(target_os = "linux"))]
+    async fn get_details_windows(&self) -> CpuDetails {
+        if let Some(details) = self.details.get() {
+            return details.clone();
+        }
+
+        let parsed = self
+            .ps
+            .execute(CPU_DETAILS_SCRIPT)
+            .await
+            .and_then(|output| {
+                serde_json::from_str::<CpuDetailsSample>(
+                    output.trim_start_matches('\u{feff}').trim(),
+                )
+                .context("Failed to parse CPU details")
+            });
+        match parsed {
+            Ok(sample) => {
+                let details = sample.into_details();
+                let _ = self.details.set(details.clone());
+                details
+            }
+            Err(e) => {
+                log::debug!("CPU details unavailable: {}", e);
+                CpuDetails::default()
+            }
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
     async fn collect_data_windows(&self) -> Result<CpuData> {
         let outputs = self
             .ps
             .execute_batch(&[
-                CPU_INFO_SCRIPT,
                 CORE_USAGE_SCRIPT,
                 OVERALL_USAGE_SCRIPT,
                 TOP_PROCESSES_SCRIPT,
                 PERF_INFO_SCRIPT,
                 TEMPERATURE_SCRIPT,
+                CORE_FREQUENCY_SCRIPT,
+                POWER_SCRIPT,
             ])
             .await
             .context("Failed to execute CPU monitor batch")?;
 
-        let cpu_info = Self::parse_cpu_info(&outputs[0])?;
-        let core_usage = Self::parse_core_usage(&outputs[1])?;
-        let overall_usage = Self::parse_overall_usage(&outputs[2])?;
-        let top_processes = Self::parse_top_processes(&outputs[3])?;
-        let perf_info = Self::parse_perf_info(&outputs[4])?;
-        let temperature = Self::parse_temperature(&outputs[5]).ok();
+        let cpu_info = self.get_cpu_info().await?;
+        let mut core_usage = Self::parse_core_usage(&outputs[0])?;
+        // Per-core clocks are a nice-to-have; a failed query leaves them unset
+        if let Ok(frequencies) =
+            Self::parse_core_frequencies(&outputs[5], cpu_info.max_clock_speed as f32)
+        {
+            for core in &mut core_usage {
+                core.frequency_mhz = frequencies.get(core.core_id).copied();
+            }
+        }
+        let overall_usage = Self::parse_overall_usage(&outputs[1])?;
+        let top_processes = Self::parse_top_processes(&outputs[2])?;
+        let perf_info = Self::parse_perf_info(&outputs[3])?;
+        let temperature = Self::parse_temperature(&outputs[4]).ok();
         let frequency = self.get_frequency_info(&cpu_info, &perf_info)?;
-        let power = self.get_power_info(&cpu_info, overall_usage, &perf_info);
+        let sensor_power = Self::parse_power(&outputs[6]);
+        let power = self.get_power_info(&cpu_info, overall_usage, &perf_info, sensor_power);
         let (core_count, thread_count) = self.get_core_counts(&cpu_info)?;
 
         Ok(CpuData {
@@ -269,9 +570,32 @@ impl CpuMonitor {
             power,
             temperature,
             top_processes,
+            usage_history: VecDeque::new(),
+            details: self.get_details_windows().await,
         })
     }
 
+    async fn get_cpu_info(&self) -> Result<CpuInfo> {
+        if let Some(info) = self.static_info.get() {
+            return Ok(info.clone());
+        }
+
+        let output = self
+            .ps
+            .execute(CPU_INFO_SCRIPT)
+            .await
+            .context("Failed to query CPU info")?;
+        let info = Self::parse_cpu_info(output.trim_start_matches('\u{feff}').trim())?;
+
+        // The script falls back to an "Unknown" placeholder on WMI errors;
+        // don't pin that for the rest of the session.
+        if info.number_of_logical_processors > 0 {
+            let _ = self.static_info.set(info.clone());
+        }
+
+        Ok(info)
+    }
+
     fn parse_cpu_info(output: &str) -> Result<CpuInfo> {
         let info: Win32Processor = serde_json::from_str(output)
             .context("Failed to parse CPU info")?;
@@ -311,10 +635,32 @@ impl CpuMonitor {
             .map(|(id, sample)| CoreUsage {
                 core_id: sample.Core.parse::<usize>().unwrap_or(id),
                 usage: sample.Usage.min(100.0),
+                frequency_mhz: None,
             })
             .collect())
     }
 
+    /// Per-core MHz in logical processor order. Counter instances are named
+    /// "group,index", so sorting by that pair gives the global numbering.
+    fn parse_core_frequencies(output: &str, base_mhz: f32) -> Result<Vec<f32>> {
+        let samples: Vec<CoreFrequencySample> =
+            parse_json_array(output).context("Failed to parse core frequencies")?;
+        if base_mhz <= 0.0 {
+            return Ok(Vec::new());
+        }
+
+        let mut cores: Vec<((u32, u32), f32)> = samples
+            .into_iter()
+            .filter_map(|sample| {
+                let (group, index) = sample.Core.split_once(',')?;
+                let key = (group.trim().parse().ok()?, index.trim().parse().ok()?);
+                Some((key, base_mhz * sample.Performance / 100.0))
+            })
+            .collect();
+        cores.sort_by_key(|(key, _)| *key);
+        Ok(cores.into_iter().map(|(_, mhz)| mhz).collect())
+    }
+
     fn get_frequency_info(&self, cpu_info: &CpuInfo, perf: &PerfInfo) -> Result<FrequencyInfo> {
         let base_mhz = cpu_info.max_clock_speed.max(1) as f32;
         let avg_mhz = perf
@@ -335,7 +681,23 @@ impl CpuMonitor {
         })
     }
 
-    fn get_power_info(&self, cpu_info: &CpuInfo, overall_usage: f32, perf: &PerfInfo) -> PowerInfo {
+    /// A hardware sensor reading when one is available, otherwise the TDP
+    /// scaled by the performance-adjusted utility.
+    fn get_power_info(
+        &self,
+        cpu_info: &CpuInfo,
+        overall_usage: f32,
+        perf: &PerfInfo,
+        sensor_power: Option<f32>,
+    ) -> PowerInfo {
+        if let Some(current_power) = sensor_power {
+            return PowerInfo {
+                current_power,
+                max_power: cpu_info.tdp,
+                measured: true,
+            };
+        }
+
         let util = perf
             .avg_utility()
             .unwrap_or(overall_usage)
@@ -344,9 +706,18 @@ impl CpuMonitor {
         PowerInfo {
             current_power,
             max_power: cpu_info.tdp,
+            measured: false,
         }
     }
 
+    fn parse_power(output: &str) -> Option<f32> {
+        output
+            .trim()
+            .parse::<f32>()
+            .ok()
+            .filter(|watts| watts.is_finite() && *watts >= 0.0)
+    }
+
     fn parse_top_processes(output: &str) -> Result<Vec<ProcessInfo>> {
         let processes: Vec<ProcessSample> = parse_json_array(output)
             .context("Failed to parse top processes")?;
@@ -383,6 +754,67 @@ impl CpuMonitor {
     }
 }
 
+/// Average watts across all packages between two RAPL readings taken
+/// `seconds` apart, allowing for counters that wrapped in between.
+#[allow(dead_code)]
+fn rapl_watts(
+    previous: &[linux_sys::RaplZone],
+    current: &[linux_sys::RaplZone],
+    seconds: f64,
+) -> Option<f32> {
+    if seconds <= 0.0 {
+        return None;
+    }
+
+    let mut used_uj = 0u64;
+    let mut matched = false;
+    for zone in current {
+        let Some(before) = previous.iter().find(|p| p.name == zone.name) else {
+            continue;
+        };
+        used_uj += if zone.energy_uj >= before.energy_uj {
+            zone.energy_uj - before.energy_uj
+        } else {
+            zone.max_energy_uj.saturating_sub(before.energy_uj) + zone.energy_uj
+        };
+        matched = true;
+    }
+
+    matched.then(|| (used_uj as f64 / 1_000_000.0 / seconds) as f32)
+}
+
+/// Processes that used CPU between two samples, busiest first. Processes
+/// new since the previous sample (or whose PID was reused) are skipped.
+#[allow(dead_code)]
+fn rank_by_cpu(
+    processes: &[linux_sys::ProcessInfo],
+    previous: &HashMap<u32, (u64, f64)>,
+    time_delta: f64,
+    cpu_count: f64,
+) -> Vec<ProcessInfo> {
+    let mut ranked: Vec<ProcessInfo> = processes
+        .iter()
+        .filter_map(|p| {
+            let &(start_ticks, seconds) = previous.get(&p.pid)?;
+            if start_ticks != p.start_ticks || time_delta <= 0.0 {
+                return None;
+            }
+            let usage = cpu_percent(Some(seconds), p.cpu_seconds, time_delta, 0.0, cpu_count);
+            (usage > 0.0).then(|| ProcessInfo {
+                pid: p.pid,
+                name: p.name.clone(),
+                cpu_usage: usage as f32,
+                threads: p.threads,
+                memory: p.memory,
+            })
+        })
+        .collect();
+
+    ranked.sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage));
+    ranked.truncate(5);
+    ranked
+}
+
 // PowerShell data structures
 #[derive(Debug, Deserialize)]
 #[allow(non_snake_case)]
@@ -395,7 +827,56 @@ struct Win32Processor {
     TDP: Option<f32>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Deserialize)]
+#[allow(non_snake_case, dead_code)]
+struct CpuDetailsSample {
+    Manufacturer: Option<String>,
+    L1CacheKB: Option<u64>,
+    L2CacheKB: Option<u64>,
+    L3CacheKB: Option<u64>,
+    #[serde(default)]
+    Virtualization: bool,
+    #[serde(default)]
+    Sse42: bool,
+    #[serde(default)]
+    Avx: bool,
+    #[serde(default)]
+    Avx2: bool,
+    #[serde(default)]
+    Avx512: bool,
+}
+
+impl CpuDetailsSample {
+    #[allow(dead_code)]
+    fn into_details(self) -> CpuDetails {
+        // WMI reports 0 for sizes it doesn't know
+        let bytes = |kb: Option<u64>| kb.filter(|&kb| kb > 0).map(|kb| kb * 1024);
+        let virtualization = match self.Manufacturer.as_deref() {
+            Some("AuthenticAMD") => "AMD-V",
+            _ => "VT-x",
+        };
+        let features = [
+            (self.Sse42, "SSE4.2"),
+            (self.Avx, "AVX"),
+            (self.Avx2, "AVX2"),
+            (self.Avx512, "AVX-512"),
+            (self.Virtualization, virtualization),
+        ]
+        .into_iter()
+        .filter(|(present, _)| *present)
+        .map(|(_, label)| label.to_string())
+        .collect();
+
+        CpuDetails {
+            l1_cache: bytes(self.L1CacheKB),
+            l2_cache: bytes(self.L2CacheKB),
+            l3_cache: bytes(self.L3CacheKB),
+            features,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
 struct CpuInfo {
     name: String,
     max_clock_speed: u32,
@@ -412,6 +893,14 @@ struct CoreSample {
     Usage: f32,
 }
 
+#[derive(Debug, Deserialize)]
+#[allow(non_snake_case)]
+#[allow(dead_code)]
+struct CoreFrequencySample {
+    Core: String,
+    Performance: f32,
+}
+
 #[derive(Debug, Deserialize)]
 #[allow(non_snake_case)]
 struct PerfInfo {
@@ -445,3 +934,62 @@ struct ProcessSample {
     Threads: Option<u32>,
     Memory: Option<u64>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(pid: u32, cpu_seconds: f64, start_ticks: u64) -> linux_sys::ProcessInfo {
+        linux_sys::ProcessInfo {
+            pid,
+            parent_pid: None,
+            name: format!("p{}", pid),
+            cmdline: None,
+            threads: 1,
+            memory: 0,
+            private_memory: 0,
+            cpu_seconds,
+            start_ticks,
+        }
+    }
+
+    #[test]
+    fn ranks_processes_by_cpu_growth() {
+        let previous = HashMap::from([(1, (10, 5.0)), (2, (20, 1.0)), (3, (30, 0.0)), (4, (40, 2.0))]);
+        let processes = [
+            process(1, 5.5, 10),
+            process(2, 3.0, 20),
+            // Reused PID: a different process, no baseline
+            process(3, 9.0, 99),
+            // Idle
+            process(4, 2.0, 40),
+            // New since the last sample
+            process(5, 1.0, 50),
+        ];
+
+        let top = rank_by_cpu(&processes, &previous, 1.0, 4.0);
+        let pids: Vec<u32> = top.iter().map(|p| p.pid).collect();
+        assert_eq!(pids, vec![2, 1]);
+        assert_eq!(top[0].cpu_usage, 50.0);
+        assert_eq!(top[1].cpu_usage, 12.5);
+
+        assert!(rank_by_cpu(&processes, &previous, 0.0, 4.0).is_empty());
+    }
+
+    #[test]
+    fn rapl_power_handles_counter_wrap() {
+        let zone = |name: &str, energy_uj: u64| linux_sys::RaplZone {
+            name: name.to_string(),
+            energy_uj,
+            max_energy_uj: 1_000_000_000,
+            ..Default::default()
+        };
+        let previous = [zone("intel-rapl:0", 10_000_000), zone("intel-rapl:1", 999_000_000)];
+        let current = [zone("intel-rapl:0", 30_000_000), zone("intel-rapl:1", 1_000_000)];
+
+        // 20 J on package 0 plus 2 J across package 1's wrap, over 2 s
+        assert_eq!(rapl_watts(&previous, &current, 2.0), Some(11.0));
+        assert_eq!(rapl_watts(&previous, &current, 0.0), None);
+        assert_eq!(rapl_watts(&[], &current, 2.0), None);
+    }
+}
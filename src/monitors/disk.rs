@@ -1,7 +1,11 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use crate::integrations::{PowerShellExecutor, LinuxSysMonitor};
-use std::collections::VecDeque;
+use crate::integrations::{DiskStatCounters, PowerShellExecutor, LinuxSysMonitor};
+use crate::utils::format::format_bytes;
+use crate::utils::json::parse_json_array;
+use crate::utils::markdown::{self, ToMarkdown};
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DiskData {
@@ -36,12 +40,12 @@ pub struct DiskProcessActivity {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DiskIOHistory {
     pub disk_number: u32,
-    pub read_history: VecDeque<f64>,   // Last 60 samples of read speed
-    pub write_history: VecDeque<f64>,  // Last 60 samples of write speed
-    pub iops_history: VecDeque<f64>,   // Last 60 samples of total IOPS
+    pub read_history: VecDeque<f64>,   // Recent samples of read speed
+    pub write_history: VecDeque<f64>,  // Recent samples of write speed
+    pub iops_history: VecDeque<f64>,   // Recent samples of total IOPS
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PhysicalDiskInfo {
     pub disk_number: u32,
     pub friendly_name: String,
@@ -58,9 +62,143 @@ pub struct PhysicalDiskInfo {
     pub power_on_hours: Option<u64>,
     pub tbw: Option<u64>,         // Total Bytes Written (for SSD)
     pub wear_level: Option<f32>,  // Wear leveling percentage
+    #[serde(default)]
+    pub smart_attributes: Vec<SmartAttribute>, // Empty when the controller hides SMART
 
     // Associated logical drives
     pub partitions: Vec<String>,  // Drive letters (C:, D:, etc.)
+    #[serde(default)]
+    pub raw_partitions: Vec<RawPartition>, // Partitions without a drive letter or mount point
+
+    #[serde(default)]
+    pub health: DiskHealth,
+}
+
+/// A partition that isn't mounted as a drive, e.g. recovery, EFI or MSR.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawPartition {
+    pub number: u32,
+    pub kind: String, // Partition type on Windows, GPT name (or "Partition") on Linux
+    pub size: u64,
+}
+
+/// Combined verdict from the OS health flag, wear, SMART and temperature.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DiskHealth {
+    /// 0-100, higher is healthier; `None` when nothing about the disk is known
+    pub score: Option<u8>,
+    pub predicted_failure: bool,
+    /// What lowered the score
+    pub reasons: Vec<String>,
+}
+
+/// Below this score a disk is reported as trending toward failure.
+const FAILURE_SCORE: u8 = 40;
+
+impl PhysicalDiskInfo {
+    pub fn assess_health(&self) -> DiskHealth {
+        let mut penalty = 0u32;
+        let mut failing = false;
+        let mut reasons = Vec::new();
+        let mut known = false;
+
+        match self.health_status.as_str() {
+            "Healthy" => known = true,
+            "Warning" => {
+                known = true;
+                penalty += 30;
+                reasons.push("OS reports a health warning".to_string());
+            }
+            "Unhealthy" => {
+                known = true;
+                failing = true;
+                penalty += 60;
+                reasons.push("OS reports the disk unhealthy".to_string());
+            }
+            _ => {}
+        }
+
+        if self.operational_status.contains("Predictive Failure") {
+            failing = true;
+            penalty += 50;
+            reasons.push("Predictive failure reported".to_string());
+        } else if !matches!(self.operational_status.as_str(), "" | "OK" | "Online") {
+            penalty += 20;
+            reasons.push(format!("Status {}", self.operational_status));
+        }
+
+        // Remaining life; the Windows script reports 100 minus wear used
+        if let Some(life) = self.wear_level {
+            known = true;
+            if life <= 10.0 {
+                failing = true;
+                penalty += 40;
+                reasons.push(format!("{:.0}% life left", life));
+            } else if life <= 30.0 {
+                penalty += 15;
+                reasons.push(format!("{:.0}% life left", life));
+            }
+        }
+
+        for attr in &self.smart_attributes {
+            known = true;
+            if attr.is_failing() {
+                failing = true;
+                penalty += 50;
+                reasons.push(format!("SMART {} below threshold", attr.name));
+                continue;
+            }
+            let bad_blocks = matches!(attr.id, Some(5 | 197 | 198))
+                || matches!(
+                    attr.name.as_str(),
+                    "media errors" | "Read Errors Uncorrected" | "Write Errors Uncorrected"
+                );
+            if bad_blocks && attr.raw > 0 {
+                penalty += if attr.raw >= 100 { 40 } else { 20 };
+                reasons.push(format!("{}: {}", attr.name, attr.raw));
+            } else if attr.name == "critical warning" && attr.raw != 0 {
+                failing = true;
+                penalty += 50;
+                reasons.push("NVMe critical warning set".to_string());
+            }
+        }
+
+        if let Some(temp) = self.temperature {
+            known = true;
+            if temp >= 65.0 {
+                penalty += 20;
+                reasons.push(format!("Running hot at {:.0}°C", temp));
+            }
+        }
+
+        if !known {
+            return DiskHealth::default();
+        }
+        let score = 100u32.saturating_sub(penalty) as u8;
+        DiskHealth {
+            score: Some(score),
+            predicted_failure: failing || score < FAILURE_SCORE,
+            reasons,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmartAttribute {
+    pub id: Option<u32>,          // ATA attribute ID; None for NVMe/Windows counters
+    pub name: String,
+    pub value: Option<u32>,       // Normalized value (ATA), higher is better
+    pub worst: Option<u32>,
+    pub threshold: Option<u32>,
+    pub raw: u64,
+}
+
+impl SmartAttribute {
+    /// Normalized value at or below the vendor threshold. A zero threshold
+    /// means the attribute can never trip, so it is not treated as failing.
+    pub fn is_failing(&self) -> bool {
+        matches!((self.value, self.threshold), (Some(value), Some(threshold)) if threshold > 0 && value <= threshold)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -75,13 +213,71 @@ pub struct DriveInfo {
     pub disk_number: Option<u32>, // Link to physical disk
 }
 
+impl ToMarkdown for [DriveInfo] {
+    fn to_markdown(&self) -> String {
+        markdown::table(
+            &["Drive", "Name", "Type", "File System", "Total", "Used", "Free", "Use%"],
+            self.iter().map(|drive| {
+                let percent = if drive.total > 0 {
+                    drive.used as f64 / drive.total as f64 * 100.0
+                } else {
+                    0.0
+                };
+                vec![
+                    drive.letter.clone(),
+                    drive.name.clone(),
+                    drive.drive_type.clone(),
+                    drive.file_system.clone(),
+                    format_bytes(drive.total),
+                    format_bytes(drive.used),
+                    format_bytes(drive.free),
+                    format!("{:.1}", percent),
+                ]
+            }),
+        )
+    }
+}
+
 pub struct DiskMonitor {
     ps: PowerShellExecutor,
     #[allow(dead_code)]
     linux_sys: LinuxSysMonitor,
     io_history_map: std::sync::Arc<parking_lot::Mutex<std::collections::HashMap<u32, DiskIOHistory>>>,
+    /// Samples kept in each `DiskIOHistory`
+    history_len: usize,
+    // SMART attributes barely change, so they are re-read every SMART_REFRESH
+    #[allow(dead_code)]
+    smart_cache: parking_lot::Mutex<Option<SmartSnapshot>>,
+    // Previous /proc/diskstats sample; rates are the delta to the next one
+    #[allow(dead_code)]
+    last_diskstats: parking_lot::Mutex<Option<(Instant, Vec<DiskStatCounters>)>>,
 }
 
+type SmartSnapshot = (Instant, HashMap<u32, Vec<SmartAttribute>>);
+
+#[allow(dead_code)]
+const SMART_REFRESH: Duration = Duration::from_secs(60);
+
+// Windows reliability counters shown when smartctl is missing: (property, ATA id, label)
+#[allow(dead_code)]
+const RELIABILITY_COUNTERS: &[(&str, Option<u32>, &str)] = &[
+    ("PowerOnHours", Some(9), "Power-On Hours"),
+    ("StartStopCycleCount", Some(4), "Start/Stop Count"),
+    ("LoadUnloadCycleCount", Some(193), "Load/Unload Cycle Count"),
+    ("Temperature", Some(194), "Temperature (C)"),
+    ("TemperatureMax", None, "Max Temperature (C)"),
+    ("Wear", None, "Wear (%)"),
+    ("ReadErrorsTotal", None, "Read Errors Total"),
+    ("ReadErrorsCorrected", None, "Read Errors Corrected"),
+    ("ReadErrorsUncorrected", None, "Read Errors Uncorrected"),
+    ("WriteErrorsTotal", None, "Write Errors Total"),
+    ("WriteErrorsCorrected", None, "Write Errors Corrected"),
+    ("WriteErrorsUncorrected", None, "Write Errors Uncorrected"),
+    ("ReadLatencyMax", None, "Max Read Latency (ms)"),
+    ("WriteLatencyMax", None, "Max Write Latency (ms)"),
+    ("FlushLatencyMax", None, "Max Flush Latency (ms)"),
+];
+
 const PHYSICAL_DISKS_SCRIPT: &str = r#"
     if (-not (Get-Command Get-PhysicalDisk -ErrorAction SilentlyContinue)) {
         "[]"
@@ -91,9 +287,20 @@ const PHYSICAL_DISKS_SCRIPT: &str = r#"
 
         foreach ($disk in $disks) {
             # Get partitions for this disk
-            $partitions = Get-Partition -DiskNumber $disk.DeviceId -ErrorAction SilentlyContinue |
+            $allPartitions = @(Get-Partition -DiskNumber $disk.DeviceId -ErrorAction SilentlyContinue)
+            $partitions = $allPartitions |
                 Where-Object { $_.DriveLetter } |
                 ForEach-Object { "$($_.DriveLetter):" }
+            # Recovery, EFI and reserved partitions have no letter
+            $rawPartitions = $allPartitions |
+                Where-Object { -not $_.DriveLetter } |
+                ForEach-Object {
+                    [PSCustomObject]@{
+                        Number = [uint32]$_.PartitionNumber
+                        Kind = "$($_.Type)"
+                        Size = [uint64]$_.Size
+                    }
+                }
 
             # Try to get SMART data (may not be available on all systems)
             $smart = $null
@@ -172,6 +379,7 @@ const PHYSICAL_DISKS_SCRIPT: &str = r#"
                 TBW = $tbw
                 WearLevel = $wearLevel
                 Partitions = @($partitions)
+                RawPartitions = @($rawPartitions)
             }
         }
 
@@ -179,6 +387,43 @@ const PHYSICAL_DISKS_SCRIPT: &str = r#"
     }
 "#;
 
+#[allow(dead_code)]
+const SMART_SCRIPT: &str = r#"
+    if (-not (Get-Command Get-PhysicalDisk -ErrorAction SilentlyContinue)) {
+        "[]"
+    } else {
+        $smartctl = Get-Command smartctl -ErrorAction SilentlyContinue
+        $result = @()
+
+        foreach ($disk in Get-PhysicalDisk -ErrorAction SilentlyContinue) {
+            $counters = $null
+            try {
+                $counters = Get-StorageReliabilityCounter -PhysicalDisk $disk -ErrorAction SilentlyContinue |
+                    Select-Object PowerOnHours, StartStopCycleCount, LoadUnloadCycleCount, Temperature,
+                        TemperatureMax, Wear, ReadErrorsTotal, ReadErrorsCorrected, ReadErrorsUncorrected,
+                        WriteErrorsTotal, WriteErrorsCorrected, WriteErrorsUncorrected,
+                        ReadLatencyMax, WriteLatencyMax, FlushLatencyMax
+            } catch {}
+
+            # smartctl addresses Windows disks as /dev/pdN
+            $smartctlJson = $null
+            if ($smartctl) {
+                try {
+                    $smartctlJson = (& $smartctl.Source -A -j "/dev/pd$($disk.DeviceId)" 2>$null) -join "`n"
+                } catch {}
+            }
+
+            $result += [PSCustomObject]@{
+                DiskNumber = [uint32]$disk.DeviceId
+                Counters = $counters
+                Smartctl = $smartctlJson
+            }
+        }
+
+        ConvertTo-Json -InputObject @($result) -Depth 3
+    }
+"#;
+
 const LOGICAL_DRIVES_SCRIPT: &str = r#"
     try {
         $drives = Get-CimInstance Win32_LogicalDisk -ErrorAction Stop |
@@ -374,9 +619,12 @@ const PROCESS_ACTIVITY_SCRIPT: &str = r#"
 impl DiskMonitor {
     pub fn new(ps: PowerShellExecutor) -> Result<Self> {
         Ok(Self {
+            linux_sys: LinuxSysMonitor::new().with_remote(ps.remote().cloned()),
             ps,
-            linux_sys: LinuxSysMonitor::new(),
             io_history_map: std::sync::Arc::new(parking_lot::Mutex::new(std::collections::HashMap::new())),
+            history_len: 60,
+            smart_cache: parking_lot::Mutex::new(None),
+            last_diskstats: parking_lot::Mutex::new(None),
         })
     }
 
@@ -394,7 +642,31 @@ impl DiskMonitor {
 
     #[allow(dead_code)]
     async fn collect_data_linux(&self) -> Result<DiskData> {
-        let disks = self.linux_sys.get_disk_info()?;
+        let (disks, devices, device_info, partitions) = self
+            .linux_sys
+            .blocking(|sys| -> Result<_> {
+                let disks = sys.get_disk_info()?;
+                // Device numbers are positions in /proc/diskstats, which the kernel keeps stable
+                let devices = sys.get_disk_stats().unwrap_or_else(|e| {
+                    log::debug!("Disk I/O counters unavailable: {}", e);
+                    Vec::new()
+                });
+                let names: Vec<String> = devices.iter().map(|dev| dev.name.clone()).collect();
+                let device_info = sys.get_block_device_info(&names);
+                let partitions = sys.get_partitions(&names);
+                Ok((disks, devices, device_info, partitions))
+            })
+            .await??;
+        let device_number = |fs_name: &str| {
+            let fs_name = fs_name.strip_prefix("/dev/")?;
+            // Longest match, so sdaa1 maps to sdaa rather than sda
+            devices
+                .iter()
+                .enumerate()
+                .filter(|(_, dev)| fs_name.starts_with(&dev.name))
+                .max_by_key(|(_, dev)| dev.name.len())
+                .map(|(i, _)| i as u32)
+        };
 
         let logical_drives: Vec<DriveInfo> = disks
             .iter()
@@ -406,16 +678,79 @@ impl DiskMonitor {
                 total: d.total,
                 used: d.used,
                 free: d.available,
-                disk_number: Some(0),
+                disk_number: device_number(&d.name),
+            })
+            .collect();
+
+        let physical_disks = devices
+            .iter()
+            .zip(device_info)
+            .zip(partitions)
+            .enumerate()
+            .map(|(i, ((dev, info), partitions))| {
+                let is_nvme = dev.name.starts_with("nvme");
+                let media_type = match (is_nvme, info.rotational) {
+                    (true, _) => "NVMe",
+                    (false, Some(true)) => "HDD",
+                    (false, Some(false)) => "SSD",
+                    (false, None) => "Unknown",
+                };
+                let mut disk = PhysicalDiskInfo {
+                    disk_number: i as u32,
+                    friendly_name: dev.name.clone(),
+                    model: info.model.unwrap_or_else(|| dev.name.clone()),
+                    media_type: media_type.to_string(),
+                    bus_type: if is_nvme { "NVMe" } else { "Unknown" }.to_string(),
+                    size: info.size,
+                    health_status: "Unknown".to_string(),
+                    operational_status: "Online".to_string(),
+                    temperature: None,
+                    write_cache_enabled: false,
+                    power_on_hours: None,
+                    tbw: None,
+                    wear_level: None,
+                    smart_attributes: Vec::new(),
+                    partitions: logical_drives
+                        .iter()
+                        .filter(|drive| drive.disk_number == Some(i as u32))
+                        .map(|drive| drive.letter.clone())
+                        .collect(),
+                    raw_partitions: partitions
+                        .into_iter()
+                        .filter(|part| {
+                            let device = format!("/dev/{}", part.name);
+                            !part.in_use && !logical_drives.iter().any(|drive| drive.name == device)
+                        })
+                        .map(|part| RawPartition {
+                            number: part.number,
+                            kind: part.label.unwrap_or_else(|| "Partition".to_string()),
+                            size: part.size,
+                        })
+                        .collect(),
+                    health: DiskHealth::default(),
+                };
+                disk.health = disk.assess_health();
+                disk
             })
             .collect();
 
+        let now = Instant::now();
+        let previous = self.last_diskstats.lock().replace((now, devices.clone()));
+        let io_stats = match previous {
+            Some((at, previous)) => {
+                io_stats_between(&previous, &devices, now.duration_since(at).as_secs_f64())
+            }
+            // Rates need two samples; the first pass only primes the counters
+            None => Vec::new(),
+        };
+        let io_history = self.record_io_history(&io_stats);
+
         Ok(DiskData {
-            physical_disks: Vec::new(),
+            physical_disks,
             logical_drives,
-            io_stats: Vec::new(),
+            io_stats,
             process_activity: Vec::new(),
-            io_history: Vec::new(),
+            io_history,
         })
     }
 
@@ -431,21 +766,41 @@ impl DiskMonitor {
             .await
             .context("Failed to execute disk monitor batch")?;
 
-        let physical_disks = Self::parse_physical_disks(&outputs[0])?;
+        let mut physical_disks = Self::parse_physical_disks(&outputs[0])?;
+        let mut smart = self.get_smart_attributes().await;
+        for disk in &mut physical_disks {
+            disk.smart_attributes = smart.remove(&disk.disk_number).unwrap_or_default();
+            disk.health = disk.assess_health();
+        }
         let logical_drives = Self::parse_logical_drives(&outputs[1])?;
         let io_stats = Self::parse_io_stats(&outputs[2])?;
         let process_activity = Self::parse_process_activity(&outputs[3])?;
+        let io_history = self.record_io_history(&io_stats);
 
-        // Update history
+        Ok(DiskData {
+            physical_disks,
+            logical_drives,
+            io_stats,
+            process_activity,
+            io_history,
+        })
+    }
+
+    pub fn set_history_len(&mut self, len: usize) {
+        self.history_len = len;
+    }
+
+    /// Append the latest rates to each disk's rolling history.
+    fn record_io_history(&self, io_stats: &[DiskIOStats]) -> Vec<DiskIOHistory> {
         let mut history_map = self.io_history_map.lock();
-        for stat in &io_stats {
+        for stat in io_stats {
             let history = history_map
                 .entry(stat.disk_number)
                 .or_insert_with(|| DiskIOHistory {
                     disk_number: stat.disk_number,
-                    read_history: VecDeque::with_capacity(60),
-                    write_history: VecDeque::with_capacity(60),
-                    iops_history: VecDeque::with_capacity(60),
+                    read_history: VecDeque::new(),
+                    write_history: VecDeque::new(),
+                    iops_history: VecDeque::new(),
                 });
 
             // Add new data points
@@ -453,28 +808,19 @@ impl DiskMonitor {
             history.write_history.push_back(stat.write_speed);
             history.iops_history.push_back(stat.read_iops + stat.write_iops);
 
-            // Keep only last 60 samples
-            if history.read_history.len() > 60 {
-                history.read_history.pop_front();
-            }
-            if history.write_history.len() > 60 {
-                history.write_history.pop_front();
-            }
-            if history.iops_history.len() > 60 {
-                history.iops_history.pop_front();
+            // Keep only the last `history_len` samples
+            for series in [
+                &mut history.read_history,
+                &mut history.write_history,
+                &mut history.iops_history,
+            ] {
+                while series.len() > self.history_len {
+                    series.pop_front();
+                }
             }
         }
 
-        let io_history: Vec<DiskIOHistory> = history_map.values().cloned().collect();
-        drop(history_map);
-
-        Ok(DiskData {
-            physical_disks,
-            logical_drives,
-            io_stats,
-            process_activity,
-            io_history,
-        })
+        history_map.values().cloned().collect()
     }
 
     fn parse_physical_disks(output: &str) -> Result<Vec<PhysicalDiskInfo>> {
@@ -510,7 +856,68 @@ impl DiskMonitor {
                 power_on_hours: d.PowerOnHours,
                 tbw: d.TBW,
                 wear_level: d.WearLevel,
+                smart_attributes: Vec::new(),
                 partitions: d.Partitions.unwrap_or_default(),
+                raw_partitions: d
+                    .RawPartitions
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|p| RawPartition {
+                        number: p.Number,
+                        kind: p.Kind.unwrap_or_else(|| "Unknown".to_string()),
+                        size: p.Size.unwrap_or(0),
+                    })
+                    .collect(),
+                health: DiskHealth::default(),
+            })
+            .collect())
+    }
+
+    /// SMART attributes per disk number, re-read at most every SMART_REFRESH.
+    /// Failures leave the disks without attributes instead of failing the tab.
+    #[allow(dead_code)]
+    async fn get_smart_attributes(&self) -> HashMap<u32, Vec<SmartAttribute>> {
+        if let Some((taken, cached)) = self.smart_cache.lock().as_ref() {
+            if taken.elapsed() < SMART_REFRESH {
+                return cached.clone();
+            }
+        }
+
+        let attributes = match self.ps.execute(SMART_SCRIPT).await {
+            Ok(output) => Self::parse_smart_attributes(&output).unwrap_or_else(|e| {
+                log::warn!("Failed to parse SMART data: {e:#}");
+                HashMap::new()
+            }),
+            Err(e) => {
+                log::warn!("Failed to read SMART data: {e:#}");
+                HashMap::new()
+            }
+        };
+
+        *self.smart_cache.lock() = Some((Instant::now(), attributes.clone()));
+        attributes
+    }
+
+    #[allow(dead_code)]
+    fn parse_smart_attributes(output: &str) -> Result<HashMap<u32, Vec<SmartAttribute>>> {
+        let samples: Vec<SmartSample> =
+            parse_json_array(output).context("Failed to parse SMART samples")?;
+
+        Ok(samples
+            .into_iter()
+            .map(|sample| {
+                // smartctl reports the real attribute table; prefer it when it parsed
+                let mut attributes = sample
+                    .Smartctl
+                    .as_deref()
+                    .map(parse_smartctl_attributes)
+                    .unwrap_or_default();
+                if attributes.is_empty() {
+                    if let Some(counters) = sample.Counters {
+                        attributes = reliability_counter_attributes(&counters);
+                    }
+                }
+                (sample.DiskNumber, attributes)
             })
             .collect())
     }
@@ -662,6 +1069,137 @@ struct PhysicalDiskSample {
     TBW: Option<u64>,
     WearLevel: Option<f32>,
     Partitions: Option<Vec<String>>,
+    RawPartitions: Option<Vec<RawPartitionSample>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(non_snake_case)]
+struct RawPartitionSample {
+    Number: u32,
+    Kind: Option<String>,
+    Size: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(non_snake_case, dead_code)]
+struct SmartSample {
+    DiskNumber: u32,
+    Counters: Option<serde_json::Map<String, serde_json::Value>>,
+    Smartctl: Option<String>,
+}
+
+/// Attributes from `smartctl -A -j`: the ATA table, or the NVMe health log.
+#[allow(dead_code)]
+/// Per-device rates from two `/proc/diskstats` samples `elapsed_secs` apart,
+/// numbered by position in `current`. Devices missing from `previous` (just
+/// attached) are skipped until the next sample.
+fn io_stats_between(
+    previous: &[DiskStatCounters],
+    current: &[DiskStatCounters],
+    elapsed_secs: f64,
+) -> Vec<DiskIOStats> {
+    const MB: f64 = 1024.0 * 1024.0;
+    if elapsed_secs <= 0.0 {
+        return Vec::new();
+    }
+
+    current
+        .iter()
+        .enumerate()
+        .filter_map(|(i, now)| {
+            let before = previous.iter().find(|dev| dev.name == now.name)?;
+            // Counters can wrap or reset when a device is re-attached
+            let delta = |now: u64, before: u64| now.saturating_sub(before) as f64;
+            let reads = delta(now.reads_completed, before.reads_completed);
+            let writes = delta(now.writes_completed, before.writes_completed);
+            let io_ms = delta(now.read_time_ms, before.read_time_ms)
+                + delta(now.write_time_ms, before.write_time_ms);
+
+            Some(DiskIOStats {
+                disk_number: i as u32,
+                read_speed: delta(now.sectors_read, before.sectors_read) * 512.0 / MB / elapsed_secs,
+                write_speed: delta(now.sectors_written, before.sectors_written) * 512.0 / MB
+                    / elapsed_secs,
+                read_iops: reads / elapsed_secs,
+                write_iops: writes / elapsed_secs,
+                queue_depth: now.in_flight as f64,
+                avg_response_time: if reads + writes > 0.0 {
+                    io_ms / (reads + writes)
+                } else {
+                    0.0
+                },
+                active_time: (delta(now.io_time_ms, before.io_time_ms) / (elapsed_secs * 1000.0)
+                    * 100.0)
+                    .min(100.0),
+            })
+        })
+        .collect()
+}
+
+fn parse_smartctl_attributes(output: &str) -> Vec<SmartAttribute> {
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(output.trim()) else {
+        return Vec::new();
+    };
+    let as_u32 = |value: &serde_json::Value| value.as_u64().map(|v| v as u32);
+
+    if let Some(table) = json["ata_smart_attributes"]["table"].as_array() {
+        return table
+            .iter()
+            .map(|attr| SmartAttribute {
+                id: as_u32(&attr["id"]),
+                name: attr["name"].as_str().unwrap_or("Unknown").replace('_', " "),
+                value: as_u32(&attr["value"]),
+                worst: as_u32(&attr["worst"]),
+                threshold: as_u32(&attr["thresh"]),
+                raw: attr["raw"]["value"].as_u64().unwrap_or(0),
+            })
+            .collect();
+    }
+
+    let Some(log) = json["nvme_smart_health_information_log"].as_object() else {
+        return Vec::new();
+    };
+    let spare_threshold = log.get("available_spare_threshold").and_then(as_u32);
+    log.iter()
+        .filter(|(key, _)| key.as_str() != "available_spare_threshold")
+        .filter_map(|(key, value)| {
+            let raw = value.as_u64()?;
+            // Available spare is the one NVMe field with a vendor threshold
+            let (value, threshold) = if key == "available_spare" {
+                (Some(raw as u32), spare_threshold)
+            } else {
+                (None, None)
+            };
+            Some(SmartAttribute {
+                id: None,
+                name: key.replace('_', " "),
+                value,
+                worst: None,
+                threshold,
+                raw,
+            })
+        })
+        .collect()
+}
+
+#[allow(dead_code)]
+fn reliability_counter_attributes(
+    counters: &serde_json::Map<String, serde_json::Value>,
+) -> Vec<SmartAttribute> {
+    RELIABILITY_COUNTERS
+        .iter()
+        .filter_map(|(property, id, label)| {
+            let raw = counters.get(*property)?.as_u64()?;
+            Some(SmartAttribute {
+                id: *id,
+                name: label.to_string(),
+                value: None,
+                worst: None,
+                threshold: None,
+                raw,
+            })
+        })
+        .collect()
 }
 
 #[derive(Debug, Deserialize)]
@@ -686,3 +1224,97 @@ struct ProcessActivitySample {
     ReadBytesPerSec: Option<f64>,
     WriteBytesPerSec: Option<f64>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diskstats_deltas_become_rates() {
+        let sample = |name: &str, sectors_read: u64, reads: u64, io_time_ms: u64| DiskStatCounters {
+            name: name.to_string(),
+            reads_completed: reads,
+            sectors_read,
+            read_time_ms: reads,
+            in_flight: 1,
+            io_time_ms,
+            ..Default::default()
+        };
+        let previous = vec![sample("sda", 0, 0, 0)];
+        // 4 MiB read in 200 operations over two seconds, busy for one of them
+        let current = vec![sample("sda", 8192, 200, 1000), sample("sdb", 10, 1, 0)];
+
+        let stats = io_stats_between(&previous, &current, 2.0);
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].disk_number, 0);
+        assert!((stats[0].read_speed - 2.0).abs() < 1e-9);
+        assert!((stats[0].read_iops - 100.0).abs() < 1e-9);
+        assert!((stats[0].active_time - 50.0).abs() < 1e-9);
+        assert!((stats[0].avg_response_time - 1.0).abs() < 1e-9);
+        assert!(io_stats_between(&current, &previous, 2.0)[0].read_speed == 0.0);
+    }
+
+    #[test]
+    fn health_score_combines_status_wear_and_smart() {
+        let disk = |health_status: &str, wear_level: Option<f32>, reallocated: u64| PhysicalDiskInfo {
+            health_status: health_status.to_string(),
+            wear_level,
+            smart_attributes: vec![SmartAttribute {
+                id: Some(5),
+                name: "Reallocated Sector Ct".to_string(),
+                value: Some(100),
+                worst: Some(100),
+                threshold: Some(10),
+                raw: reallocated,
+            }],
+            ..Default::default()
+        };
+
+        let healthy = disk("Healthy", Some(95.0), 0).assess_health();
+        assert_eq!(healthy.score, Some(100));
+        assert!(!healthy.predicted_failure);
+
+        let worn = disk("Healthy", Some(25.0), 8).assess_health();
+        assert_eq!(worn.score, Some(65));
+        assert_eq!(worn.reasons.len(), 2);
+        assert!(!worn.predicted_failure);
+
+        let failing = disk("Warning", Some(20.0), 500).assess_health();
+        assert_eq!(failing.score, Some(15));
+        assert!(failing.predicted_failure);
+
+        let mut unknown = disk("Unknown", None, 0);
+        unknown.smart_attributes.clear();
+        unknown.temperature = None;
+        assert_eq!(unknown.assess_health().score, None);
+    }
+
+    #[test]
+    fn smart_prefers_smartctl_and_falls_back_to_counters() {
+        let smartctl = r#"{"ata_smart_attributes":{"table":[
+            {"id":5,"name":"Reallocated_Sector_Ct","value":10,"worst":10,"thresh":36,"raw":{"value":2048}},
+            {"id":9,"name":"Power_On_Hours","value":95,"worst":95,"thresh":0,"raw":{"value":4321}}
+        ]}}"#;
+        let output = serde_json::json!([
+            {"DiskNumber": 0, "Counters": {"PowerOnHours": 100}, "Smartctl": smartctl},
+            {"DiskNumber": 1, "Counters": {"PowerOnHours": 12, "Wear": 3}, "Smartctl": null},
+            {"DiskNumber": 2, "Counters": null, "Smartctl": null}
+        ])
+        .to_string();
+
+        let smart = DiskMonitor::parse_smart_attributes(&output).unwrap();
+
+        let ata = &smart[&0];
+        assert_eq!(ata.len(), 2);
+        assert_eq!(ata[0].name, "Reallocated Sector Ct");
+        assert!(ata[0].is_failing());
+        assert!(!ata[1].is_failing());
+
+        let counters = &smart[&1];
+        assert_eq!(counters[0].id, Some(9));
+        assert_eq!(counters[0].raw, 12);
+        assert_eq!(counters.len(), 2);
+
+        assert!(smart[&2].is_empty());
+    }
+}
@@ -1,12 +1,20 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::hash::Hasher;
+use std::io::{ErrorKind, Read};
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
-use std::time::Duration;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 use tokio::io::{AsyncRead, AsyncReadExt};
 use tokio::process::Command;
 use tokio::time::timeout;
 
+#[cfg(target_os = "linux")]
+use crate::integrations::LinuxSysMonitor;
 use crate::integrations::PowerShellExecutor;
 use crate::utils::parse_json_array;
 
@@ -33,13 +41,60 @@ pub struct RootFolderInfo {
     pub size: u64,
 }
 
+#[cfg(test)]
+impl RootFolderInfo {
+    pub fn new(name: &str, path: &str, size: u64) -> Self {
+        Self {
+            name: name.to_string(),
+            path: path.to_string(),
+            size,
+        }
+    }
+}
+
+impl AnalyzedDrive {
+    /// Drop a deleted folder. Its size goes back to the drive's free space
+    /// only when `freed`: a folder in the Recycle Bin or trash still takes
+    /// up the same room.
+    pub fn remove_folder(&mut self, path: &str, freed: bool) -> bool {
+        let Some(index) = self.root_folders.iter().position(|f| f.path == path) else {
+            return false;
+        };
+        let folder = self.root_folders.remove(index);
+        if freed {
+            self.used = self.used.saturating_sub(folder.size);
+            self.free = self.free.saturating_add(folder.size);
+        }
+        true
+    }
+}
+
+/// How `delete_entry` got rid of a file or folder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Deletion {
+    /// In the Recycle Bin or trash, still using its space
+    Trashed,
+    Permanent,
+}
+
 pub struct DiskAnalyzerMonitor {
     ps: PowerShellExecutor,
-    es_executable: String,
+    /// `None` when the Everything CLI is not available; folder sizes are then
+    /// computed by walking the filesystem.
+    es_executable: Option<String>,
     max_results: usize,
     timeout: Duration,
+    native_cache: Option<(Instant, DiskAnalyzerData)>,
+    #[cfg(target_os = "linux")]
+    linux_sys: LinuxSysMonitor,
 }
 
+/// Deepest directory level the native walker descends into.
+const NATIVE_MAX_DEPTH: usize = 64;
+/// Walking a whole drive is expensive, so native results are reused for a
+/// while instead of rescanning on every refresh.
+const NATIVE_RESCAN_INTERVAL: Duration = Duration::from_secs(60);
+
 const LOGICAL_DRIVES_SCRIPT: &str = r#"
     try {
         $drives = Get-CimInstance Win32_LogicalDisk -ErrorAction Stop |
@@ -71,29 +126,94 @@ impl DiskAnalyzerMonitor {
         max_results: usize,
         timeout_seconds: u64,
     ) -> Result<Self> {
-        let path = Path::new(&es_executable);
-        if !path.exists() {
-            anyhow::bail!("Everything CLI not found at {}", es_executable);
-        }
+        let es_executable = if cfg!(target_os = "linux") {
+            None
+        } else if Path::new(&es_executable).exists() {
+            Some(es_executable)
+        } else {
+            log::info!(
+                "Everything CLI not found at {}, using native folder scan",
+                es_executable
+            );
+            None
+        };
 
         Ok(Self {
+            #[cfg(target_os = "linux")]
+            linux_sys: LinuxSysMonitor::new().with_remote(ps.remote().cloned()),
             ps,
             es_executable,
             max_results,
             timeout: Duration::from_secs(timeout_seconds.max(1)),
+            native_cache: None,
         })
     }
 
-    pub async fn collect_data(&self) -> Result<DiskAnalyzerData> {
-        #[cfg(target_os = "linux")]
-        {
-            anyhow::bail!("Disk analyzer is only supported on Windows");
+    pub async fn collect_data(&mut self) -> Result<DiskAnalyzerData> {
+        if self.es_executable.is_none() {
+            let local = self.ps.remote().is_none();
+            if let Some((scanned_at, data)) = &mut self.native_cache {
+                if scanned_at.elapsed() < NATIVE_RESCAN_INTERVAL && local {
+                    // Folders deleted from the tab must not come back from the cache
+                    for drive in &mut data.drives {
+                        let missing: Vec<String> = drive
+                            .root_folders
+                            .iter()
+                            .filter(|f| !Path::new(&f.path).exists())
+                            .map(|f| f.path.clone())
+                            .collect();
+                        // Gone from disk, but maybe only to the trash
+                        for path in missing {
+                            drive.remove_folder(&path, false);
+                        }
+                    }
+                    return Ok(data.clone());
+                }
+            }
         }
 
+        #[cfg(target_os = "linux")]
+        let data = self.collect_data_linux().await?;
+
         #[cfg(not(target_os = "linux"))]
-        {
-            return self.collect_data_windows().await;
+        let data = self.collect_data_windows().await?;
+
+        if self.es_executable.is_none() {
+            self.native_cache = Some((Instant::now(), data.clone()));
+        }
+
+        Ok(data)
+    }
+
+    #[cfg(target_os = "linux")]
+    async fn collect_data_linux(&self) -> Result<DiskAnalyzerData> {
+        let disks = self
+            .linux_sys
+            .blocking(|sys| sys.get_disk_info())
+            .await?
+            .context("Failed to query mounted filesystems")?;
+
+        let mut results = Vec::new();
+
+        for disk in disks {
+            let (root_folders, error) = if self.ps.remote().is_some() {
+                self.scan_root_folders_remote(&disk.mount_point).await
+            } else {
+                self.scan_root_folders_native(&disk.mount_point).await
+            };
+
+            results.push(AnalyzedDrive {
+                letter: disk.mount_point,
+                name: disk.name,
+                total: disk.total,
+                used: disk.used,
+                free: disk.available,
+                root_folders,
+                error,
+            });
         }
+
+        Ok(DiskAnalyzerData { drives: results })
     }
 
     async fn collect_data_windows(&self) -> Result<DiskAnalyzerData> {
@@ -117,16 +237,20 @@ impl DiskAnalyzerMonitor {
             let mut root_folders = Vec::new();
             let mut error = None;
 
-            match self.query_root_folders(&drive_root).await {
-                Ok(mut folders) => {
-                    folders.sort_by(|a, b| b.size.cmp(&a.size));
-                    if self.max_results > 0 && folders.len() > self.max_results {
-                        folders.truncate(self.max_results);
+            if self.es_executable.is_none() {
+                (root_folders, error) = self.scan_root_folders_native(&drive_root).await;
+            } else {
+                match self.query_root_folders(&drive_root).await {
+                    Ok(mut folders) => {
+                        folders.sort_by_key(|folder| std::cmp::Reverse(folder.size));
+                        if self.max_results > 0 && folders.len() > self.max_results {
+                            folders.truncate(self.max_results);
+                        }
+                        root_folders = folders;
+                    }
+                    Err(e) => {
+                        error = Some(e.to_string());
                     }
-                    root_folders = folders;
-                }
-                Err(e) => {
-                    error = Some(e.to_string());
                 }
             }
 
@@ -174,8 +298,71 @@ impl DiskAnalyzerMonitor {
         Ok(parse_everything_output(&output, drive_root))
     }
 
+    /// Sizes every top-level folder under `root` by walking it on a blocking
+    /// thread. Unreadable folders and timeouts are reported in the returned
+    /// error rather than failing the drive.
+    async fn scan_root_folders_native(
+        &self,
+        root: &str,
+    ) -> (Vec<RootFolderInfo>, Option<String>) {
+        let root = root.to_string();
+        let max_results = self.max_results;
+        let timeout = self.timeout;
+
+        match tokio::task::spawn_blocking(move || {
+            scan_root_folders(&root, max_results, timeout, &AtomicBool::new(false))
+        })
+        .await
+        {
+            Ok(result) => result,
+            Err(e) => (Vec::new(), Some(format!("Folder scan failed: {}", e))),
+        }
+    }
+
+    /// `du` one level deep on the remote host, staying on `root`'s filesystem.
+    #[allow(dead_code)]
+    async fn scan_root_folders_remote(&self, root: &str) -> (Vec<RootFolderInfo>, Option<String>) {
+        let timeout = self.timeout.as_secs().to_string();
+        let mount_point = root.to_string();
+        let output = self
+            .linux_sys
+            .blocking(move |sys| {
+                sys.output("timeout", &[&timeout, "du", "-x", "-b", "-d", "1", &mount_point])
+            })
+            .await
+            .and_then(|output| output);
+        let output = match output {
+            Ok(output) => output,
+            Err(e) => return (Vec::new(), Some(format!("Folder scan failed: {}", e))),
+        };
+
+        let mut folders: Vec<RootFolderInfo> = output
+            .lines()
+            .filter_map(|line| {
+                let (size, path) = line.split_once('\t')?;
+                if path == root {
+                    return None;
+                }
+                Some(RootFolderInfo {
+                    name: Path::new(path).file_name()?.to_string_lossy().into_owned(),
+                    path: path.to_string(),
+                    size: size.parse().ok()?,
+                })
+            })
+            .collect();
+        folders.sort_by_key(|folder| std::cmp::Reverse(folder.size));
+        if self.max_results > 0 && folders.len() > self.max_results {
+            folders.truncate(self.max_results);
+        }
+        (folders, None)
+    }
+
     async fn run_everything(&self, args: &[&str]) -> Result<String> {
-        let mut child = Command::new(&self.es_executable)
+        let es_executable = self
+            .es_executable
+            .as_deref()
+            .context("Everything CLI is not available")?;
+        let mut child = Command::new(es_executable)
             .args(args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -226,6 +413,500 @@ impl DiskAnalyzerMonitor {
     }
 }
 
+#[derive(Serialize)]
+struct DriveExport<'a> {
+    drive: &'a str,
+    exported_at: String,
+    total: u64,
+    used: u64,
+    free: u64,
+    /// Only the largest `max_results` folders were loaded
+    truncated: bool,
+    folders: Vec<FolderExport<'a>>,
+}
+
+#[derive(Serialize)]
+struct FolderExport<'a> {
+    name: &'a str,
+    path: &'a str,
+    size: u64,
+    share_pct: f64,
+}
+
+/// Write the loaded folders of `drive` to a timestamped `csv` or `json` file
+/// in `dir`, named `-topN` when the list was truncated. Returns the written
+/// path and whether it was.
+pub fn export_drive(
+    drive: &AnalyzedDrive,
+    format: &str,
+    max_results: usize,
+    dir: &Path,
+) -> Result<(PathBuf, bool)> {
+    let now = chrono::Local::now();
+    let truncated = max_results > 0 && drive.root_folders.len() >= max_results;
+    let denom = if drive.used > 0 { drive.used } else { drive.total };
+    let folders: Vec<FolderExport> = drive
+        .root_folders
+        .iter()
+        .map(|folder| FolderExport {
+            name: &folder.name,
+            path: &folder.path,
+            size: folder.size,
+            share_pct: if denom > 0 {
+                (folder.size as f64 / denom as f64 * 1000.0).round() / 10.0
+            } else {
+                0.0
+            },
+        })
+        .collect();
+
+    let label: String = drive
+        .letter
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect();
+    let label = if label.is_empty() { "root".to_string() } else { label };
+    let is_json = format.eq_ignore_ascii_case("json");
+    // CSV has nowhere else to say the list is partial without breaking parsers
+    let scope = if truncated { format!("-top{}", max_results) } else { String::new() };
+    let path = dir.join(format!(
+        "disk-analyzer-{}-{}{}.{}",
+        label,
+        now.format("%Y%m%d-%H%M%S"),
+        scope,
+        if is_json { "json" } else { "csv" }
+    ));
+
+    let contents = if is_json {
+        serde_json::to_string_pretty(&DriveExport {
+            drive: &drive.letter,
+            exported_at: now.to_rfc3339(),
+            total: drive.total,
+            used: drive.used,
+            free: drive.free,
+            truncated,
+            folders,
+        })?
+    } else {
+        let mut csv = String::from("name,path,size,share_pct\n");
+        for folder in &folders {
+            csv.push_str(&format!(
+                "{},{},{},{}\n",
+                csv_field(folder.name),
+                csv_field(folder.path),
+                folder.size,
+                folder.share_pct
+            ));
+        }
+        csv
+    };
+
+    fs::write(&path, contents).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok((path, truncated))
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Move a file or folder to the Recycle Bin (Windows) or the trash (Linux,
+/// via `gio` or `trash-put`; without either it is removed permanently).
+pub async fn delete_entry(ps: &PowerShellExecutor, path: &str) -> Result<Deletion> {
+    if is_drive_root(path) {
+        anyhow::bail!("Refusing to delete drive root {}", path);
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        if let Some(target) = ps.remote() {
+            anyhow::bail!("Deleting files on {} is not supported", target.destination());
+        }
+        delete_entry_linux(path).await
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        // The shell runs hidden, so any dialog would block with nobody to
+        // answer it. MoveHere into the Recycle Bin (namespace 10) with
+        // FOF_SILENT | FOF_NOCONFIRMATION | FOF_ALLOWUNDO | FOF_NOERRORUI
+        // shows none; a path still present afterwards means it failed.
+        let script = format!(
+            r#"
+            $path = '{}'
+            $item = Get-Item -LiteralPath $path -Force -ErrorAction Stop
+            (New-Object -ComObject Shell.Application).Namespace(10).MoveHere($item.FullName, 0x454)
+            if (Test-Path -LiteralPath $path) {{
+                throw "Could not move $path to the Recycle Bin (in use or access denied)"
+            }}
+            "#,
+            path.replace('\'', "''")
+        );
+        ps.execute_uncached(&script).await?;
+        Ok(Deletion::Trashed)
+    }
+}
+
+#[allow(dead_code)]
+async fn delete_entry_linux(path: &str) -> Result<Deletion> {
+    for (program, args) in [("gio", &["trash", "--"][..]), ("trash-put", &["--"][..])] {
+        match Command::new(program).args(args).arg(path).output().await {
+            Ok(output) if output.status.success() => return Ok(Deletion::Trashed),
+            Ok(output) => anyhow::bail!(
+                "{} failed: {}",
+                program,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+            Err(e) if e.kind() == ErrorKind::NotFound => continue,
+            Err(e) => return Err(e).with_context(|| format!("Failed to run {}", program)),
+        }
+    }
+
+    let metadata = tokio::fs::symlink_metadata(path)
+        .await
+        .with_context(|| format!("Cannot access {}", path))?;
+    if metadata.is_dir() {
+        tokio::fs::remove_dir_all(path).await
+    } else {
+        tokio::fs::remove_file(path).await
+    }
+    .with_context(|| format!("Failed to delete {}", path))?;
+    Ok(Deletion::Permanent)
+}
+
+/// `C:\`, `C:` or `/` - anything without a parent directory.
+pub fn is_drive_root(path: &str) -> bool {
+    let trimmed = path.trim();
+    trimmed.is_empty()
+        || Path::new(trimmed).parent().is_none()
+        || (trimmed.len() <= 3 && trimmed.trim_end_matches(['\\', '/']).ends_with(':'))
+}
+
+/// Sizes the immediate subfolders of `path`, largest first. Used to drill
+/// one level into a folder without rescanning the whole drive.
+/// Stops early, with partial sizes, once `cancelled` is set.
+pub fn scan_subfolders(
+    path: &str,
+    timeout: Duration,
+    cancelled: &AtomicBool,
+) -> Vec<RootFolderInfo> {
+    scan_root_folders(path, 0, timeout, cancelled).0
+}
+
+fn scan_root_folders(
+    root: &str,
+    max_results: usize,
+    timeout: Duration,
+    cancelled: &AtomicBool,
+) -> (Vec<RootFolderInfo>, Option<String>) {
+    let entries = match fs::read_dir(root) {
+        Ok(entries) => entries,
+        Err(e) => return (Vec::new(), Some(format!("Cannot read {}: {}", root, e))),
+    };
+
+    let mut scan = NativeScan {
+        device: fs::metadata(root).ok().and_then(|m| device_id(&m)),
+        deadline: Instant::now() + timeout,
+        cancelled,
+        denied: Vec::new(),
+        timed_out: false,
+    };
+
+    let mut folders = Vec::new();
+    for entry in entries.flatten() {
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if !metadata.is_dir() || !scan.same_device(&metadata) {
+            continue;
+        }
+
+        let path = entry.path();
+        let size = scan.folder_size(&path, 1);
+        folders.push(RootFolderInfo {
+            name: entry.file_name().to_string_lossy().to_string(),
+            path: path.to_string_lossy().to_string(),
+            size,
+        });
+    }
+
+    folders.sort_by_key(|folder| std::cmp::Reverse(folder.size));
+    if max_results > 0 && folders.len() > max_results {
+        folders.truncate(max_results);
+    }
+
+    let mut problems = Vec::new();
+    if scan.timed_out {
+        problems.push(format!(
+            "scan timed out after {}s, sizes are partial",
+            timeout.as_secs()
+        ));
+    }
+    if !scan.denied.is_empty() {
+        let shown: Vec<&str> = scan.denied.iter().take(3).map(String::as_str).collect();
+        let more = scan.denied.len().saturating_sub(shown.len());
+        let mut message = format!("access denied: {}", shown.join(", "));
+        if more > 0 {
+            message.push_str(&format!(" (+{} more)", more));
+        }
+        problems.push(message);
+    }
+
+    let error = if problems.is_empty() {
+        None
+    } else {
+        Some(problems.join("; "))
+    };
+
+    (folders, error)
+}
+
+struct NativeScan<'a> {
+    device: Option<u64>,
+    deadline: Instant,
+    cancelled: &'a AtomicBool,
+    denied: Vec<String>,
+    timed_out: bool,
+}
+
+impl NativeScan<'_> {
+    fn folder_size(&mut self, path: &Path, depth: usize) -> u64 {
+        if self.timed_out || depth > NATIVE_MAX_DEPTH || self.cancelled.load(Ordering::Relaxed) {
+            return 0;
+        }
+        if Instant::now() >= self.deadline {
+            self.timed_out = true;
+            return 0;
+        }
+
+        let entries = match fs::read_dir(path) {
+            Ok(entries) => entries,
+            Err(e) => {
+                if e.kind() == ErrorKind::PermissionDenied {
+                    self.denied.push(path.to_string_lossy().to_string());
+                }
+                return 0;
+            }
+        };
+
+        let mut total = 0u64;
+        for entry in entries.flatten() {
+            // DirEntry::metadata does not follow symlinks, so linked trees
+            // are not counted twice.
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if metadata.is_dir() {
+                if self.same_device(&metadata) {
+                    total = total.saturating_add(self.folder_size(&entry.path(), depth + 1));
+                }
+            } else if metadata.is_file() {
+                total = total.saturating_add(metadata.len());
+            }
+        }
+
+        total
+    }
+
+    /// Keeps the walk on the scanned filesystem (skips /proc, other mounts).
+    fn same_device(&self, metadata: &fs::Metadata) -> bool {
+        match (self.device, device_id(metadata)) {
+            (Some(root), Some(dev)) => root == dev,
+            _ => true,
+        }
+    }
+}
+
+#[cfg(unix)]
+fn device_id(metadata: &fs::Metadata) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+    Some(metadata.dev())
+}
+
+#[cfg(not(unix))]
+fn device_id(_metadata: &fs::Metadata) -> Option<u64> {
+    None
+}
+
+/// `(device, inode)`, so hard links to the same data are recognised.
+#[cfg(unix)]
+fn file_id(metadata: &fs::Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+fn file_id(_metadata: &fs::Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+/// Counters the duplicate scan bumps as it goes, read by the UI for progress.
+#[derive(Debug, Default)]
+pub struct DuplicateProgress {
+    pub files_seen: AtomicUsize,
+    /// Files sharing their size with another, i.e. the ones to hash
+    pub candidates: AtomicUsize,
+    pub hashed: AtomicUsize,
+    /// Set when the view is closed; the scan stops and returns nothing
+    pub cancelled: AtomicBool,
+}
+
+/// Files with identical contents. The first path is the copy that is kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateGroup {
+    pub size: u64,
+    pub paths: Vec<String>,
+}
+
+impl DuplicateGroup {
+    /// Space freed by deleting every copy but the first.
+    pub fn reclaimable(&self) -> u64 {
+        self.size
+            .saturating_mul(self.paths.len().saturating_sub(1) as u64)
+    }
+}
+
+/// Groups of identical files of at least `min_size` bytes under `root`, most
+/// reclaimable space first. Files are matched by size, then by a streamed
+/// hash, then byte for byte, so a hash collision can't pair different files.
+/// Unreadable files and other filesystems are skipped.
+pub fn find_duplicates(
+    root: &Path,
+    min_size: u64,
+    max_results: usize,
+    progress: &DuplicateProgress,
+) -> Vec<DuplicateGroup> {
+    let device = fs::metadata(root).ok().and_then(|m| device_id(&m));
+    let mut by_size: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+    let mut seen = HashSet::new();
+    let mut pending = vec![(root.to_path_buf(), 0usize)];
+
+    while let Some((dir, depth)) = pending.pop() {
+        if progress.cancelled.load(Ordering::Relaxed) {
+            return Vec::new();
+        }
+        if depth > NATIVE_MAX_DEPTH {
+            continue;
+        }
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            // Symlinks are neither followed nor counted
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if metadata.is_dir() {
+                if device.is_none() || device_id(&metadata) == device {
+                    pending.push((entry.path(), depth + 1));
+                }
+            } else if metadata.is_file() {
+                progress.files_seen.fetch_add(1, Ordering::Relaxed);
+                // Deleting one hard link of a pair frees nothing
+                let first_link = file_id(&metadata).is_none_or(|id| seen.insert(id));
+                if metadata.len() >= min_size && first_link {
+                    by_size.entry(metadata.len()).or_default().push(entry.path());
+                }
+            }
+        }
+    }
+
+    by_size.retain(|_, paths| paths.len() > 1);
+    progress
+        .candidates
+        .store(by_size.values().map(Vec::len).sum(), Ordering::Relaxed);
+
+    let mut groups = Vec::new();
+    for (size, paths) in by_size {
+        if progress.cancelled.load(Ordering::Relaxed) {
+            return Vec::new();
+        }
+        let mut by_hash: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+        for path in paths {
+            if let Ok(hash) = hash_file(&path) {
+                by_hash.entry(hash).or_default().push(path);
+            }
+            progress.hashed.fetch_add(1, Ordering::Relaxed);
+        }
+        for paths in by_hash.into_values().filter(|paths| paths.len() > 1) {
+            groups.extend(split_identical(size, paths));
+        }
+    }
+
+    groups.sort_by_key(|group| std::cmp::Reverse(group.reclaimable()));
+    if max_results > 0 && groups.len() > max_results {
+        groups.truncate(max_results);
+    }
+    groups
+}
+
+const HASH_CHUNK: usize = 64 * 1024;
+
+fn hash_file(path: &Path) -> std::io::Result<u64> {
+    let mut file = fs::File::open(path)?;
+    let mut hasher = DefaultHasher::new();
+    let mut buf = vec![0u8; HASH_CHUNK];
+    loop {
+        let read = file.read(&mut buf)?;
+        if read == 0 {
+            return Ok(hasher.finish());
+        }
+        hasher.write(&buf[..read]);
+    }
+}
+
+/// Split files that hashed alike into groups whose bytes really match.
+fn split_identical(size: u64, mut paths: Vec<PathBuf>) -> Vec<DuplicateGroup> {
+    paths.sort();
+    let mut groups = Vec::new();
+    while !paths.is_empty() {
+        let first = paths.remove(0);
+        let (same, rest): (Vec<PathBuf>, Vec<PathBuf>) = paths
+            .into_iter()
+            .partition(|path| same_contents(&first, path).unwrap_or(false));
+        paths = rest;
+        if !same.is_empty() {
+            groups.push(DuplicateGroup {
+                size,
+                paths: std::iter::once(first)
+                    .chain(same)
+                    .map(|path| path.to_string_lossy().to_string())
+                    .collect(),
+            });
+        }
+    }
+    groups
+}
+
+fn same_contents(a: &Path, b: &Path) -> std::io::Result<bool> {
+    let (mut a, mut b) = (fs::File::open(a)?, fs::File::open(b)?);
+    let (mut buf_a, mut buf_b) = (vec![0u8; HASH_CHUNK], vec![0u8; HASH_CHUNK]);
+    loop {
+        let read = fill(&mut a, &mut buf_a)?;
+        if read != fill(&mut b, &mut buf_b)? || buf_a[..read] != buf_b[..read] {
+            return Ok(false);
+        }
+        if read == 0 {
+            return Ok(true);
+        }
+    }
+}
+
+/// Read until `buf` is full or the file ends.
+fn fill(file: &mut fs::File, buf: &mut [u8]) -> std::io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match file.read(&mut buf[filled..])? {
+            0 => break,
+            read => filled += read,
+        }
+    }
+    Ok(filled)
+}
+
 async fn read_to_end<R>(mut reader: R) -> Result<Vec<u8>>
 where
     R: AsyncRead + Unpin,
@@ -546,3 +1227,88 @@ struct DriveSample {
     Total: Option<u64>,
     Free: Option<u64>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drive_roots_are_protected() {
+        for root in ["", "/", "C:", "C:\\", "d:/"] {
+            assert!(is_drive_root(root), "{root:?} should be a root");
+        }
+        assert!(!is_drive_root("/home"));
+        assert!(!is_drive_root("C:\\Users"));
+    }
+
+    #[test]
+    fn finds_duplicates_by_content_not_just_size() {
+        let root = std::env::temp_dir().join(format!("tui-plus-dupes-{}", std::process::id()));
+        fs::create_dir_all(root.join("nested")).unwrap();
+        fs::write(root.join("a.bin"), [7u8; 4096]).unwrap();
+        fs::write(root.join("nested/b.bin"), [7u8; 4096]).unwrap();
+        fs::write(root.join("same-size.bin"), [8u8; 4096]).unwrap();
+        fs::write(root.join("small.bin"), [7u8; 16]).unwrap();
+        fs::write(root.join("nested/small.bin"), [7u8; 16]).unwrap();
+
+        let progress = DuplicateProgress::default();
+        let groups = find_duplicates(&root, 1024, 0, &progress);
+        fs::remove_dir_all(&root).ok();
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].size, 4096);
+        assert_eq!(groups[0].reclaimable(), 4096);
+        assert!(groups[0].paths[0].ends_with("a.bin"));
+        assert!(groups[0].paths[1].ends_with("b.bin"));
+        assert_eq!(progress.candidates.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn removing_a_folder_frees_its_space_unless_trashed() {
+        let folder = |name: &str, size| RootFolderInfo::new(name, &format!("/{name}"), size);
+        let mut drive = AnalyzedDrive {
+            letter: "/".to_string(),
+            name: String::new(),
+            total: 1000,
+            used: 600,
+            free: 400,
+            root_folders: vec![folder("a", 300), folder("b", 100)],
+            error: None,
+        };
+
+        assert!(drive.remove_folder("/a", true));
+        assert!(!drive.remove_folder("/missing", true));
+        assert_eq!((drive.used, drive.free), (300, 700));
+        assert_eq!(drive.root_folders.len(), 1);
+
+        // Trashed folders keep their space
+        assert!(drive.remove_folder("/b", false));
+        assert_eq!((drive.used, drive.free), (300, 700));
+        assert!(drive.root_folders.is_empty());
+    }
+
+    #[test]
+    fn export_writes_csv_with_quoting() {
+        let drive = AnalyzedDrive {
+            letter: "C:".to_string(),
+            name: String::new(),
+            total: 1000,
+            used: 500,
+            free: 500,
+            root_folders: vec![RootFolderInfo::new("a,b", "C:\\a,b", 250)],
+            error: None,
+        };
+        let dir = std::env::temp_dir().join(format!("tui-export-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let (path, truncated) = export_drive(&drive, "csv", 1, &dir).unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(truncated);
+        let file_name = path.file_name().unwrap().to_string_lossy().into_owned();
+        assert!(file_name.starts_with("disk-analyzer-C-"), "{file_name}");
+        assert!(file_name.ends_with("-top1.csv"), "{file_name}");
+        assert_eq!(contents, "name,path,size,share_pct\n\"a,b\",\"C:\\a,b\",250,50\n");
+    }
+}
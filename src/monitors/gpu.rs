@@ -2,9 +2,40 @@ use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use crate::integrations::PowerShellExecutor;
 use crate::utils::parse_json_array;
+#[cfg(feature = "nvidia")]
+use nvml_wrapper::bitmasks::device::ThrottleReasons;
+#[cfg(feature = "nvidia")]
+use nvml_wrapper::enum_wrappers::device::{Clock, TemperatureSensor};
+#[cfg(feature = "nvidia")]
+use nvml_wrapper::error::NvmlError;
+#[cfg(feature = "nvidia")]
+use nvml_wrapper::Nvml;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GpuData {
+    pub devices: Vec<GpuDevice>,
+    pub processes: Vec<GpuProcessInfo>,
+}
+
+impl GpuData {
+    /// Device with the highest current utilization, used for compact summaries.
+    pub fn busiest(&self) -> Option<&GpuDevice> {
+        self.devices.iter().max_by(|a, b| {
+            a.utilization
+                .partial_cmp(&b.utilization)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+    }
+
+    /// Device with the most dedicated memory, i.e. the discrete card on
+    /// iGPU + dGPU machines.
+    pub fn primary(&self) -> Option<&GpuDevice> {
+        self.devices.iter().max_by_key(|d| d.memory_total)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GpuDevice {
     pub name: String,
     pub gpu_index: u32,
     pub utilization: f32,
@@ -19,9 +50,40 @@ pub struct GpuData {
     pub driver_version: String,
     pub bus_id: String,
     pub cuda_version: String,
-    pub processes: Vec<GpuProcessInfo>,
+    /// Why clocks are held down right now, e.g. "power cap" or "HW thermal".
+    /// Only NVML reports this; empty means not throttled or not known.
+    #[serde(default)]
+    pub throttle_reasons: Vec<String>,
+    /// Fans on the card; `Some(0)` is a passively cooled card, as opposed to
+    /// a fan spinning at 0%. `None` when the source doesn't say.
+    #[serde(default)]
+    pub fan_count: Option<u32>,
+    /// VRAM on the card. Same as `memory_*` except that Windows perf
+    /// counters report it next to `shared_*`.
+    #[serde(default)]
+    pub dedicated_used: u64,
+    #[serde(default)]
+    pub dedicated_total: u64,
+    /// System RAM the GPU may borrow, which is all an iGPU has. Only the
+    /// Windows perf counters report it; zero elsewhere, including NVML.
+    #[serde(default)]
+    pub shared_used: u64,
+    #[serde(default)]
+    pub shared_total: u64,
 }
 
+impl GpuDevice {
+    /// Hardware-triggered slowdowns cost far more than hitting the power
+    /// limit, so the GPU tab shows them in red rather than yellow.
+    pub fn throttle_is_severe(&self) -> bool {
+        self.throttle_reasons
+            .iter()
+            .any(|reason| SEVERE_THROTTLE_REASONS.contains(&reason.as_str()))
+    }
+}
+
+const SEVERE_THROTTLE_REASONS: &[&str] = &["HW slowdown", "HW thermal", "power brake"];
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GpuProcessInfo {
     pub pid: u32,
@@ -33,44 +95,187 @@ pub struct GpuProcessInfo {
 
 pub struct GpuMonitor {
     ps: PowerShellExecutor,
+    /// Initialized once and reused by every collection; `None` when NVML is
+    /// disabled, unavailable, or monitoring a remote host.
+    #[cfg(feature = "nvidia")]
+    nvml: parking_lot::Mutex<Option<Nvml>>,
+    /// Source of the last successful reading, logged when it changes
+    last_source: parking_lot::Mutex<Option<GpuSource>>,
 }
 
-impl GpuMonitor {
-    pub fn new(ps: PowerShellExecutor) -> Result<Self> {
-        Ok(Self { ps })
-    }
+/// Where GPU readings come from, tried in `monitors.gpu.source_priority`
+/// order. Sources that don't apply to the platform are skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GpuSource {
+    Nvml,
+    NvidiaSmi,
+    /// WMI and performance counters
+    Windows,
+    Rocm,
+    Drm,
+}
 
-    pub async fn collect_data(&self) -> Result<GpuData> {
-        #[cfg(target_os = "linux")]
-        {
-            return self.collect_data_linux().await;
+impl GpuSource {
+    pub const DEFAULT: [GpuSource; 5] = [
+        Self::Rocm,
+        Self::Nvml,
+        Self::NvidiaSmi,
+        Self::Windows,
+        Self::Drm,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "nvml" => Some(Self::Nvml),
+            "nvidia_smi" | "nvidia-smi" => Some(Self::NvidiaSmi),
+            "windows" | "wmi" => Some(Self::Windows),
+            "rocm" | "rocm_smi" | "rocm-smi" => Some(Self::Rocm),
+            "drm" | "sysfs" => Some(Self::Drm),
+            _ => None,
         }
+    }
 
-        #[cfg(not(target_os = "linux"))]
-        {
-            return self.collect_data_windows().await;
+    pub fn config_name(self) -> &'static str {
+        match self {
+            Self::Nvml => "nvml",
+            Self::NvidiaSmi => "nvidia_smi",
+            Self::Windows => "windows",
+            Self::Rocm => "rocm",
+            Self::Drm => "drm",
         }
     }
+}
 
-    #[allow(dead_code)]
-    async fn collect_data_linux(&self) -> Result<GpuData> {
-        // Try nvidia-smi directly (for NVIDIA GPUs)
-        if let Ok(nvidia_data) = self.get_nvidia_smi_linux().await {
-            return Ok(nvidia_data);
+impl GpuMonitor {
+    pub fn new(ps: PowerShellExecutor, use_nvml: bool) -> Result<Self> {
+        #[cfg(feature = "nvidia")]
+        let nvml = if use_nvml && ps.remote().is_none() {
+            init_nvml()
+        } else {
+            None
+        };
+        #[cfg(not(feature = "nvidia"))]
+        let _ = use_nvml;
+
+        Ok(Self {
+            ps,
+            #[cfg(feature = "nvidia")]
+            nvml: parking_lot::Mutex::new(nvml),
+            last_source: parking_lot::Mutex::new(None),
+        })
+    }
+
+    /// Readings from the first of `sources` that works here. Linux shows a
+    /// placeholder device when none do, rather than an error.
+    pub async fn collect_data(&self, sources: &[GpuSource]) -> Result<GpuData> {
+        let mut last_error = None;
+        for &source in sources {
+            match self.collect_from(source).await {
+                Ok(data) => {
+                    let mut last_source = self.last_source.lock();
+                    if *last_source != Some(source) {
+                        log::info!("GPU data source: {}", source.config_name());
+                        *last_source = Some(source);
+                    }
+                    return Ok(data);
+                }
+                Err(e) => {
+                    log::debug!("GPU source {} failed: {:#}", source.config_name(), e);
+                    last_error = Some(e);
+                }
+            }
         }
 
-        // Fallback to stub data if no GPU found
-        Ok(self.get_stub_gpu_data())
+        if cfg!(target_os = "linux") && self.ps.remote().is_none() {
+            return Ok(self.get_stub_gpu_data());
+        }
+        Err(last_error.unwrap_or_else(|| anyhow::anyhow!("No GPU data source configured")))
     }
 
-    async fn collect_data_windows(&self) -> Result<GpuData> {
-        // Try nvidia-smi first (for NVIDIA GPUs)
-        if let Ok(nvidia_data) = self.get_nvidia_smi_data().await {
-            return Ok(nvidia_data);
+    /// Readings from one source; fails when it is unsupported on this
+    /// platform or build. Only nvidia-smi is queried over SSH.
+    async fn collect_from(&self, source: GpuSource) -> Result<GpuData> {
+        let local = self.ps.remote().is_none();
+        match source {
+            GpuSource::Nvml => {
+                #[cfg(feature = "nvidia")]
+                {
+                    let devices = self.get_nvml_devices()?;
+                    let processes = if cfg!(target_os = "linux") {
+                        self.get_gpu_processes_linux().await
+                    } else {
+                        self.get_gpu_processes().await
+                    };
+                    Ok(GpuData {
+                        devices,
+                        processes: processes.unwrap_or_default(),
+                    })
+                }
+                #[cfg(not(feature = "nvidia"))]
+                anyhow::bail!("built without NVML support")
+            }
+            GpuSource::NvidiaSmi => {
+                if cfg!(target_os = "linux") {
+                    self.get_nvidia_smi_linux().await
+                } else {
+                    self.get_nvidia_smi_data().await
+                }
+            }
+            GpuSource::Windows => {
+                if cfg!(target_os = "linux") {
+                    anyhow::bail!("Windows counters are not available on Linux");
+                }
+                self.get_wmi_gpu_data().await
+            }
+            GpuSource::Rocm => {
+                // AMD cards (PCI vendor 0x1002) only
+                #[cfg(feature = "amd")]
+                if cfg!(target_os = "linux") && local && linux_has_amd_gpu() {
+                    return self.get_rocm_smi_linux().await;
+                }
+                anyhow::bail!("rocm-smi is not available")
+            }
+            GpuSource::Drm => {
+                // Whatever the kernel driver exposes under /sys/class/drm
+                #[cfg(target_os = "linux")]
+                if local {
+                    if let Some(drm_data) = get_drm_gpu_data_linux() {
+                        return Ok(drm_data);
+                    }
+                }
+                let _ = local;
+                anyhow::bail!("no GPU found under /sys/class/drm")
+            }
         }
+    }
+
+    /// Read every NVIDIA device through the shared NVML handle. A handle the
+    /// driver no longer recognizes is re-initialized once before giving up.
+    #[cfg(feature = "nvidia")]
+    fn get_nvml_devices(&self) -> Result<Vec<GpuDevice>> {
+        let mut nvml = self.nvml.lock();
+        let Some(handle) = nvml.as_ref() else {
+            anyhow::bail!("NVML is not initialized");
+        };
+
+        let devices = match read_nvml_devices(handle) {
+            Err(NvmlError::Uninitialized | NvmlError::DriverNotLoaded) => {
+                log::info!("NVML handle went stale, re-initializing");
+                // Shut the old handle down before opening a new one
+                *nvml = None;
+                *nvml = init_nvml();
+                match nvml.as_ref() {
+                    Some(handle) => read_nvml_devices(handle)?,
+                    None => anyhow::bail!("NVML re-initialization failed"),
+                }
+            }
+            result => result?,
+        };
 
-        // Fallback to WMI/perf counters
-        self.get_wmi_gpu_data().await
+        if devices.is_empty() {
+            anyhow::bail!("NVML reported no GPUs");
+        }
+        Ok(devices)
     }
 
     async fn get_nvidia_smi_data(&self) -> Result<GpuData> {
@@ -112,7 +317,6 @@ impl GpuMonitor {
             $cudaVersion = "N/A"
             $fallbackPowerDraw = 0.0
             $fallbackPowerLimit = 0.0
-            $gpuIndex = 0
 
             if ($standardOutput) {
                 # Extract CUDA version
@@ -127,15 +331,9 @@ impl GpuMonitor {
                     $fallbackPowerDraw = [float]$Matches[1]
                     $fallbackPowerLimit = [float]$Matches[2]
                 }
-
-                # Extract GPU index from Bus-Id line
-                $busLine = $standardOutput | Where-Object { $_ -match 'Bus-Id' } | Select-Object -First 1
-                if ($busLine -match '\|\s*(\d+)\s+') {
-                    $gpuIndex = [int]$Matches[1]
-                }
             }
 
-            $raw = & $nvidiaPath --query-gpu=name,pci.bus_id,temperature.gpu,utilization.gpu,utilization.memory,memory.used,memory.total,power.draw,power.limit,fan.speed,clocks.current.graphics,clocks.current.memory,driver_version --format=csv,noheader,nounits
+            $raw = & $nvidiaPath --query-gpu=name,pci.bus_id,temperature.gpu,utilization.gpu,utilization.memory,memory.used,memory.total,power.draw,power.limit,fan.speed,clocks.current.graphics,clocks.current.memory,driver_version,index --format=csv,noheader,nounits
             $lines = $raw -split "`n" | Where-Object { $_ -match '\S' }
             if (-not $lines) {
                 throw "nvidia-smi returned empty output"
@@ -143,30 +341,26 @@ impl GpuMonitor {
 
             $rows = foreach ($line in $lines) {
                 $parts = $line.Split(',') | ForEach-Object { $_.Trim() }
-                if ($parts.Count -lt 13) { continue }
+                if ($parts.Count -lt 14) { continue }
 
                 $powerDraw = Parse-Float $parts[7] 0.0
                 $powerLimit = Parse-Float $parts[8] 0.0
 
-                # Use fallback power values if query returned 0
-                if ($powerDraw -eq 0.0 -and $fallbackPowerDraw -gt 0.0) {
-                    $powerDraw = $fallbackPowerDraw
-                }
-                if ($powerLimit -eq 0.0 -and $fallbackPowerLimit -gt 0.0) {
-                    $powerLimit = $fallbackPowerLimit
-                }
-
-                # Parse GPU index from bus_id (format: 00000000:01:00.0)
-                $busId = $parts[1]
-                $busIdIndex = 0
-                if ($busId -match ':(\d+):') {
-                    $busIdIndex = [int]$Matches[1]
+                # The summary table only reliably shows the first card, so the
+                # fallback is limited to single-GPU systems
+                if ($lines.Count -eq 1) {
+                    if ($powerDraw -eq 0.0 -and $fallbackPowerDraw -gt 0.0) {
+                        $powerDraw = $fallbackPowerDraw
+                    }
+                    if ($powerLimit -eq 0.0 -and $fallbackPowerLimit -gt 0.0) {
+                        $powerLimit = $fallbackPowerLimit
+                    }
                 }
 
                 [PSCustomObject]@{
                     Name = $parts[0]
                     BusId = $parts[1]
-                    GpuIndex = $busIdIndex
+                    GpuIndex = [uint32](Parse-UInt64 $parts[13] 0)
                     Temperature = Parse-Float $parts[2] 0.0
                     UtilizationGpu = Parse-Float $parts[3] 0.0
                     UtilizationMemory = Parse-Float $parts[4] 0.0
@@ -182,117 +376,266 @@ impl GpuMonitor {
                 }
             }
 
-            $best = $rows | Sort-Object -Property MemoryTotal -Descending | Select-Object -First 1
-            if (-not $best) {
+            $rows = @($rows | Sort-Object -Property GpuIndex)
+            if ($rows.Count -eq 0) {
                 throw "nvidia-smi parsing failed"
             }
 
-            $best | ConvertTo-Json
+            ConvertTo-Json -InputObject $rows
         "#;
 
         let output = self.ps.execute(script).await?;
-        let trimmed = output.trim_start_matches('\u{feff}').trim();
-        let info: NvidiaSmiData = serde_json::from_str(trimmed)
-            .context("Failed to parse nvidia-smi data")?;
+        let samples: Vec<NvidiaSmiData> =
+            parse_json_array(&output).context("Failed to parse nvidia-smi data")?;
+        if samples.is_empty() {
+            anyhow::bail!("nvidia-smi returned no GPUs");
+        }
 
-        let processes = self.get_gpu_processes().await.unwrap_or_default();
+        let devices = samples
+            .into_iter()
+            .map(|info| {
+                let memory_total = info.MemoryTotal;
+                let memory_used = if memory_total > 0 {
+                    info.MemoryUsed.min(memory_total)
+                } else {
+                    info.MemoryUsed
+                };
+
+                GpuDevice {
+                    name: info.Name,
+                    gpu_index: info.GpuIndex,
+                    utilization: info.UtilizationGpu.clamp(0.0, 100.0),
+                    memory_used,
+                    memory_total,
+                    temperature: info.Temperature,
+                    power_usage: info.PowerDraw,
+                    power_limit: info.PowerLimit,
+                    fan_speed: info.FanSpeed,
+                    clock_speed: info.ClockGraphics,
+                    memory_clock: info.ClockMemory,
+                    driver_version: info.DriverVersion,
+                    bus_id: info.BusId,
+                    cuda_version: info.CudaVersion,
+                    throttle_reasons: Vec::new(),
+                    fan_count: None,
+                    dedicated_used: memory_used,
+                    dedicated_total: memory_total,
+                    shared_used: 0,
+                    shared_total: 0,
+                }
+            })
+            .collect();
 
-        let memory_total = info.MemoryTotal;
-        let memory_used = info.MemoryUsed;
-        let memory_used = if memory_total > 0 {
-            memory_used.min(memory_total)
-        } else {
-            memory_used
-        };
+        let processes = self.get_gpu_processes().await.unwrap_or_default();
 
-        Ok(GpuData {
-            name: info.Name,
-            gpu_index: info.GpuIndex,
-            utilization: info.UtilizationGpu.clamp(0.0, 100.0),
-            memory_used,
-            memory_total,
-            temperature: info.Temperature,
-            power_usage: info.PowerDraw,
-            power_limit: info.PowerLimit,
-            fan_speed: info.FanSpeed,
-            clock_speed: info.ClockGraphics,
-            memory_clock: info.ClockMemory,
-            driver_version: info.DriverVersion,
-            bus_id: info.BusId,
-            cuda_version: info.CudaVersion,
-            processes,
-        })
+        Ok(GpuData { devices, processes })
     }
 
     async fn get_wmi_gpu_data(&self) -> Result<GpuData> {
         let script = r#"
-            $gpus = Get-CimInstance Win32_VideoController -ErrorAction SilentlyContinue
-            $gpu = $gpus | Sort-Object AdapterRAM -Descending | Select-Object -First 1
-            if (-not $gpu) {
+            $gpus = @(Get-CimInstance Win32_VideoController -ErrorAction SilentlyContinue |
+                Sort-Object AdapterRAM -Descending)
+            if ($gpus.Count -eq 0) {
                 throw "No GPU detected"
             }
 
-            $engine = Get-CimInstance Win32_PerfFormattedData_GPUPerformanceCounters_GPUEngine -ErrorAction SilentlyContinue
-            $util = if ($engine) {
-                ($engine | Measure-Object -Property UtilizationPercentage -Maximum).Maximum
-            } else {
-                0
+            # Perf counters are keyed by adapter LUID, which WMI doesn't expose on
+            # Win32_VideoController. Both lists are ordered by dedicated memory and
+            # paired by position, which holds for the usual iGPU + dGPU layout.
+            $limits = @{}
+            $limitSamples = (Get-Counter -Counter '\GPU Adapter Memory(*)\Dedicated Limit', '\GPU Adapter Memory(*)\Shared Limit' -ErrorAction SilentlyContinue).CounterSamples
+            foreach ($sample in $limitSamples) {
+                if ($sample.InstanceName -match '^(luid_0x[0-9a-fA-F]+_0x[0-9a-fA-F]+)') {
+                    $kind = if ($sample.Path -like '*shared limit') { 'Shared' } else { 'Dedicated' }
+                    $limits["$($Matches[1])/$kind"] = [uint64]$sample.CookedValue
+                }
             }
 
-            $procMem = Get-CimInstance Win32_PerfFormattedData_GPUPerformanceCounters_GPUProcessMemory -ErrorAction SilentlyContinue
-            $memUsed = if ($procMem) {
-                ($procMem | Measure-Object -Property DedicatedUsage -Sum).Sum
-            } else {
-                0
+            $adapters = @{}
+            $adapterMem = Get-CimInstance Win32_PerfFormattedData_GPUPerformanceCounters_GPUAdapterMemory -ErrorAction SilentlyContinue
+            foreach ($item in $adapterMem) {
+                if ($item.Name -match '^(luid_0x[0-9a-fA-F]+_0x[0-9a-fA-F]+)') {
+                    $luid = $Matches[1]
+                    $total = $limits["$luid/Dedicated"]
+                    if (-not $total) { $total = [uint64]$item.TotalDedicatedMemory }
+                    $adapters[$luid] = [PSCustomObject]@{
+                        Total = [uint64]$total
+                        Used = [uint64]$item.DedicatedUsage
+                        SharedTotal = [uint64]$limits["$luid/Shared"]
+                        SharedUsed = [uint64]$item.SharedUsage
+                        Util = 0.0
+                    }
+                }
             }
 
-            $adapterMem = Get-CimInstance Win32_PerfFormattedData_GPUPerformanceCounters_GPUAdapterMemory -ErrorAction SilentlyContinue |
-                Sort-Object TotalDedicatedMemory -Descending | Select-Object -First 1
-            $memTotal = if ($adapterMem -and $adapterMem.TotalDedicatedMemory) {
-                [uint64]$adapterMem.TotalDedicatedMemory
-            } else {
-                [uint64]$gpu.AdapterRAM
+            $engine = Get-CimInstance Win32_PerfFormattedData_GPUPerformanceCounters_GPUEngine -ErrorAction SilentlyContinue
+            foreach ($item in $engine) {
+                if ($item.Name -match '(luid_0x[0-9a-fA-F]+_0x[0-9a-fA-F]+)') {
+                    $luid = $Matches[1]
+                    if ($adapters.ContainsKey($luid)) {
+                        $util = [float]$item.UtilizationPercentage
+                        if ($util -gt $adapters[$luid].Util) { $adapters[$luid].Util = $util }
+                    }
+                }
             }
 
-            [PSCustomObject]@{
-                Name = $gpu.Name
-                DriverVersion = $gpu.DriverVersion
-                MemoryTotal = $memTotal
-                MemoryUsed = [uint64]$memUsed
-                Utilization = [float]$util
-            } | ConvertTo-Json
+            $stats = @($adapters.Values | Sort-Object Total -Descending)
+
+            $result = for ($i = 0; $i -lt $gpus.Count; $i++) {
+                $gpu = $gpus[$i]
+                $stat = if ($i -lt $stats.Count) { $stats[$i] } else { $null }
+                $memTotal = if ($stat -and $stat.Total) { $stat.Total } else { [uint64]$gpu.AdapterRAM }
+                [PSCustomObject]@{
+                    Index = [uint32]$i
+                    Name = $gpu.Name
+                    Vendor = $gpu.AdapterCompatibility
+                    DriverVersion = $gpu.DriverVersion
+                    PnpDeviceId = $gpu.PNPDeviceID
+                    MemoryTotal = [uint64]$memTotal
+                    MemoryUsed = if ($stat) { $stat.Used } else { [uint64]0 }
+                    SharedUsed = if ($stat) { $stat.SharedUsed } else { [uint64]0 }
+                    SharedTotal = if ($stat) { $stat.SharedTotal } else { [uint64]0 }
+                    Utilization = if ($stat) { [float]$stat.Util } else { [float]0 }
+                }
+            }
+
+            ConvertTo-Json -InputObject @($result)
         "#;
 
         let output = self.ps.execute(script).await?;
-        let trimmed = output.trim_start_matches('\u{feff}').trim();
-        let info: GpuInfo = serde_json::from_str(trimmed)
-            .context("Failed to parse GPU info")?;
+        let samples: Vec<GpuInfo> =
+            parse_json_array(&output).context("Failed to parse GPU info")?;
+        if samples.is_empty() {
+            anyhow::bail!("No GPU detected");
+        }
 
-        let processes = self.get_gpu_processes().await.unwrap_or_default();
+        let amd_indices: Vec<u32> = samples
+            .iter()
+            .filter(|info| is_amd_gpu(info.Vendor.as_deref().unwrap_or(""), &info.Name))
+            .map(|info| info.Index)
+            .collect();
+
+        let mut devices: Vec<GpuDevice> = samples
+            .into_iter()
+            .map(|info| {
+                let utilization = info.Utilization.unwrap_or(0.0).clamp(0.0, 100.0);
+                let memory_total = info.MemoryTotal.unwrap_or(0);
+                let mut memory_used = info.MemoryUsed.unwrap_or(0);
+                if memory_total > 0 {
+                    memory_used = memory_used.min(memory_total);
+                }
 
-        let utilization = info.Utilization.unwrap_or(0.0).clamp(0.0, 100.0);
-        let memory_total = info.MemoryTotal.unwrap_or(0);
-        let mut memory_used = info.MemoryUsed.unwrap_or(0);
-        if memory_total > 0 {
-            memory_used = memory_used.min(memory_total);
+                GpuDevice {
+                    name: info.Name,
+                    gpu_index: info.Index,
+                    utilization,
+                    memory_used,
+                    memory_total,
+                    temperature: 0.0,
+                    power_usage: 0.0,
+                    power_limit: 0.0,
+                    fan_speed: -1.0,
+                    clock_speed: 0,
+                    memory_clock: 0,
+                    driver_version: info.DriverVersion,
+                    bus_id: info
+                        .PnpDeviceId
+                        .filter(|id| !id.trim().is_empty())
+                        .unwrap_or_else(|| "N/A".to_string()),
+                    cuda_version: "N/A".to_string(),
+                    throttle_reasons: Vec::new(),
+                    fan_count: None,
+                    dedicated_used: memory_used,
+                    dedicated_total: memory_total,
+                    shared_used: info.SharedUsed.unwrap_or(0),
+                    shared_total: info.SharedTotal.unwrap_or(0),
+                }
+            })
+            .collect();
+
+        // Perf counters carry no temperature/power/clocks; AMD sensors fill them in
+        #[cfg(feature = "amd")]
+        if !amd_indices.is_empty() {
+            match self.get_amd_sensors_windows().await {
+                Ok(sensors) => {
+                    // Sensor providers number AMD cards in adapter order
+                    for (index, sensor) in amd_indices.iter().zip(sensors) {
+                        if let Some(device) = devices.iter_mut().find(|d| d.gpu_index == *index) {
+                            sensor.apply(device);
+                        }
+                    }
+                }
+                Err(e) => log::debug!("AMD GPU sensors unavailable: {e:#}"),
+            }
         }
+        #[cfg(not(feature = "amd"))]
+        let _ = amd_indices;
+
+        let processes = self.get_gpu_processes().await.unwrap_or_default();
+
+        Ok(GpuData { devices, processes })
+    }
+
+    /// AMD temperature, power, clocks and fan from the LibreHardwareMonitor (or
+    /// OpenHardwareMonitor) WMI provider, which reads them through AMD's driver
+    /// interfaces. Returns one entry per AMD card in adapter order.
+    #[cfg(feature = "amd")]
+    #[allow(dead_code)]
+    async fn get_amd_sensors_windows(&self) -> Result<Vec<AmdSensors>> {
+        let script = r#"
+            $sensors = $null
+            foreach ($ns in @("root/LibreHardwareMonitor", "root/OpenHardwareMonitor")) {
+                $sensors = Get-CimInstance -Namespace $ns -ClassName Sensor -ErrorAction SilentlyContinue |
+                    Where-Object { $_.Identifier -like "/gpu-amd/*" }
+                if ($sensors) { break }
+            }
+            if (-not $sensors) {
+                throw "No AMD GPU sensor provider (LibreHardwareMonitor) is running"
+            }
+
+            $result = @($sensors | ForEach-Object {
+                [PSCustomObject]@{
+                    Card = [uint32]($_.Identifier -split '/')[2]
+                    Type = $_.SensorType
+                    Name = $_.Name
+                    Value = [double]$_.Value
+                }
+            })
+            ConvertTo-Json -InputObject $result
+        "#;
+
+        let output = self.ps.execute(script).await?;
+        let readings: Vec<AmdSensorSample> =
+            parse_json_array(&output).context("Failed to parse AMD sensor data")?;
+        Ok(AmdSensors::from_readings(&readings))
+    }
+
+    /// Parses `rocm-smi --json`; values arrive as strings like `"45.0"` or `"(1800Mhz)"`.
+    #[cfg(feature = "amd")]
+    #[allow(dead_code)]
+    async fn get_rocm_smi_linux(&self) -> Result<GpuData> {
+        let output = tokio::process::Command::new("rocm-smi")
+            .args([
+                "--showtemp",
+                "--showuse",
+                "--showmeminfo",
+                "vram",
+                "--showpower",
+                "--showmaxpower",
+                "--showclocks",
+                "--showfan",
+                "--showproductname",
+                "--showdriverversion",
+                "--json",
+            ])
+            .output()
+            .await
+            .context("Failed to run rocm-smi")?;
 
+        let devices = parse_rocm_smi(&String::from_utf8_lossy(&output.stdout))?;
         Ok(GpuData {
-            name: info.Name,
-            gpu_index: 0,
-            utilization,
-            memory_used,
-            memory_total,
-            temperature: 0.0,
-            power_usage: 0.0,
-            power_limit: 0.0,
-            fan_speed: -1.0,
-            clock_speed: 0,
-            memory_clock: 0,
-            driver_version: info.DriverVersion,
-            bus_id: "N/A".to_string(),
-            cuda_version: "N/A".to_string(),
-            processes,
+            devices,
+            processes: Vec::new(),
         })
     }
 
@@ -315,14 +658,29 @@ impl GpuMonitor {
             }
 
             if ($nvidiaPath) {
+                # nvidia-smi has no per-process load; sum the PID's 3D, compute
+                # and copy engines from the same counters Task Manager reads
+                $gpuByPid = @{}
+                $counters = Get-Counter '\GPU Engine(pid_*)\Utilization Percentage' -ErrorAction SilentlyContinue
+                if ($counters) {
+                    foreach ($sample in $counters.CounterSamples) {
+                        if ($sample.InstanceName -match '^pid_(\d+)_.*engtype_(3D|Graphics|Compute|Copy)') {
+                            $procId = [int]$matches[1]
+                            if (-not $gpuByPid.ContainsKey($procId)) { $gpuByPid[$procId] = 0.0 }
+                            $gpuByPid[$procId] += [float]$sample.CookedValue
+                        }
+                    }
+                }
+
                 & $nvidiaPath --query-compute-apps=pid,process_name,used_memory --format=csv,noheader,nounits | ForEach-Object {
                     $parts = $_.Split(',') | ForEach-Object { $_.Trim() }
                     if ($parts.Count -lt 3) { return }
+                    $procId = [int]$parts[0]
                     [PSCustomObject]@{
-                        Pid = [uint32]$parts[0]
+                        Pid = [uint32]$procId
                         Name = $parts[1]
                         Vram = [uint64]($parts[2]) * 1MB
-                        GpuUsage = -1.0
+                        GpuUsage = if ($gpuByPid.ContainsKey($procId)) { [float]$gpuByPid[$procId] } else { -1.0 }
                         Type = "Compute"
                     }
                 } | ConvertTo-Json
@@ -343,7 +701,7 @@ impl GpuMonitor {
             .map(|p| GpuProcessInfo {
                 pid: p.Pid,
                 name: p.Name,
-                gpu_usage: if p.GpuUsage < 0.0 { -1.0 } else { p.GpuUsage },
+                gpu_usage: if p.GpuUsage < 0.0 { -1.0 } else { p.GpuUsage.min(100.0) },
                 vram: p.Vram,
                 process_type: if p.Type.trim().is_empty() {
                     "Compute".to_string()
@@ -365,11 +723,11 @@ impl GpuMonitor {
             $byPid = @{}
             foreach ($item in $items) {
                 if ($item.Name -match '^pid_(\d+)_') {
-                    $pid = [int]$matches[1]
-                    if (-not $byPid.ContainsKey($pid)) {
-                        $byPid[$pid] = [uint64]0
+                    $procId = [int]$matches[1]
+                    if (-not $byPid.ContainsKey($procId)) {
+                        $byPid[$procId] = [uint64]0
                     }
-                    $byPid[$pid] += [uint64]$item.DedicatedUsage
+                    $byPid[$procId] += [uint64]$item.DedicatedUsage
                 }
             }
 
@@ -380,10 +738,14 @@ impl GpuMonitor {
             if ($engine) {
                 foreach ($item in $engine) {
                     if ($item.Name -match '^pid_(\d+)_') {
-                        $pid = [int]$matches[1]
+                        $procId = [int]$matches[1]
                         $util = [float]$item.UtilizationPercentage
-                        if (-not $gpuByPid.ContainsKey($pid)) { $gpuByPid[$pid] = 0.0 }
-                        $gpuByPid[$pid] += $util
+                        if (-not $gpuByPid.ContainsKey($procId)) { $gpuByPid[$procId] = 0.0 }
+                        # Video encode/decode run beside the shader engines, so only
+                        # 3D, compute and copy count towards the process's GPU%
+                        if ($item.Name -match 'engtype_(3D|Graphics|Compute|Copy)') {
+                            $gpuByPid[$procId] += $util
+                        }
 
                         $etype = "Unknown"
                         if ($item.Name -match 'engtype_3D' -or $item.Name -match 'engtype_Graphics') {
@@ -393,9 +755,9 @@ impl GpuMonitor {
                         } elseif ($item.Name -match 'engtype_Copy') {
                             $etype = "Copy"
                         }
-                        if (-not $typeUtilByPid.ContainsKey($pid) -or $util -gt $typeUtilByPid[$pid]) {
-                            $typeUtilByPid[$pid] = $util
-                            $typeByPid[$pid] = $etype
+                        if (-not $typeUtilByPid.ContainsKey($procId) -or $util -gt $typeUtilByPid[$procId]) {
+                            $typeUtilByPid[$procId] = $util
+                            $typeByPid[$procId] = $etype
                         }
                     }
                 }
@@ -414,13 +776,13 @@ impl GpuMonitor {
                 }
             } catch {}
 
-            $result = foreach ($pid in $allPids) {
-                $vram = if ($byPid.ContainsKey($pid)) { [uint64]$byPid[$pid] } else { [uint64]0 }
-                $gpu = if ($gpuByPid.ContainsKey($pid)) { [float]$gpuByPid[$pid] } else { -1.0 }
-                $ptype = if ($typeByPid.ContainsKey($pid)) { $typeByPid[$pid] } else { "Unknown" }
+            $result = foreach ($procId in $allPids) {
+                $vram = if ($byPid.ContainsKey($procId)) { [uint64]$byPid[$procId] } else { [uint64]0 }
+                $gpu = if ($gpuByPid.ContainsKey($procId)) { [float]$gpuByPid[$procId] } else { -1.0 }
+                $ptype = if ($typeByPid.ContainsKey($procId)) { $typeByPid[$procId] } else { "Unknown" }
                 [PSCustomObject]@{
-                    Pid = [uint32]$pid
-                    Name = if ($procMap.ContainsKey($pid)) { $procMap[$pid] } else { "PID $pid" }
+                    Pid = [uint32]$procId
+                    Name = if ($procMap.ContainsKey($procId)) { $procMap[$procId] } else { "PID $procId" }
                     Vram = $vram
                     GpuUsage = $gpu
                     Type = $ptype
@@ -442,7 +804,7 @@ impl GpuMonitor {
             .map(|p| GpuProcessInfo {
                 pid: p.Pid,
                 name: p.Name,
-                gpu_usage: if p.GpuUsage < 0.0 { -1.0 } else { p.GpuUsage },
+                gpu_usage: if p.GpuUsage < 0.0 { -1.0 } else { p.GpuUsage.min(100.0) },
                 vram: p.Vram,
                 process_type: if p.Type.trim().is_empty() {
                     "Unknown".to_string()
@@ -453,76 +815,90 @@ impl GpuMonitor {
             .collect())
     }
 
+    /// stdout of `nvidia-smi args`, run on the remote host when one is set.
+    #[allow(dead_code)]
+    fn nvidia_smi_linux(&self, args: &[&str]) -> Result<String> {
+        if let Some(target) = self.ps.remote() {
+            return target.output("nvidia-smi", args);
+        }
+        let output = std::process::Command::new("nvidia-smi").args(args).output()?;
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
     // Linux-specific nvidia-smi implementation
     #[allow(dead_code)]
     async fn get_nvidia_smi_linux(&self) -> Result<GpuData> {
-        use std::process::Command;
+        let stdout = self.nvidia_smi_linux(&[
+            "--query-gpu=name,temperature.gpu,utilization.gpu,utilization.memory,memory.used,memory.total,power.draw,power.limit,fan.speed,clocks.current.graphics,clocks.current.memory,driver_version,index,pci.bus_id",
+            "--format=csv,noheader,nounits"
+        ])?;
+        let mut devices = Vec::new();
 
-        let output = Command::new("nvidia-smi")
-            .args(&[
-                "--query-gpu=name,temperature.gpu,utilization.gpu,utilization.memory,memory.used,memory.total,power.draw,power.limit,fan.speed,clocks.current.graphics,clocks.current.memory,driver_version",
-                "--format=csv,noheader,nounits"
-            ])
-            .output()?;
+        for line in stdout.lines().filter(|l| !l.trim().is_empty()) {
+            let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+            if parts.len() < 14 {
+                continue;
+            }
 
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let parts: Vec<&str> = stdout.trim().split(',').map(|s| s.trim()).collect();
+            let name = parts[0].to_string();
+            let temperature = parts[1].parse::<f32>().unwrap_or(0.0);
+            let utilization_gpu = parts[2].parse::<f32>().unwrap_or(0.0);
+            let _utilization_memory = parts[3].parse::<f32>().unwrap_or(0.0);
+            let memory_used = parts[4].parse::<u64>().unwrap_or(0) * 1024 * 1024; // MB to bytes
+            let memory_total = parts[5].parse::<u64>().unwrap_or(0) * 1024 * 1024; // MB to bytes
+            let power_draw = parts[6].parse::<f32>().unwrap_or(0.0);
+            let power_limit = parts[7].parse::<f32>().unwrap_or(300.0);
+            let fan_speed = if parts[8] == "[N/A]" || parts[8] == "N/A" {
+                -1.0
+            } else {
+                parts[8].parse::<f32>().unwrap_or(-1.0)
+            };
+            let clock_graphics = parts[9].parse::<u32>().unwrap_or(0);
+            let clock_memory = parts[10].parse::<u32>().unwrap_or(0);
+            let driver_version = parts[11].to_string();
+            let gpu_index = parts[12].parse::<u32>().unwrap_or(devices.len() as u32);
+            let bus_id = parts[13].to_string();
+
+            devices.push(GpuDevice {
+                name,
+                gpu_index,
+                utilization: utilization_gpu,
+                memory_used,
+                memory_total,
+                temperature,
+                power_usage: power_draw,
+                power_limit,
+                fan_speed,
+                clock_speed: clock_graphics,
+                memory_clock: clock_memory,
+                driver_version,
+                bus_id,
+                cuda_version: "N/A".to_string(),
+                throttle_reasons: Vec::new(),
+                fan_count: None,
+                dedicated_used: memory_used,
+                dedicated_total: memory_total,
+                shared_used: 0,
+                shared_total: 0,
+            });
+        }
 
-        if parts.len() < 12 {
+        if devices.is_empty() {
             anyhow::bail!("Invalid nvidia-smi output");
         }
 
-        let name = parts[0].to_string();
-        let temperature = parts[1].parse::<f32>().unwrap_or(0.0);
-        let utilization_gpu = parts[2].parse::<f32>().unwrap_or(0.0);
-        let _utilization_memory = parts[3].parse::<f32>().unwrap_or(0.0);
-        let memory_used = parts[4].parse::<u64>().unwrap_or(0) * 1024 * 1024; // MB to bytes
-        let memory_total = parts[5].parse::<u64>().unwrap_or(0) * 1024 * 1024; // MB to bytes
-        let power_draw = parts[6].parse::<f32>().unwrap_or(0.0);
-        let power_limit = parts[7].parse::<f32>().unwrap_or(300.0);
-        let fan_speed = if parts[8] == "[N/A]" || parts[8] == "N/A" {
-            -1.0
-        } else {
-            parts[8].parse::<f32>().unwrap_or(-1.0)
-        };
-        let clock_graphics = parts[9].parse::<u32>().unwrap_or(0);
-        let clock_memory = parts[10].parse::<u32>().unwrap_or(0);
-        let driver_version = parts[11].to_string();
-
         // Get GPU processes
         let processes = self.get_gpu_processes_linux().await.unwrap_or_default();
 
-        Ok(GpuData {
-            name,
-            gpu_index: 0,
-            utilization: utilization_gpu,
-            memory_used,
-            memory_total,
-            temperature,
-            power_usage: power_draw,
-            power_limit,
-            fan_speed,
-            clock_speed: clock_graphics,
-            memory_clock: clock_memory,
-            driver_version,
-            bus_id: "N/A".to_string(),
-            cuda_version: "N/A".to_string(),
-            processes,
-        })
+        Ok(GpuData { devices, processes })
     }
 
     #[allow(dead_code)]
     async fn get_gpu_processes_linux(&self) -> Result<Vec<GpuProcessInfo>> {
-        use std::process::Command;
-
-        let output = Command::new("nvidia-smi")
-            .args(&[
-                "--query-compute-apps=pid,process_name,used_memory",
-                "--format=csv,noheader,nounits"
-            ])
-            .output()?;
-
-        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stdout = self.nvidia_smi_linux(&[
+            "--query-compute-apps=pid,process_name,used_memory",
+            "--format=csv,noheader,nounits"
+        ])?;
         if stdout.trim().is_empty() {
             return Ok(Vec::new());
         }
@@ -550,7 +926,7 @@ impl GpuMonitor {
 
     #[allow(dead_code)]
     fn get_stub_gpu_data(&self) -> GpuData {
-        GpuData {
+        let device = GpuDevice {
             name: "No GPU detected".to_string(),
             gpu_index: 0,
             utilization: 0.0,
@@ -565,6 +941,16 @@ impl GpuMonitor {
             driver_version: "N/A".to_string(),
             bus_id: "N/A".to_string(),
             cuda_version: "N/A".to_string(),
+            throttle_reasons: Vec::new(),
+            fan_count: None,
+            dedicated_used: 0,
+            dedicated_total: 0,
+            shared_used: 0,
+            shared_total: 0,
+        };
+
+        GpuData {
+            devices: vec![device],
             processes: Vec::new(),
         }
     }
@@ -606,9 +992,552 @@ struct GpuProcessSample {
 #[derive(Debug, Deserialize)]
 #[allow(non_snake_case)]
 struct GpuInfo {
+    #[serde(default)]
+    Index: u32,
     Name: String,
+    #[serde(default)]
+    Vendor: Option<String>,
     DriverVersion: String,
+    #[serde(default)]
+    PnpDeviceId: Option<String>,
     MemoryTotal: Option<u64>,
     MemoryUsed: Option<u64>,
+    #[serde(default)]
+    SharedUsed: Option<u64>,
+    #[serde(default)]
+    SharedTotal: Option<u64>,
     Utilization: Option<f32>,
 }
+
+/// `AdapterCompatibility` reads "Advanced Micro Devices, Inc."; older drivers
+/// leave it as "ATI Technologies Inc.", so the device name is checked too.
+#[cfg(feature = "nvidia")]
+fn init_nvml() -> Option<Nvml> {
+    match Nvml::init() {
+        Ok(nvml) => Some(nvml),
+        Err(e) => {
+            log::debug!("NVML unavailable, falling back to nvidia-smi: {}", e);
+            None
+        }
+    }
+}
+
+/// Per-device readings; only missing devices and a dead handle are errors,
+/// individual sensors a card lacks fall back to the nvidia-smi defaults.
+#[cfg(feature = "nvidia")]
+fn read_nvml_devices(nvml: &Nvml) -> std::result::Result<Vec<GpuDevice>, NvmlError> {
+    let driver_version = nvml
+        .sys_driver_version()
+        .unwrap_or_else(|_| "N/A".to_string());
+    let cuda_version = nvml
+        .sys_cuda_driver_version()
+        .map(|version| {
+            format!(
+                "{}.{}",
+                nvml_wrapper::cuda_driver_version_major(version),
+                nvml_wrapper::cuda_driver_version_minor(version)
+            )
+        })
+        .unwrap_or_else(|_| "N/A".to_string());
+
+    let mut devices = Vec::new();
+    for index in 0..nvml.device_count()? {
+        let device = nvml.device_by_index(index)?;
+        let memory = device.memory_info()?;
+        let fan_count = device.num_fans().ok();
+        devices.push(GpuDevice {
+            name: device.name()?,
+            gpu_index: index,
+            utilization: device
+                .utilization_rates()
+                .map(|rates| rates.gpu as f32)
+                .unwrap_or(0.0),
+            memory_used: memory.used,
+            memory_total: memory.total,
+            temperature: device
+                .temperature(TemperatureSensor::Gpu)
+                .map(|t| t as f32)
+                .unwrap_or(0.0),
+            // NVML reports milliwatts
+            power_usage: device.power_usage().map(|mw| mw as f32 / 1000.0).unwrap_or(0.0),
+            power_limit: device
+                .enforced_power_limit()
+                .map(|mw| mw as f32 / 1000.0)
+                .unwrap_or(300.0),
+            fan_speed: match fan_count {
+                Some(0) => -1.0,
+                _ => device.fan_speed(0).map(|speed| speed as f32).unwrap_or(-1.0),
+            },
+            clock_speed: device.clock_info(Clock::Graphics).unwrap_or(0),
+            memory_clock: device.clock_info(Clock::Memory).unwrap_or(0),
+            driver_version: driver_version.clone(),
+            bus_id: device.pci_info().map(|pci| pci.bus_id).unwrap_or_default(),
+            cuda_version: cuda_version.clone(),
+            throttle_reasons: device
+                .current_throttle_reasons()
+                .map(throttle_reason_names)
+                .unwrap_or_default(),
+            fan_count,
+            dedicated_used: memory.used,
+            dedicated_total: memory.total,
+            shared_used: 0,
+            shared_total: 0,
+        });
+    }
+    Ok(devices)
+}
+
+/// Reasons that slow the card down, in display order. Idle, sync boost and
+/// clock settings the user chose are left out.
+#[cfg(feature = "nvidia")]
+fn throttle_reason_names(reasons: ThrottleReasons) -> Vec<String> {
+    [
+        (ThrottleReasons::SW_POWER_CAP, "power cap"),
+        (ThrottleReasons::SW_THERMAL_SLOWDOWN, "thermal"),
+        (ThrottleReasons::HW_SLOWDOWN, "HW slowdown"),
+        (ThrottleReasons::HW_THERMAL_SLOWDOWN, "HW thermal"),
+        (ThrottleReasons::HW_POWER_BRAKE_SLOWDOWN, "power brake"),
+    ]
+    .into_iter()
+    .filter(|(flag, _)| reasons.contains(*flag))
+    .map(|(_, name)| name.to_string())
+    .collect()
+}
+
+fn is_amd_gpu(vendor: &str, name: &str) -> bool {
+    let vendor = vendor.to_lowercase();
+    let name = name.to_lowercase();
+    vendor.contains("advanced micro devices")
+        || vendor.contains("amd")
+        || vendor.contains("ati technologies")
+        || name.contains("radeon")
+        || name.starts_with("amd ")
+}
+
+#[cfg(feature = "amd")]
+#[allow(dead_code)]
+fn linux_has_amd_gpu() -> bool {
+    let Ok(cards) = std::fs::read_dir("/sys/class/drm") else {
+        return false;
+    };
+    cards.flatten().any(|card| {
+        std::fs::read_to_string(card.path().join("device/vendor"))
+            .map(|vendor| vendor.trim() == "0x1002")
+            .unwrap_or(false)
+    })
+}
+
+/// Cards from `/sys/class/drm/card*/device`, with whatever the driver exposes:
+/// amdgpu fills in load, VRAM, clocks and hwmon sensors, other drivers often
+/// only give a vendor and bus id.
+#[cfg(target_os = "linux")]
+fn get_drm_gpu_data_linux() -> Option<GpuData> {
+    use std::fs;
+
+    let read = |path: &std::path::Path| fs::read_to_string(path).ok().map(|v| v.trim().to_string());
+    let read_num = |path: &std::path::Path| read(path).and_then(|v| v.parse::<f64>().ok());
+
+    let mut cards: Vec<(u32, std::path::PathBuf)> = fs::read_dir("/sys/class/drm")
+        .ok()?
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            // cardN only; cardN-DP-1 etc. are connectors
+            let index = name.strip_prefix("card")?.parse::<u32>().ok()?;
+            Some((index, entry.path().join("device")))
+        })
+        .collect();
+    cards.sort_by_key(|(index, _)| *index);
+
+    let mut devices = Vec::new();
+    for (index, device) in cards {
+        let Some(vendor) = read(&device.join("vendor")) else {
+            continue;
+        };
+        let uevent = read(&device.join("uevent")).unwrap_or_default();
+        let uevent_value = |key: &str| {
+            uevent
+                .lines()
+                .find_map(|line| line.strip_prefix(key)?.strip_prefix('='))
+                .map(str::to_string)
+        };
+        let driver = uevent_value("DRIVER").unwrap_or_else(|| "unknown".to_string());
+        let vendor_name = match vendor.as_str() {
+            "0x1002" => "AMD",
+            "0x10de" => "NVIDIA",
+            "0x8086" => "Intel",
+            _ => "Unknown",
+        };
+        let name = read(&device.join("product_name"))
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| format!("{} GPU ({})", vendor_name, driver));
+
+        let hwmon = fs::read_dir(device.join("hwmon"))
+            .ok()
+            .and_then(|mut dirs| dirs.next())
+            .and_then(|entry| entry.ok())
+            .map(|entry| entry.path());
+        let sensor = |file: &str| hwmon.as_ref().and_then(|dir| read_num(&dir.join(file)));
+
+        let memory_used = read_num(&device.join("mem_info_vram_used")).unwrap_or(0.0) as u64;
+        let memory_total = read_num(&device.join("mem_info_vram_total")).unwrap_or(0.0) as u64;
+        devices.push(GpuDevice {
+            name,
+            gpu_index: index,
+            utilization: read_num(&device.join("gpu_busy_percent")).unwrap_or(0.0) as f32,
+            memory_used,
+            memory_total,
+            temperature: sensor("temp1_input").map_or(0.0, |t| t / 1000.0) as f32,
+            // hwmon reports microwatts
+            power_usage: sensor("power1_average").map_or(0.0, |p| p / 1_000_000.0) as f32,
+            power_limit: sensor("power1_cap").map_or(0.0, |p| p / 1_000_000.0) as f32,
+            fan_speed: -1.0,
+            clock_speed: read(&device.join("pp_dpm_sclk"))
+                .and_then(|table| parse_active_dpm_clock(&table))
+                .unwrap_or(0),
+            memory_clock: read(&device.join("pp_dpm_mclk"))
+                .and_then(|table| parse_active_dpm_clock(&table))
+                .unwrap_or(0),
+            driver_version: read(&std::path::Path::new("/sys/module").join(&driver).join("version"))
+                .unwrap_or_else(|| driver.clone()),
+            bus_id: uevent_value("PCI_SLOT_NAME").unwrap_or_else(|| "N/A".to_string()),
+            cuda_version: "N/A".to_string(),
+            throttle_reasons: Vec::new(),
+            fan_count: None,
+            dedicated_used: memory_used,
+            dedicated_total: memory_total,
+            shared_used: 0,
+            shared_total: 0,
+        });
+    }
+
+    if devices.is_empty() {
+        return None;
+    }
+
+    Some(GpuData {
+        devices,
+        processes: get_drm_processes_linux(),
+    })
+}
+
+/// `pp_dpm_sclk` lists one level per line, e.g. `1: 1800Mhz *`; the starred
+/// level is the current one.
+#[cfg(target_os = "linux")]
+fn parse_active_dpm_clock(table: &str) -> Option<u32> {
+    table
+        .lines()
+        .find(|line| line.trim_end().ends_with('*'))
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|clock| clock.to_lowercase().strip_suffix("mhz")?.parse().ok())
+}
+
+/// Processes holding a DRM client open, from `/proc/<pid>/fdinfo`. Each client
+/// is counted once even when several fds share it.
+#[cfg(target_os = "linux")]
+fn get_drm_processes_linux() -> Vec<GpuProcessInfo> {
+    use std::collections::HashMap;
+    use std::fs;
+
+    let Ok(procs) = fs::read_dir("/proc") else {
+        return Vec::new();
+    };
+
+    let mut processes = Vec::new();
+    for entry in procs.flatten() {
+        let Ok(pid) = entry.file_name().to_string_lossy().parse::<u32>() else {
+            continue;
+        };
+        let Ok(fds) = fs::read_dir(entry.path().join("fdinfo")) else {
+            continue;
+        };
+
+        let mut clients: HashMap<String, u64> = HashMap::new();
+        for fd in fds.flatten() {
+            let Ok(info) = fs::read_to_string(fd.path()) else {
+                continue;
+            };
+            if let Some((client, vram)) = parse_drm_fdinfo(&info) {
+                clients.entry(client).or_insert(vram);
+            }
+        }
+        if clients.is_empty() {
+            continue;
+        }
+
+        let name = fs::read_to_string(entry.path().join("comm"))
+            .map(|comm| comm.trim().to_string())
+            .unwrap_or_else(|_| pid.to_string());
+        processes.push(GpuProcessInfo {
+            pid,
+            name,
+            gpu_usage: 0.0,
+            vram: clients.values().sum(),
+            process_type: "Graphics".to_string(),
+        });
+    }
+
+    processes.sort_by_key(|p| std::cmp::Reverse(p.vram));
+    processes
+}
+
+/// `(drm-client-id, VRAM bytes)` from one fdinfo file, or `None` for non-DRM fds.
+#[cfg(target_os = "linux")]
+fn parse_drm_fdinfo(info: &str) -> Option<(String, u64)> {
+    let mut client = None;
+    let mut vram = 0;
+    for line in info.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim() {
+            "drm-client-id" => client = Some(value.to_string()),
+            "drm-memory-vram" => {
+                // e.g. "1024 KiB"
+                let mut parts = value.split_whitespace();
+                let amount: u64 = parts.next().and_then(|n| n.parse().ok()).unwrap_or(0);
+                vram = match parts.next() {
+                    Some("KiB") => amount * 1024,
+                    Some("MiB") => amount * 1024 * 1024,
+                    Some("GiB") => amount * 1024 * 1024 * 1024,
+                    _ => amount,
+                };
+            }
+            _ => {}
+        }
+    }
+    client.map(|client| (client, vram))
+}
+
+#[cfg(feature = "amd")]
+#[derive(Debug, Deserialize)]
+#[allow(non_snake_case, dead_code)]
+struct AmdSensorSample {
+    Card: u32,
+    Type: String,
+    Name: String,
+    Value: f64,
+}
+
+/// Readings for one AMD card from a hardware-monitor WMI provider.
+#[cfg(feature = "amd")]
+#[derive(Debug, Default)]
+#[allow(dead_code)]
+struct AmdSensors {
+    temperature: Option<f32>,
+    power: Option<f32>,
+    core_clock: Option<u32>,
+    memory_clock: Option<u32>,
+    fan: Option<f32>,
+    memory_used_mb: Option<f64>,
+    memory_total_mb: Option<f64>,
+}
+
+#[cfg(feature = "amd")]
+#[allow(dead_code)]
+impl AmdSensors {
+    fn from_readings(readings: &[AmdSensorSample]) -> Vec<Self> {
+        let cards = readings.iter().map(|r| r.Card).max().map_or(0, |max| max as usize + 1);
+        let mut result: Vec<Self> = (0..cards).map(|_| Self::default()).collect();
+
+        for reading in readings {
+            let sensors = &mut result[reading.Card as usize];
+            let value = reading.Value;
+            match (reading.Type.as_str(), reading.Name.as_str()) {
+                ("Temperature", "GPU Core") => sensors.temperature = Some(value as f32),
+                ("Power", "GPU Package") => sensors.power = Some(value as f32),
+                ("Power", "GPU Core") => {
+                    sensors.power.get_or_insert(value as f32);
+                }
+                ("Clock", "GPU Core") => sensors.core_clock = Some(value as u32),
+                ("Clock", "GPU Memory") => sensors.memory_clock = Some(value as u32),
+                ("Control", "GPU Fan") => sensors.fan = Some(value as f32),
+                ("SmallData", "GPU Memory Used") => sensors.memory_used_mb = Some(value),
+                ("SmallData", "GPU Memory Total") => sensors.memory_total_mb = Some(value),
+                _ => {}
+            }
+        }
+
+        result
+    }
+
+    fn apply(&self, device: &mut GpuDevice) {
+        const MB: f64 = 1024.0 * 1024.0;
+        if let Some(temperature) = self.temperature {
+            device.temperature = temperature;
+        }
+        if let Some(power) = self.power {
+            device.power_usage = power;
+        }
+        if let Some(clock) = self.core_clock {
+            device.clock_speed = clock;
+        }
+        if let Some(clock) = self.memory_clock {
+            device.memory_clock = clock;
+        }
+        if let Some(fan) = self.fan {
+            device.fan_speed = fan;
+        }
+        if let Some(total) = self.memory_total_mb.filter(|_| device.memory_total == 0) {
+            device.memory_total = (total * MB) as u64;
+            device.dedicated_total = device.memory_total;
+        }
+        if let Some(used) = self.memory_used_mb.filter(|_| device.memory_used == 0) {
+            device.memory_used = (used * MB) as u64;
+            device.dedicated_used = device.memory_used;
+        }
+    }
+}
+
+#[cfg(feature = "amd")]
+#[allow(dead_code)]
+fn parse_rocm_smi(output: &str) -> Result<Vec<GpuDevice>> {
+    let json: serde_json::Value =
+        serde_json::from_str(output.trim()).context("Failed to parse rocm-smi output")?;
+    let Some(entries) = json.as_object() else {
+        anyhow::bail!("Unexpected rocm-smi output");
+    };
+
+    let driver_version = json["system"]["Driver version"]
+        .as_str()
+        .unwrap_or("N/A")
+        .to_string();
+
+    let mut devices = Vec::new();
+    for (key, card) in entries {
+        let Some(index) = key.strip_prefix("card").and_then(|i| i.parse::<u32>().ok()) else {
+            continue;
+        };
+        let Some(fields) = card.as_object() else {
+            continue;
+        };
+
+        // Field names shift between ROCm releases, so match on stable fragments
+        let number = |predicate: &dyn Fn(&str) -> bool| -> Option<f64> {
+            fields.iter().find(|(name, _)| predicate(name)).and_then(|(_, value)| {
+                let text = match value {
+                    serde_json::Value::String(text) => text.clone(),
+                    other => other.to_string(),
+                };
+                let digits: String = text
+                    .chars()
+                    .skip_while(|c| !c.is_ascii_digit())
+                    .take_while(|c| c.is_ascii_digit() || *c == '.')
+                    .collect();
+                digits.parse().ok()
+            })
+        };
+
+        let temperature = number(&|n| n.starts_with("Temperature (Sensor edge)"))
+            .or_else(|| number(&|n| n.starts_with("Temperature")));
+        let name = ["Card series", "Card model", "Card SKU"]
+            .iter()
+            .find_map(|field| fields.get(*field).and_then(|v| v.as_str()))
+            .filter(|name| !name.trim().is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("AMD GPU {}", index));
+
+        let memory_used = number(&|n| n == "VRAM Total Used Memory (B)").unwrap_or(0.0) as u64;
+        let memory_total = number(&|n| n == "VRAM Total Memory (B)").unwrap_or(0.0) as u64;
+        devices.push(GpuDevice {
+            name,
+            gpu_index: index,
+            utilization: number(&|n| n == "GPU use (%)").unwrap_or(0.0) as f32,
+            memory_used,
+            memory_total,
+            temperature: temperature.unwrap_or(0.0) as f32,
+            power_usage: number(&|n| n.contains("Graphics Package Power (W)") && !n.starts_with("Max"))
+                .unwrap_or(0.0) as f32,
+            power_limit: number(&|n| n.starts_with("Max Graphics Package Power")).unwrap_or(0.0) as f32,
+            fan_speed: number(&|n| n == "Fan speed (%)").map_or(-1.0, |v| v as f32),
+            clock_speed: number(&|n| n == "sclk clock speed:").unwrap_or(0.0) as u32,
+            memory_clock: number(&|n| n == "mclk clock speed:").unwrap_or(0.0) as u32,
+            driver_version: driver_version.clone(),
+            bus_id: "N/A".to_string(),
+            cuda_version: "N/A".to_string(),
+            throttle_reasons: Vec::new(),
+            fan_count: None,
+            dedicated_used: memory_used,
+            dedicated_total: memory_total,
+            shared_used: 0,
+            shared_total: 0,
+        });
+    }
+
+    if devices.is_empty() {
+        anyhow::bail!("rocm-smi reported no GPUs");
+    }
+    devices.sort_by_key(|d| d.gpu_index);
+    Ok(devices)
+}
+
+#[cfg(all(test, feature = "amd"))]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "nvidia")]
+    #[test]
+    fn names_only_performance_throttle_reasons() {
+        let reasons = ThrottleReasons::GPU_IDLE
+            | ThrottleReasons::SW_POWER_CAP
+            | ThrottleReasons::HW_THERMAL_SLOWDOWN;
+        assert_eq!(throttle_reason_names(reasons), vec!["power cap", "HW thermal"]);
+        assert!(throttle_reason_names(ThrottleReasons::NONE).is_empty());
+    }
+
+    #[test]
+    fn parses_rocm_smi_json() {
+        let output = r#"{
+            "card0": {
+                "Temperature (Sensor edge) (C)": "52.0",
+                "Temperature (Sensor junction) (C)": "61.0",
+                "GPU use (%)": "37",
+                "VRAM Total Memory (B)": "17163091968",
+                "VRAM Total Used Memory (B)": "2147483648",
+                "Average Graphics Package Power (W)": "98.0",
+                "Max Graphics Package Power (W)": "255.0",
+                "sclk clock speed:": "(2450Mhz)",
+                "mclk clock speed:": "(1000Mhz)",
+                "Fan speed (%)": "31",
+                "Card series": "Radeon RX 6800 XT"
+            },
+            "system": {"Driver version": "6.7.0"}
+        }"#;
+
+        let devices = parse_rocm_smi(output).unwrap();
+        assert_eq!(devices.len(), 1);
+        let gpu = &devices[0];
+        assert_eq!(gpu.name, "Radeon RX 6800 XT");
+        assert_eq!(gpu.temperature, 52.0);
+        assert_eq!(gpu.utilization, 37.0);
+        assert_eq!(gpu.memory_total, 17_163_091_968);
+        assert_eq!(gpu.memory_used, 2_147_483_648);
+        assert_eq!(gpu.power_usage, 98.0);
+        assert_eq!(gpu.power_limit, 255.0);
+        assert_eq!(gpu.clock_speed, 2450);
+        assert_eq!(gpu.memory_clock, 1000);
+        assert_eq!(gpu.fan_speed, 31.0);
+        assert_eq!(gpu.driver_version, "6.7.0");
+
+        assert!(is_amd_gpu("Advanced Micro Devices, Inc.", "AMD Radeon RX 6800 XT"));
+        assert!(!is_amd_gpu("NVIDIA", "NVIDIA GeForce RTX 4090"));
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod drm_tests {
+    use super::*;
+
+    #[test]
+    fn parses_sysfs_clock_and_fdinfo() {
+        let sclk = "0: 500Mhz\n1: 1800Mhz *\n2: 2450Mhz\n";
+        assert_eq!(parse_active_dpm_clock(sclk), Some(1800));
+        assert_eq!(parse_active_dpm_clock("0: 500Mhz\n"), None);
+
+        let fdinfo = "pos:\t0\nflags:\t02100002\ndrm-driver:\tamdgpu\n\
+                      drm-client-id:\t42\ndrm-memory-vram:\t2048 KiB\n";
+        assert_eq!(parse_drm_fdinfo(fdinfo), Some(("42".to_string(), 2048 * 1024)));
+        assert_eq!(parse_drm_fdinfo("pos:\t0\nflags:\t0\n"), None);
+    }
+}
@@ -6,6 +6,7 @@ pub mod disk_analyzer;
 pub mod network;
 pub mod processes;
 pub mod services;
+pub mod system;
 
 pub use cpu::{CpuMonitor, CpuData};
 pub use gpu::{GpuMonitor, GpuData};
@@ -15,3 +16,4 @@ pub use disk_analyzer::{DiskAnalyzerMonitor, DiskAnalyzerData, AnalyzedDrive};
 pub use network::{NetworkMonitor, NetworkData};
 pub use processes::{ProcessMonitor, ProcessData};
 pub use services::{ServiceMonitor, ServiceData};
+pub use system::{SystemInfoMonitor, SystemInfo};
@@ -1,15 +1,36 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use crate::app::config::InterfaceFilterConfig;
+use crate::integrations::remote::SshTarget;
 use crate::integrations::{PowerShellExecutor, LinuxSysMonitor};
+use crate::utils::markdown::{self, ToMarkdown};
 use crate::utils::parse_json_array;
-use std::collections::VecDeque;
+use regex::{Regex, RegexBuilder};
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+
+const PING_INTERVAL: Duration = Duration::from_secs(5);
+const PING_TIMEOUT_SECS: u64 = 2;
+const PING_HISTORY_LEN: usize = 30;
+/// Traffic samples kept per interface and in aggregate for the graphs,
+/// until `set_history_len` applies `general.history_samples`.
+const TRAFFIC_HISTORY_LEN: usize = 60;
+
+/// RTT per target from one ping round (`None` = timed out)
+type PingRound = Vec<(String, Option<f64>)>;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NetworkData {
     pub interfaces: Vec<NetworkInterface>,
     pub connections: Vec<NetworkConnection>,
     pub traffic_history: VecDeque<TrafficSample>,
+    /// The same window per interface name; interfaces that disappear drop
+    /// out. `all_*` equal the plain figures here.
+    #[serde(default)]
+    pub interface_history: HashMap<String, VecDeque<TrafficSample>>,
     pub bandwidth_consumers: Vec<BandwidthConsumer>,
+    pub latency: Vec<PingResult>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,6 +56,10 @@ pub struct NetworkInterface {
     pub upload_speed: f64,     // Mbps
     pub peak_download: f64,
     pub peak_upload: f64,
+    /// Excluded by `interface_filter`: left out of the traffic totals and
+    /// only listed in the All view.
+    #[serde(default)]
+    pub hidden: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -47,24 +72,136 @@ pub struct NetworkConnection {
     pub remote_address: String,
     pub remote_port: u16,
     pub state: String,
+    #[serde(default)]
+    pub address_family: AddressFamily,
+}
+
+impl NetworkConnection {
+    pub fn local_endpoint(&self) -> String {
+        format_endpoint(&self.local_address, self.local_port, self.address_family)
+    }
+
+    pub fn remote_endpoint(&self) -> String {
+        if self.remote_address == "*" {
+            "*:*".to_string()
+        } else {
+            format_endpoint(&self.remote_address, self.remote_port, self.address_family)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AddressFamily {
+    #[default]
+    IPv4,
+    IPv6,
+}
+
+impl AddressFamily {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AddressFamily::IPv4 => "IPv4",
+            AddressFamily::IPv6 => "IPv6",
+        }
+    }
+}
+
+impl ToMarkdown for [&NetworkConnection] {
+    fn to_markdown(&self) -> String {
+        markdown::table(
+            &["Process", "PID", "Protocol", "Local", "Remote", "State"],
+            self.iter().map(|conn| {
+                vec![
+                    conn.process_name.clone(),
+                    conn.pid.to_string(),
+                    conn.protocol.clone(),
+                    conn.local_endpoint(),
+                    conn.remote_endpoint(),
+                    conn.state.clone(),
+                ]
+            }),
+        )
+    }
 }
 
+fn push_sample(history: &mut VecDeque<TrafficSample>, sample: TrafficSample, len: usize) {
+    history.push_back(sample);
+    while history.len() > len {
+        history.pop_front();
+    }
+}
+
+/// Canonical form of `address` and its family. IPv6 is `::`-compressed; a
+/// link-local `%zone` suffix is kept as-is after the parsed address.
+pub fn normalize_address(address: &str) -> (String, AddressFamily) {
+    let (ip, zone) = match address.split_once('%') {
+        Some((ip, zone)) => (ip, Some(zone)),
+        None => (address, None),
+    };
+    match ip.parse::<std::net::IpAddr>() {
+        Ok(std::net::IpAddr::V4(v4)) => (v4.to_string(), AddressFamily::IPv4),
+        Ok(std::net::IpAddr::V6(v6)) => {
+            let mut text = v6.to_string();
+            if let Some(zone) = zone {
+                text.push('%');
+                text.push_str(zone);
+            }
+            (text, AddressFamily::IPv6)
+        }
+        // Placeholders such as "*" stay as they are
+        Err(_) if ip.contains(':') => (address.to_string(), AddressFamily::IPv6),
+        Err(_) => (address.to_string(), AddressFamily::IPv4),
+    }
+}
+
+/// `addr:port`, or `[addr]:port` for IPv6 so the port stays readable.
+fn format_endpoint(address: &str, port: u16, family: AddressFamily) -> String {
+    match family {
+        AddressFamily::IPv4 => format!("{}:{}", address, port),
+        AddressFamily::IPv6 => format!("[{}]:{}", address, port),
+    }
+}
+
+/// Traffic summed over the interfaces the filter keeps, plus the unfiltered
+/// total for the All view.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrafficSample {
     pub timestamp: u64,
     pub download_mbps: f64,
     pub upload_mbps: f64,
+    #[serde(default)]
+    pub all_download_mbps: f64,
+    #[serde(default)]
+    pub all_upload_mbps: f64,
 }
 
+/// Per-process TCP traffic. Byte figures come from TCP extended statistics
+/// and are `None` when they could not be measured (e.g. not elevated).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BandwidthConsumer {
     pub process_name: String,
     pub pid: u32,
-    pub download_speed: f64,  // Mbps
-    pub upload_speed: f64,    // Mbps
-    pub total_bytes_received: u64,
-    pub total_bytes_sent: u64,
-    pub estimated: bool,
+    pub connection_count: u32,
+    pub download_speed: Option<f64>,  // Mbps over the last interval
+    pub upload_speed: Option<f64>,    // Mbps over the last interval
+    pub total_bytes_received: Option<u64>,
+    pub total_bytes_sent: Option<u64>,
+}
+
+impl BandwidthConsumer {
+    pub fn is_measured(&self) -> bool {
+        self.total_bytes_received.is_some()
+    }
+}
+
+/// Latest ping to one target. `loss_pct` and `history` cover the last
+/// `PING_HISTORY_LEN` samples; a timeout is recorded as `None`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PingResult {
+    pub target: String,
+    pub rtt_ms: Option<f64>,
+    pub loss_pct: f64,
+    pub history: VecDeque<Option<f64>>,
 }
 
 impl Default for NetworkData {
@@ -72,8 +209,10 @@ impl Default for NetworkData {
         Self {
             interfaces: Vec::new(),
             connections: Vec::new(),
-            traffic_history: VecDeque::with_capacity(60),
+            traffic_history: VecDeque::with_capacity(TRAFFIC_HISTORY_LEN),
+            interface_history: HashMap::new(),
             bandwidth_consumers: Vec::new(),
+            latency: Vec::new(),
         }
     }
 }
@@ -84,20 +223,83 @@ pub struct NetworkMonitor {
     linux_sys: LinuxSysMonitor,
     last_stats: Option<Vec<InterfaceStats>>,
     last_timestamp: Option<std::time::Instant>,
-    last_process_stats: Option<std::collections::HashMap<u32, ProcessNetworkStats>>,
+    last_connection_bytes: HashMap<String, (u64, u64)>,
+    last_bandwidth_sample: Option<Instant>,
+    ping_targets: Vec<String>,
+    ping_history: HashMap<String, VecDeque<Option<f64>>>,
+    last_ping: Option<Instant>,
+    pending_ping: Option<JoinHandle<PingRound>>,
+    interface_filter: InterfaceFilter,
+    show_connections: bool,
+    traffic_history: VecDeque<TrafficSample>,
+    interface_history: HashMap<String, VecDeque<TrafficSample>>,
+    history_len: usize,
 }
 
-#[derive(Debug, Clone)]
-struct InterfaceStats {
-    name: String,
-    bytes_received: u64,
-    bytes_sent: u64,
+/// `InterfaceFilterConfig` with its patterns compiled.
+#[derive(Default)]
+struct InterfaceFilter {
+    config: InterfaceFilterConfig,
+    include: Vec<Regex>,
+    exclude: Vec<Regex>,
+}
+
+impl InterfaceFilter {
+    fn new(config: &InterfaceFilterConfig) -> Self {
+        let compile = |patterns: &[String]| -> Vec<Regex> {
+            patterns
+                .iter()
+                .filter_map(|pattern| {
+                    match RegexBuilder::new(pattern).case_insensitive(true).build() {
+                        Ok(regex) => Some(regex),
+                        Err(e) => {
+                            log::warn!("Ignoring interface filter '{}': {}", pattern, e);
+                            None
+                        }
+                    }
+                })
+                .collect()
+        };
+
+        Self {
+            config: config.clone(),
+            include: compile(&config.include),
+            exclude: compile(&config.exclude),
+        }
+    }
+
+    fn hides(&self, iface: &NetworkInterface) -> bool {
+        let matches =
+            |regex: &Regex| regex.is_match(&iface.name) || regex.is_match(&iface.description);
+        (!self.include.is_empty() && !self.include.iter().any(matches))
+            || self.exclude.iter().any(matches)
+            || (self.config.hide_virtual && is_virtual_interface(&iface.name, &iface.description))
+    }
+}
+
+/// Loopback, tunnels, bridges and VM/container adapters, judged by the usual
+/// Linux interface names and Windows adapter descriptions.
+fn is_virtual_interface(name: &str, description: &str) -> bool {
+    const NAME_PREFIXES: &[&str] = &[
+        "docker", "veth", "br-", "virbr", "vboxnet", "vmnet", "tun", "tap", "wg",
+        "tailscale", "zt", "utun",
+    ];
+    const DESCRIPTION_PARTS: &[&str] = &[
+        "loopback", "virtual", "vpn", "hyper-v", "vmware", "wireguard", "tap-windows",
+        "tunnel", "pseudo-interface",
+    ];
+
+    let name = name.to_lowercase();
+    let description = description.to_lowercase();
+    // Not a prefix: Windows names adapters "Local Area Connection"
+    name == "lo"
+        || NAME_PREFIXES.iter().any(|prefix| name.starts_with(prefix))
+        || DESCRIPTION_PARTS.iter().any(|part| description.contains(part))
 }
 
 #[derive(Debug, Clone)]
-struct ProcessNetworkStats {
-    #[allow(dead_code)]
-    process_name: String,
+struct InterfaceStats {
+    name: String,
     bytes_received: u64,
     bytes_sent: u64,
 }
@@ -107,7 +309,10 @@ const INTERFACES_SCRIPT: &str = r#"
         "[]"
     } else {
         try {
-            $adapters = Get-NetAdapter -ErrorAction Stop | Where-Object { $_.Status -eq 'Up' }
+            # Disabled adapters stay listed (after the live ones) so they can be re-enabled
+            $adapters = Get-NetAdapter -ErrorAction Stop |
+                Where-Object { $_.Status -eq 'Up' -or $_.Status -eq 'Disabled' } |
+                Sort-Object { $_.Status -ne 'Up' }
 
             $result = foreach ($adapter in $adapters) {
                 $stats = Get-NetAdapterStatistics -Name $adapter.Name -ErrorAction SilentlyContinue
@@ -149,30 +354,41 @@ const CONNECTIONS_SCRIPT: &str = r#"
         "[]"
     } else {
         try {
-            $connections = Get-NetTCPConnection -State Established -ErrorAction Stop |
-                Select-Object -First 10 OwningProcess, LocalAddress, LocalPort, RemoteAddress, RemotePort, State
-
-            $result = foreach ($conn in $connections) {
-                try {
-                    $process = Get-Process -Id $conn.OwningProcess -ErrorAction SilentlyContinue
-                    $processName = if ($process) { $process.ProcessName } else { "Unknown" }
-                } catch {
-                    $processName = "Unknown"
-                }
+            $names = @{}
+            foreach ($process in Get-Process -ErrorAction SilentlyContinue) {
+                $names[$process.Id] = $process.ProcessName
+            }
 
-                [PSCustomObject]@{
-                    ProcessName = $processName
-                    PID = $conn.OwningProcess
-                    Protocol = "TCP"
-                    LocalAddress = $conn.LocalAddress
-                    LocalPort = $conn.LocalPort
-                    RemoteAddress = $conn.RemoteAddress
-                    RemotePort = $conn.RemotePort
-                    State = $conn.State
+            $result = @(
+                foreach ($conn in Get-NetTCPConnection -ErrorAction SilentlyContinue) {
+                    [PSCustomObject]@{
+                        ProcessName = if ($names.ContainsKey([int]$conn.OwningProcess)) { $names[[int]$conn.OwningProcess] } else { "Unknown" }
+                        PID = [int]$conn.OwningProcess
+                        Protocol = "TCP"
+                        LocalAddress = $conn.LocalAddress
+                        LocalPort = $conn.LocalPort
+                        RemoteAddress = $conn.RemoteAddress
+                        RemotePort = $conn.RemotePort
+                        State = "$($conn.State)"
+                    }
                 }
-            }
+                if (Get-Command Get-NetUDPEndpoint -ErrorAction SilentlyContinue) {
+                    foreach ($endpoint in Get-NetUDPEndpoint -ErrorAction SilentlyContinue) {
+                        [PSCustomObject]@{
+                            ProcessName = if ($names.ContainsKey([int]$endpoint.OwningProcess)) { $names[[int]$endpoint.OwningProcess] } else { "Unknown" }
+                            PID = [int]$endpoint.OwningProcess
+                            Protocol = "UDP"
+                            LocalAddress = $endpoint.LocalAddress
+                            LocalPort = $endpoint.LocalPort
+                            RemoteAddress = "*"
+                            RemotePort = 0
+                            State = "Bound"
+                        }
+                    }
+                }
+            )
 
-            if ($result) {
+            if ($result.Count -gt 0) {
                 $result | ConvertTo-Json -Depth 2
             } else {
                 "[]"
@@ -183,35 +399,96 @@ const CONNECTIONS_SCRIPT: &str = r#"
     }
 "#;
 
+// Per-connection byte counters from TCP extended statistics
+// (GetPerTcpConnectionEStats). Enabling collection requires elevation; without
+// it, or for IPv6 connections, BytesIn/BytesOut are null.
 const BANDWIDTH_SCRIPT: &str = r#"
     if (-not (Get-Command Get-NetTCPConnection -ErrorAction SilentlyContinue)) {
         "[]"
     } else {
         try {
-            $netstat = Get-NetTCPConnection -ErrorAction Stop |
-                Where-Object { $_.State -eq 'Established' } |
-                Group-Object -Property OwningProcess |
-                ForEach-Object {
-                    $pid = $_.Name
+            $principal = [Security.Principal.WindowsPrincipal][Security.Principal.WindowsIdentity]::GetCurrent()
+            $estats = $false
+            if ($principal.IsInRole([Security.Principal.WindowsBuiltInRole]::Administrator)) {
+                if (-not ('TuiTcpEStats' -as [type])) {
                     try {
-                        $process = Get-Process -Id $pid -ErrorAction SilentlyContinue
-                        if ($process) {
-                            $connCount = $_.Count
-
-                            [PSCustomObject]@{
-                                ProcessName = $process.ProcessName
-                                PID = [int]$pid
-                                ConnectionCount = $connCount
-                            }
-                        }
+                        Add-Type -ErrorAction Stop -TypeDefinition @'
+using System;
+using System.Net;
+using System.Runtime.InteropServices;
+
+public static class TuiTcpEStats {
+    [StructLayout(LayoutKind.Sequential)]
+    struct MibTcpRow { public uint State; public uint LocalAddr; public uint LocalPort; public uint RemoteAddr; public uint RemotePort; }
+
+    [StructLayout(LayoutKind.Sequential)]
+    struct DataRw { public byte EnableCollection; }
+
+    [StructLayout(LayoutKind.Sequential)]
+    struct DataRod {
+        public ulong DataBytesOut; public ulong DataSegsOut; public ulong DataBytesIn; public ulong DataSegsIn;
+        public ulong SegsOut; public ulong SegsIn; public uint SoftErrors; public uint SoftErrorReason;
+        public uint SndUna; public uint SndNxt; public uint SndMax; public ulong ThruBytesAcked;
+        public uint RcvNxt; public ulong ThruBytesReceived;
+    }
+
+    const int TcpConnectionEstatsData = 1;
+    const uint MibTcpStateEstab = 5;
+
+    [DllImport("iphlpapi.dll")]
+    static extern uint SetPerTcpConnectionEStats(ref MibTcpRow row, int type, ref DataRw rw, uint version, uint size, uint offset);
+
+    [DllImport("iphlpapi.dll")]
+    static extern uint GetPerTcpConnectionEStats(ref MibTcpRow row, int type, IntPtr rw, uint rwVersion, uint rwSize, IntPtr ros, uint rosVersion, uint rosSize, out DataRod rod, uint rodVersion, uint rodSize);
+
+    static uint Addr(string ip) { return BitConverter.ToUInt32(IPAddress.Parse(ip).GetAddressBytes(), 0); }
+    static uint Port(int port) { return (uint)IPAddress.HostToNetworkOrder((short)port) & 0xFFFF; }
+
+    public static ulong[] Read(string localAddr, int localPort, string remoteAddr, int remotePort) {
+        var row = new MibTcpRow { State = MibTcpStateEstab, LocalAddr = Addr(localAddr), LocalPort = Port(localPort), RemoteAddr = Addr(remoteAddr), RemotePort = Port(remotePort) };
+        var rw = new DataRw { EnableCollection = 1 };
+        if (SetPerTcpConnectionEStats(ref row, TcpConnectionEstatsData, ref rw, 0, (uint)Marshal.SizeOf(rw), 0) != 0) return null;
+        DataRod rod;
+        if (GetPerTcpConnectionEStats(ref row, TcpConnectionEstatsData, IntPtr.Zero, 0, 0, IntPtr.Zero, 0, 0, out rod, 0, (uint)Marshal.SizeOf(typeof(DataRod))) != 0) return null;
+        return new ulong[] { rod.DataBytesIn, rod.DataBytesOut };
+    }
+}
+'@
                     } catch {
                     }
                 }
+                $estats = [bool]('TuiTcpEStats' -as [type])
+            }
+
+            $names = @{}
+            $result = Get-NetTCPConnection -State Established -ErrorAction Stop |
+                Where-Object { $_.OwningProcess -ne 0 } |
+                ForEach-Object {
+                    $procId = $_.OwningProcess
+                    if (-not $names.ContainsKey($procId)) {
+                        $process = Get-Process -Id $procId -ErrorAction SilentlyContinue
+                        $names[$procId] = if ($process) { $process.ProcessName } else { "Unknown" }
+                    }
+
+                    $bytes = $null
+                    if ($estats -and $_.LocalAddress -notmatch ':') {
+                        try {
+                            $bytes = [TuiTcpEStats]::Read($_.LocalAddress, $_.LocalPort, $_.RemoteAddress, $_.RemotePort)
+                        } catch {
+                        }
+                    }
+
+                    [PSCustomObject]@{
+                        PID = [int]$procId
+                        ProcessName = $names[$procId]
+                        Connection = "$($_.LocalAddress):$($_.LocalPort)-$($_.RemoteAddress):$($_.RemotePort)"
+                        BytesIn = if ($bytes) { [uint64]$bytes[0] } else { $null }
+                        BytesOut = if ($bytes) { [uint64]$bytes[1] } else { $null }
+                    }
+                }
 
-            if ($netstat) {
-                $netstat | Sort-Object -Property ConnectionCount -Descending |
-                    Select-Object -First 10 |
-                    ConvertTo-Json -Depth 2
+            if ($result) {
+                @($result) | ConvertTo-Json -Depth 2
             } else {
                 "[]"
             }
@@ -224,14 +501,158 @@ const BANDWIDTH_SCRIPT: &str = r#"
 impl NetworkMonitor {
     pub fn new(ps: PowerShellExecutor) -> Result<Self> {
         Ok(Self {
+            linux_sys: LinuxSysMonitor::new().with_remote(ps.remote().cloned()),
             ps,
-            linux_sys: LinuxSysMonitor::new(),
             last_stats: None,
             last_timestamp: None,
-            last_process_stats: None,
+            last_connection_bytes: HashMap::new(),
+            last_bandwidth_sample: None,
+            ping_targets: Vec::new(),
+            ping_history: HashMap::new(),
+            last_ping: None,
+            pending_ping: None,
+            interface_filter: InterfaceFilter::default(),
+            show_connections: true,
+            traffic_history: VecDeque::with_capacity(TRAFFIC_HISTORY_LEN),
+            interface_history: HashMap::new(),
+            history_len: TRAFFIC_HISTORY_LEN,
         })
     }
 
+    /// Carry the traffic graphs over from the monitor this one replaces.
+    pub fn take_traffic_history(&mut self, previous: NetworkMonitor) {
+        self.traffic_history = previous.traffic_history;
+        self.interface_history = previous.interface_history;
+    }
+
+    pub fn clear_traffic_history(&mut self) {
+        self.traffic_history.clear();
+        self.interface_history.clear();
+    }
+
+    pub fn set_interface_filter(&mut self, config: &InterfaceFilterConfig) {
+        if self.interface_filter.config != *config {
+            self.interface_filter = InterfaceFilter::new(config);
+        }
+    }
+
+    /// With `false` the connection table is left empty instead of queried.
+    pub fn set_show_connections(&mut self, show: bool) {
+        self.show_connections = show;
+    }
+
+    /// Cap for the traffic graphs; longer histories are trimmed on the next
+    /// sample.
+    pub fn set_history_len(&mut self, len: usize) {
+        self.history_len = len;
+    }
+
+    pub fn set_ping_targets(&mut self, targets: &[String]) {
+        if self.ping_targets.as_slice() != targets {
+            self.ping_targets = targets.to_vec();
+            self.ping_history.retain(|target, _| targets.contains(target));
+            self.last_ping = None;
+        }
+    }
+
+    /// Fold in the results of a finished ping round and start the next one
+    /// when due. Pings run in the background so an unreachable target never
+    /// delays the throughput refresh.
+    async fn update_latency(&mut self) -> Vec<PingResult> {
+        if self.pending_ping.as_ref().is_some_and(|handle| handle.is_finished()) {
+            if let Some(handle) = self.pending_ping.take() {
+                match handle.await {
+                    Ok(samples) => self.record_pings(samples),
+                    Err(e) => log::warn!("Ping task failed: {}", e),
+                }
+            }
+        }
+
+        let due = self
+            .last_ping
+            .is_none_or(|last| last.elapsed() >= PING_INTERVAL);
+        if self.pending_ping.is_none() && due && !self.ping_targets.is_empty() {
+            let ps = self.ps.clone();
+            let targets = self.ping_targets.clone();
+            self.last_ping = Some(Instant::now());
+            self.pending_ping = Some(tokio::spawn(async move {
+                futures::future::join_all(targets.into_iter().map(|target| {
+                    let ps = ps.clone();
+                    async move {
+                        let rtt = ping_target(&ps, &target).await;
+                        (target, rtt)
+                    }
+                }))
+                .await
+            }));
+        }
+
+        self.ping_targets
+            .iter()
+            .filter_map(|target| {
+                let history = self.ping_history.get(target)?;
+                let lost = history.iter().filter(|sample| sample.is_none()).count();
+                Some(PingResult {
+                    target: target.clone(),
+                    rtt_ms: history.back().copied().flatten(),
+                    loss_pct: lost as f64 / history.len().max(1) as f64 * 100.0,
+                    history: history.clone(),
+                })
+            })
+            .collect()
+    }
+
+    fn record_pings(&mut self, samples: PingRound) {
+        for (target, rtt) in samples {
+            // Skip results for targets removed while the round was running
+            if !self.ping_targets.contains(&target) {
+                continue;
+            }
+            let history = self.ping_history.entry(target).or_default();
+            history.push_back(rtt);
+            while history.len() > PING_HISTORY_LEN {
+                history.pop_front();
+            }
+        }
+    }
+
+    /// Bring an adapter down or up; the new status shows on the next refresh.
+    pub async fn set_adapter_enabled(&self, name: &str, enabled: bool) -> Result<()> {
+        #[cfg(target_os = "linux")]
+        {
+            let state = if enabled { "up" } else { "down" };
+            run_tool(self.ps.remote(), "ip", &["link", "set", "dev", name, state]).await?;
+            Ok(())
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            let script = format!(
+                "{}-NetAdapter -Name '{}' -Confirm:$false -ErrorAction Stop",
+                if enabled { "Enable" } else { "Disable" },
+                name.replace('\'', "''")
+            );
+            self.ps.execute_uncached(&script).await?;
+            Ok(())
+        }
+    }
+
+    pub async fn flush_dns(&self) -> Result<()> {
+        #[cfg(target_os = "linux")]
+        {
+            run_tool(self.ps.remote(), "resolvectl", &["flush-caches"]).await?;
+            Ok(())
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            self.ps
+                .execute_uncached("Clear-DnsClientCache -ErrorAction Stop")
+                .await?;
+            Ok(())
+        }
+    }
+
     pub async fn collect_data(&mut self) -> Result<NetworkData> {
         #[cfg(target_os = "linux")]
         {
@@ -246,39 +667,56 @@ impl NetworkMonitor {
 
     #[allow(dead_code)]
     async fn collect_data_linux(&mut self) -> Result<NetworkData> {
-        let interfaces = self.get_interfaces_linux().await?;
-        let connections = self.get_connections_linux().await?;
+        let mut interfaces = self.get_interfaces_linux().await?;
+        self.mark_hidden(&mut interfaces);
+        let connections = if self.show_connections {
+            self.get_connections_linux().await?
+        } else {
+            Vec::new()
+        };
         let bandwidth_consumers = Vec::new(); // TODO: Implement for Linux
 
-        // Calculate traffic history
-        let traffic_history = self.calculate_traffic_history(&interfaces);
+        self.record_traffic(&interfaces);
+        let latency = self.update_latency().await;
 
         Ok(NetworkData {
             interfaces,
             connections,
-            traffic_history,
+            traffic_history: self.traffic_history.clone(),
+            interface_history: self.interface_history.clone(),
             bandwidth_consumers,
+            latency,
         })
     }
 
     async fn collect_data_windows(&mut self) -> Result<NetworkData> {
+        let mut scripts = vec![INTERFACES_SCRIPT, BANDWIDTH_SCRIPT];
+        if self.show_connections {
+            scripts.push(CONNECTIONS_SCRIPT);
+        }
         let outputs = self
             .ps
-            .execute_batch(&[INTERFACES_SCRIPT, CONNECTIONS_SCRIPT, BANDWIDTH_SCRIPT])
+            .execute_batch(&scripts)
             .await
             .context("Failed to execute network monitor batch")?;
-        let interfaces = self.parse_interfaces(&outputs[0])?;
-        let connections = self.parse_connections(&outputs[1])?;
-        let bandwidth_consumers = self.parse_bandwidth_consumers(&outputs[2])?;
+        let mut interfaces = self.parse_interfaces(&outputs[0])?;
+        self.mark_hidden(&mut interfaces);
+        let bandwidth_consumers = self.parse_bandwidth_consumers(&outputs[1])?;
+        let connections = match outputs.get(2) {
+            Some(output) => self.parse_connections(output)?,
+            None => Vec::new(),
+        };
 
-        // Calculate traffic history
-        let traffic_history = self.calculate_traffic_history(&interfaces);
+        self.record_traffic(&interfaces);
+        let latency = self.update_latency().await;
 
         Ok(NetworkData {
             interfaces,
             connections,
-            traffic_history,
+            traffic_history: self.traffic_history.clone(),
+            interface_history: self.interface_history.clone(),
             bandwidth_consumers,
+            latency,
         })
     }
 
@@ -335,6 +773,7 @@ impl NetworkMonitor {
                 upload_speed,
                 peak_download,
                 peak_upload,
+                hidden: false,
             });
         }
 
@@ -363,118 +802,149 @@ impl NetworkMonitor {
         (0.0, 0.0, 0.0, 0.0)
     }
 
-    // 5.2: Traffic History for graphs (60s)
-    fn calculate_traffic_history(&self, interfaces: &[NetworkInterface]) -> VecDeque<TrafficSample> {
-        let mut history = VecDeque::with_capacity(60);
+    fn mark_hidden(&self, interfaces: &mut [NetworkInterface]) {
+        for iface in interfaces {
+            iface.hidden = self.interface_filter.hides(iface);
+        }
+    }
 
-        // Sum all interfaces' traffic
-        let total_download: f64 = interfaces.iter().map(|i| i.download_speed).sum();
-        let total_upload: f64 = interfaces.iter().map(|i| i.upload_speed).sum();
+    // 5.2: Traffic History for graphs
+    fn record_traffic(&mut self, interfaces: &[NetworkInterface]) {
+        let sum = |speed: fn(&NetworkInterface) -> f64, all: bool| -> f64 {
+            interfaces
+                .iter()
+                .filter(|i| all || !i.hidden)
+                .map(speed)
+                .sum()
+        };
 
         let timestamp = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
             .as_secs();
 
-        history.push_back(TrafficSample {
-            timestamp,
-            download_mbps: total_download,
-            upload_mbps: total_upload,
-        });
-
-        history
+        push_sample(
+            &mut self.traffic_history,
+            TrafficSample {
+                timestamp,
+                download_mbps: sum(|i| i.download_speed, false),
+                upload_mbps: sum(|i| i.upload_speed, false),
+                all_download_mbps: sum(|i| i.download_speed, true),
+                all_upload_mbps: sum(|i| i.upload_speed, true),
+            },
+            self.history_len,
+        );
+
+        self.interface_history
+            .retain(|name, _| interfaces.iter().any(|iface| &iface.name == name));
+        for iface in interfaces {
+            push_sample(
+                self.interface_history.entry(iface.name.clone()).or_default(),
+                TrafficSample {
+                    timestamp,
+                    download_mbps: iface.download_speed,
+                    upload_mbps: iface.upload_speed,
+                    all_download_mbps: iface.download_speed,
+                    all_upload_mbps: iface.upload_speed,
+                },
+                self.history_len,
+            );
+        }
     }
 
     // 5.3: Active Connections
     fn parse_connections(&self, output: &str) -> Result<Vec<NetworkConnection>> {
         let connections_raw: Vec<ConnectionData> = parse_json_array(output)
             .context("Failed to parse connection data")?;
-        if connections_raw.is_empty() {
-            return Ok(Vec::new());
-        }
 
-        let mut connections = Vec::new();
-        for conn in connections_raw {
-            connections.push(NetworkConnection {
-                process_name: conn.ProcessName,
-                pid: conn.PID,
-                protocol: conn.Protocol,
-                local_address: conn.LocalAddress,
-                local_port: conn.LocalPort,
-                remote_address: conn.RemoteAddress,
-                remote_port: conn.RemotePort,
-                state: conn.State,
-            });
-        }
+        let connections = connections_raw
+            .into_iter()
+            .map(|conn| {
+                let (local_address, address_family) = normalize_address(&conn.LocalAddress);
+                let (remote_address, _) = normalize_address(&conn.RemoteAddress);
+                NetworkConnection {
+                    process_name: conn.ProcessName,
+                    pid: conn.PID,
+                    protocol: conn.Protocol,
+                    local_address,
+                    local_port: conn.LocalPort,
+                    remote_address,
+                    remote_port: conn.RemotePort,
+                    state: conn.State,
+                    address_family,
+                }
+            })
+            .collect();
 
-        Ok(connections)
+        Ok(dedup_connections(connections))
     }
 
     // 5.4: Bandwidth Consumers - Top processes by network usage
     fn parse_bandwidth_consumers(&mut self, output: &str) -> Result<Vec<BandwidthConsumer>> {
-        let consumers_raw: Vec<ProcessBandwidthData> = parse_json_array(output)
+        let connections: Vec<ConnectionBandwidthData> = parse_json_array(output)
             .context("Failed to parse bandwidth consumers data")?;
-        if consumers_raw.is_empty() {
-            return Ok(Vec::new());
-        }
 
-        let current_time = std::time::Instant::now();
-        let time_delta = if let Some(last_time) = self.last_timestamp {
-            current_time.duration_since(last_time).as_secs_f64()
-        } else {
-            1.0
-        };
-
-        let mut bandwidth_consumers = Vec::new();
-        let mut current_process_stats = std::collections::HashMap::new();
+        let now = Instant::now();
+        let elapsed = self
+            .last_bandwidth_sample
+            .map(|last| now.duration_since(last).as_secs_f64())
+            .filter(|secs| *secs > 0.0);
 
-        for consumer in consumers_raw {
-            // Estimate bandwidth based on connection count
-            // This is a rough approximation since Windows doesn't provide per-process network stats
-            let connection_count = consumer.ConnectionCount as f64;
-            let estimated_bytes = (connection_count * 1024.0 * 100.0) as u64; // ~100KB per connection
+        let mut by_pid: HashMap<u32, BandwidthConsumer> = HashMap::new();
+        let mut current_bytes = HashMap::new();
 
-            let (download_speed, upload_speed) = if let Some(ref last_stats) = self.last_process_stats {
-                if let Some(last) = last_stats.get(&consumer.PID) {
-                    let bytes_rx_delta = estimated_bytes.saturating_sub(last.bytes_received) as f64;
-                    let bytes_tx_delta = estimated_bytes.saturating_sub(last.bytes_sent) as f64;
-
-                    let download = (bytes_rx_delta / time_delta) * 8.0 / 1_000_000.0; // Mbps
-                    let upload = (bytes_tx_delta / time_delta) * 8.0 / 1_000_000.0;   // Mbps
+        for conn in connections {
+            let consumer = by_pid.entry(conn.PID).or_insert_with(|| BandwidthConsumer {
+                process_name: conn.ProcessName.clone(),
+                pid: conn.PID,
+                connection_count: 0,
+                download_speed: None,
+                upload_speed: None,
+                total_bytes_received: None,
+                total_bytes_sent: None,
+            });
+            consumer.connection_count += 1;
 
-                    (download, upload)
-                } else {
-                    (0.0, 0.0)
-                }
-            } else {
-                (0.0, 0.0)
+            let (Some(bytes_in), Some(bytes_out)) = (conn.BytesIn, conn.BytesOut) else {
+                continue;
             };
+            *consumer.total_bytes_received.get_or_insert(0) += bytes_in;
+            *consumer.total_bytes_sent.get_or_insert(0) += bytes_out;
+
+            // A connection seen for the first time contributes no delta: its
+            // counters may cover traffic from before the previous sample.
+            if let Some(secs) = elapsed {
+                let (delta_in, delta_out) = match self.last_connection_bytes.get(&conn.Connection) {
+                    Some(&(prev_in, prev_out)) => (
+                        bytes_in.saturating_sub(prev_in),
+                        bytes_out.saturating_sub(prev_out),
+                    ),
+                    None => (0, 0),
+                };
+                *consumer.download_speed.get_or_insert(0.0) +=
+                    delta_in as f64 / secs * 8.0 / 1_000_000.0;
+                *consumer.upload_speed.get_or_insert(0.0) +=
+                    delta_out as f64 / secs * 8.0 / 1_000_000.0;
+            }
 
-            current_process_stats.insert(consumer.PID, ProcessNetworkStats {
-                process_name: consumer.ProcessName.clone(),
-                bytes_received: estimated_bytes,
-                bytes_sent: estimated_bytes,
-            });
-
-            bandwidth_consumers.push(BandwidthConsumer {
-                process_name: consumer.ProcessName,
-                pid: consumer.PID,
-                download_speed,
-                upload_speed,
-                total_bytes_received: estimated_bytes,
-                total_bytes_sent: estimated_bytes,
-                estimated: true,
-            });
+            current_bytes.insert(conn.Connection, (bytes_in, bytes_out));
         }
 
-        self.last_process_stats = Some(current_process_stats);
+        self.last_connection_bytes = current_bytes;
+        self.last_bandwidth_sample = Some(now);
 
-        // Sort by total bandwidth (download + upload)
+        // Measured traffic first, then by number of connections
+        let mut bandwidth_consumers: Vec<BandwidthConsumer> = by_pid.into_values().collect();
         bandwidth_consumers.sort_by(|a, b| {
-            let total_a = a.download_speed + a.upload_speed;
-            let total_b = b.download_speed + b.upload_speed;
-            total_b.partial_cmp(&total_a).unwrap_or(std::cmp::Ordering::Equal)
+            let rate = |c: &BandwidthConsumer| {
+                c.download_speed.unwrap_or(0.0) + c.upload_speed.unwrap_or(0.0)
+            };
+            b.is_measured()
+                .cmp(&a.is_measured())
+                .then(rate(b).partial_cmp(&rate(a)).unwrap_or(std::cmp::Ordering::Equal))
+                .then(b.connection_count.cmp(&a.connection_count))
         });
+        bandwidth_consumers.truncate(10);
 
         Ok(bandwidth_consumers)
     }
@@ -482,7 +952,19 @@ impl NetworkMonitor {
     // Linux-specific implementation
     #[allow(dead_code)]
     async fn get_interfaces_linux(&mut self) -> Result<Vec<NetworkInterface>> {
-        let linux_interfaces = self.linux_sys.get_network_stats()?;
+        // Addresses and routes for every interface at once, rather than two
+        // commands per interface
+        let (linux_interfaces, addresses, routes) = self
+            .linux_sys
+            .blocking(|sys| -> Result<_> {
+                Ok((
+                    sys.get_network_stats()?,
+                    sys.output("ip", &["-o", "-4", "addr", "show"]).unwrap_or_default(),
+                    sys.output("ip", &["route"]).unwrap_or_default(),
+                ))
+            })
+            .await??;
+        let gateway = default_gateway(&routes);
 
         let current_time = std::time::Instant::now();
         let time_delta = if let Some(last_time) = self.last_timestamp {
@@ -504,20 +986,19 @@ impl NetworkMonitor {
                 bytes_sent: iface.tx_bytes,
             });
 
-            // Try to get IP address using ip command
-            let (ipv4, gateway) = self.get_ip_info_linux(&iface.name);
+            let ipv4 = interface_ipv4(&addresses, &iface.name);
 
             interfaces.push(NetworkInterface {
                 name: iface.name.clone(),
                 description: format!("Linux Network Interface {}", iface.name),
-                status: "Up".to_string(),
+                status: operstate_label(&iface.operstate),
                 link_speed: "Unknown".to_string(),
                 mac_address: "00:00:00:00:00:00".to_string(),
                 mtu: 1500,
                 duplex: "Full".to_string(),
                 ipv4_address: ipv4,
                 ipv6_address: "N/A".to_string(),
-                gateway,
+                gateway: gateway.clone(),
                 dns_servers: Vec::new(),
                 bytes_received: iface.rx_bytes,
                 bytes_sent: iface.tx_bytes,
@@ -525,6 +1006,7 @@ impl NetworkMonitor {
                 upload_speed,
                 peak_download,
                 peak_upload,
+                hidden: false,
             });
         }
 
@@ -534,82 +1016,38 @@ impl NetworkMonitor {
         Ok(interfaces)
     }
 
-    #[allow(dead_code)]
-    fn get_ip_info_linux(&self, interface: &str) -> (String, String) {
-        use std::process::Command;
-
-        // Get IPv4 address
-        let ipv4 = if let Ok(output) = Command::new("ip")
-            .args(&["addr", "show", interface])
-            .output()
-        {
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            if let Some(line) = stdout.lines().find(|l| l.contains("inet ")) {
-                let parts: Vec<&str> = line.split_whitespace().collect();
-                if parts.len() > 1 {
-                    parts[1].split('/').next().unwrap_or("N/A").to_string()
-                } else {
-                    "N/A".to_string()
-                }
-            } else {
-                "N/A".to_string()
-            }
-        } else {
-            "N/A".to_string()
-        };
-
-        // Get default gateway
-        let gateway = if let Ok(output) = Command::new("ip").args(&["route"]).output() {
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            if let Some(line) = stdout.lines().find(|l| l.contains("default")) {
-                let parts: Vec<&str> = line.split_whitespace().collect();
-                if let Some(pos) = parts.iter().position(|&p| p == "via") {
-                    if pos + 1 < parts.len() {
-                        parts[pos + 1].to_string()
-                    } else {
-                        "N/A".to_string()
-                    }
-                } else {
-                    "N/A".to_string()
-                }
-            } else {
-                "N/A".to_string()
-            }
-        } else {
-            "N/A".to_string()
-        };
-
-        (ipv4, gateway)
-    }
-
     #[allow(dead_code)]
     async fn get_connections_linux(&self) -> Result<Vec<NetworkConnection>> {
-        use std::fs;
-
         let mut connections = Vec::new();
 
-        // Read /proc/net/tcp for established connections
-        if let Ok(content) = fs::read_to_string("/proc/net/tcp") {
+        const TABLES: [(&str, &str); 4] = [
+            ("/proc/net/tcp", "TCP"),
+            ("/proc/net/tcp6", "TCP"),
+            ("/proc/net/udp", "UDP"),
+            ("/proc/net/udp6", "UDP"),
+        ];
+        let paths: Vec<String> = TABLES.iter().map(|(path, _)| path.to_string()).collect();
+        let contents = self.linux_sys.blocking(move |sys| sys.read_many(&paths)).await?;
+
+        for ((_, protocol), content) in TABLES.into_iter().zip(contents) {
+            let Some(content) = content else {
+                continue;
+            };
+
             for line in content.lines().skip(1) {
                 let parts: Vec<&str> = line.split_whitespace().collect();
                 if parts.len() < 10 {
                     continue;
                 }
 
-                // Parse connection state (0A = LISTEN, 01 = ESTABLISHED, etc.)
-                let state_hex = parts.get(3).unwrap_or(&"00");
-                if state_hex != &"01" {
-                    // Skip non-established connections
-                    continue;
-                }
-
-                // Parse local address:port
-                let local = parts.get(1).unwrap_or(&"00000000:0000");
-                let (local_addr, local_port) = self.parse_hex_address(local);
-
-                // Parse remote address:port
-                let remote = parts.get(2).unwrap_or(&"00000000:0000");
-                let (remote_addr, remote_port) = self.parse_hex_address(remote);
+                let (local_address, local_port) = self.parse_hex_address(parts[1]);
+                let (remote_address, remote_port) = self.parse_hex_address(parts[2]);
+                let (_, address_family) = normalize_address(&local_address);
+                let state = if protocol == "UDP" {
+                    "Bound".to_string()
+                } else {
+                    tcp_state_name(parts[3]).to_string()
+                };
 
                 // Parse inode to find process (simplified - would need to search /proc/*/fd/)
                 let uid = parts.get(7).and_then(|s| s.parse().ok()).unwrap_or(0);
@@ -617,21 +1055,18 @@ impl NetworkMonitor {
                 connections.push(NetworkConnection {
                     process_name: "unknown".to_string(),
                     pid: uid, // Using UID as placeholder
-                    protocol: "TCP".to_string(),
-                    local_address: local_addr,
+                    protocol: protocol.to_string(),
+                    local_address,
                     local_port,
-                    remote_address: remote_addr,
+                    remote_address,
                     remote_port,
-                    state: "ESTABLISHED".to_string(),
+                    state,
+                    address_family,
                 });
-
-                if connections.len() >= 10 {
-                    break;
-                }
             }
         }
 
-        Ok(connections)
+        Ok(dedup_connections(connections))
     }
 
     #[allow(dead_code)]
@@ -653,12 +1088,197 @@ impl NetworkMonitor {
             );
             let port = u16::from_str_radix(parts[1], 16).unwrap_or(0);
             (ip, port)
+        } else if ip_hex.len() == 32 {
+            // IPv6: four 32-bit words, each in host (little-endian) order
+            let mut octets = [0u8; 16];
+            for (word, chunk) in octets.chunks_mut(4).enumerate() {
+                for (i, octet) in chunk.iter_mut().enumerate() {
+                    let start = word * 8 + (3 - i) * 2;
+                    *octet = u8::from_str_radix(&ip_hex[start..start + 2], 16).unwrap_or(0);
+                }
+            }
+            let ip = std::net::Ipv6Addr::from(octets).to_string();
+            let port = u16::from_str_radix(parts[1], 16).unwrap_or(0);
+            (ip, port)
         } else {
             ("0.0.0.0".to_string(), 0)
         }
     }
 }
 
+/// Drop repeated endpoints, keeping the first (pid, local, remote, protocol).
+fn dedup_connections(connections: Vec<NetworkConnection>) -> Vec<NetworkConnection> {
+    let mut seen = std::collections::HashSet::new();
+    connections
+        .into_iter()
+        .filter(|conn| {
+            seen.insert((
+                conn.pid,
+                conn.local_address.clone(),
+                conn.local_port,
+                conn.remote_address.clone(),
+                conn.remote_port,
+                conn.protocol.clone(),
+            ))
+        })
+        .collect()
+}
+
+/// Map a `/proc/net/tcp` state code to the names `Get-NetTCPConnection` uses.
+#[allow(dead_code)]
+fn tcp_state_name(code: &str) -> &'static str {
+    match code {
+        "01" => "Established",
+        "02" => "SynSent",
+        "03" => "SynReceived",
+        "04" => "FinWait1",
+        "05" => "FinWait2",
+        "06" => "TimeWait",
+        "07" => "Closed",
+        "08" => "CloseWait",
+        "09" => "LastAck",
+        "0A" => "Listen",
+        "0B" => "Closing",
+        _ => "Unknown",
+    }
+}
+
+async fn ping_target(ps: &PowerShellExecutor, target: &str) -> Option<f64> {
+    // A leading '-' would be taken as an option by ping
+    if target.trim().is_empty() || target.starts_with('-') {
+        return None;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let _ = ps;
+        ping_linux(target).await
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        ping_windows(ps, target).await
+    }
+}
+
+/// `up` -> `Up`, matching the Windows adapter status.
+#[allow(dead_code)]
+fn operstate_label(operstate: &str) -> String {
+    let mut chars = operstate.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => "Unknown".to_string(),
+    }
+}
+
+/// First IPv4 address of `interface` in `ip -o -4 addr show` output.
+#[allow(dead_code)]
+fn interface_ipv4(addresses: &str, interface: &str) -> String {
+    addresses
+        .lines()
+        .find_map(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            match parts[..] {
+                [_, name, "inet", address, ..] if name == interface => {
+                    address.split('/').next().map(str::to_string)
+                }
+                _ => None,
+            }
+        })
+        .unwrap_or_else(|| "N/A".to_string())
+}
+
+/// Next hop of the default route in `ip route` output.
+#[allow(dead_code)]
+fn default_gateway(routes: &str) -> String {
+    routes
+        .lines()
+        .find(|line| line.starts_with("default"))
+        .and_then(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            let pos = parts.iter().position(|&p| p == "via")?;
+            parts.get(pos + 1).map(|gateway| gateway.to_string())
+        })
+        .unwrap_or_else(|| "N/A".to_string())
+}
+
+/// Run `program` locally or on the remote host, failing with its stderr.
+#[allow(dead_code)]
+async fn run_tool(remote: Option<&SshTarget>, program: &str, args: &[&str]) -> Result<String> {
+    let mut command = match remote {
+        Some(target) => {
+            target.check_connection()?;
+            target.tokio_command(program, args)
+        }
+        None => {
+            let mut command = tokio::process::Command::new(program);
+            command.args(args);
+            command
+        }
+    };
+    let output = command
+        .output()
+        .await
+        .with_context(|| format!("Failed to run {}", program))?;
+    if let Some(target) = remote {
+        target.record_status(&output.status);
+    }
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("{}", stderr.trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[allow(dead_code)]
+async fn ping_linux(target: &str) -> Option<f64> {
+    let output = tokio::process::Command::new("ping")
+        .args(["-c", "1", "-W", &PING_TIMEOUT_SECS.to_string(), target])
+        .kill_on_drop(true)
+        .output();
+    let output = tokio::time::timeout(Duration::from_secs(PING_TIMEOUT_SECS + 1), output)
+        .await
+        .ok()?
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_ping_rtt(&String::from_utf8_lossy(&output.stdout))
+}
+
+#[allow(dead_code)]
+async fn ping_windows(ps: &PowerShellExecutor, target: &str) -> Option<f64> {
+    // Windows PowerShell reports ResponseTime; PowerShell 7 reports Latency
+    // and returns a non-Success status instead of an error on timeout.
+    let script = format!(
+        r#"
+        $r = Test-Connection -ComputerName '{}' -Count 1 -ErrorAction SilentlyContinue | Select-Object -First 1
+        if ($r) {{
+            if ($null -ne $r.Latency) {{
+                if ("$($r.Status)" -eq 'Success') {{ $r.Latency }}
+            }} else {{
+                $r.ResponseTime
+            }}
+        }}
+        "#,
+        target.replace('\'', "''")
+    );
+    let output = ps.execute_uncached(&script).await.ok()?;
+    output.trim().parse::<f64>().ok()
+}
+
+/// Extract the round-trip time from `ping` output (`time=12.3 ms`,
+/// `time<1ms`).
+fn parse_ping_rtt(output: &str) -> Option<f64> {
+    let start = output.find("time=").or_else(|| output.find("time<"))? + 5;
+    let value: String = output[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    value.parse().ok()
+}
+
 #[derive(Debug, Deserialize)]
 #[allow(non_snake_case)]
 struct InterfaceData {
@@ -692,8 +1312,193 @@ struct ConnectionData {
 
 #[derive(Debug, Deserialize)]
 #[allow(non_snake_case)]
-struct ProcessBandwidthData {
-    ProcessName: String,
+struct ConnectionBandwidthData {
     PID: u32,
-    ConnectionCount: u32,
+    ProcessName: String,
+    Connection: String,
+    BytesIn: Option<u64>,
+    BytesOut: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An idle, visible interface that is up
+    fn interface(name: &str) -> NetworkInterface {
+        NetworkInterface {
+            name: name.to_string(),
+            description: String::new(),
+            status: "Up".to_string(),
+            link_speed: String::new(),
+            mac_address: String::new(),
+            mtu: 1500,
+            duplex: String::new(),
+            ipv4_address: String::new(),
+            ipv6_address: String::new(),
+            gateway: String::new(),
+            dns_servers: Vec::new(),
+            bytes_received: 0,
+            bytes_sent: 0,
+            download_speed: 0.0,
+            upload_speed: 0.0,
+            peak_download: 0.0,
+            peak_upload: 0.0,
+            hidden: false,
+        }
+    }
+
+    #[test]
+    fn reads_addresses_and_gateway_from_ip_output() {
+        let addresses = "1: lo    inet 127.0.0.1/8 scope host lo\n\
+2: eth0    inet 192.168.1.20/24 brd 192.168.1.255 scope global eth0\n";
+        assert_eq!(interface_ipv4(addresses, "eth0"), "192.168.1.20");
+        assert_eq!(interface_ipv4(addresses, "wlan0"), "N/A");
+
+        let routes = "default via 192.168.1.1 dev eth0 proto dhcp\n192.168.1.0/24 dev eth0\n";
+        assert_eq!(default_gateway(routes), "192.168.1.1");
+        assert_eq!(default_gateway(""), "N/A");
+    }
+
+    #[test]
+    fn operstate_matches_windows_status() {
+        assert_eq!(operstate_label("up"), "Up");
+        assert_eq!(operstate_label("down"), "Down");
+        assert_eq!(operstate_label(""), "Unknown");
+    }
+
+    #[test]
+    fn parses_ping_rtt() {
+        let linux = "64 bytes from 1.1.1.1: icmp_seq=1 ttl=57 time=12.4 ms\n";
+        assert_eq!(parse_ping_rtt(linux), Some(12.4));
+        assert_eq!(parse_ping_rtt("Reply from 8.8.8.8: bytes=32 time<1ms TTL=118"), Some(1.0));
+        assert_eq!(parse_ping_rtt("1 packets transmitted, 0 received, 100% packet loss"), None);
+    }
+
+    #[test]
+    fn bandwidth_uses_per_connection_deltas() {
+        let ps = PowerShellExecutor::new("powershell.exe".to_string(), 1, 0, false);
+        let mut monitor = NetworkMonitor::new(ps).unwrap();
+        let sample = |bytes_in: u64| {
+            format!(
+                r#"[{{"PID":10,"ProcessName":"app","Connection":"a","BytesIn":{},"BytesOut":0}},
+                    {{"PID":20,"ProcessName":"other","Connection":"b","BytesIn":null,"BytesOut":null}}]"#,
+                bytes_in
+            )
+        };
+
+        let first = monitor.parse_bandwidth_consumers(&sample(1_000)).unwrap();
+        assert!(first[0].is_measured());
+        assert_eq!(first[0].download_speed, None);
+        assert!(!first[1].is_measured());
+
+        monitor.last_bandwidth_sample = Some(Instant::now() - Duration::from_secs(1));
+        let second = monitor.parse_bandwidth_consumers(&sample(126_000)).unwrap();
+        let app = &second[0];
+        assert_eq!(app.pid, 10);
+        assert_eq!(app.total_bytes_received, Some(126_000));
+        let mbps = app.download_speed.unwrap();
+        assert!((0.9..=1.0).contains(&mbps), "unexpected rate {mbps}");
+        assert_eq!(second[1].download_speed, None);
+    }
+
+    #[test]
+    fn traffic_history_is_kept_per_interface() {
+        let ps = PowerShellExecutor::new("powershell.exe".to_string(), 1, 0, false);
+        let mut monitor = NetworkMonitor::new(ps).unwrap();
+        let iface = |name: &str, download_speed: f64, hidden: bool| NetworkInterface {
+            download_speed,
+            hidden,
+            ..interface(name)
+        };
+
+        monitor.record_traffic(&[iface("eth0", 10.0, false), iface("docker0", 5.0, true)]);
+        monitor.record_traffic(&[iface("eth0", 20.0, false)]);
+
+        let aggregate: Vec<_> = monitor.traffic_history.iter().map(|s| s.all_download_mbps).collect();
+        assert_eq!(aggregate, vec![15.0, 20.0]);
+        assert_eq!(monitor.traffic_history[0].download_mbps, 10.0);
+        let eth0: Vec<_> = monitor.interface_history["eth0"]
+            .iter()
+            .map(|s| s.download_mbps)
+            .collect();
+        assert_eq!(eth0, vec![10.0, 20.0]);
+        assert!(!monitor.interface_history.contains_key("docker0"));
+
+        for _ in 0..TRAFFIC_HISTORY_LEN {
+            monitor.record_traffic(&[iface("eth0", 1.0, false)]);
+        }
+        assert_eq!(monitor.interface_history["eth0"].len(), TRAFFIC_HISTORY_LEN);
+
+        monitor.set_history_len(5);
+        monitor.record_traffic(&[iface("eth0", 1.0, false)]);
+        assert_eq!(monitor.traffic_history.len(), 5);
+        assert_eq!(monitor.interface_history["eth0"].len(), 5);
+    }
+
+    #[test]
+    fn normalizes_ipv6_and_keeps_zone() {
+        assert_eq!(
+            normalize_address("fe80:0000:0000:0000:0202:b3ff:fe1e:8329%12"),
+            ("fe80::202:b3ff:fe1e:8329%12".to_string(), AddressFamily::IPv6)
+        );
+        assert_eq!(
+            normalize_address("192.168.1.10"),
+            ("192.168.1.10".to_string(), AddressFamily::IPv4)
+        );
+        assert_eq!(normalize_address("*").1, AddressFamily::IPv4);
+        assert_eq!(
+            format_endpoint("::1", 443, AddressFamily::IPv6),
+            "[::1]:443"
+        );
+    }
+
+    #[test]
+    fn interface_filter_hides_excluded_and_virtual() {
+        let iface = |name: &str, description: &str| NetworkInterface {
+            description: description.to_string(),
+            ..interface(name)
+        };
+        let ethernet = iface("Ethernet", "Intel(R) Ethernet Connection I219-V");
+        let hyperv = iface("vEthernet (WSL)", "Hyper-V Virtual Ethernet Adapter");
+        let wifi = iface("Wi-Fi", "Intel(R) Wi-Fi 6 AX201");
+
+        let filter = InterfaceFilter::new(&InterfaceFilterConfig {
+            include: Vec::new(),
+            exclude: vec!["^wi-fi$".to_string(), "[invalid".to_string()],
+            hide_virtual: true,
+        });
+        assert!(!filter.hides(&ethernet));
+        assert!(filter.hides(&hyperv));
+        assert!(filter.hides(&wifi));
+        assert!(filter.hides(&iface("lo", "Linux Network Interface lo")));
+
+        let filter = InterfaceFilter::new(&InterfaceFilterConfig {
+            include: vec!["i219".to_string()],
+            ..Default::default()
+        });
+        assert!(!filter.hides(&ethernet));
+        assert!(filter.hides(&wifi));
+        assert!(!InterfaceFilter::default().hides(&hyperv));
+    }
+
+    #[test]
+    fn connections_keep_protocol_and_dedup() {
+        let ps = PowerShellExecutor::new("powershell.exe".to_string(), 1, 0, false);
+        let monitor = NetworkMonitor::new(ps).unwrap();
+        let output = r#"[
+            {"ProcessName":"svc","PID":4,"Protocol":"TCP","LocalAddress":"0.0.0.0","LocalPort":445,"RemoteAddress":"0.0.0.0","RemotePort":0,"State":"Listen"},
+            {"ProcessName":"svc","PID":4,"Protocol":"TCP","LocalAddress":"0.0.0.0","LocalPort":445,"RemoteAddress":"0.0.0.0","RemotePort":0,"State":"Listen"},
+            {"ProcessName":"svc","PID":4,"Protocol":"UDP","LocalAddress":"0.0.0.0","LocalPort":445,"RemoteAddress":"*","RemotePort":0,"State":"Bound"}
+        ]"#;
+
+        let connections = monitor.parse_connections(output).unwrap();
+        assert_eq!(connections.len(), 2);
+        assert_eq!(connections[1].protocol, "UDP");
+
+        assert_eq!(
+            monitor.parse_hex_address("00000000000000000000000001000000:0016"),
+            ("::1".to_string(), 22)
+        );
+    }
 }
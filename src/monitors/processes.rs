@@ -4,6 +4,8 @@ use crate::integrations::{PowerShellExecutor, LinuxSysMonitor};
 use crate::utils::parse_json_array;
 use parking_lot::Mutex;
 use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,6 +16,8 @@ pub struct ProcessData {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProcessEntry {
     pub pid: u32,
+    #[serde(default)]
+    pub parent_pid: Option<u32>,
     pub name: String,
     pub cpu_usage: f32,
     pub memory: u64,
@@ -26,24 +30,120 @@ pub struct ProcessEntry {
     pub io_write_bytes: u64,
 }
 
+/// Facts about one process gathered when its detail popup opens, too costly
+/// to collect for every process on every refresh. Anything the platform or
+/// our privileges don't allow is `None`.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessDetails {
+    pub executable_path: Option<String>,
+    pub command_line: Option<String>,
+    pub parent_pid: Option<u32>,
+    pub start_time: Option<String>,
+    pub threads: Option<u32>,
+    /// Windows handles; open file descriptors on Linux
+    pub handles: Option<u32>,
+    pub working_set: Option<u64>,
+    pub private_bytes: Option<u64>,
+    /// Loaded DLLs (Windows only)
+    pub module_count: Option<u32>,
+}
+
+const PROCESS_DETAILS_SCRIPT: &str = r#"
+$cim = Get-CimInstance Win32_Process -Filter "ProcessId={pid}" -ErrorAction Stop
+if (-not $cim) { throw "Process {pid} has exited" }
+$modules = $null
+try { $modules = (Get-Process -Id {pid} -ErrorAction Stop).Modules.Count } catch {}
+[PSCustomObject]@{
+    ExecutablePath = $cim.ExecutablePath
+    CommandLine = $cim.CommandLine
+    ParentProcessId = $cim.ParentProcessId
+    CreationDate = if ($cim.CreationDate) { $cim.CreationDate.ToString('o') } else { $null }
+    ThreadCount = $cim.ThreadCount
+    HandleCount = $cim.HandleCount
+    WorkingSetSize = [uint64]$cim.WorkingSetSize
+    PrivatePageCount = [uint64]$cim.PrivatePageCount
+    ModuleCount = $modules
+} | ConvertTo-Json -Compress
+"#;
+
 pub struct ProcessMonitor {
     ps: PowerShellExecutor,
     #[allow(dead_code)]
     linux_sys: LinuxSysMonitor,
-    last_cpu_times: Mutex<HashMap<u32, f64>>,
+    /// Cumulative CPU seconds per PID, with the start time so a reused PID
+    /// isn't diffed against the previous process. Windows only.
+    #[allow(dead_code)]
+    last_cpu_times: Mutex<HashMap<u32, (Option<String>, f64)>>,
+    #[allow(dead_code)]
     last_timestamp: Mutex<Option<Instant>>,
 }
 
 impl ProcessMonitor {
     pub fn new(ps: PowerShellExecutor) -> Result<Self> {
         Ok(Self {
+            linux_sys: LinuxSysMonitor::new().with_remote(ps.remote().cloned()),
             ps,
-            linux_sys: LinuxSysMonitor::new(),
             last_cpu_times: Mutex::new(HashMap::new()),
             last_timestamp: Mutex::new(None),
         })
     }
 
+    pub async fn kill_process(&self, pid: u32) -> Result<()> {
+        #[cfg(target_os = "linux")]
+        {
+            self.linux_sys.blocking(move |sys| sys.kill_process(pid)).await?
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            let script = format!("Stop-Process -Id {} -Force -ErrorAction Stop", pid);
+            self.ps.execute_uncached(&script).await?;
+            Ok(())
+        }
+    }
+
+    /// Look up the fields shown in the process detail popup.
+    pub async fn get_details(&self, pid: u32) -> Result<ProcessDetails> {
+        #[cfg(target_os = "linux")]
+        let details = self.get_details_linux(pid).await;
+        #[cfg(not(target_os = "linux"))]
+        let details = self.get_details_windows(pid).await;
+        details
+    }
+
+    #[allow(dead_code)]
+    async fn get_details_linux(&self, pid: u32) -> Result<ProcessDetails> {
+        let info = self
+            .linux_sys
+            .blocking(move |sys| sys.get_process_details(pid))
+            .await??;
+        Ok(ProcessDetails {
+            executable_path: info.executable,
+            command_line: info.command_line,
+            parent_pid: info.parent_pid,
+            start_time: info.started_at.and_then(|secs| {
+                let started = chrono::DateTime::from_timestamp(secs as i64, 0)?;
+                Some(started.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string())
+            }),
+            threads: info.threads,
+            handles: info.open_files,
+            working_set: info.resident,
+            private_bytes: info.private,
+            module_count: None,
+        })
+    }
+
+    #[allow(dead_code)]
+    async fn get_details_windows(&self, pid: u32) -> Result<ProcessDetails> {
+        let script = PROCESS_DETAILS_SCRIPT.replace("{pid}", &pid.to_string());
+        let output = self.ps.execute_uncached(&script).await?;
+        let sample = parse_json_array::<ProcessDetailsSample>(&output)
+            .context("Failed to parse process details")?
+            .pop()
+            .ok_or_else(|| anyhow::anyhow!("Process {} has exited", pid))?;
+        Ok(sample.into())
+    }
+
     pub async fn collect_data(&mut self) -> Result<ProcessData> {
         #[cfg(target_os = "linux")]
         {
@@ -58,12 +158,13 @@ impl ProcessMonitor {
 
     #[allow(dead_code)]
     async fn collect_data_linux(&self) -> Result<ProcessData> {
-        let linux_processes = self.linux_sys.get_processes()?;
+        let linux_processes = self.linux_sys.blocking(|sys| sys.get_processes()).await??;
 
         let processes: Vec<ProcessEntry> = linux_processes
             .into_iter()
             .map(|p| ProcessEntry {
                 pid: p.pid,
+                parent_pid: p.parent_pid,
                 name: p.name,
                 cpu_usage: 0.0,  // Will calculate later
                 memory: p.memory,
@@ -137,6 +238,7 @@ impl ProcessMonitor {
 
                 [PSCustomObject]@{
                     Id = $_.Id
+                    ParentId = if ($cim) { $cim.ParentProcessId } else { $null }
                     ProcessName = $_.ProcessName
                     CpuPercent = if ($null -ne $cpu) { [double]$cpu } else { 0.0 }
                     CpuTimeSeconds = if ($null -ne $_.CPU) { [double]$_.CPU } else { 0.0 }
@@ -184,30 +286,24 @@ impl ProcessMonitor {
 
         for sample in samples {
             let cpu_time = sample.CpuTimeSeconds.unwrap_or(0.0);
-            current_cpu_times.insert(sample.Id, cpu_time);
-
-            let mut cpu_usage = sample.CpuPercent.unwrap_or(0.0);
-            if time_delta > 0.0 {
-                if let Some(prev) = last_cpu_times.get(&sample.Id) {
-                    let delta = (cpu_time - prev).max(0.0);
-                    let computed = (delta / time_delta) * 100.0 / cpu_count;
-                    if computed.is_finite() {
-                        cpu_usage = computed;
-                    }
-                }
-            }
-
-            if !cpu_usage.is_finite() || cpu_usage < 0.0 {
-                cpu_usage = 0.0;
-            }
-            if cpu_usage > 100.0 {
-                cpu_usage = 100.0;
-            }
+            let previous = last_cpu_times
+                .get(&sample.Id)
+                .filter(|(start_time, _)| *start_time == sample.StartTime)
+                .map(|&(_, seconds)| seconds);
+            let cpu_usage = cpu_percent(
+                previous,
+                cpu_time,
+                time_delta,
+                sample.CpuPercent.unwrap_or(0.0),
+                cpu_count,
+            );
+            current_cpu_times.insert(sample.Id, (sample.StartTime.clone(), cpu_time));
 
             let user = normalize_user(sample.User, sample.SessionId);
 
             entries.push(ProcessEntry {
                 pid: sample.Id,
+                parent_pid: sample.ParentId.filter(|&ppid| ppid != 0 && ppid != sample.Id),
                 name: sample.ProcessName,
                 cpu_usage: cpu_usage as f32,
                 memory: sample.Memory.unwrap_or(0),
@@ -228,6 +324,32 @@ impl ProcessMonitor {
     }
 }
 
+/// Share of the whole machine a process used since the last refresh: the
+/// growth of its cumulative CPU seconds over the wall-clock interval, divided
+/// by the logical processor count. Without a previous sample (first refresh
+/// or new process) the perf counter's value is used instead; it is per core,
+/// so it gets the same normalization.
+#[allow(dead_code)]
+pub(crate) fn cpu_percent(
+    previous_cpu_time: Option<f64>,
+    cpu_time: f64,
+    time_delta: f64,
+    counter_percent: f64,
+    cpu_count: f64,
+) -> f64 {
+    let core_percent = match previous_cpu_time {
+        Some(prev) if time_delta > 0.0 => (cpu_time - prev).max(0.0) / time_delta * 100.0,
+        _ => counter_percent,
+    };
+    let usage = core_percent / cpu_count;
+
+    if usage.is_finite() {
+        usage.clamp(0.0, 100.0)
+    } else {
+        0.0
+    }
+}
+
 fn normalize_user(user: Option<String>, session_id: Option<u32>) -> String {
     if let Some(value) = user {
         let trimmed = value.trim();
@@ -243,10 +365,48 @@ fn normalize_user(user: Option<String>, session_id: Option<u32>) -> String {
     }
 }
 
+#[derive(Serialize)]
+struct ProcessExport<'a> {
+    pid: u32,
+    name: &'a str,
+    cpu: f32,
+    memory: u64,
+    threads: usize,
+    user: &'a str,
+}
+
+/// Write `processes` (already filtered and sorted for display) to
+/// `processes-<timestamp>.jsonl` in `dir`, one JSON object per line.
+pub fn export_processes_jsonl<'a>(
+    processes: impl IntoIterator<Item = &'a ProcessEntry>,
+    dir: &Path,
+) -> Result<PathBuf> {
+    let mut contents = String::new();
+    for process in processes {
+        contents.push_str(&serde_json::to_string(&ProcessExport {
+            pid: process.pid,
+            name: &process.name,
+            cpu: process.cpu_usage,
+            memory: process.memory,
+            threads: process.threads,
+            user: &process.user,
+        })?);
+        contents.push('\n');
+    }
+
+    let path = dir.join(format!(
+        "processes-{}.jsonl",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    fs::write(&path, contents).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
+
 #[derive(Debug, Deserialize)]
 #[allow(non_snake_case)]
 struct ProcessSample {
     Id: u32,
+    ParentId: Option<u32>,
     ProcessName: String,
     CpuPercent: Option<f64>,
     CpuTimeSeconds: Option<f64>,
@@ -260,3 +420,50 @@ struct ProcessSample {
     IOReadBytes: Option<u64>,
     IOWriteBytes: Option<u64>,
 }
+
+#[derive(Debug, Deserialize)]
+#[allow(non_snake_case, dead_code)]
+struct ProcessDetailsSample {
+    ExecutablePath: Option<String>,
+    CommandLine: Option<String>,
+    ParentProcessId: Option<u32>,
+    CreationDate: Option<String>,
+    ThreadCount: Option<u32>,
+    HandleCount: Option<u32>,
+    WorkingSetSize: Option<u64>,
+    PrivatePageCount: Option<u64>,
+    ModuleCount: Option<u32>,
+}
+
+impl From<ProcessDetailsSample> for ProcessDetails {
+    fn from(sample: ProcessDetailsSample) -> Self {
+        Self {
+            executable_path: sample.ExecutablePath,
+            command_line: sample.CommandLine,
+            parent_pid: sample.ParentProcessId.filter(|&ppid| ppid != 0),
+            start_time: sample.CreationDate,
+            threads: sample.ThreadCount,
+            handles: sample.HandleCount,
+            working_set: sample.WorkingSetSize,
+            // Win32_Process names it a page count but reports bytes
+            private_bytes: sample.PrivatePageCount,
+            module_count: sample.ModuleCount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cpu_percent;
+
+    #[test]
+    fn cpu_percent_is_share_of_all_cores() {
+        // 2 CPU seconds over 1 s on 4 cores is half the machine
+        assert_eq!(cpu_percent(Some(10.0), 12.0, 1.0, 0.0, 4.0), 50.0);
+        // First sample: the per-core counter is normalized the same way
+        assert_eq!(cpu_percent(None, 12.0, 1.0, 200.0, 4.0), 50.0);
+        // A restarted process reports less CPU time than before
+        assert_eq!(cpu_percent(Some(10.0), 1.0, 1.0, 0.0, 4.0), 0.0);
+        assert_eq!(cpu_percent(Some(0.0), 100.0, 1.0, 0.0, 4.0), 100.0);
+    }
+}
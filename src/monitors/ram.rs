@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use crate::integrations::{PowerShellExecutor, LinuxSysMonitor};
+use std::collections::VecDeque;
+use crate::integrations::{linux_sys, PowerShellExecutor, LinuxSysMonitor};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RamData {
@@ -11,6 +12,12 @@ pub struct RamData {
     pub free: u64,
     pub speed: String,
     pub type_name: String,
+    /// Populated DIMM slots
+    #[serde(default)]
+    pub modules: Vec<MemoryModule>,
+    /// Slots on the board (populated or not), when the firmware reports it
+    #[serde(default)]
+    pub total_slots: Option<u32>,
 
     // Memory Breakdown
     pub in_use: u64,
@@ -29,6 +36,11 @@ pub struct RamData {
     pub pagefiles: Vec<PagefileInfo>,
     pub total_pagefile_size: u64,
     pub total_pagefile_used: u64,
+
+    // History (percent samples, oldest first)
+    pub usage_history: VecDeque<f64>,
+    pub commit_history: VecDeque<f64>,
+    pub pagefile_history: VecDeque<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -39,6 +51,17 @@ pub struct ProcessMemoryInfo {
     pub private_bytes: u64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryModule {
+    pub slot: String,
+    pub capacity: u64,
+    /// Configured speed in MT/s (reported as MHz by WMI)
+    pub speed: Option<u32>,
+    pub manufacturer: String,
+    pub part_number: String,
+    pub memory_type: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PagefileInfo {
     pub name: String,
@@ -78,7 +101,7 @@ const PHYSICAL_MEMORY_SCRIPT: &str = r#"
     try {
         $modules = Get-CimInstance Win32_PhysicalMemory -ErrorAction Stop
         if (-not $modules) {
-            [PSCustomObject]@{ Speed = "Unknown"; MemoryType = "Unknown"; Modules = @() } | ConvertTo-Json
+            [PSCustomObject]@{ Speed = "Unknown"; MemoryType = "Unknown"; Modules = @(); TotalSlots = $null } | ConvertTo-Json
             return
         }
 
@@ -141,13 +164,21 @@ const PHYSICAL_MEMORY_SCRIPT: &str = r#"
         $speeds = $list | ForEach-Object { $_.Speed } | Where-Object { $_ -ne $null } | Sort-Object -Unique
         $speedSummary = if ($speeds.Count -eq 0) { "Unknown" } elseif ($speeds.Count -eq 1) { "$($speeds[0]) MHz" } else { "$($speeds[0])-$($speeds[-1]) MHz" }
 
+        $totalSlots = $null
+        $arrays = Get-CimInstance Win32_PhysicalMemoryArray -ErrorAction SilentlyContinue |
+            Where-Object { $_.Use -eq 3 }
+        if ($arrays) {
+            $totalSlots = [uint32](($arrays | Measure-Object -Property MemoryDevices -Sum).Sum)
+        }
+
         [PSCustomObject]@{
             Speed = $speedSummary
             MemoryType = $typeSummary
-            Modules = $list
+            Modules = @($list)
+            TotalSlots = $totalSlots
         } | ConvertTo-Json -Depth 4
     } catch {
-        [PSCustomObject]@{ Speed = "Unknown"; MemoryType = "Unknown"; Modules = @() } | ConvertTo-Json
+        [PSCustomObject]@{ Speed = "Unknown"; MemoryType = "Unknown"; Modules = @(); TotalSlots = $null } | ConvertTo-Json
     }
 "#;
 
@@ -293,45 +324,111 @@ const PAGEFILE_SCRIPT: &str = r#"
 impl RamMonitor {
     pub fn new(ps: PowerShellExecutor) -> Result<Self> {
         Ok(Self {
+            linux_sys: LinuxSysMonitor::new().with_remote(ps.remote().cloned()),
             ps,
-            linux_sys: LinuxSysMonitor::new(),
         })
     }
 
     pub async fn collect_data(&self) -> Result<RamData> {
+        // Check if we're on Linux - use linux_sys, otherwise use PowerShell
         #[cfg(target_os = "linux")]
         {
-            return self.collect_data_linux().await;
+            self.collect_data_linux().await
         }
 
         #[cfg(not(target_os = "linux"))]
         {
-            return self.collect_data_windows().await;
+            self.collect_data_windows().await
         }
     }
 
+    /// The ten largest processes by resident memory, as on Windows.
+    #[allow(dead_code)]
+    fn top_processes_linux(processes: Result<Vec<linux_sys::ProcessInfo>>) -> Vec<ProcessMemoryInfo> {
+        let mut processes = match processes {
+            Ok(processes) => processes,
+            Err(e) => {
+                log::debug!("Failed to list processes: {}", e);
+                return Vec::new();
+            }
+        };
+        processes.sort_by_key(|p| std::cmp::Reverse(p.memory));
+
+        processes
+            .into_iter()
+            .take(10)
+            .map(|p| ProcessMemoryInfo {
+                pid: p.pid,
+                name: p.name,
+                working_set: p.memory,
+                private_bytes: p.private_memory,
+            })
+            .collect()
+    }
+
     #[allow(dead_code)]
     async fn collect_data_linux(&self) -> Result<RamData> {
-        let mem_info = self.linux_sys.get_memory_info()?;
+        let (mem_info, processes) = self
+            .linux_sys
+            .blocking(|sys| -> Result<_> { Ok((sys.get_memory_info()?, sys.get_processes())) })
+            .await??;
+
+        // Fall back to physical + swap when the kernel doesn't report a commit limit
+        let commit_limit = if mem_info.commit_limit > 0 {
+            mem_info.commit_limit
+        } else {
+            mem_info.total + mem_info.swap_total
+        };
+        let commit_percent = if commit_limit > 0 {
+            mem_info.committed as f64 / commit_limit as f64 * 100.0
+        } else {
+            0.0
+        };
+
+        // Swap stands in for the Windows pagefile
+        let pagefiles = if mem_info.swap_total > 0 {
+            vec![PagefileInfo {
+                name: String::from("swap"),
+                total_size: mem_info.swap_total,
+                current_usage: mem_info.swap_used,
+                peak_usage: 0,
+                usage_percent: mem_info.swap_used as f64 / mem_info.swap_total as f64 * 100.0,
+            }]
+        } else {
+            Vec::new()
+        };
 
         Ok(RamData {
             total: mem_info.total,
             used: mem_info.used,
             available: mem_info.available,
-            cached: mem_info.cached,
+            cached: mem_info.cached + mem_info.buffers,
             free: mem_info.free,
             speed: String::from("Unknown"),
-            type_name: String::from("DDR4"),
+            type_name: String::from("Unknown"),
+            modules: Vec::new(),
+            total_slots: None,
+
+            // Memory Breakdown (no standby/modified lists on Linux)
             in_use: mem_info.used,
             standby: 0,
             modified: 0,
-            committed: mem_info.used,
-            commit_limit: mem_info.total + mem_info.swap_total,
-            commit_percent: (mem_info.used as f64 / mem_info.total as f64) * 100.0,
-            top_processes: Vec::new(),
-            pagefiles: Vec::new(),
+
+            // Committed Memory
+            committed: mem_info.committed,
+            commit_limit,
+            commit_percent,
+
+            top_processes: Self::top_processes_linux(processes),
+
+            // Pagefile Information
+            pagefiles,
             total_pagefile_size: mem_info.swap_total,
             total_pagefile_used: mem_info.swap_used,
+
+            usage_history: VecDeque::new(),
+            commit_history: VecDeque::new(),
+            pagefile_history: VecDeque::new(),
         })
     }
 
@@ -367,6 +464,8 @@ impl RamMonitor {
             free: detailed_memory.free(),
             speed: physical_memory.speed,
             type_name: physical_memory.memory_type,
+            modules: physical_memory.modules,
+            total_slots: physical_memory.total_slots,
 
             // Memory Breakdown
             in_use: detailed_memory.in_use(),
@@ -385,6 +484,10 @@ impl RamMonitor {
             pagefiles,
             total_pagefile_size,
             total_pagefile_used,
+
+            usage_history: VecDeque::new(),
+            commit_history: VecDeque::new(),
+            pagefile_history: VecDeque::new(),
         })
     }
 
@@ -397,9 +500,17 @@ impl RamMonitor {
         let info: PhysicalMemory = serde_json::from_str(trimmed)
             .context("Failed to parse physical memory info")?;
 
+        // Speed and type are still worth showing when the module list is odd
+        let modules = parse_memory_modules(&info.Modules).unwrap_or_else(|e| {
+            log::warn!("Ignoring memory modules: {:#}", e);
+            Vec::new()
+        });
+
         Ok(PhysicalMemoryInfo {
             speed: info.Speed,
             memory_type: info.MemoryType,
+            modules,
+            total_slots: info.TotalSlots.filter(|&slots| slots > 0),
         })
     }
 
@@ -481,12 +592,52 @@ struct Win32OperatingSystem {
 struct PhysicalMemory {
     Speed: String,
     MemoryType: String,
+    #[serde(default)]
+    Modules: serde_json::Value,
+    #[serde(default)]
+    TotalSlots: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(non_snake_case, dead_code)]
+struct PhysicalMemoryModule {
+    Slot: Option<String>,
+    Manufacturer: Option<String>,
+    PartNumber: Option<String>,
+    #[serde(default)]
+    Capacity: u64,
+    Speed: Option<u32>,
+    MemoryType: Option<String>,
+}
+
+/// Populated slots from the `Modules` field; a single DIMM may still come back
+/// as a bare object.
+#[allow(dead_code)]
+fn parse_memory_modules(modules: &serde_json::Value) -> Result<Vec<MemoryModule>> {
+    let modules: Vec<PhysicalMemoryModule> =
+        crate::utils::json::parse_json_array(&modules.to_string())
+            .context("Failed to parse memory modules")?;
+
+    Ok(modules
+        .into_iter()
+        .filter(|m| m.Capacity > 0)
+        .map(|m| MemoryModule {
+            slot: m.Slot.unwrap_or_default(),
+            capacity: m.Capacity,
+            speed: m.Speed.filter(|&speed| speed > 0),
+            manufacturer: m.Manufacturer.unwrap_or_default(),
+            part_number: m.PartNumber.unwrap_or_default(),
+            memory_type: m.MemoryType.unwrap_or_else(|| "Unknown".to_string()),
+        })
+        .collect())
 }
 
 #[derive(Debug)]
 struct PhysicalMemoryInfo {
     speed: String,
     memory_type: String,
+    modules: Vec<MemoryModule>,
+    total_slots: Option<u32>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -541,3 +692,32 @@ struct PagefileSample {
     PeakUsage: u64,
     UsagePercent: f64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_single_and_multiple_modules() {
+        let single: serde_json::Value = serde_json::from_str(
+            r#"{"Slot": "DIMM_A1", "Manufacturer": "Kingston", "PartNumber": "KF432C16",
+                "Capacity": 17179869184, "Speed": 3200, "MemoryType": "DIMM DDR4"}"#,
+        )
+        .unwrap();
+        let modules = parse_memory_modules(&single).unwrap();
+        assert_eq!(modules.len(), 1);
+        assert_eq!(modules[0].slot, "DIMM_A1");
+        assert_eq!(modules[0].speed, Some(3200));
+        assert_eq!(modules[0].part_number, "KF432C16");
+
+        let with_empty_slot: serde_json::Value = serde_json::from_str(
+            r#"[{"Slot": "A1", "Capacity": 8589934592, "Speed": null},
+                {"Slot": "B1", "Capacity": 0}]"#,
+        )
+        .unwrap();
+        let modules = parse_memory_modules(&with_empty_slot).unwrap();
+        assert_eq!(modules.len(), 1);
+        assert_eq!(modules[0].speed, None);
+        assert_eq!(modules[0].memory_type, "Unknown");
+    }
+}
@@ -1,6 +1,8 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use crate::integrations::remote::SshTarget;
 use crate::integrations::PowerShellExecutor;
+use crate::utils::markdown::{self, ToMarkdown};
 use crate::utils::parse_json_array;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -8,7 +10,7 @@ pub struct ServiceData {
     pub services: Vec<ServiceEntry>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ServiceEntry {
     pub name: String,
     pub display_name: String,
@@ -21,7 +23,7 @@ pub struct ServiceEntry {
     pub service_type: Option<String>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ServiceStatus {
     Running,
     Stopped,
@@ -30,6 +32,7 @@ pub enum ServiceStatus {
     StopPending,
     ContinuePending,
     PausePending,
+    #[default]
     Unknown,
 }
 
@@ -61,12 +64,13 @@ impl ServiceStatus {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ServiceStartType {
     Automatic,
     Manual,
     Disabled,
     AutomaticDelayedStart,
+    #[default]
     Unknown,
 }
 
@@ -92,6 +96,22 @@ impl ServiceStartType {
     }
 }
 
+impl ToMarkdown for [ServiceEntry] {
+    fn to_markdown(&self) -> String {
+        markdown::table(
+            &["Name", "Display Name", "Status", "Start Type"],
+            self.iter().map(|service| {
+                vec![
+                    service.name.clone(),
+                    service.display_name.clone(),
+                    service.status.as_str().to_string(),
+                    service.start_type.as_str().to_string(),
+                ]
+            }),
+        )
+    }
+}
+
 pub struct ServiceMonitor {
     ps: PowerShellExecutor,
 }
@@ -102,10 +122,26 @@ impl ServiceMonitor {
     }
 
     pub async fn collect_data(&self) -> Result<ServiceData> {
+        #[cfg(target_os = "linux")]
+        let services = self.get_services_linux().await?;
+        #[cfg(not(target_os = "linux"))]
         let services = self.get_services().await?;
         Ok(ServiceData { services })
     }
 
+    /// Units from `systemctl list-units`, with the start type taken from
+    /// `list-unit-files`.
+    #[allow(dead_code)]
+    async fn get_services_linux(&self) -> Result<Vec<ServiceEntry>> {
+        let remote = self.ps.remote();
+        let units = systemctl(remote, &["list-units", "--type=service", "--all"]).await?;
+        let unit_files = systemctl(remote, &["list-unit-files", "--type=service"])
+            .await
+            .unwrap_or_default();
+        Ok(parse_systemd_services(&units, &unit_files))
+    }
+
+    #[allow(dead_code)]
     async fn get_services(&self) -> Result<Vec<ServiceEntry>> {
         let script = r#"
             try {
@@ -165,25 +201,58 @@ impl ServiceMonitor {
             .collect())
     }
 
-    #[allow(dead_code)]
     pub async fn start_service(&self, service_name: &str) -> Result<()> {
-        let script = format!("Start-Service -Name '{}'", service_name);
-        self.ps.execute(&script).await?;
-        Ok(())
+        #[cfg(target_os = "linux")]
+        {
+            systemctl(self.ps.remote(), &["start", service_name]).await?;
+            Ok(())
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            let script = format!(
+                "Start-Service -Name '{}' -ErrorAction Stop",
+                escape_ps_string(service_name)
+            );
+            self.ps.execute_uncached(&script).await?;
+            Ok(())
+        }
     }
 
-    #[allow(dead_code)]
     pub async fn stop_service(&self, service_name: &str) -> Result<()> {
-        let script = format!("Stop-Service -Name '{}'", service_name);
-        self.ps.execute(&script).await?;
-        Ok(())
+        #[cfg(target_os = "linux")]
+        {
+            systemctl(self.ps.remote(), &["stop", service_name]).await?;
+            Ok(())
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            let script = format!(
+                "Stop-Service -Name '{}' -ErrorAction Stop",
+                escape_ps_string(service_name)
+            );
+            self.ps.execute_uncached(&script).await?;
+            Ok(())
+        }
     }
 
-    #[allow(dead_code)]
     pub async fn restart_service(&self, service_name: &str) -> Result<()> {
-        let script = format!("Restart-Service -Name '{}'", service_name);
-        self.ps.execute(&script).await?;
-        Ok(())
+        #[cfg(target_os = "linux")]
+        {
+            systemctl(self.ps.remote(), &["restart", service_name]).await?;
+            Ok(())
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            let script = format!(
+                "Restart-Service -Name '{}' -ErrorAction Stop",
+                escape_ps_string(service_name)
+            );
+            self.ps.execute_uncached(&script).await?;
+            Ok(())
+        }
     }
 
     #[allow(dead_code)]
@@ -196,13 +265,13 @@ impl ServiceMonitor {
             _ => return Err(anyhow::anyhow!("Invalid startup type")),
         };
         let script = format!("Set-Service -Name '{}' -StartupType {}", service_name, startup_str);
-        self.ps.execute(&script).await?;
+        self.ps.execute_uncached(&script).await?;
         Ok(())
     }
 }
 
 #[derive(Debug, Deserialize)]
-#[allow(non_snake_case)]
+#[allow(non_snake_case, dead_code)]
 struct ServiceSample {
     Name: String,
     DisplayName: String,
@@ -214,3 +283,154 @@ struct ServiceSample {
     DependentServices: Option<String>,
     ServiceType: Option<String>,
 }
+
+/// Run `systemctl <args>` without paging or headers and return stdout,
+/// on `remote` when one is configured.
+#[allow(dead_code)]
+async fn systemctl(remote: Option<&SshTarget>, args: &[&str]) -> Result<String> {
+    let args: Vec<&str> = args
+        .iter()
+        .copied()
+        .chain(["--plain", "--no-legend", "--no-pager"])
+        .collect();
+    let mut command = match remote {
+        Some(target) => {
+            target.check_connection()?;
+            target.tokio_command("systemctl", &args)
+        }
+        None => {
+            let mut command = tokio::process::Command::new("systemctl");
+            command.args(&args);
+            command
+        }
+    };
+    let output = command
+        .output()
+        .await
+        .context("Failed to run systemctl")?;
+    if let Some(target) = remote {
+        target.record_status(&output.status);
+    }
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("{}", stderr.trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// `units` lines are `UNIT LOAD ACTIVE SUB DESCRIPTION...`; `unit_files`
+/// lines are `UNIT STATE [PRESET]`.
+#[allow(dead_code)]
+fn parse_systemd_services(units: &str, unit_files: &str) -> Vec<ServiceEntry> {
+    let start_types: std::collections::HashMap<&str, &str> = unit_files
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            Some((fields.next()?, fields.next()?))
+        })
+        .collect();
+
+    let mut services: Vec<ServiceEntry> = units
+        .lines()
+        .filter_map(|line| {
+            // Units whose file is missing are prefixed with a bullet
+            let line = line.trim_start().trim_start_matches('●').trim_start();
+            let mut fields = line.split_whitespace();
+            let unit = fields.next()?;
+            let _load = fields.next()?;
+            let active = fields.next()?;
+            let _sub = fields.next()?;
+            let description = fields.collect::<Vec<_>>().join(" ");
+
+            let status = match active {
+                "active" | "reloading" => ServiceStatus::Running,
+                "inactive" | "failed" => ServiceStatus::Stopped,
+                "activating" => ServiceStatus::StartPending,
+                "deactivating" => ServiceStatus::StopPending,
+                _ => ServiceStatus::Unknown,
+            };
+            let start_type = match start_types.get(unit).copied() {
+                Some("enabled") | Some("enabled-runtime") => ServiceStartType::Automatic,
+                Some("disabled") | Some("masked") => ServiceStartType::Disabled,
+                Some("static") | Some("indirect") | Some("generated") | Some("transient") => {
+                    ServiceStartType::Manual
+                }
+                _ => ServiceStartType::Unknown,
+            };
+            let name = unit.strip_suffix(".service").unwrap_or(unit).to_string();
+
+            Some(ServiceEntry {
+                display_name: if description.is_empty() {
+                    name.clone()
+                } else {
+                    description
+                },
+                name,
+                status,
+                start_type,
+                description: None,
+                can_stop: status == ServiceStatus::Running,
+                can_pause_and_continue: false,
+                dependent_services: Vec::new(),
+                service_type: Some("systemd".to_string()),
+            })
+        })
+        .collect();
+
+    services.sort_by(|a, b| a.name.cmp(&b.name));
+    services
+}
+
+#[allow(dead_code)]
+fn escape_ps_string(value: &str) -> String {
+    value.replace('\'', "''")
+}
+
+/// Service control fails with "Cannot open ... service" / "Access is denied"
+/// when TUI+ is not running elevated.
+pub fn is_access_denied(message: &str) -> bool {
+    let lower = message.to_lowercase();
+    lower.contains("access is denied")
+        || lower.contains("permissiondenied")
+        || lower.contains("cannot open")
+        || lower.contains("operation not permitted")
+        || lower.contains("authentication required")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_systemctl_plain_output() {
+        let units = "\
+cron.service      loaded    active   running Regular background program processing daemon
+● ghost.service   not-found inactive dead    ghost.service
+nginx.service     loaded    failed   failed  A high performance web server
+";
+        let unit_files = "\
+cron.service   enabled  enabled
+nginx.service  disabled enabled
+";
+
+        let services = parse_systemd_services(units, unit_files);
+        assert_eq!(services.len(), 3);
+
+        let cron = &services[0];
+        assert_eq!(cron.name, "cron");
+        assert_eq!(cron.display_name, "Regular background program processing daemon");
+        assert_eq!(cron.status, ServiceStatus::Running);
+        assert_eq!(cron.start_type, ServiceStartType::Automatic);
+        assert!(cron.can_stop);
+
+        let ghost = &services[1];
+        assert_eq!(ghost.status, ServiceStatus::Stopped);
+        assert_eq!(ghost.start_type, ServiceStartType::Unknown);
+
+        let nginx = &services[2];
+        assert_eq!(nginx.status, ServiceStatus::Stopped);
+        assert_eq!(nginx.start_type, ServiceStartType::Disabled);
+        assert!(!nginx.can_stop);
+    }
+}
@@ -0,0 +1,98 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use crate::integrations::{PowerShellExecutor, LinuxSysMonitor};
+
+/// Host-wide facts that change rarely, shown in the header.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemInfo {
+    /// Unix seconds of the last boot
+    pub boot_time: u64,
+    /// Uptime when this sample was taken
+    pub uptime_seconds: u64,
+}
+
+impl SystemInfo {
+    fn from_boot_time(boot_time: u64) -> Self {
+        Self {
+            boot_time,
+            uptime_seconds: unix_now().saturating_sub(boot_time),
+        }
+    }
+
+    /// Uptime as of now, so the header keeps ticking between samples.
+    pub fn uptime_now(&self) -> u64 {
+        unix_now().saturating_sub(self.boot_time)
+    }
+}
+
+const BOOT_TIME_SCRIPT: &str = r#"
+$os = Get-CimInstance Win32_OperatingSystem
+([DateTimeOffset]$os.LastBootUpTime).ToUnixTimeSeconds()
+"#;
+
+pub struct SystemInfoMonitor {
+    #[allow(dead_code)]
+    ps: PowerShellExecutor,
+    #[allow(dead_code)]
+    linux_sys: LinuxSysMonitor,
+}
+
+impl SystemInfoMonitor {
+    pub fn new(ps: PowerShellExecutor) -> Result<Self> {
+        Ok(Self {
+            linux_sys: LinuxSysMonitor::new().with_remote(ps.remote().cloned()),
+            ps,
+        })
+    }
+
+    pub async fn collect_data(&self) -> Result<SystemInfo> {
+        #[cfg(target_os = "linux")]
+        let info = self.collect_data_linux().await;
+        #[cfg(not(target_os = "linux"))]
+        let info = self.collect_data_windows().await;
+        info
+    }
+
+    #[allow(dead_code)]
+    async fn collect_data_linux(&self) -> Result<SystemInfo> {
+        let uptime = self.linux_sys.blocking(|sys| sys.read("/proc/uptime")).await??;
+        let uptime = parse_proc_uptime(&uptime)
+            .context("Failed to parse /proc/uptime")?;
+        Ok(SystemInfo::from_boot_time(unix_now().saturating_sub(uptime)))
+    }
+
+    #[allow(dead_code)]
+    async fn collect_data_windows(&self) -> Result<SystemInfo> {
+        let output = self.ps.execute(BOOT_TIME_SCRIPT).await?;
+        let boot_time = output
+            .trim_start_matches('\u{feff}')
+            .trim()
+            .parse::<u64>()
+            .context("Failed to parse LastBootUpTime")?;
+        Ok(SystemInfo::from_boot_time(boot_time))
+    }
+}
+
+/// Whole seconds since boot from the first field of `/proc/uptime`.
+fn parse_proc_uptime(contents: &str) -> Option<u64> {
+    let seconds: f64 = contents.split_whitespace().next()?.parse().ok()?;
+    Some(seconds as u64)
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_proc_uptime() {
+        assert_eq!(parse_proc_uptime("350735.47 234388.90\n"), Some(350735));
+        assert_eq!(parse_proc_uptime(""), None);
+    }
+}
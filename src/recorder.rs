@@ -0,0 +1,231 @@
+//! Background recorder that appends sampled monitor data to a newline-delimited
+//! JSON file for post-mortem analysis.
+
+use anyhow::{Context, Result};
+use chrono::Local;
+use parking_lot::{Mutex, RwLock};
+use serde::Serialize;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+use crate::app::config::RecordingConfig;
+use crate::monitors::{CpuData, DiskData, GpuData, NetworkData, RamData};
+
+/// Handles to the monitor data sampled on each tick.
+#[derive(Clone)]
+pub struct RecordSources {
+    pub cpu: Arc<RwLock<Option<CpuData>>>,
+    pub ram: Arc<RwLock<Option<RamData>>>,
+    pub gpu: Arc<RwLock<Option<GpuData>>>,
+    pub disk: Arc<RwLock<Option<DiskData>>>,
+    pub network: Arc<RwLock<Option<NetworkData>>>,
+}
+
+/// One line of the recording.
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub timestamp: String,
+    pub cpu: Option<CpuData>,
+    pub ram: Option<RamData>,
+    pub gpu: Option<GpuData>,
+    pub disk: Option<DiskData>,
+    pub network: Option<NetworkData>,
+}
+
+impl Record {
+    /// Copy the current monitor state, dropping the rolling histories (every
+    /// record would repeat them) and per-socket connection lists.
+    fn sample(sources: &RecordSources) -> Self {
+        let mut cpu = sources.cpu.read().clone();
+        if let Some(cpu) = cpu.as_mut() {
+            cpu.usage_history.clear();
+        }
+        let mut disk = sources.disk.read().clone();
+        if let Some(disk) = disk.as_mut() {
+            disk.io_history.clear();
+        }
+        let mut network = sources.network.read().clone();
+        if let Some(network) = network.as_mut() {
+            network.traffic_history.clear();
+            network.interface_history.clear();
+            network.connections.clear();
+        }
+
+        Self {
+            timestamp: Local::now().to_rfc3339(),
+            cpu,
+            ram: sources.ram.read().clone(),
+            gpu: sources.gpu.read().clone(),
+            disk,
+            network,
+        }
+    }
+}
+
+/// Appends records to `path`, moving the file aside once it grows past
+/// `max_bytes`.
+struct RecordWriter {
+    path: PathBuf,
+    max_bytes: u64,
+    file: BufWriter<File>,
+    written: u64,
+}
+
+impl RecordWriter {
+    fn open(path: PathBuf, max_bytes: u64) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create {}", parent.display()))?;
+            }
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        let written = file.metadata().map(|m| m.len()).unwrap_or(0);
+
+        Ok(Self {
+            path,
+            max_bytes,
+            file: BufWriter::new(file),
+            written,
+        })
+    }
+
+    fn write(&mut self, record: &Record) -> Result<()> {
+        let mut line = serde_json::to_vec(record)?;
+        line.push(b'\n');
+
+        if self.written > 0 && self.written + line.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+
+        self.file.write_all(&line)?;
+        self.written += line.len() as u64;
+        Ok(())
+    }
+
+    /// Rename the full file to `<stem>.<timestamp>.<ext>` and start a fresh one.
+    /// A second rotation within the same second gets `-1`, `-2`, ... appended.
+    fn rotate(&mut self) -> Result<()> {
+        self.file.flush()?;
+        let timestamp = Local::now().format("%Y%m%d-%H%M%S").to_string();
+        let mut rotated = rotated_path(&self.path, &timestamp);
+        let mut counter = 1;
+        while rotated.exists() {
+            rotated = rotated_path(&self.path, &format!("{}-{}", timestamp, counter));
+            counter += 1;
+        }
+        fs::rename(&self.path, &rotated)
+            .with_context(|| format!("Failed to rotate {}", self.path.display()))?;
+        log::info!("Rotated metrics recording to {}", rotated.display());
+
+        *self = Self::open(self.path.clone(), self.max_bytes)?;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.file.flush()?;
+        Ok(())
+    }
+}
+
+fn rotated_path(path: &Path, suffix: &str) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "metrics".to_string());
+    let name = match path.extension() {
+        Some(ext) => format!("{}.{}.{}", stem, suffix, ext.to_string_lossy()),
+        None => format!("{}.{}", stem, suffix),
+    };
+    path.with_file_name(name)
+}
+
+/// Keeps the recording task running; dropping it stops sampling.
+pub struct Recorder {
+    writer: Arc<Mutex<RecordWriter>>,
+    task: JoinHandle<()>,
+}
+
+impl Recorder {
+    pub fn start(config: &RecordingConfig, sources: RecordSources) -> Result<Self> {
+        let max_bytes = config.max_file_mb.max(1) * 1024 * 1024;
+        let writer = Arc::new(Mutex::new(RecordWriter::open(
+            PathBuf::from(&config.path),
+            max_bytes,
+        )?));
+        log::info!("Recording metrics to {}", config.path);
+
+        let interval = Duration::from_secs(config.interval_seconds.max(1));
+        let task_writer = Arc::clone(&writer);
+        let task = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                let record = Record::sample(&sources);
+                if let Err(e) = task_writer.lock().write(&record) {
+                    log::warn!("Failed to write metrics record: {}", e);
+                }
+            }
+        });
+
+        Ok(Self { writer, task })
+    }
+
+    pub fn flush(&self) {
+        if let Err(e) = self.writer.lock().flush() {
+            log::warn!("Failed to flush metrics recording: {}", e);
+        }
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        self.task.abort();
+        self.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotates_when_the_file_is_full() {
+        let dir = std::env::temp_dir().join(format!("tui-plus-recorder-{}", std::process::id()));
+        let path = dir.join("metrics.ndjson");
+        let record = Record {
+            timestamp: "now".to_string(),
+            cpu: None,
+            ram: None,
+            gpu: None,
+            disk: None,
+            network: None,
+        };
+        let line_len = serde_json::to_vec(&record).unwrap().len() as u64 + 1;
+
+        // Two rotations, most likely within the same second
+        let mut writer = RecordWriter::open(path.clone(), line_len * 2).unwrap();
+        for _ in 0..5 {
+            writer.write(&record).unwrap();
+        }
+        writer.flush().unwrap();
+
+        let files = fs::read_dir(&dir).unwrap().count();
+        let current = fs::read_to_string(&path).unwrap();
+        fs::remove_dir_all(&dir).ok();
+        assert_eq!(files, 3);
+        assert_eq!(current.lines().count(), 1);
+        assert_eq!(
+            rotated_path(Path::new("logs/metrics.ndjson"), "x"),
+            Path::new("logs/metrics.x.ndjson")
+        );
+    }
+}
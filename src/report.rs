@@ -0,0 +1,302 @@
+//! Diagnostic report: one snapshot of every monitor plus system info and the
+//! effective config, written as Markdown (or JSON for a `.json` path). Used by
+//! `--report <path>` and the in-app report hotkey.
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use crate::app::Config;
+use crate::monitors::services::{ServiceEntry, ServiceStartType, ServiceStatus};
+use crate::snapshot::Snapshot;
+use crate::utils::format::{format_uptime, FormatOptions};
+use crate::utils::markdown::{self, ToMarkdown};
+
+/// Processes listed in the Markdown report, busiest first.
+const REPORT_TOP_PROCESSES: usize = 25;
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    version: &'static str,
+    snapshot: &'a Snapshot,
+    config: &'a Config,
+}
+
+/// Timestamped default name, e.g. `tui-plus-report-20240101-120000.md`.
+pub fn default_file_name() -> String {
+    format!(
+        "tui-plus-report-{}.md",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    )
+}
+
+/// Write the report to `path`; a `.json` extension selects JSON.
+pub fn write(path: &Path, snapshot: &Snapshot, config: &Config) -> Result<()> {
+    let is_json = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+    let contents = if is_json {
+        serde_json::to_string_pretty(&JsonReport {
+            version: env!("CARGO_PKG_VERSION"),
+            snapshot,
+            config,
+        })?
+    } else {
+        render_markdown(snapshot, config)
+    };
+    fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+pub fn render_markdown(snapshot: &Snapshot, config: &Config) -> String {
+    let fmt = FormatOptions::from_config(config);
+    let mut out = String::new();
+    let _ = writeln!(out, "# {} diagnostic report\n", config.general.app_name);
+    let _ = writeln!(out, "- Generated: {}", snapshot.timestamp);
+    let _ = writeln!(out, "- Version: {}", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(out, "- OS: {} ({})", std::env::consts::OS, std::env::consts::ARCH);
+    if let Some(target) = config.remote.target() {
+        let _ = writeln!(out, "- Remote host: {}", target.destination());
+    }
+    match &snapshot.system {
+        Some(system) => {
+            let boot = chrono::DateTime::from_timestamp(system.boot_time as i64, 0)
+                .map(|time| time.with_timezone(&chrono::Local).to_rfc3339())
+                .unwrap_or_else(|| "unknown".to_string());
+            let _ = writeln!(out, "- Booted: {}", boot);
+            let _ = writeln!(out, "- Uptime: {}", format_uptime(system.uptime_seconds));
+        }
+        None => {
+            let _ = writeln!(out, "- Uptime: unavailable");
+        }
+    }
+
+    out.push_str("\n## Monitor status\n\n");
+    let status = |name: &str, collected: bool| -> Vec<String> {
+        let state = match snapshot.errors.get(name) {
+            Some(error) => format!("Error: {}", error),
+            None if collected => "OK".to_string(),
+            None => "Disabled".to_string(),
+        };
+        vec![name.to_string(), state]
+    };
+    out.push_str(&markdown::table(
+        &["Monitor", "Status"],
+        [
+            status("cpu", snapshot.cpu.is_some()),
+            status("gpu", snapshot.gpu.is_some()),
+            status("ram", snapshot.ram.is_some()),
+            status("disk", snapshot.disk.is_some()),
+            status("network", snapshot.network.is_some()),
+            status("processes", snapshot.processes.is_some()),
+            status("services", snapshot.services.is_some()),
+            status("system", snapshot.system.is_some()),
+        ],
+    ));
+
+    if let Some(cpu) = &snapshot.cpu {
+        out.push_str("\n## CPU\n\n");
+        let _ = writeln!(out, "- Model: {}", cpu.name);
+        let _ = writeln!(out, "- Cores / threads: {} / {}", cpu.core_count, cpu.thread_count);
+        let _ = writeln!(out, "- Usage: {:.1}%", cpu.overall_usage);
+        let _ = writeln!(out, "- Frequency: {:.2} GHz", cpu.frequency.avg_frequency);
+        if let Some(temperature) = cpu.temperature {
+            let _ = writeln!(out, "- Temperature: {:.0}°C", temperature);
+        }
+        if !cpu.details.features.is_empty() {
+            let _ = writeln!(out, "- Features: {}", cpu.details.features.join(", "));
+        }
+    }
+
+    if let Some(gpu) = &snapshot.gpu {
+        out.push_str("\n## GPU\n\n");
+        out.push_str(&markdown::table(
+            &["#", "Name", "Usage", "Memory", "Temp", "Power", "Driver"],
+            gpu.devices.iter().map(|device| {
+                vec![
+                    device.gpu_index.to_string(),
+                    device.name.clone(),
+                    format!("{:.0}%", device.utilization),
+                    format!(
+                        "{} / {}",
+                        fmt.bytes(device.memory_used),
+                        fmt.bytes(device.memory_total)
+                    ),
+                    format!("{:.0}°C", device.temperature),
+                    format!("{:.0}W / {:.0}W", device.power_usage, device.power_limit),
+                    device.driver_version.clone(),
+                ]
+            }),
+        ));
+    }
+
+    if let Some(ram) = &snapshot.ram {
+        out.push_str("\n## Memory\n\n");
+        let _ = writeln!(
+            out,
+            "- Used: {} / {}",
+            fmt.bytes(ram.used),
+            fmt.bytes(ram.total)
+        );
+        let _ = writeln!(
+            out,
+            "- Committed: {} / {} ({:.1}%)",
+            fmt.bytes(ram.committed),
+            fmt.bytes(ram.commit_limit),
+            ram.commit_percent
+        );
+        if ram.total_pagefile_size > 0 {
+            let _ = writeln!(
+                out,
+                "- Pagefile: {} / {}",
+                fmt.bytes(ram.total_pagefile_used),
+                fmt.bytes(ram.total_pagefile_size)
+            );
+        }
+    }
+
+    if let Some(disk) = &snapshot.disk {
+        out.push_str("\n## Disks\n\n");
+        out.push_str(&disk.logical_drives.to_markdown());
+        if !disk.physical_disks.is_empty() {
+            out.push('\n');
+            out.push_str(&markdown::table(
+                &["#", "Model", "Type", "Size", "Health", "Temp"],
+                disk.physical_disks.iter().map(|physical| {
+                    vec![
+                        physical.disk_number.to_string(),
+                        physical.friendly_name.clone(),
+                        physical.media_type.clone(),
+                        fmt.bytes(physical.size),
+                        physical.health_status.clone(),
+                        physical
+                            .temperature
+                            .map_or_else(|| "-".to_string(), |t| format!("{:.0}°C", t)),
+                    ]
+                }),
+            ));
+        }
+    }
+
+    if let Some(network) = &snapshot.network {
+        out.push_str("\n## Network\n\n");
+        out.push_str(&markdown::table(
+            &[
+                "Interface",
+                "Status",
+                "IPv4",
+                "Link",
+                &format!("↓ {}", fmt.rate_unit()),
+                &format!("↑ {}", fmt.rate_unit()),
+            ],
+            network.interfaces.iter().map(|iface| {
+                vec![
+                    iface.name.clone(),
+                    iface.status.clone(),
+                    iface.ipv4_address.clone(),
+                    iface.link_speed.clone(),
+                    format!("{:.2}", fmt.rate_value(iface.download_speed)),
+                    format!("{:.2}", fmt.rate_value(iface.upload_speed)),
+                ]
+            }),
+        ));
+        let _ = writeln!(out, "\n- Connections: {}", network.connections.len());
+        for ping in &network.latency {
+            let rtt = ping
+                .rtt_ms
+                .map_or_else(|| "timeout".to_string(), |rtt| format!("{:.0} ms", rtt));
+            let _ = writeln!(out, "- Ping {}: {} ({:.0}% loss)", ping.target, rtt, ping.loss_pct);
+        }
+    }
+
+    if let Some(processes) = &snapshot.processes {
+        let mut busiest: Vec<_> = processes.processes.iter().collect();
+        busiest.sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage));
+        let _ = writeln!(
+            out,
+            "\n## Processes (top {} of {} by CPU)\n",
+            REPORT_TOP_PROCESSES.min(busiest.len()),
+            busiest.len()
+        );
+        out.push_str(&markdown::table(
+            &["PID", "Name", "CPU%", "Memory", "Threads", "User"],
+            busiest.iter().take(REPORT_TOP_PROCESSES).map(|process| {
+                vec![
+                    process.pid.to_string(),
+                    process.name.clone(),
+                    format!("{:.1}", process.cpu_usage),
+                    fmt.bytes(process.memory),
+                    process.threads.to_string(),
+                    process.user.clone(),
+                ]
+            }),
+        ));
+    }
+
+    if let Some(services) = &snapshot.services {
+        let stopped: Vec<ServiceEntry> = services
+            .services
+            .iter()
+            .filter(|service| {
+                matches!(
+                    service.start_type,
+                    ServiceStartType::Automatic | ServiceStartType::AutomaticDelayedStart
+                ) && service.status != ServiceStatus::Running
+            })
+            .cloned()
+            .collect();
+        let running = services
+            .services
+            .iter()
+            .filter(|service| service.status == ServiceStatus::Running)
+            .count();
+        out.push_str("\n## Services\n\n");
+        let _ = writeln!(out, "- Running: {} of {}", running, services.services.len());
+        if !stopped.is_empty() {
+            out.push_str("\nAutomatic services that are not running:\n\n");
+            out.push_str(&stopped.to_markdown());
+        }
+    }
+
+    out.push_str("\n## Configuration\n\n```toml\n");
+    match toml::to_string_pretty(config) {
+        Ok(text) => out.push_str(&text),
+        Err(e) => {
+            let _ = writeln!(out, "# failed to serialize config: {}", e);
+        }
+    }
+    out.push_str("```\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn markdown_report_shows_failed_and_disabled_monitors() {
+        let mut errors = BTreeMap::new();
+        errors.insert("gpu", "nvidia-smi | not found".to_string());
+        let snapshot = Snapshot {
+            timestamp: "2024-01-01T00:00:00+00:00".to_string(),
+            cpu: None,
+            gpu: None,
+            ram: None,
+            disk: None,
+            network: None,
+            processes: None,
+            services: None,
+            system: None,
+            errors,
+        };
+
+        let config: Config = toml::from_str(include_str!("../config.toml")).unwrap();
+        let report = render_markdown(&snapshot, &config);
+        assert!(report.contains("| gpu | Error: nvidia-smi \\| not found |"));
+        assert!(report.contains("| cpu | Disabled |"));
+        assert!(report.contains("- Uptime: unavailable"));
+        assert!(report.contains("```toml\n[general]"));
+    }
+}
@@ -0,0 +1,140 @@
+//! Headless `--json` mode: collect every enabled monitor once and print the
+//! combined result to stdout instead of starting the TUI.
+
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::timeout;
+
+use crate::app::Config;
+use crate::integrations::PowerShellExecutor;
+use crate::monitors::{
+    CpuData, CpuMonitor, DiskData, DiskMonitor, GpuData, GpuMonitor, NetworkData,
+    NetworkMonitor, ProcessData, ProcessMonitor, RamData, RamMonitor, ServiceData,
+    ServiceMonitor, SystemInfo, SystemInfoMonitor,
+};
+
+#[derive(Debug, Serialize)]
+pub struct Snapshot {
+    pub timestamp: String,
+    pub cpu: Option<CpuData>,
+    pub gpu: Option<GpuData>,
+    pub ram: Option<RamData>,
+    pub disk: Option<DiskData>,
+    pub network: Option<NetworkData>,
+    pub processes: Option<ProcessData>,
+    pub services: Option<ServiceData>,
+    /// Boot time and uptime; collected regardless of which monitors are on.
+    pub system: Option<SystemInfo>,
+    /// Monitor name -> error, for monitors that were enabled but failed.
+    pub errors: BTreeMap<&'static str, String>,
+}
+
+impl Snapshot {
+    /// True when at least one monitor ran and none of them produced data.
+    /// System info is not a monitor and doesn't count either way.
+    pub fn all_failed(&self) -> bool {
+        self.errors.keys().any(|name| *name != "system")
+            && self.cpu.is_none()
+            && self.gpu.is_none()
+            && self.ram.is_none()
+            && self.disk.is_none()
+            && self.network.is_none()
+            && self.processes.is_none()
+            && self.services.is_none()
+    }
+}
+
+/// Collect one sample per enabled monitor, giving each at most `limit`.
+pub async fn collect(config: &Config, limit: Duration) -> Snapshot {
+    let ps = || {
+        // No cache: every query runs exactly once
+        PowerShellExecutor::new(
+            config.powershell.executable.clone(),
+            config.powershell.timeout_seconds,
+            0,
+            false,
+        )
+        .with_max_concurrent(config.powershell.max_concurrent)
+        .with_remote(config.remote.target())
+    };
+    let monitors = &config.monitors;
+
+    let (cpu, gpu, ram, disk, network, processes, services, system) = tokio::join!(
+        run(monitors.cpu.enabled, limit, async {
+            CpuMonitor::new(ps())?.collect_data().await
+        }),
+        run(monitors.gpu.enabled, limit, async {
+            GpuMonitor::new(ps(), monitors.gpu.use_nvml)?
+                .collect_data(&monitors.gpu.sources())
+                .await
+        }),
+        run(monitors.ram.enabled, limit, async {
+            RamMonitor::new(ps())?.collect_data().await
+        }),
+        run(monitors.disk.enabled, limit, async {
+            DiskMonitor::new(ps())?.collect_data().await
+        }),
+        run(monitors.network.enabled, limit, async {
+            let mut monitor = NetworkMonitor::new(ps())?;
+            monitor.set_interface_filter(&monitors.network.interface_filter);
+            monitor.collect_data().await
+        }),
+        run(monitors.processes.enabled, limit, async {
+            ProcessMonitor::new(ps())?.collect_data().await
+        }),
+        run(monitors.services.enabled, limit, async {
+            ServiceMonitor::new(ps())?.collect_data().await
+        }),
+        run(true, limit, async {
+            SystemInfoMonitor::new(ps())?.collect_data().await
+        }),
+    );
+
+    let mut errors = BTreeMap::new();
+
+    Snapshot {
+        timestamp: chrono::Local::now().to_rfc3339(),
+        cpu: take(&mut errors, "cpu", cpu),
+        gpu: take(&mut errors, "gpu", gpu),
+        ram: take(&mut errors, "ram", ram),
+        disk: take(&mut errors, "disk", disk),
+        network: take(&mut errors, "network", network),
+        processes: take(&mut errors, "processes", processes),
+        services: take(&mut errors, "services", services),
+        system: take(&mut errors, "system", system),
+        errors,
+    }
+}
+
+fn take<T>(
+    errors: &mut BTreeMap<&'static str, String>,
+    name: &'static str,
+    result: Option<Result<T>>,
+) -> Option<T> {
+    match result {
+        Some(Ok(data)) => Some(data),
+        Some(Err(e)) => {
+            errors.insert(name, e.to_string());
+            None
+        }
+        None => None,
+    }
+}
+
+async fn run<T>(
+    enabled: bool,
+    limit: Duration,
+    collect: impl Future<Output = Result<T>>,
+) -> Option<Result<T>> {
+    if !enabled {
+        return None;
+    }
+
+    Some(match timeout(limit, collect).await {
+        Ok(result) => result,
+        Err(_) => Err(anyhow::anyhow!("timed out after {}s", limit.as_secs())),
+    })
+}
@@ -6,13 +6,21 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Paragraph, Tabs as RatatuiTabs},
+    widgets::{block::Title, Block, Borders, Clear, Paragraph, Tabs as RatatuiTabs, Wrap},
     Frame,
 };
 
+use crate::app::notifications::NotificationLevel;
 use crate::app::{App, TabType};
+use crate::utils::format::{format_uptime, FormatOptions};
 use theme::Theme;
 
+/// Smallest terminal the tab layouts fit in; below this only a notice is drawn.
+pub const MIN_WIDTH: u16 = 80;
+pub const MIN_HEIGHT: u16 = 20;
+/// Below this the split view falls back to the focused tab alone.
+pub const MIN_SPLIT_WIDTH: u16 = 2 * MIN_WIDTH;
+
 pub fn render(f: &mut Frame, app: &App) {
     // Get the full size of the frame
     let size = f.size();
@@ -23,6 +31,11 @@ pub fn render(f: &mut Frame, app: &App) {
         .style(Style::default().bg(Color::Reset));
     f.render_widget(background, size);
 
+    if size.width < MIN_WIDTH || size.height < MIN_HEIGHT {
+        render_too_small(f, size);
+        return;
+    }
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -37,23 +50,136 @@ pub fn render(f: &mut Frame, app: &App) {
     render_tabs(f, chunks[1], app);
     render_content(f, chunks[2], app);
     render_footer(f, chunks[3], app);
+    render_notifications(f, chunks[2], app);
 
     // Render command history menu if active
     if app.state.command_menu_active {
         render_command_menu(f, size, app);
     }
+
+    if let Some(menu) = app.state.tab_menu.as_ref() {
+        menu.render(f, size, app.state.tab_manager.current());
+    }
+
+    if let Some((output, success)) = app.state.command_output.as_ref() {
+        render_command_output(f, output, *success, app.state.command_output_scroll);
+    }
+
+    if app.state.help_visible {
+        render_help(f, app);
+    }
+
+    if app.state.errors_visible {
+        render_errors(f, app);
+    }
+
+    if app.state.logs_visible {
+        render_logs(f, app);
+    }
+
+    if let Some(input) = app.state.pid_prompt.as_ref() {
+        render_pid_prompt(f, input);
+    }
+}
+
+fn render_too_small(f: &mut Frame, size: Rect) {
+    let lines = vec![
+        Line::from(Span::styled(
+            "Terminal too small",
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(format!(
+            "need at least {}x{}, have {}x{}",
+            MIN_WIDTH, MIN_HEIGHT, size.width, size.height
+        )),
+    ];
+    // Vertically centre when there's room; otherwise start at the top
+    let top = size.height.saturating_sub(lines.len() as u16) / 2;
+    let area = Rect {
+        y: size.y + top,
+        height: size.height - top,
+        ..size
+    };
+    f.render_widget(
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        area,
+    );
 }
 
 fn render_header(f: &mut Frame, area: Rect, app: &App) {
     let config = app.state.config.read();
     let theme = Theme::from_config(&config);
+    let fmt = FormatOptions::from_config(&config);
     let title = format!("{} System Monitor v1.0", config.general.app_name);
 
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(theme.foreground));
 
-    let text = Paragraph::new(title)
+    let mut spans = vec![Span::raw(title)];
+    if let Some(info) = app.state.system_info.read().as_ref() {
+        spans.push(Span::styled(
+            format!("  Uptime: {}", format_uptime(info.uptime_now())),
+            Style::default().fg(Color::DarkGray).remove_modifier(Modifier::BOLD),
+        ));
+    }
+    if app.state.is_paused() {
+        spans.push(Span::raw("  "));
+        spans.push(Span::styled(
+            " PAUSED ",
+            Style::default().fg(Color::Black).bg(Color::Yellow),
+        ));
+    }
+    if app.state.is_idle() {
+        spans.push(Span::raw("  "));
+        spans.push(Span::styled("idle", Style::default().fg(Color::DarkGray)));
+    }
+    if app.state.read_only {
+        spans.push(Span::raw("  "));
+        spans.push(Span::styled(
+            " READ-ONLY ",
+            Style::default().fg(Color::Black).bg(Color::Cyan),
+        ));
+    }
+    if let Some(target) = config.remote.target() {
+        let color = if target.is_connected() {
+            Color::Cyan
+        } else {
+            Color::Red
+        };
+        spans.push(Span::styled(
+            format!("  ⇄ {}", target.destination()),
+            Style::default().fg(color),
+        ));
+    }
+    if !app.state.elevated {
+        spans.push(Span::styled(
+            "  (not elevated — some actions unavailable)",
+            Style::default().fg(Color::DarkGray).remove_modifier(Modifier::BOLD),
+        ));
+    }
+    // Ambient throughput from the latest sample; absent while loading or failed
+    let traffic = app
+        .state
+        .network_data
+        .read()
+        .as_ref()
+        .and_then(|data| data.traffic_history.back().cloned());
+    if let Some(sample) = traffic.filter(|_| app.state.network_error.read().is_none()) {
+        spans.push(Span::styled(
+            format!(
+                "  ↓ {:.1} ↑ {:.1} {}",
+                fmt.rate_value(sample.download_mbps),
+                fmt.rate_value(sample.upload_mbps),
+                fmt.rate_unit()
+            ),
+            Style::default().fg(theme.network_color).remove_modifier(Modifier::BOLD),
+        ));
+    }
+
+    let text = Paragraph::new(Line::from(spans))
         .block(block)
         .alignment(Alignment::Center)
         .style(Style::default().fg(theme.foreground).add_modifier(Modifier::BOLD));
@@ -66,12 +192,14 @@ fn render_tabs(f: &mut Frame, area: Rect, app: &App) {
     let theme = Theme::from_config(&config);
     let highlight_config = &config.ui.section_highlight;
 
+    let split = visible_split_tab(app, area.width);
     let tab_titles: Vec<Line> = app.state.tab_manager.tabs
         .iter()
         .enumerate()
         .map(|(i, tab)| {
             let is_selected = i == app.state.tab_manager.current_index;
             let tab_name = tab.as_str();
+            let alert = app.state.alerts.is_active(*tab);
 
             if is_selected {
                 let bracket_left = match highlight_config.highlighted_bracket.as_str() {
@@ -92,7 +220,7 @@ fn render_tabs(f: &mut Frame, area: Rect, app: &App) {
                     Span::styled(
                         tab_name,
                         Style::default()
-                            .fg(Color::Yellow)
+                            .fg(if alert { Color::Red } else { Color::Yellow })
                             .add_modifier(Modifier::BOLD),
                     ),
                     Span::raw(bracket_right),
@@ -115,7 +243,13 @@ fn render_tabs(f: &mut Frame, area: Rect, app: &App) {
                     Span::raw(bracket_left),
                     Span::styled(
                         tab_name,
-                        Style::default().fg(Color::White),
+                        Style::default().fg(if alert {
+                            Color::Red
+                        } else if split == Some(*tab) {
+                            Color::Cyan
+                        } else {
+                            Color::White
+                        }),
                     ),
                     Span::raw(bracket_right),
                 ])
@@ -123,17 +257,49 @@ fn render_tabs(f: &mut Frame, area: Rect, app: &App) {
         })
         .collect();
 
+    let highlight_color = if app.state.alerts.is_active(app.state.tab_manager.current()) {
+        Color::Red
+    } else {
+        Color::Yellow
+    };
+
+    let mut block = Block::default().borders(Borders::ALL);
+    if let Some(age) = tabs::staleness_span(app, &config, app.state.tab_manager.current()) {
+        block = block.title(Title::from(age).alignment(Alignment::Right));
+    }
+
     let tabs = RatatuiTabs::new(tab_titles)
-        .block(Block::default().borders(Borders::ALL))
+        .block(block)
         .select(app.state.tab_manager.current_index)
         .style(Style::default().fg(theme.foreground))
-        .highlight_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+        .highlight_style(Style::default().fg(highlight_color).add_modifier(Modifier::BOLD));
 
     f.render_widget(tabs, area);
 }
 
+/// The split view's second tab, if the terminal is wide enough to show it.
+fn visible_split_tab(app: &App, width: u16) -> Option<TabType> {
+    app.state.split_tab().filter(|_| width >= MIN_SPLIT_WIDTH)
+}
+
 fn render_content(f: &mut Frame, area: Rect, app: &App) {
-    match app.state.tab_manager.current() {
+    let current = app.state.tab_manager.current();
+    match visible_split_tab(app, area.width) {
+        Some(other) => {
+            let panes = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(area);
+            render_tab(f, panes[0], app, current);
+            render_tab(f, panes[1], app, other);
+        }
+        None => render_tab(f, area, app, current),
+    }
+}
+
+fn render_tab(f: &mut Frame, area: Rect, app: &App, tab: TabType) {
+    match tab {
+        TabType::Overview => tabs::overview::render(f, area, app),
         TabType::Cpu => tabs::cpu::render(f, area, app),
         TabType::Gpu => tabs::gpu::render(f, area, app),
         TabType::Ram => tabs::ram::render(f, area, app),
@@ -148,8 +314,27 @@ fn render_content(f: &mut Frame, area: Rect, app: &App) {
 }
 
 fn render_footer(f: &mut Frame, area: Rect, app: &App) {
+    if app.state.command_input.is_empty() {
+        if let Some(message) = app.state.flash_message() {
+            let paragraph = Paragraph::new(message.to_string())
+                .block(Block::default().borders(Borders::ALL))
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(Color::Green));
+            f.render_widget(paragraph, area);
+            return;
+        }
+        if let Some(error) = app.state.config_error() {
+            let paragraph = Paragraph::new(format!("{} (file not applied)", error))
+                .block(Block::default().borders(Borders::ALL))
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(Color::Yellow));
+            f.render_widget(paragraph, area);
+            return;
+        }
+    }
+
     let help_text = if app.state.command_input.is_empty() {
-        "[F1] Help │ [F2] Compact │ [Tab] Next │ [Ctrl+F] History │ [Ctrl+C] Exit"
+        "[F1] Help │ [F2] Compact │ [F4] Pause │ [Tab] Next │ [Ctrl+F] History │ [Ctrl+C] Exit"
     } else {
         &format!("Command: {} [Enter] Execute [Esc] Cancel", app.state.command_input)
     };
@@ -184,12 +369,16 @@ fn render_command_menu(f: &mut Frame, _area: Rect, app: &App) {
         height: popup_area.height.saturating_sub(4),
     };
 
-    let commands: Vec<Line> = app.state.command_history
+    let history = &app.state.command_history;
+    let offset = history.scroll_offset(inner.height as usize);
+    let commands: Vec<Line> = history
         .get_all()
         .iter()
         .enumerate()
+        .skip(offset)
+        .take(inner.height as usize)
         .map(|(i, cmd)| {
-            let is_selected = i == app.state.command_history.selected_index();
+            let is_selected = i == history.selected_index();
             let style = if is_selected {
                 Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
             } else {
@@ -209,7 +398,262 @@ fn render_command_menu(f: &mut Frame, _area: Rect, app: &App) {
     f.render_widget(paragraph, inner);
 }
 
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+/// Recent background events as toasts stacked in the top-right corner of
+/// `area`, newest at the bottom.
+fn render_notifications(f: &mut Frame, area: Rect, app: &App) {
+    if app.state.notifications.is_empty() {
+        return;
+    }
+    let theme = Theme::from_config(&app.state.config.read());
+
+    let mut y = area.y;
+    for notification in &app.state.notifications {
+        if y + 3 > area.bottom() {
+            break;
+        }
+        let color = match notification.level {
+            NotificationLevel::Info => theme.success_color,
+            NotificationLevel::Warn => theme.warning_color,
+            NotificationLevel::Error => theme.error_color,
+        };
+        let width = (notification.text.chars().count() as u16 + 4)
+            .min(area.width / 2)
+            .max(area.width.min(20));
+        let toast = Rect::new(area.right().saturating_sub(width), y, width, 3);
+
+        f.render_widget(Clear, toast);
+        f.render_widget(
+            Paragraph::new(notification.text.as_str())
+                .style(Style::default().fg(color))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(color)),
+                ),
+            toast,
+        );
+        y += 3;
+    }
+}
+
+fn render_help(f: &mut Frame, app: &App) {
+    let area = f.size();
+    f.render_widget(Clear, area);
+
+    let tab = app.state.tab_manager.current();
+    let block = Block::default()
+        .title("Help [Esc/F1] Close")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(inner);
+
+    let key_style = Style::default()
+        .fg(Color::Cyan)
+        .add_modifier(Modifier::BOLD);
+    let heading_style = Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+
+    let to_lines = |title: String, entries: Vec<crate::app::keybindings::HelpEntry>| {
+        let key_width = entries.iter().map(|e| e.keys.chars().count()).max().unwrap_or(0);
+        let mut lines = vec![Line::from(Span::styled(title, heading_style)), Line::from("")];
+        if entries.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "No tab-specific keys",
+                Style::default().fg(Color::Gray),
+            )));
+        }
+        for entry in entries {
+            lines.push(Line::from(vec![
+                Span::styled(format!("{:<width$}", entry.keys, width = key_width), key_style),
+                Span::raw("  "),
+                Span::styled(entry.description, Style::default().fg(Color::White)),
+            ]));
+        }
+        lines
+    };
+
+    let global = to_lines("Global".to_string(), app.state.keybindings.global_help());
+    let current = to_lines(
+        format!("{} tab", tab.as_str()),
+        app.state.keybindings.tab_help(tab),
+    );
+
+    f.render_widget(Paragraph::new(global), columns[0]);
+    f.render_widget(Paragraph::new(current), columns[1]);
+}
+
+fn render_errors(f: &mut Frame, app: &App) {
+    let popup_area = centered_rect(80, 70, f.size());
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title("Monitors [y] Copy [Esc/F6] Close")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .style(Style::default().bg(Color::Black));
+
+    let mut lines = Vec::new();
+    for health in app.state.monitor_health() {
+        let color = match health.state() {
+            "error" => Color::Red,
+            "waiting" => Color::Yellow,
+            "disabled" => Color::DarkGray,
+            _ => Color::Green,
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:<14}", health.tab.as_str()),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("{:<10}", health.state()), Style::default().fg(color)),
+            Span::styled(health.updated(), Style::default().fg(Color::Gray)),
+        ]));
+        if let Some(error) = health.error {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", error),
+                Style::default().fg(Color::Red),
+            )));
+        }
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    f.render_widget(paragraph, popup_area);
+}
+
+fn render_logs(f: &mut Frame, app: &App) {
+    let popup_area = centered_rect(90, 80, f.size());
+    f.render_widget(Clear, popup_area);
+
+    let records = app.state.log_buffer.snapshot();
+    let scroll = app.state.log_scroll.min(records.len().saturating_sub(1));
+    let title = if scroll > 0 {
+        format!("Log ({} back) [↑/↓/PgUp/PgDn] Scroll [End] Newest [Esc/F8] Close", scroll)
+    } else {
+        "Log [↑/↓/PgUp/PgDn] Scroll [Esc/F8] Close".to_string()
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    if records.is_empty() {
+        f.render_widget(
+            Paragraph::new("No log messages yet").style(Style::default().fg(Color::Gray)),
+            inner,
+        );
+        return;
+    }
+
+    // Newest at the bottom, ending `scroll` records before the last one
+    let rows = inner.height as usize;
+    let end = records.len() - scroll;
+    let lines: Vec<Line> = records[end.saturating_sub(rows)..end]
+        .iter()
+        .map(|record| {
+            let color = match record.level {
+                log::Level::Error => Color::Red,
+                log::Level::Warn => Color::Yellow,
+                log::Level::Info => Color::Green,
+                log::Level::Debug | log::Level::Trace => Color::DarkGray,
+            };
+            Line::from(vec![
+                Span::styled(
+                    record.time.format("%H:%M:%S ").to_string(),
+                    Style::default().fg(Color::Gray),
+                ),
+                Span::styled(format!("{:<5} ", record.level), Style::default().fg(color)),
+                Span::styled(
+                    format!("{}: ", record.target),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(record.message.clone(), Style::default().fg(Color::White)),
+            ])
+        })
+        .collect();
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+fn render_pid_prompt(f: &mut Frame, input: &str) {
+    let area = centered_rect(40, 20, f.size());
+    let popup_area = Rect {
+        y: area.y + area.height.saturating_sub(4) / 2,
+        height: area.height.min(4),
+        ..area
+    };
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title("Jump to PID")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .style(Style::default().bg(Color::Black));
+    let lines = vec![
+        Line::from(vec![
+            Span::raw("PID: "),
+            Span::styled(
+                format!("{}_", input),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Enter", Style::default().fg(Color::Cyan)),
+            Span::raw(": Go  "),
+            Span::styled("Esc", Style::default().fg(Color::Cyan)),
+            Span::raw(": Cancel"),
+        ]),
+    ];
+    f.render_widget(Paragraph::new(lines).block(block), popup_area);
+}
+
+fn render_command_output(f: &mut Frame, output: &str, success: bool, scroll: usize) {
+    let popup_area = centered_rect(80, 70, f.size());
+
+    f.render_widget(Clear, popup_area);
+
+    let (title, color) = if success {
+        ("Command Output [↑/↓] Scroll [Enter/Esc] Close", Color::Green)
+    } else {
+        ("Command Failed [↑/↓] Scroll [Enter/Esc] Close", Color::Red)
+    };
+
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(color))
+        .style(Style::default().bg(Color::Black));
+
+    let text = if output.trim().is_empty() {
+        "(no output)"
+    } else {
+        output.trim_end()
+    };
+    let line_count = text.lines().count();
+    let visible = popup_area.height.saturating_sub(2) as usize;
+    let max_scroll = line_count.saturating_sub(visible);
+    let scroll = scroll.min(max_scroll) as u16;
+
+    let paragraph = Paragraph::new(text)
+        .block(block)
+        .style(Style::default().fg(Color::White))
+        .scroll((scroll, 0));
+
+    f.render_widget(paragraph, popup_area);
+}
+
+pub(crate) fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -2,15 +2,21 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Gauge, Paragraph, Row, Table},
+    widgets::{Block, Borders, Gauge, Paragraph, Row, Sparkline, Table},
     Frame,
 };
 
-use crate::app::App;
+use crate::app::{App, TabType};
+use crate::monitors::cpu::{CpuDetails, PowerInfo};
 use crate::ui::theme::Theme;
-use crate::utils::format::{create_progress_bar, format_bytes, format_percentage};
+use crate::ui::widgets::graph::fit_sparkline;
+use crate::utils::format::{create_progress_bar, format_percentage, FormatOptions};
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
+    if super::render_if_disabled(f, area, app, TabType::Cpu, "CPU Monitor") {
+        return;
+    }
+
     let cpu_data = app.state.cpu_data.read();
     let cpu_error = app.state.cpu_error.read();
 
@@ -34,7 +40,15 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
         if app.state.compact_mode {
             render_compact(f, area, data, &theme);
         } else {
-            render_full(f, area, data, &theme);
+            render_full(
+                f,
+                area,
+                data,
+                &theme,
+                &FormatOptions::from_config(&config),
+                config.monitors.cpu.show_core_frequency,
+                app.state.cpu_state.show_details,
+            );
         }
     } else {
         let block = Block::default()
@@ -50,14 +64,24 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
     }
 }
 
-fn render_full(f: &mut Frame, area: Rect, data: &crate::monitors::CpuData, theme: &Theme) {
+fn render_full(
+    f: &mut Frame,
+    area: Rect,
+    data: &crate::monitors::CpuData,
+    theme: &Theme,
+    fmt: &FormatOptions,
+    show_core_frequency: bool,
+    show_details: bool,
+) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Header
             Constraint::Length(3), // Overall usage
+            Constraint::Length(5), // Usage history
             Constraint::Min(8),    // Core usage
             Constraint::Length(5), // Frequency & Power
+            Constraint::Length(if show_details { 4 } else { 0 }), // Details
             Constraint::Length(9), // Top Processes
         ])
         .split(area);
@@ -97,7 +121,7 @@ fn render_full(f: &mut Frame, area: Rect, data: &crate::monitors::CpuData, theme
                 .fg(theme.cpu_color)
                 .add_modifier(Modifier::BOLD),
         )
-        .percent(data.overall_usage as u16)
+        .percent(data.overall_usage.clamp(0.0, 100.0) as u16)
         .label(format!(
             "{}% - Cores: {}/{}",
             data.overall_usage as u16, data.core_count, data.thread_count
@@ -105,6 +129,27 @@ fn render_full(f: &mut Frame, area: Rect, data: &crate::monitors::CpuData, theme
 
     f.render_widget(gauge, chunks[1]);
 
+    // Usage history
+    let history: Vec<u64> = data
+        .usage_history
+        .iter()
+        .map(|&v| v.clamp(0.0, 100.0) as u64)
+        .collect();
+    let history = fit_sparkline(&history, chunks[2].width.saturating_sub(2));
+
+    let sparkline = Sparkline::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Usage History")
+                .border_style(Style::default().fg(theme.cpu_color)),
+        )
+        .data(&history)
+        .style(Style::default().fg(theme.cpu_color))
+        .max(100);
+
+    f.render_widget(sparkline, chunks[2]);
+
     // Core usage
     let core_text: Vec<Line> = data
         .core_usage
@@ -114,11 +159,16 @@ fn render_full(f: &mut Frame, area: Rect, data: &crate::monitors::CpuData, theme
                 .iter()
                 .map(|core| {
                     let bar = create_progress_bar(core.usage, 15);
+                    let clock = match core.frequency_mhz {
+                        Some(mhz) if show_core_frequency => format!(" {:.2} GHz", mhz / 1000.0),
+                        _ => String::new(),
+                    };
                     Span::raw(format!(
-                        "  Core {:2} [{}] {:>5}     ",
+                        "  Core {:2} [{}] {:>5}{}     ",
                         core.core_id,
                         bar,
-                        format_percentage(core.usage)
+                        format_percentage(core.usage),
+                        clock
                     ))
                 })
                 .collect();
@@ -135,7 +185,7 @@ fn render_full(f: &mut Frame, area: Rect, data: &crate::monitors::CpuData, theme
         .block(core_block)
         .style(Style::default().fg(Color::White));
 
-    f.render_widget(core_paragraph, chunks[2]);
+    f.render_widget(core_paragraph, chunks[3]);
 
     // Frequency & Power
     let freq_text = vec![
@@ -163,10 +213,7 @@ fn render_full(f: &mut Frame, area: Rect, data: &crate::monitors::CpuData, theme
             ),
             Span::raw("  │  Power: "),
             Span::styled(
-                format!(
-                    "{:.0}W/{:.0}W",
-                    data.power.current_power, data.power.max_power
-                ),
+                power_label(&data.power),
                 Style::default()
                     .fg(Color::Magenta)
                     .add_modifier(Modifier::BOLD),
@@ -175,7 +222,11 @@ fn render_full(f: &mut Frame, area: Rect, data: &crate::monitors::CpuData, theme
     ];
 
     let freq_block = Block::default()
-        .title("Frequency & Power")
+        .title(if show_details {
+            "Frequency & Power"
+        } else {
+            "Frequency & Power (Space: details)"
+        })
         .borders(Borders::ALL)
         .border_style(Style::default().fg(theme.cpu_color));
 
@@ -183,7 +234,11 @@ fn render_full(f: &mut Frame, area: Rect, data: &crate::monitors::CpuData, theme
         .block(freq_block)
         .style(Style::default().fg(Color::White));
 
-    f.render_widget(freq_paragraph, chunks[3]);
+    f.render_widget(freq_paragraph, chunks[4]);
+
+    if show_details {
+        render_details(f, chunks[5], &data.details, theme, fmt);
+    }
 
     // Top Processes
     let rows: Vec<Row> = data
@@ -195,7 +250,7 @@ fn render_full(f: &mut Frame, area: Rect, data: &crate::monitors::CpuData, theme
                 p.name.clone(),
                 format!("{:.1}%", p.cpu_usage),
                 format!("{}", p.threads),
-                format_bytes(p.memory),
+                fmt.bytes(p.memory),
             ])
             .style(Style::default().fg(Color::White))
         })
@@ -225,18 +280,80 @@ fn render_full(f: &mut Frame, area: Rect, data: &crate::monitors::CpuData, theme
             .border_style(Style::default().fg(theme.cpu_color)),
     );
 
-    f.render_widget(table, chunks[4]);
+    f.render_widget(table, chunks[6]);
+}
+
+/// Cache sizes and instruction set extensions; unknown values are left out.
+fn render_details(
+    f: &mut Frame,
+    area: Rect,
+    details: &CpuDetails,
+    theme: &Theme,
+    fmt: &FormatOptions,
+) {
+    let caches: Vec<String> = [
+        ("L1", details.l1_cache),
+        ("L2", details.l2_cache),
+        ("L3", details.l3_cache),
+    ]
+    .into_iter()
+    .filter_map(|(level, size)| size.map(|size| format!("{} {}", level, fmt.bytes(size))))
+    .collect();
+
+    let mut lines = Vec::new();
+    if !caches.is_empty() {
+        lines.push(Line::from(vec![
+            Span::raw("  Cache: "),
+            Span::styled(caches.join("  │  "), Style::default().fg(Color::Yellow)),
+        ]));
+    }
+    if !details.features.is_empty() {
+        lines.push(Line::from(vec![
+            Span::raw("  Features: "),
+            Span::styled(details.features.join(" "), Style::default().fg(Color::Green)),
+        ]));
+    }
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No cache or feature data reported",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    let block = Block::default()
+        .title("Details (Space: hide)")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.cpu_color));
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .style(Style::default().fg(Color::White)),
+        area,
+    );
+}
+
+/// "42W/65W", with a `~` and "(est.)" when the draw is estimated from load
+/// rather than measured.
+fn power_label(power: &PowerInfo) -> String {
+    if power.measured {
+        format!("{:.0}W/{:.0}W", power.current_power, power.max_power)
+    } else {
+        format!("~{:.0}W/{:.0}W (est.)", power.current_power, power.max_power)
+    }
 }
 
 fn render_compact(f: &mut Frame, area: Rect, data: &crate::monitors::CpuData, theme: &Theme) {
+    let temperature = data
+        .temperature
+        .map(|temp| format!(" │ {}°C", temp))
+        .unwrap_or_default();
     let compact_text = format!(
-        "CPU: {} │ {}% │ {:.2} GHz │ {}°C │ {:.0}W/{:.0}W",
+        "CPU: {} │ {}% │ {:.2} GHz{} │ {}",
         data.name.split_whitespace().next().unwrap_or("CPU"),
         data.overall_usage as u16,
         data.frequency.avg_frequency,
-        data.temperature.unwrap_or(0.0),
-        data.power.current_power,
-        data.power.max_power
+        temperature,
+        power_label(&data.power)
     );
 
     let block = Block::default()
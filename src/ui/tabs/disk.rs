@@ -2,15 +2,23 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Gauge, Paragraph, Row, Sparkline, Table},
+    widgets::{Block, Borders, Clear, Gauge, Paragraph, Row, Table},
     Frame,
 };
 
-use crate::app::App;
+use crate::app::state::DiskUIState;
+use crate::app::{App, TabType};
+use crate::monitors::disk::DiskHealth;
+use crate::ui::centered_rect;
 use crate::ui::theme::Theme;
-use crate::utils::format::{create_progress_bar, format_bytes};
+use crate::ui::widgets::graph::Graph;
+use crate::utils::format::{create_progress_bar, safe_percent, FormatOptions};
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
+    if super::render_if_disabled(f, area, app, TabType::Disk, "Disk Monitor") {
+        return;
+    }
+
     let disk_data = app.state.disk_data.read();
     let disk_error = app.state.disk_error.read();
 
@@ -30,11 +38,19 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
     } else if let Some(data) = disk_data.as_ref() {
         let config = app.state.config.read();
         let theme = Theme::from_config(&config);
+        let fmt = FormatOptions::from_config(&config);
+        let area = render_failure_banner(f, area, data);
 
+        let selected = app.state.disk_state.selected_disk;
         if app.state.compact_mode {
-            render_compact(f, area, data, &theme);
+            render_compact(f, area, data, selected, &theme);
         } else {
-            render_full(f, area, data, &theme);
+            let sample_secs = config.monitors.disk.refresh_interval_ms as f64 / 1000.0;
+            render_full(f, area, data, selected, sample_secs, &theme, &fmt);
+        }
+
+        if app.state.disk_state.show_smart {
+            render_smart_popup(f, area, data, &app.state.disk_state);
         }
     } else {
         let block = Block::default()
@@ -50,7 +66,51 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
     }
 }
 
-fn render_full(f: &mut Frame, area: Rect, data: &crate::monitors::DiskData, theme: &Theme) {
+/// Red strip above the disk panels for every disk trending toward failure.
+/// Returns the area left for the rest of the tab.
+fn render_failure_banner(f: &mut Frame, area: Rect, data: &crate::monitors::DiskData) -> Rect {
+    let failing: Vec<Line> = data
+        .physical_disks
+        .iter()
+        .filter(|disk| disk.health.predicted_failure)
+        .map(|disk| {
+            Line::from(format!(
+                " ⚠ Disk {} ({}) may be failing: {} — back up its data",
+                disk.disk_number,
+                disk.model,
+                disk.health.reasons.join(", ")
+            ))
+        })
+        .collect();
+    if failing.is_empty() {
+        return area;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(failing.len() as u16), Constraint::Min(0)])
+        .split(area);
+    f.render_widget(
+        Paragraph::new(failing).style(
+            Style::default()
+                .fg(Color::White)
+                .bg(Color::Red)
+                .add_modifier(Modifier::BOLD),
+        ),
+        chunks[0],
+    );
+    chunks[1]
+}
+
+fn render_full(
+    f: &mut Frame,
+    area: Rect,
+    data: &crate::monitors::DiskData,
+    selected: usize,
+    sample_secs: f64,
+    theme: &Theme,
+    fmt: &FormatOptions,
+) {
     if data.physical_disks.is_empty() {
         let block = Block::default()
             .title("Disk Monitor")
@@ -89,22 +149,38 @@ fn render_full(f: &mut Frame, area: Rect, data: &crate::monitors::DiskData, them
     // Render each physical disk
     for (i, disk) in data.physical_disks.iter().enumerate() {
         if i < chunks.len() {
-            render_physical_disk(f, chunks[i], disk, data, theme);
+            render_physical_disk(
+                f,
+                chunks[i],
+                disk,
+                data,
+                i == selected,
+                sample_secs,
+                theme,
+                fmt,
+            );
         }
     }
 }
 
-fn render_compact(f: &mut Frame, area: Rect, data: &crate::monitors::DiskData, theme: &Theme) {
+fn render_compact(
+    f: &mut Frame,
+    area: Rect,
+    data: &crate::monitors::DiskData,
+    selected: usize,
+    theme: &Theme,
+) {
     let mut info_lines = vec![];
 
     // Show summary of all disks
-    for disk in &data.physical_disks {
-        let health_indicator = get_health_indicator(&disk.health_status);
+    for (i, disk) in data.physical_disks.iter().enumerate() {
+        let health_indicator = get_health_indicator(&disk.health);
         let usage_pct = ((disk.size as f64 - get_disk_free_space(disk, data) as f64)
             / disk.size as f64
             * 100.0) as u16;
 
         info_lines.push(Line::from(vec![
+            Span::raw(if i == selected { "► " } else { "  " }),
             Span::styled(
                 format!("Disk {}: ", disk.disk_number),
                 Style::default()
@@ -131,12 +207,16 @@ fn render_compact(f: &mut Frame, area: Rect, data: &crate::monitors::DiskData, t
     f.render_widget(para, area);
 }
 
+#[allow(clippy::too_many_arguments)]
 fn render_physical_disk(
     f: &mut Frame,
     area: Rect,
     disk: &crate::monitors::PhysicalDiskInfo,
     all_data: &crate::monitors::DiskData,
+    is_selected: bool,
+    sample_secs: f64,
     theme: &Theme,
+    fmt: &FormatOptions,
 ) {
     let system_drive = system_drive_letter();
     let chunks = Layout::default()
@@ -150,7 +230,7 @@ fn render_physical_disk(
         .split(area);
 
     // Header
-    let health_indicator = get_health_indicator(&disk.health_status);
+    let health_indicator = get_health_indicator(&disk.health);
     let temp_str = if let Some(temp) = disk.temperature {
         format!("  {}°C", temp)
     } else {
@@ -171,19 +251,21 @@ fn render_physical_disk(
         format!("Disk {}", disk.disk_number)
     };
     let header = format!(
-        "{} {}: {} {} | {} | {}{}",
+        "{}{} {}: {} {} | {} | {}{}{}",
+        if is_selected { "► " } else { "" },
         health_indicator,
         disk_label,
         disk.model,
         disk.media_type,
         disk.bus_type,
-        format_bytes(disk.size),
-        temp_str
+        fmt.bytes(disk.size),
+        temp_str,
+        if is_selected { "  [S] SMART" } else { "" }
     );
 
     let header_block = Block::default()
         .borders(Borders::LEFT | Borders::RIGHT | Borders::TOP)
-        .border_style(Style::default().fg(get_health_color(&disk.health_status)));
+        .border_style(Style::default().fg(get_health_color(&disk.health)));
 
     let header_text = Paragraph::new(header).block(header_block).style(
         Style::default()
@@ -196,11 +278,7 @@ fn render_physical_disk(
     // Overall usage gauge
     let free_space = get_disk_free_space(disk, all_data);
     let used_space = disk.size.saturating_sub(free_space);
-    let usage_percent = if disk.size > 0 {
-        ((used_space as f64 / disk.size as f64) * 100.0) as u16
-    } else {
-        0
-    };
+    let usage_percent = safe_percent(used_space, disk.size) as u16;
 
     let gauge = Gauge::default()
         .block(
@@ -217,17 +295,17 @@ fn render_physical_disk(
         .label(format!(
             "{}% - {} / {}",
             usage_percent,
-            format_bytes(used_space),
-            format_bytes(disk.size)
+            fmt.bytes(used_space),
+            fmt.bytes(disk.size)
         ));
 
     f.render_widget(gauge, chunks[1]);
 
     // I/O Statistics and Graphs
-    render_io_stats(f, chunks[2], disk, all_data, theme);
+    render_io_stats(f, chunks[2], disk, all_data, sample_secs, theme);
 
     // Details, partitions, and process table
-    render_disk_details(f, chunks[3], disk, all_data, theme);
+    render_disk_details(f, chunks[3], disk, all_data, theme, fmt);
 }
 
 fn render_io_stats(
@@ -235,6 +313,7 @@ fn render_io_stats(
     area: Rect,
     disk: &crate::monitors::PhysicalDiskInfo,
     all_data: &crate::monitors::DiskData,
+    sample_secs: f64,
     theme: &Theme,
 ) {
     // Find I/O stats for this disk
@@ -323,81 +402,33 @@ fn render_io_stats(
     f.render_widget(metrics_para, chunks[0]);
 
     // Right side: Graphs
-    render_io_graphs(f, chunks[1], io_history, theme);
+    render_io_graphs(f, chunks[1], io_history, sample_secs, theme);
 }
 
 fn render_io_graphs(
     f: &mut Frame,
     area: Rect,
     io_history: Option<&crate::monitors::DiskIOHistory>,
+    sample_secs: f64,
     theme: &Theme,
 ) {
     if let Some(history) = io_history {
         let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Percentage(33),
-                Constraint::Percentage(33),
-                Constraint::Percentage(34),
-            ])
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
             .split(area);
 
-        // Read speed graph
-        if !history.read_history.is_empty() {
-            let data: Vec<u64> = history.read_history.iter().map(|&v| v as u64).collect();
-            let max_value = data.iter().max().copied().unwrap_or(1).max(1);
-
-            let sparkline = Sparkline::default()
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .title(format!("Read (max {:.1} MB/s)", max_value))
-                        .border_style(Style::default().fg(Color::Green)),
-                )
-                .data(&data)
-                .style(Style::default().fg(Color::Green))
-                .max(max_value);
-
-            f.render_widget(sparkline, chunks[0]);
-        }
-
-        // Write speed graph
-        if !history.write_history.is_empty() {
-            let data: Vec<u64> = history.write_history.iter().map(|&v| v as u64).collect();
-            let max_value = data.iter().max().copied().unwrap_or(1).max(1);
-
-            let sparkline = Sparkline::default()
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .title(format!("Write (max {:.1} MB/s)", max_value))
-                        .border_style(Style::default().fg(Color::Cyan)),
-                )
-                .data(&data)
-                .style(Style::default().fg(Color::Cyan))
-                .max(max_value);
-
-            f.render_widget(sparkline, chunks[1]);
-        }
-
-        // IOPS graph
-        if !history.iops_history.is_empty() {
-            let data: Vec<u64> = history.iops_history.iter().map(|&v| v as u64).collect();
-            let max_value = data.iter().max().copied().unwrap_or(1).max(1);
-
-            let sparkline = Sparkline::default()
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .title(format!("Total IOPS (max {})", max_value))
-                        .border_style(Style::default().fg(Color::Yellow)),
-                )
-                .data(&data)
-                .style(Style::default().fg(Color::Yellow))
-                .max(max_value);
-
-            f.render_widget(sparkline, chunks[2]);
-        }
+        Graph::new("Throughput", Color::Green)
+            .series("Read", &history.read_history, Color::Green)
+            .series("Write", &history.write_history, Color::Cyan)
+            .unit("MB/s")
+            .sample_secs(sample_secs)
+            .render(f, chunks[0]);
+
+        Graph::new("Total IOPS", Color::Yellow)
+            .series("IOPS", &history.iops_history, Color::Yellow)
+            .sample_secs(sample_secs)
+            .render(f, chunks[1]);
     } else {
         let block = Block::default()
             .borders(Borders::ALL)
@@ -418,6 +449,7 @@ fn render_disk_details(
     disk: &crate::monitors::PhysicalDiskInfo,
     all_data: &crate::monitors::DiskData,
     theme: &Theme,
+    fmt: &FormatOptions,
 ) {
     let system_drive = system_drive_letter();
     let chunks = Layout::default()
@@ -432,17 +464,28 @@ fn render_disk_details(
     let mut detail_lines = vec![];
 
     // Health and operational status
+    let health_color = get_health_color(&disk.health);
+    let health_label = match disk.health.score {
+        Some(score) => format!("{} ({}/100)", disk.health_status, score),
+        None => disk.health_status.clone(),
+    };
     detail_lines.push(Line::from(vec![
         Span::raw("  Health: "),
         Span::styled(
-            &disk.health_status,
+            health_label,
             Style::default()
-                .fg(get_health_color(&disk.health_status))
+                .fg(health_color)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw("  |  Status: "),
         Span::styled(&disk.operational_status, Style::default().fg(Color::Cyan)),
     ]));
+    if !disk.health.reasons.is_empty() {
+        detail_lines.push(Line::from(Span::styled(
+            format!("  {}", disk.health.reasons.join(", ")),
+            Style::default().fg(health_color),
+        )));
+    }
 
     // SMART data if available
     if let Some(hours) = disk.power_on_hours {
@@ -455,7 +498,7 @@ fn render_disk_details(
     if let Some(tbw) = disk.tbw {
         detail_lines.push(Line::from(vec![
             Span::raw("  Total Bytes Written: "),
-            Span::styled(format_bytes(tbw), Style::default().fg(Color::Magenta)),
+            Span::styled(fmt.bytes(tbw), Style::default().fg(Color::Magenta)),
         ]));
     }
 
@@ -467,7 +510,7 @@ fn render_disk_details(
     }
 
     // Partitions
-    if !disk.partitions.is_empty() {
+    if !disk.partitions.is_empty() || !disk.raw_partitions.is_empty() {
         detail_lines.push(Line::from(""));
         detail_lines.push(Line::from(vec![Span::styled(
             "  Partitions:",
@@ -491,24 +534,59 @@ fn render_disk_details(
                 } else {
                     drive.letter.clone()
                 };
-                let usage_pct = if drive.total > 0 {
-                    (drive.used as f64 / drive.total as f64 * 100.0) as f32
-                } else {
-                    0.0
-                };
+                let usage_pct = safe_percent(drive.used, drive.total) as f32;
+                let usage_color = get_usage_color(usage_pct);
 
-                detail_lines.push(Line::from(vec![
+                let mut header = vec![
                     Span::raw(format!("    {:12} ", label)),
                     Span::styled(
                         format!("{:15}", drive.name),
                         Style::default().fg(Color::Cyan),
                     ),
-                    Span::raw("  "),
-                    Span::raw(create_progress_bar(usage_pct, 15)),
-                    Span::raw(format!("  {:.0}%", usage_pct)),
+                    Span::styled(
+                        format!(" {}", drive.file_system),
+                        Style::default().fg(Color::Gray),
+                    ),
+                ];
+                if usage_pct >= NEARLY_FULL_PERCENT {
+                    header.push(Span::styled(
+                        "  ⚠ nearly full",
+                        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    ));
+                }
+                detail_lines.push(Line::from(header));
+                detail_lines.push(Line::from(vec![
+                    Span::raw("      "),
+                    Span::styled(
+                        create_progress_bar(usage_pct, 15),
+                        Style::default().fg(usage_color),
+                    ),
+                    Span::styled(
+                        format!(" {:>3.0}%", usage_pct),
+                        Style::default().fg(usage_color),
+                    ),
+                    Span::raw(format!(
+                        "  {} / {}, {} free",
+                        fmt.bytes(drive.used),
+                        fmt.bytes(drive.total),
+                        fmt.bytes(drive.free)
+                    )),
                 ]));
             }
         }
+
+        for partition in &disk.raw_partitions {
+            let label = format!("#{}", partition.number);
+            detail_lines.push(Line::from(vec![
+                Span::raw(format!("    {:12} ", label)),
+                Span::styled(
+                    format!("{:15}", partition.kind),
+                    Style::default().fg(Color::Gray),
+                ),
+                Span::raw(format!(" {}", fmt.bytes(partition.size))),
+                Span::styled("  (not mounted)", Style::default().fg(Color::DarkGray)),
+            ]));
+        }
     }
 
     let block = Block::default()
@@ -603,6 +681,83 @@ fn render_process_table(
     f.render_widget(table, area);
 }
 
+fn render_smart_popup(
+    f: &mut Frame,
+    area: Rect,
+    data: &crate::monitors::DiskData,
+    ui_state: &DiskUIState,
+) {
+    let Some(disk) = data.physical_disks.get(ui_state.selected_disk) else {
+        return;
+    };
+    let rect = centered_rect(80, 80, area);
+    f.render_widget(Clear, rect);
+
+    let block = Block::default()
+        .title(format!(
+            "SMART - Disk {}: {} [↑/↓] Scroll [S/Esc] Close",
+            disk.disk_number, disk.model
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .style(Style::default().bg(Color::Black));
+
+    if disk.smart_attributes.is_empty() {
+        let text = Paragraph::new("SMART not exposed by controller")
+            .block(block)
+            .style(Style::default().fg(Color::Gray));
+        f.render_widget(text, rect);
+        return;
+    }
+
+    let header = Row::new(vec!["ID", "Attribute", "Value", "Worst", "Thresh", "Raw"]).style(
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let optional = |value: Option<u32>| value.map(|v| v.to_string()).unwrap_or_else(|| "-".to_string());
+    let visible = rect.height.saturating_sub(3) as usize;
+    let scroll = ui_state
+        .smart_scroll
+        .min(disk.smart_attributes.len().saturating_sub(visible));
+
+    let rows: Vec<Row> = disk
+        .smart_attributes
+        .iter()
+        .skip(scroll)
+        .take(visible)
+        .map(|attr| {
+            let color = if attr.is_failing() { Color::Red } else { Color::White };
+            Row::new(vec![
+                optional(attr.id),
+                attr.name.clone(),
+                optional(attr.value),
+                optional(attr.worst),
+                optional(attr.threshold),
+                attr.raw.to_string(),
+            ])
+            .style(Style::default().fg(color))
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(5),
+        Constraint::Min(24),
+        Constraint::Length(6),
+        Constraint::Length(6),
+        Constraint::Length(7),
+        Constraint::Length(16),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .column_spacing(1);
+
+    f.render_widget(table, rect);
+}
+
 fn system_drive_letter() -> Option<String> {
     let drive = std::env::var("SystemDrive").ok()?;
     let trimmed = drive.trim().trim_end_matches('\\');
@@ -614,24 +769,27 @@ fn system_drive_letter() -> Option<String> {
     Some(normalized.to_uppercase())
 }
 
-fn get_health_indicator(health_status: &str) -> &'static str {
-    match health_status {
-        "Healthy" => "●●●●●",
-        "Warning" => "●●●●○",
-        "Unhealthy" => "●●○○○",
-        _ => "●●●○○",
-    }
+/// Five dots, one per 20 points of health score.
+fn get_health_indicator(health: &DiskHealth) -> String {
+    let filled = match health.score {
+        Some(score) => (score as usize).div_ceil(20),
+        None => 3,
+    };
+    "●".repeat(filled) + &"○".repeat(5 - filled)
 }
 
-fn get_health_color(health_status: &str) -> Color {
-    match health_status {
-        "Healthy" => Color::Green,
-        "Warning" => Color::Yellow,
-        "Unhealthy" => Color::Red,
-        _ => Color::Gray,
+fn get_health_color(health: &DiskHealth) -> Color {
+    match health.score {
+        _ if health.predicted_failure => Color::Red,
+        Some(score) if score >= 80 => Color::Green,
+        Some(_) => Color::Yellow,
+        None => Color::Gray,
     }
 }
 
+/// Partitions at or above this usage are flagged in the details panel.
+const NEARLY_FULL_PERCENT: f32 = 90.0;
+
 fn get_usage_color(usage_percent: f32) -> Color {
     if usage_percent < 70.0 {
         Color::Green
@@ -2,14 +2,30 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Gauge, Paragraph},
     Frame,
 };
-use crate::app::App;
+use std::sync::atomic::Ordering as AtomicOrdering;
+use crate::app::state::{visible_folders, DiskAnalyzerUIState, DuplicateScan};
+use crate::app::{App, TabType};
+use crate::monitors::disk_analyzer::{DuplicateGroup, RootFolderInfo};
+use crate::ui::centered_rect;
 use crate::ui::theme::Theme;
-use crate::utils::format::{create_progress_bar, format_bytes};
+use crate::utils::format::{create_progress_bar, safe_percent, FormatOptions};
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
+    if super::render_if_disabled(f, area, app, TabType::DiskAnalyzer, "Disk Analyzer") {
+        return;
+    }
+
+    if let Some(scan) = app.state.disk_analyzer_state.duplicates.as_ref() {
+        let config = app.state.config.read();
+        let theme = Theme::from_config(&config);
+        let fmt = FormatOptions::from_config(&config);
+        render_duplicates(f, area, scan, &app.state.disk_analyzer_state, &theme, &fmt);
+        return;
+    }
+
     let analyzer_data = app.state.disk_analyzer_data.read();
     let analyzer_error = app.state.disk_analyzer_error.read();
 
@@ -29,6 +45,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
     } else if let Some(data) = analyzer_data.as_ref() {
         let config = app.state.config.read();
         let theme = Theme::from_config(&config);
+        let fmt = FormatOptions::from_config(&config);
 
         if data.drives.is_empty() {
             let block = Block::default()
@@ -44,7 +61,11 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
             return;
         }
 
-        render_drives(f, area, data, &theme);
+        render_drives(f, area, data, &app.state.disk_analyzer_state, &theme, &fmt);
+
+        if app.state.disk_analyzer_state.show_delete_confirm {
+            render_delete_confirm(f, area, &app.state.disk_analyzer_state, &fmt);
+        }
     } else {
         let block = Block::default()
             .title("Disk Analyzer")
@@ -63,8 +84,22 @@ fn render_drives(
     f: &mut Frame,
     area: Rect,
     data: &crate::monitors::DiskAnalyzerData,
+    ui_state: &DiskAnalyzerUIState,
     theme: &Theme,
+    fmt: &FormatOptions,
 ) {
+    // With several drives, a summary of all of them goes on top
+    let area = if data.drives.len() > 1 && area.height > 1 {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(area);
+        render_capacity_summary(f, rows[0], data, theme, fmt);
+        rows[1]
+    } else {
+        area
+    };
+
     let drive_count = data.drives.len().max(1);
     let constraints: Vec<Constraint> = (0..drive_count)
         .map(|_| Constraint::Ratio(1, drive_count as u32))
@@ -77,17 +112,55 @@ fn render_drives(
 
     for (i, drive) in data.drives.iter().enumerate() {
         if let Some(chunk) = chunks.get(i) {
-            render_drive_panel(f, *chunk, drive, theme);
+            let selected = i == ui_state.selected_drive;
+            render_drive_panel(f, *chunk, drive, ui_state, selected, theme, fmt);
         }
     }
 }
 
+/// One gauge for the combined used and free space of every drive.
+fn render_capacity_summary(
+    f: &mut Frame,
+    area: Rect,
+    data: &crate::monitors::DiskAnalyzerData,
+    theme: &Theme,
+    fmt: &FormatOptions,
+) {
+    let used: u64 = data.drives.iter().map(|drive| drive.used).sum();
+    let total: u64 = data.drives.iter().map(|drive| drive.total).sum();
+    let free: u64 = data.drives.iter().map(|drive| drive.free).sum();
+    let used_pct = safe_percent(used, total);
+
+    let gauge = Gauge::default()
+        .gauge_style(
+            Style::default()
+                .fg(theme.get_usage_color(used_pct as f32))
+                .bg(Color::DarkGray),
+        )
+        .percent(used_pct as u16)
+        .label(format!(
+            "{} drives: {} used / {} ({:.0}%), {} free",
+            data.drives.len(),
+            fmt.bytes(used),
+            fmt.bytes(total),
+            used_pct,
+            fmt.bytes(free)
+        ));
+    f.render_widget(gauge, area);
+}
+
 fn render_drive_panel(
     f: &mut Frame,
     area: Rect,
     drive: &crate::monitors::AnalyzedDrive,
+    ui_state: &DiskAnalyzerUIState,
+    selected: bool,
     theme: &Theme,
+    fmt: &FormatOptions,
 ) {
+    let selection = selected.then_some(ui_state);
+    let search = ui_state.search.as_str();
+    let folders = visible_folders(drive, search);
     let system_drive = system_drive_letter();
     let is_system = system_drive
         .as_ref()
@@ -98,16 +171,46 @@ fn render_drive_panel(
     } else {
         drive.letter.clone()
     };
-    let title = if drive.name.is_empty() {
+    let name = if drive.name.is_empty() {
         format!("Drive {}", label)
     } else {
         format!("Drive {} ({})", label, drive.name)
     };
+    let used_pct = safe_percent(drive.used, drive.total);
+    let mut title = format!(" {:.0}% full", used_pct);
+    match selection {
+        Some(state) if state.search_editing => {
+            title.push_str(&format!(" [Search: {}_] Enter: Apply  Esc: Clear", state.search));
+        }
+        Some(_) => title.push_str(
+            " - ↑/↓: Select  ←/→: Drive  /: Search  D: Delete  S: Export  F: Duplicates  M: Treemap  O: Open  T: Terminal",
+        ),
+        None => {}
+    }
+    if !search.is_empty() && selection.is_none_or(|state| !state.search_editing) {
+        title.push_str(&format!(
+            " [Search: {}] {} match{}",
+            search,
+            folders.len(),
+            if folders.len() == 1 { "" } else { "es" }
+        ));
+    }
 
+    let border_color = if selection.is_some() { Color::Yellow } else { theme.disk_color };
+    // The drive name takes its fill level's color so a nearly full drive stands out
+    let title = Line::from(vec![
+        Span::styled(
+            name,
+            Style::default()
+                .fg(theme.get_usage_color(used_pct as f32))
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(title),
+    ]);
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(theme.disk_color));
+        .border_style(Style::default().fg(border_color));
     let inner = block.inner(area);
     f.render_widget(block, area);
 
@@ -115,38 +218,51 @@ fn render_drive_panel(
         return;
     }
 
-    if let Some(err) = drive.error.as_ref() {
-        let text = Paragraph::new(format!("Everything error: {}", err))
+    if let Some(err) = drive.error.as_ref().filter(|_| drive.root_folders.is_empty()) {
+        let text = Paragraph::new(format!("Scan error: {}", err))
             .style(Style::default().fg(theme.warning_color));
         f.render_widget(text, inner);
         return;
     }
 
-    let used_pct = if drive.total > 0 {
-        (drive.used as f64 / drive.total as f64 * 100.0).min(100.0)
-    } else {
-        0.0
-    };
-
     let mut lines = Vec::new();
     lines.push(Line::from(vec![
         Span::raw("Used "),
         Span::styled(
-            format_bytes(drive.used),
+            fmt.bytes(drive.used),
             Style::default()
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw(" / "),
-        Span::raw(format_bytes(drive.total)),
+        Span::raw(fmt.bytes(drive.total)),
         Span::raw(format!(" ({:.0}%)  ", used_pct)),
         Span::raw("Free "),
-        Span::styled(format_bytes(drive.free), Style::default().fg(Color::Green)),
+        Span::styled(fmt.bytes(drive.free), Style::default().fg(Color::Green)),
     ]));
 
+    if let Some(status) = selection.and_then(|state| state.action_status.as_ref()) {
+        lines.push(Line::from(Span::styled(
+            status.clone(),
+            Style::default().fg(Color::Cyan),
+        )));
+    }
+
+    // Partial scans (unreadable folders, timeout) still list what was sized.
+    if let Some(err) = drive.error.as_ref() {
+        lines.push(Line::from(Span::styled(
+            format!("Partial: {}", err),
+            Style::default().fg(theme.warning_color),
+        )));
+    }
+
     if inner.height > 1 {
         lines.push(Line::from(vec![Span::styled(
-            "Root folders (share of used space)",
+            if ui_state.treemap {
+                "Root folders (area = size)"
+            } else {
+                "Root folders (share of used space)"
+            },
             Style::default()
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
@@ -160,19 +276,53 @@ fn render_drive_panel(
         return;
     }
 
-    if drive.root_folders.is_empty() {
-        lines.push(Line::from("No root folder data"));
+    if folders.is_empty() {
+        lines.push(Line::from(if drive.root_folders.is_empty() {
+            "No root folder data"
+        } else {
+            "No folders match the search"
+        }));
         let text = Paragraph::new(lines).style(Style::default().fg(Color::Gray));
         f.render_widget(text, inner);
         return;
     }
 
+    if ui_state.treemap {
+        let map_area = Rect {
+            y: inner.y + lines.len() as u16,
+            height: remaining_rows,
+            ..inner
+        };
+        let text = Paragraph::new(lines).style(Style::default().fg(Color::White));
+        f.render_widget(text, inner);
+        let children = selection
+            .and_then(|state| state.treemap_children.as_ref())
+            .map(|children| (children.path.as_str(), children.folders.read()));
+        let children = children
+            .as_ref()
+            .map(|(path, folders)| (*path, folders.as_deref()));
+        render_treemap(
+            f,
+            map_area,
+            &folders,
+            selection.map(|state| state.selected_folder),
+            children,
+            fmt,
+        );
+        return;
+    }
+
     let max_rows = remaining_rows as usize;
-    let size_samples: Vec<String> = drive
-        .root_folders
+    // Scroll so the selected folder stays on screen
+    let selected_folder = selection.map(|state| state.selected_folder);
+    let first_row = selected_folder
+        .map(|index| (index + 1).saturating_sub(max_rows))
+        .unwrap_or(0);
+    let size_samples: Vec<String> = folders
         .iter()
+        .skip(first_row)
         .take(max_rows)
-        .map(|entry| format_bytes(entry.size))
+        .map(|entry| fmt.bytes(entry.size))
         .collect();
     let size_width = size_samples
         .iter()
@@ -188,48 +338,491 @@ fn render_drive_panel(
 
     let denom = if drive.used > 0 { drive.used } else { drive.total };
 
-    for (entry, size_str) in drive
-        .root_folders
+    for (index, (entry, size_str)) in folders
         .iter()
+        .skip(first_row)
         .zip(size_samples.iter())
         .take(max_rows)
+        .enumerate()
     {
-        let pct = if denom > 0 {
-            (entry.size as f64 / denom as f64 * 100.0).min(100.0)
+        let row_style = if selected_folder == Some(first_row + index) {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
         } else {
-            0.0
+            Style::default()
         };
 
+        let pct = safe_percent(entry.size, denom);
+
         let name = truncate_label(&entry.name, name_width);
-        if bar_width > 0 {
+        let rest = if bar_width > 0 {
             let bar = create_progress_bar(pct as f32, bar_width);
-            lines.push(Line::from(format!(
-                "{:<name_width$}  [{}] {:>percent_width$}% {:>size_width$}",
-                name,
+            format!(
+                "  [{}] {:>percent_width$}% {:>size_width$}",
                 bar,
                 pct.round() as u16,
                 size_str,
-                name_width = name_width,
                 percent_width = percent_width,
                 size_width = size_width
-            )));
+            )
         } else {
-            lines.push(Line::from(format!(
-                "{:<name_width$}  {:>percent_width$}% {:>size_width$}",
-                name,
+            format!(
+                "  {:>percent_width$}% {:>size_width$}",
                 pct.round() as u16,
                 size_str,
-                name_width = name_width,
                 percent_width = percent_width,
                 size_width = size_width
-            )));
-        }
+            )
+        };
+
+        let mut spans = highlight_match(name, search, row_style);
+        spans.push(Span::styled(rest, row_style));
+        lines.push(Line::from(spans));
     }
 
     let text = Paragraph::new(lines).style(Style::default().fg(Color::White));
     f.render_widget(text, inner);
 }
 
+/// Folders below this share of the treemap are merged into one "Other" tile.
+const TREEMAP_MIN_SHARE: f64 = 0.02;
+/// Cells a tile needs for its name and size to stay readable.
+const TREEMAP_MIN_TILE_CELLS: usize = 40;
+const TREEMAP_COLORS: [Color; 6] = [
+    Color::Blue,
+    Color::Green,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Red,
+    Color::LightBlue,
+];
+
+/// Draw `folders` as tiles sized by folder size. The selected tile is framed
+/// and, once `children` (path and scanned subfolders) match it, shows its
+/// own subfolders one level down.
+fn render_treemap(
+    f: &mut Frame,
+    area: Rect,
+    folders: &[&RootFolderInfo],
+    selected: Option<usize>,
+    children: Option<(&str, Option<&[RootFolderInfo]>)>,
+    fmt: &FormatOptions,
+) {
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+
+    let mut order: Vec<usize> = (0..folders.len()).collect();
+    order.sort_by_key(|&index| std::cmp::Reverse(folders[index].size));
+    let sizes: Vec<u64> = order.iter().map(|&index| folders[index].size).collect();
+    let shown = treemap_tile_count(&sizes, area.width as usize * area.height as usize);
+    let mut weights = sizes[..shown].to_vec();
+    if shown < sizes.len() {
+        weights.push(sizes[shown..].iter().sum());
+    }
+
+    for (tile, rect) in treemap_layout(&weights, area).into_iter().enumerate() {
+        if rect.width == 0 || rect.height == 0 {
+            continue;
+        }
+        let folder = order.get(tile).filter(|_| tile < shown).map(|&index| folders[index]);
+        let is_selected = match folder {
+            Some(_) => selected == Some(order[tile]),
+            // The "Other" tile stands in for every folder too small to show
+            None => selected.is_some_and(|index| order[shown..].contains(&index)),
+        };
+        let (name, size) = match folder {
+            Some(folder) => (folder.name.clone(), folder.size),
+            None => (format!("Other ({})", sizes.len() - shown), weights[tile]),
+        };
+        let color = TREEMAP_COLORS[tile % TREEMAP_COLORS.len()];
+
+        if !is_selected {
+            render_tile(f, rect, &name, &fmt.bytes(size), color);
+            continue;
+        }
+
+        let title = format!("{} {}", name, fmt.bytes(size));
+        let block = Block::default()
+            .title(truncate_label(&title, rect.width as usize).trim_end().to_string())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+        let inner = block.inner(rect);
+        f.render_widget(block, rect);
+        if inner.width == 0 || inner.height == 0 {
+            continue;
+        }
+
+        let subfolders = folder.and_then(|folder| {
+            children
+                .filter(|(path, _)| *path == folder.path)
+                .map(|(_, subfolders)| subfolders)
+        });
+        match subfolders {
+            Some(Some(subfolders)) if !subfolders.is_empty() => {
+                let subfolders: Vec<&RootFolderInfo> = subfolders.iter().collect();
+                render_treemap(f, inner, &subfolders, None, None, fmt);
+            }
+            Some(Some(_)) => f.render_widget(
+                Paragraph::new("No subfolders").style(Style::default().fg(Color::Gray)),
+                inner,
+            ),
+            Some(None) => f.render_widget(
+                Paragraph::new("Scanning subfolders...").style(Style::default().fg(Color::Gray)),
+                inner,
+            ),
+            None => render_tile(f, inner, &name, &fmt.bytes(size), color),
+        }
+    }
+}
+
+fn render_tile(f: &mut Frame, rect: Rect, name: &str, size: &str, color: Color) {
+    let width = rect.width as usize;
+    let lines = vec![
+        Line::from(truncate_label(name, width)),
+        Line::from(truncate_label(size, width)),
+    ];
+    let tile = Paragraph::new(lines).style(Style::default().fg(Color::Black).bg(color));
+    f.render_widget(tile, rect);
+}
+
+/// How many of `sizes` (largest first) get their own tile in `cells` of
+/// space; the rest are merged into an "Other" tile.
+fn treemap_tile_count(sizes: &[u64], cells: usize) -> usize {
+    let total: u64 = sizes.iter().sum();
+    let max_tiles = (cells / TREEMAP_MIN_TILE_CELLS).max(1);
+    let large = sizes
+        .iter()
+        .take_while(|&&size| total > 0 && size as f64 / total as f64 >= TREEMAP_MIN_SHARE)
+        .count();
+    if large == sizes.len() && large <= max_tiles {
+        large
+    } else {
+        // Leave room for the "Other" tile
+        large.min(max_tiles - 1)
+    }
+}
+
+/// Split `area` into one rect per weight, in order. Each cut halves the
+/// remaining weight across the longer side so tiles stay roughly square;
+/// terminal cells are about twice as tall as they are wide.
+fn treemap_layout(weights: &[u64], area: Rect) -> Vec<Rect> {
+    let mut rects = vec![Rect::default(); weights.len()];
+    split_tiles(weights, area, &mut rects);
+    rects
+}
+
+fn split_tiles(weights: &[u64], area: Rect, out: &mut [Rect]) {
+    if weights.len() <= 1 {
+        if let Some(rect) = out.first_mut() {
+            *rect = area;
+        }
+        return;
+    }
+
+    let total: u64 = weights.iter().sum();
+    let mut cut = 1;
+    let mut running = weights[0];
+    while cut < weights.len() - 1 && running.saturating_mul(2) < total {
+        running += weights[cut];
+        cut += 1;
+    }
+    let share = if total > 0 {
+        running as f64 / total as f64
+    } else {
+        cut as f64 / weights.len() as f64
+    };
+
+    let (first, second) = if area.width >= area.height.saturating_mul(2) {
+        let width = (area.width as f64 * share).round() as u16;
+        (
+            Rect { width, ..area },
+            Rect {
+                x: area.x + width,
+                width: area.width - width,
+                ..area
+            },
+        )
+    } else {
+        let height = (area.height as f64 * share).round() as u16;
+        (
+            Rect { height, ..area },
+            Rect {
+                y: area.y + height,
+                height: area.height - height,
+                ..area
+            },
+        )
+    };
+
+    let (first_out, second_out) = out.split_at_mut(cut);
+    split_tiles(&weights[..cut], first, first_out);
+    split_tiles(&weights[cut..], second, second_out);
+}
+
+/// Split `label` so the first case-insensitive occurrence of `search` stands out.
+fn highlight_match(label: String, search: &str, style: Style) -> Vec<Span<'static>> {
+    let lower = label.to_lowercase();
+    // Byte offsets only line up when lowercasing kept every char's length
+    let found = (!search.is_empty() && lower.len() == label.len())
+        .then(|| lower.find(&search.to_lowercase()))
+        .flatten();
+
+    match found {
+        Some(start) => {
+            let end = start + search.to_lowercase().len();
+            vec![
+                Span::styled(label[..start].to_string(), style),
+                Span::styled(
+                    label[start..end].to_string(),
+                    style.fg(Color::Cyan).add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+                ),
+                Span::styled(label[end..].to_string(), style),
+            ]
+        }
+        None => vec![Span::styled(label, style)],
+    }
+}
+
+fn render_delete_confirm(
+    f: &mut Frame,
+    area: Rect,
+    ui_state: &DiskAnalyzerUIState,
+    fmt: &FormatOptions,
+) {
+    let Some(target) = ui_state.pending_delete.as_ref() else {
+        return;
+    };
+    let rect = centered_rect(60, 40, area);
+
+    f.render_widget(Clear, rect);
+
+    let destination = if cfg!(target_os = "linux") {
+        "Moves it to the trash (deleted permanently if no trash tool is installed)."
+    } else {
+        "Moves it to the Recycle Bin."
+    };
+
+    let mut text = vec![
+        Line::from(vec![
+            Span::styled(
+                "Delete",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(": "),
+            Span::styled(target.path.clone(), Style::default().fg(Color::White)),
+        ]),
+        Line::from(vec![
+            Span::raw("Size: "),
+            Span::styled(
+                fmt.bytes(target.size),
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(destination),
+        Line::from(""),
+    ];
+
+    if ui_state.delete_needs_second_confirm {
+        text.push(Line::from(Span::styled(
+            "Large folder: confirm twice to delete.",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )));
+        text.push(Line::from(""));
+    }
+    text.push(Line::from(vec![
+        Span::styled("Enter/Y", Style::default().fg(Color::Cyan)),
+        Span::raw(": Confirm  "),
+        Span::styled("Esc/N", Style::default().fg(Color::Cyan)),
+        Span::raw(": Cancel"),
+    ]));
+
+    let block = Block::default()
+        .title(format!("Confirm Delete - {}", target.name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Red));
+
+    let paragraph = Paragraph::new(text)
+        .block(block)
+        .wrap(ratatui::widgets::Wrap { trim: false });
+    f.render_widget(paragraph, rect);
+}
+
+fn render_duplicates(
+    f: &mut Frame,
+    area: Rect,
+    scan: &DuplicateScan,
+    ui_state: &DiskAnalyzerUIState,
+    theme: &Theme,
+    fmt: &FormatOptions,
+) {
+    let groups = scan.groups.read();
+    let title = match groups.as_ref() {
+        Some(groups) => format!(
+            "Duplicates in {} - {} group{}, {} reclaimable - ↑/↓: Select  D: Delete copies  O: Show file  Esc: Back",
+            scan.root,
+            groups.len(),
+            if groups.len() == 1 { "" } else { "s" },
+            fmt.bytes(groups.iter().map(|g| g.reclaimable()).sum())
+        ),
+        None => format!("Duplicates in {} - Esc: Cancel", scan.root),
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let mut lines = Vec::new();
+    if let Some(status) = ui_state.action_status.as_ref() {
+        lines.push(Line::from(Span::styled(
+            status.clone(),
+            Style::default().fg(Color::Cyan),
+        )));
+    }
+
+    let Some(groups) = groups.as_ref() else {
+        let progress = &scan.progress;
+        let seen = progress.files_seen.load(AtomicOrdering::Relaxed);
+        let candidates = progress.candidates.load(AtomicOrdering::Relaxed);
+        let hashed = progress.hashed.load(AtomicOrdering::Relaxed);
+        lines.push(Line::from(if candidates == 0 {
+            format!("Scanning... {} files found", seen)
+        } else {
+            format!(
+                "Comparing same-size files... {}/{} ({} files scanned)",
+                hashed, candidates, seen
+            )
+        }));
+        f.render_widget(
+            Paragraph::new(lines).style(Style::default().fg(theme.warning_color)),
+            inner,
+        );
+        return;
+    };
+
+    if groups.is_empty() {
+        lines.push(Line::from("No duplicate files found"));
+        f.render_widget(
+            Paragraph::new(lines).style(Style::default().fg(Color::Gray)),
+            inner,
+        );
+        return;
+    }
+
+    // Each group takes a header row plus one row per copy
+    let max_rows = inner.height.saturating_sub(lines.len() as u16) as usize;
+    let width = inner.width as usize;
+    let mut rows: Vec<(usize, Line)> = Vec::new();
+    for (index, group) in groups.iter().enumerate() {
+        let selected = index == scan.selected_group;
+        let header_style = if selected {
+            Style::default().fg(Color::Black).bg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        };
+        rows.push((
+            index,
+            Line::from(Span::styled(
+                truncate_label(
+                    &format!(
+                        "{} × {} - {} reclaimable",
+                        group.paths.len(),
+                        fmt.bytes(group.size),
+                        fmt.bytes(group.reclaimable())
+                    ),
+                    width,
+                ),
+                header_style,
+            )),
+        ));
+        for (copy, path) in group.paths.iter().enumerate() {
+            let (marker, style) = if copy == 0 {
+                ("keep ", Style::default().fg(Color::Green))
+            } else {
+                ("  -  ", Style::default().fg(Color::White))
+            };
+            rows.push((
+                index,
+                Line::from(Span::styled(
+                    truncate_label(&format!("  {}{}", marker, path), width),
+                    style,
+                )),
+            ));
+        }
+    }
+
+    // Scroll so the selected group's header stays on screen
+    let header_row = rows
+        .iter()
+        .position(|(index, _)| *index == scan.selected_group)
+        .unwrap_or(0);
+    let first_row = (header_row + 1).saturating_sub(max_rows.max(1));
+    lines.extend(rows.into_iter().skip(first_row).take(max_rows).map(|(_, line)| line));
+    f.render_widget(Paragraph::new(lines), inner);
+
+    if scan.confirm_delete {
+        if let Some(group) = groups.get(scan.selected_group) {
+            render_duplicate_confirm(f, area, group, fmt);
+        }
+    }
+}
+
+fn render_duplicate_confirm(
+    f: &mut Frame,
+    area: Rect,
+    group: &DuplicateGroup,
+    fmt: &FormatOptions,
+) {
+    let rect = centered_rect(60, 40, area);
+    f.render_widget(Clear, rect);
+
+    let destination = if cfg!(target_os = "linux") {
+        "Copies go to the trash (deleted permanently if no trash tool is installed)."
+    } else {
+        "Copies go to the Recycle Bin."
+    };
+    let text = vec![
+        Line::from(vec![
+            Span::styled(
+                "Delete",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(
+                " {} cop{} of {}",
+                group.paths.len() - 1,
+                if group.paths.len() == 2 { "y" } else { "ies" },
+                fmt.bytes(group.size)
+            )),
+        ]),
+        Line::from(vec![
+            Span::raw("Keeping: "),
+            Span::styled(group.paths[0].clone(), Style::default().fg(Color::Green)),
+        ]),
+        Line::from(""),
+        Line::from(destination),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Enter/Y", Style::default().fg(Color::Cyan)),
+            Span::raw(": Confirm  "),
+            Span::styled("Esc/N", Style::default().fg(Color::Cyan)),
+            Span::raw(": Cancel"),
+        ]),
+    ];
+
+    let block = Block::default()
+        .title(format!("Confirm Delete - {} reclaimable", fmt.bytes(group.reclaimable())))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Red));
+    let paragraph = Paragraph::new(text)
+        .block(block)
+        .wrap(ratatui::widgets::Wrap { trim: false });
+    f.render_widget(paragraph, rect);
+}
+
 fn system_drive_letter() -> Option<String> {
     let drive = std::env::var("SystemDrive").ok()?;
     let trimmed = drive.trim().trim_end_matches('\\');
@@ -282,3 +875,23 @@ fn truncate_label(label: &str, width: usize) -> String {
     truncated.push_str("...");
     format!("{:<width$}", truncated, width = width)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn treemap_groups_small_folders_and_fills_the_area() {
+        assert_eq!(treemap_tile_count(&[500, 300, 190, 5, 5], 10_000), 3);
+        assert_eq!(treemap_tile_count(&[50, 50], 10_000), 2);
+        // Too little room: keep one tile free for "Other"
+        assert_eq!(treemap_tile_count(&[40, 30, 30], 80), 1);
+
+        let area = Rect::new(0, 0, 40, 10);
+        let rects = treemap_layout(&[60, 30, 10], area);
+        let cells: Vec<u32> = rects.iter().map(|r| r.width as u32 * r.height as u32).collect();
+        assert_eq!(cells.iter().sum::<u32>(), 400);
+        assert_eq!(cells[0], 240);
+        assert!(cells[1] > cells[2]);
+    }
+}
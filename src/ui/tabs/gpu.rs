@@ -6,13 +6,17 @@ use ratatui::{
     Frame,
 };
 
-use crate::app::App;
+use crate::app::{App, TabType};
 use crate::app::state::GpuProcessSortColumn;
-use crate::monitors::gpu::GpuProcessInfo;
+use crate::monitors::gpu::{GpuDevice, GpuProcessInfo};
 use crate::ui::theme::Theme;
-use crate::utils::format::format_bytes;
+use crate::utils::format::{safe_percent, FormatOptions};
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
+    if super::render_if_disabled(f, area, app, TabType::Gpu, "GPU Monitor") {
+        return;
+    }
+
     let gpu_data = app.state.gpu_data.read();
     let gpu_error = app.state.gpu_error.read();
 
@@ -32,11 +36,12 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
     } else if let Some(data) = gpu_data.as_ref() {
         let config = app.state.config.read();
         let theme = Theme::from_config(&config);
+        let fmt = FormatOptions::from_config(&config);
 
         if app.state.compact_mode {
-            render_compact(f, area, data, &theme);
+            render_compact(f, area, data, &theme, &fmt);
         } else {
-            render_full(f, area, data, app, &theme);
+            render_full(f, area, data, app, &theme, &fmt);
         }
     } else {
         let block = Block::default()
@@ -58,149 +63,265 @@ fn render_full(
     data: &crate::monitors::GpuData,
     app: &App,
     theme: &Theme,
+    fmt: &FormatOptions,
 ) {
+    let gpu_state = &app.state.gpu_state;
+    let selected_device = gpu_state
+        .selected_device
+        .min(data.devices.len().saturating_sub(1));
+
+    let mut constraints: Vec<Constraint> = (0..data.devices.len())
+        .map(|i| {
+            if gpu_state.collapsed_devices.contains(&i) {
+                Constraint::Length(3)
+            } else {
+                Constraint::Length(7)
+            }
+        })
+        .collect();
+    constraints.push(Constraint::Min(7)); // GPU Processes
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(3), // Header
-            Constraint::Length(3), // Overall usage
-            Constraint::Length(7), // Performance metrics
-            Constraint::Length(5), // VRAM usage
-            Constraint::Length(7), // GPU Processes
-        ])
+        .constraints(constraints)
         .split(area);
 
-    // Header
-    let header = format!(
-        "GPU {}: {}  Bus: {}  Driver: {}  CUDA: {}  Temp: {:.1}°C",
-        data.gpu_index,
-        data.name,
-        if data.bus_id.is_empty() { "N/A" } else { &data.bus_id },
-        data.driver_version,
-        if data.cuda_version.is_empty() { "N/A" } else { &data.cuda_version },
-        data.temperature
-    );
+    for (i, device) in data.devices.iter().enumerate() {
+        let collapsed = gpu_state.collapsed_devices.contains(&i);
+        render_device_panel(
+            f,
+            chunks[i],
+            device,
+            i == selected_device && data.devices.len() > 1,
+            collapsed,
+            theme,
+            fmt,
+        );
+    }
 
-    let header_block = Block::default()
-        .borders(Borders::ALL)
-        .border_style(Style::default().fg(theme.gpu_color));
+    render_process_table(f, chunks[data.devices.len()], data, app, theme, fmt);
+}
 
-    let header_text = Paragraph::new(header).block(header_block).style(
+fn render_device_panel(
+    f: &mut Frame,
+    area: Rect,
+    device: &GpuDevice,
+    selected: bool,
+    collapsed: bool,
+    theme: &Theme,
+    fmt: &FormatOptions,
+) {
+    let vram_used_pct = percent_of(device.memory_used, device.memory_total);
+
+    let title = format!(
+        " {} GPU {}: {} ",
+        if collapsed { "▶" } else { "▼" },
+        device.gpu_index,
+        device.name
+    );
+    let title_style = if selected {
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD)
+    } else {
         Style::default()
             .fg(Color::White)
-            .add_modifier(Modifier::BOLD),
-    );
+            .add_modifier(Modifier::BOLD)
+    };
+    let mut title_spans = vec![Span::styled(title, title_style)];
+    if !device.throttle_reasons.is_empty() {
+        let color = if device.throttle_is_severe() {
+            theme.error_color
+        } else {
+            theme.warning_color
+        };
+        title_spans.push(Span::styled(
+            format!(" THROTTLED: {} ", device.throttle_reasons.join(", ")),
+            Style::default().fg(Color::Black).bg(color),
+        ));
+    }
+    let block = Block::default()
+        .title(Line::from(title_spans))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(if selected {
+            Color::Yellow
+        } else {
+            theme.gpu_color
+        }));
+
+    if collapsed {
+        let summary = format!(
+            "  {}% │ VRAM {} / {} ({}%) │ {:.1}°C │ {:.0}W/{:.0}W",
+            device.utilization.clamp(0.0, 100.0) as u16,
+            fmt.bytes(device.memory_used),
+            fmt.bytes(device.memory_total),
+            vram_used_pct,
+            device.temperature,
+            device.power_usage,
+            device.power_limit
+        );
+        let paragraph = Paragraph::new(summary)
+            .block(block)
+            .style(Style::default().fg(Color::White));
+        f.render_widget(paragraph, area);
+        return;
+    }
 
-    f.render_widget(header_text, chunks[0]);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
 
-    // Overall GPU usage
-    let utilization_pct = data.utilization.clamp(0.0, 100.0) as u16;
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Device info
+            Constraint::Length(1), // GPU usage
+            Constraint::Length(1), // VRAM usage
+            Constraint::Length(1), // Clocks & power
+            Constraint::Length(1), // Fan & temperature
+        ])
+        .split(inner);
+
+    let info = Line::from(vec![
+        Span::raw("  Bus: "),
+        Span::styled(
+            if device.bus_id.is_empty() { "N/A" } else { &device.bus_id },
+            Style::default().fg(Color::White),
+        ),
+        Span::raw("  │  Driver: "),
+        Span::styled(&device.driver_version, Style::default().fg(Color::White)),
+        Span::raw("  │  CUDA: "),
+        Span::styled(
+            if device.cuda_version.is_empty() {
+                "N/A"
+            } else {
+                &device.cuda_version
+            },
+            Style::default().fg(Color::White),
+        ),
+    ]);
+    f.render_widget(Paragraph::new(info).style(Style::default().fg(Color::Gray)), rows[0]);
+
+    let utilization_pct = device.utilization.clamp(0.0, 100.0) as u16;
     let gauge = Gauge::default()
-        .block(Block::default().borders(Borders::ALL).title("GPU Usage"))
         .gauge_style(
             Style::default()
                 .fg(theme.gpu_color)
                 .add_modifier(Modifier::BOLD),
         )
         .percent(utilization_pct)
-        .label(format!("{}%", utilization_pct));
-
-    f.render_widget(gauge, chunks[1]);
-
-    // Performance metrics
-    let perf_text = vec![
-        Line::from(vec![
-            Span::raw("  GPU Clock: "),
-            Span::styled(
-                format!("{} MHz", data.clock_speed),
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("  │  Memory Clock: "),
-            Span::styled(
-                format!("{} MHz", data.memory_clock),
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
-            ),
-        ]),
-        Line::from(vec![
-            Span::raw("  Power Draw: "),
-            Span::styled(
-                format!("{:.0}W/{:.0}W", data.power_usage, data.power_limit),
-                Style::default()
-                    .fg(Color::Magenta)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("  │  Fan Speed: "),
-            Span::styled(
-                if data.fan_speed < 0.0 {
-                    "-".to_string()
-                } else {
-                    format!("{:.0}%", data.fan_speed)
-                },
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD),
-            ),
-        ]),
-        Line::from(vec![
-            Span::raw("  Temperature: "),
-            Span::styled(
-                format!("{:.1}°C", data.temperature),
-                Style::default().fg(theme.get_temp_color(data.temperature)),
+        .label(format!("GPU {}%", utilization_pct));
+    f.render_widget(gauge, rows[1]);
+
+    // Dedicated and shared side by side where the source reports both
+    let memory_gauge = |label: &str, used: u64, total: u64, color: Color| {
+        let pct = percent_of(used, total);
+        Gauge::default()
+            .gauge_style(Style::default().fg(color).add_modifier(Modifier::BOLD))
+            .percent(pct)
+            .label(format!(
+                "{} {} / {} ({}%)",
+                label,
+                fmt.bytes(used),
+                fmt.bytes(total),
+                pct
+            ))
+    };
+    if device.shared_total > 0 {
+        let halves = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(rows[2]);
+        f.render_widget(
+            memory_gauge(
+                "Dedicated",
+                device.dedicated_used,
+                device.dedicated_total,
+                theme.success_color,
             ),
-            Span::raw("  │  Utilization: "),
-            Span::styled(
-                format!("{:.0}%", data.utilization),
-                Style::default()
-                    .fg(theme.gpu_color)
-                    .add_modifier(Modifier::BOLD),
+            halves[0],
+        );
+        f.render_widget(
+            memory_gauge(
+                "Shared",
+                device.shared_used,
+                device.shared_total,
+                theme.ram_color,
             ),
-        ]),
-    ];
-
-    let perf_block = Block::default()
-        .title("Performance Metrics")
-        .borders(Borders::ALL)
-        .border_style(Style::default().fg(theme.gpu_color));
-
-    let perf_paragraph = Paragraph::new(perf_text)
-        .block(perf_block)
-        .style(Style::default().fg(Color::White));
-
-    f.render_widget(perf_paragraph, chunks[2]);
-
-    // VRAM Usage
-    let vram_used_pct = if data.memory_total > 0 {
-        ((data.memory_used as f64 / data.memory_total as f64) * 100.0)
-            .min(100.0) as u16
+            halves[1],
+        );
     } else {
-        0
-    };
+        f.render_widget(
+            memory_gauge(
+                "VRAM",
+                device.memory_used,
+                device.memory_total,
+                theme.success_color,
+            ),
+            rows[2],
+        );
+    }
 
-    let vram_gauge = Gauge::default()
-        .block(Block::default().borders(Borders::ALL).title(format!(
-            "VRAM Usage ({} Total)",
-            format_bytes(data.memory_total)
-        )))
-        .gauge_style(
+    let clocks = Line::from(vec![
+        Span::raw("  GPU Clock: "),
+        Span::styled(
+            format!("{} MHz", device.clock_speed),
             Style::default()
-                .fg(theme.success_color)
+                .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
-        )
-        .percent(vram_used_pct)
-        .label(format!(
-            "{} / {} ({}%)",
-            format_bytes(data.memory_used),
-            format_bytes(data.memory_total),
-            vram_used_pct
-        ));
+        ),
+        Span::raw("  │  Memory Clock: "),
+        Span::styled(
+            format!("{} MHz", device.memory_clock),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw("  │  Power Draw: "),
+        Span::styled(
+            format!("{:.0}W/{:.0}W", device.power_usage, device.power_limit),
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]);
+    f.render_widget(Paragraph::new(clocks).style(Style::default().fg(Color::White)), rows[3]);
+
+    let thermals = Line::from(vec![
+        Span::raw("  Temperature: "),
+        Span::styled(
+            format!("{:.1}°C", device.temperature),
+            Style::default().fg(theme.get_temp_color(device.temperature)),
+        ),
+        Span::raw("  │  Fan Speed: "),
+        Span::styled(
+            match device.fan_count {
+                Some(0) => "passive (no fan)".to_string(),
+                _ if device.fan_speed < 0.0 => "-".to_string(),
+                _ => format!("{:.0}%", device.fan_speed),
+            },
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]);
+    f.render_widget(
+        Paragraph::new(thermals).style(Style::default().fg(Color::White)),
+        rows[4],
+    );
+}
 
-    f.render_widget(vram_gauge, chunks[3]);
+fn percent_of(used: u64, total: u64) -> u16 {
+    safe_percent(used, total) as u16
+}
 
-    // GPU Processes
+fn render_process_table(
+    f: &mut Frame,
+    area: Rect,
+    data: &crate::monitors::GpuData,
+    app: &App,
+    theme: &Theme,
+    fmt: &FormatOptions,
+) {
     let mut processes = data.processes.clone();
     sort_gpu_processes(
         &mut processes,
@@ -213,8 +334,8 @@ fn render_full(
             .gpu_state
             .selected_index
             .min(processes.len().saturating_sub(1));
-        let hotkeys_height = if chunks[4].height > 2 { 1 } else { 0 };
-        let visible_rows = chunks[4]
+        let hotkeys_height = if area.height > 2 { 1 } else { 0 };
+        let visible_rows = area
             .height
             .saturating_sub(2 + 1 + hotkeys_height)
             .max(1) as usize;
@@ -247,7 +368,7 @@ fn render_full(
                     p.process_type.clone(),
                     p.name.clone(),
                     gpu_text,
-                    format_bytes(p.vram),
+                    fmt.bytes(p.vram),
                 ])
                 .style(style)
             })
@@ -309,7 +430,7 @@ fn render_full(
         .column_spacing(1)
         .highlight_style(Style::default().fg(Color::Black).bg(Color::Green));
 
-        f.render_widget(table, chunks[4]);
+        f.render_widget(table, area);
 
         let hotkeys = vec![Line::from(vec![
             Span::styled("↑/↓", Style::default().fg(Color::Cyan)),
@@ -317,13 +438,17 @@ fn render_full(
             Span::styled("p/n/g/m/t", Style::default().fg(Color::Cyan)),
             Span::raw(": Sort by PID/Name/GPU/Memory/Type  "),
             Span::styled("PgUp/PgDn", Style::default().fg(Color::Cyan)),
-            Span::raw(": Page Up/Down"),
+            Span::raw(": Page Up/Down  "),
+            Span::styled("←/→", Style::default().fg(Color::Cyan)),
+            Span::raw(": Select GPU  "),
+            Span::styled("Space", Style::default().fg(Color::Cyan)),
+            Span::raw(": Collapse"),
         ])];
         if hotkeys_height > 0 {
             let hotkeys_area = Rect {
-                x: chunks[4].x + 2,
-                y: chunks[4].y + chunks[4].height - 2,
-                width: chunks[4].width.saturating_sub(4),
+                x: area.x + 2,
+                y: area.y + area.height - 2,
+                width: area.width.saturating_sub(4),
                 height: 1,
             };
             let hotkeys_paragraph = Paragraph::new(hotkeys);
@@ -339,7 +464,7 @@ fn render_full(
             .block(block)
             .style(Style::default().fg(Color::Gray));
 
-        f.render_widget(text, chunks[4]);
+        f.render_widget(text, area);
     }
 }
 
@@ -371,21 +496,36 @@ fn sort_gpu_processes(
     });
 }
 
-fn render_compact(f: &mut Frame, area: Rect, data: &crate::monitors::GpuData, theme: &Theme) {
-    let compact_text = format!(
-        "GPU: {} │ {}% │ {}/{} │ {:.1}°C │ {:.0}W/{:.0}W",
-        data.name
-            .split_whitespace()
-            .take(2)
-            .collect::<Vec<_>>()
-            .join(" "),
-        data.utilization as u16,
-        format_bytes(data.memory_used),
-        format_bytes(data.memory_total),
-        data.temperature,
-        data.power_usage,
-        data.power_limit
-    );
+fn render_compact(
+    f: &mut Frame,
+    area: Rect,
+    data: &crate::monitors::GpuData,
+    theme: &Theme,
+    fmt: &FormatOptions,
+) {
+    let compact_text = match data.busiest() {
+        Some(device) => format!(
+            "GPU: {} │ {}% │ {}/{} │ {:.1}°C │ {:.0}W/{:.0}W{}",
+            device
+                .name
+                .split_whitespace()
+                .take(2)
+                .collect::<Vec<_>>()
+                .join(" "),
+            device.utilization as u16,
+            fmt.bytes(device.memory_used),
+            fmt.bytes(device.memory_total),
+            device.temperature,
+            device.power_usage,
+            device.power_limit,
+            if data.devices.len() > 1 {
+                format!(" │ +{} more", data.devices.len() - 1)
+            } else {
+                String::new()
+            }
+        ),
+        None => "GPU: No devices".to_string(),
+    };
 
     let block = Block::default()
         .borders(Borders::ALL)
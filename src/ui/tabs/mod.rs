@@ -1,3 +1,4 @@
+pub mod overview;
 pub mod cpu;
 pub mod gpu;
 pub mod ram;
@@ -8,3 +9,80 @@ pub mod processes;
 pub mod services;
 pub mod disk_analyzer;
 pub mod settings;
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::Span,
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::app::monitors_task::MonitorStatus;
+use crate::app::{App, Config, TabType};
+
+/// Renders the placeholder for a monitor switched off in config and returns
+/// true, or returns false so the tab renders normally.
+pub(crate) fn render_if_disabled(f: &mut Frame, area: Rect, app: &App, tab: TabType, title: &str) -> bool {
+    if app.state.monitor_status(tab) != MonitorStatus::Disabled {
+        return false;
+    }
+
+    let block = Block::default()
+        .title(title.to_string())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray));
+    let text = Paragraph::new("Monitor disabled in config.")
+        .block(block)
+        .style(Style::default().fg(Color::Gray));
+
+    f.render_widget(text, area);
+    true
+}
+
+/// "updated 2s ago" for the current tab's monitor, turning yellow once the data
+/// is older than two refresh intervals and red past ten. Intervals are scaled
+/// like the monitors' own while idle.
+pub(crate) fn staleness_span(app: &App, config: &Config, tab: TabType) -> Option<Span<'static>> {
+    let interval_ms = config.refresh_interval_ms(tab)? * app.state.refresh_scale() as u64;
+    if app.state.monitor_status(tab) == MonitorStatus::Disabled {
+        return None;
+    }
+    let age_ms = app.state.monitor_age(tab)?.num_milliseconds().max(0) as u64;
+    let (text, color) = staleness(age_ms, interval_ms);
+    Some(Span::styled(text, Style::default().fg(color)))
+}
+
+fn staleness(age_ms: u64, interval_ms: u64) -> (String, Color) {
+    let secs = age_ms / 1000;
+    let text = if secs < 60 {
+        format!(" updated {}s ago ", secs)
+    } else if secs < 3600 {
+        format!(" updated {}m ago ", secs / 60)
+    } else {
+        format!(" updated {}h ago ", secs / 3600)
+    };
+
+    let interval_ms = interval_ms.max(100);
+    let color = if age_ms > interval_ms * 10 {
+        Color::Red
+    } else if age_ms > interval_ms * 2 {
+        Color::Yellow
+    } else {
+        Color::DarkGray
+    };
+    (text, color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn staleness_colors_follow_refresh_interval() {
+        assert_eq!(staleness(1_500, 1_000), (" updated 1s ago ".to_string(), Color::DarkGray));
+        assert_eq!(staleness(2_500, 1_000).1, Color::Yellow);
+        assert_eq!(staleness(10_500, 1_000).1, Color::Red);
+        assert_eq!(staleness(125_000, 60_000).0, " updated 2m ago ");
+    }
+}
@@ -1,16 +1,28 @@
+use std::collections::VecDeque;
+
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Row, Sparkline, Table},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Sparkline, Table},
     Frame,
 };
 
-use crate::app::App;
+use crate::app::state::{
+    visible_connections, visible_interfaces, NetworkAction, NetworkUIState, NetworkView,
+};
+use crate::app::{App, TabType};
+use crate::monitors::network::TrafficSample;
+use crate::ui::centered_rect;
 use crate::ui::theme::Theme;
-use crate::utils::format::format_bytes;
+use crate::ui::widgets::graph::Graph;
+use crate::utils::format::FormatOptions;
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
+    if super::render_if_disabled(f, area, app, TabType::Network, "Network Monitor") {
+        return;
+    }
+
     let network_data = app.state.network_data.read();
     let network_error = app.state.network_error.read();
 
@@ -30,11 +42,18 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
     } else if let Some(data) = network_data.as_ref() {
         let config = app.state.config.read();
         let theme = Theme::from_config(&config);
+        let fmt = FormatOptions::from_config(&config);
+
+        let show_connections = config.monitors.network.show_connections;
 
         if app.state.compact_mode {
-            render_compact(f, area, data, &theme);
+            render_compact(f, area, data, &app.state.network_state, &theme, &fmt, show_connections);
         } else {
-            render_full(f, area, data, &theme);
+            render_full(f, area, data, &app.state.network_state, &theme, &fmt, show_connections);
+        }
+
+        if let Some(action) = app.state.network_state.pending_action.as_ref() {
+            render_action_confirm(f, area, action);
         }
     } else {
         let block = Block::default()
@@ -50,59 +69,85 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
     }
 }
 
-fn render_full(f: &mut Frame, area: Rect, data: &crate::monitors::NetworkData, theme: &Theme) {
+fn render_full(
+    f: &mut Frame,
+    area: Rect,
+    data: &crate::monitors::NetworkData,
+    ui_state: &NetworkUIState,
+    theme: &Theme,
+    fmt: &FormatOptions,
+    show_connections: bool,
+) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Header
             Constraint::Length(8), // Interface details (per interface)
             Constraint::Length(8), // Traffic graphs (Download/Upload)
+            Constraint::Length(if data.latency.is_empty() { 0 } else { 5 }), // Latency
             Constraint::Min(10),   // Active connections and bandwidth consumers
         ])
         .split(area);
 
     // Header - show primary interface summary
-    render_header(f, chunks[0], data, theme);
+    render_header(f, chunks[0], data, ui_state, theme, fmt, show_connections);
 
     // Interface details
-    render_interface_details(f, chunks[1], data, theme);
+    render_interface_details(f, chunks[1], data, ui_state, theme, fmt);
 
     // Traffic graphs
-    render_traffic_graphs(f, chunks[2], data, theme);
+    render_traffic_graphs(f, chunks[2], data, ui_state, theme, fmt);
+
+    // Latency to ping targets
+    if !data.latency.is_empty() {
+        render_latency(f, chunks[3], data, theme);
+    }
 
     // Split bottom section for connections and bandwidth consumers
+    let connections_pct = if show_connections { 50 } else { 0 };
     let bottom_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Percentage(50), // Active connections
-            Constraint::Percentage(50), // Bandwidth consumers
+            Constraint::Percentage(connections_pct), // Active connections
+            Constraint::Percentage(100 - connections_pct), // Bandwidth consumers
         ])
-        .split(chunks[3]);
+        .split(chunks[4]);
 
     // Active connections
-    render_connections_table(f, bottom_chunks[0], data, theme);
+    if show_connections {
+        render_connections_table(f, bottom_chunks[0], data, ui_state, theme);
+    }
 
     // Bandwidth consumers
-    render_bandwidth_consumers(f, bottom_chunks[1], data, theme);
+    render_bandwidth_consumers(f, bottom_chunks[1], data, ui_state, theme, fmt);
 }
 
-fn render_compact(f: &mut Frame, area: Rect, data: &crate::monitors::NetworkData, theme: &Theme) {
+fn render_compact(
+    f: &mut Frame,
+    area: Rect,
+    data: &crate::monitors::NetworkData,
+    ui_state: &NetworkUIState,
+    theme: &Theme,
+    fmt: &FormatOptions,
+    show_connections: bool,
+) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Header
             Constraint::Length(6), // Quick stats
-            Constraint::Min(8),    // Connections (compact)
+            Constraint::Min(if show_connections { 8 } else { 0 }), // Connections (compact)
         ])
         .split(area);
 
     // Header
-    render_header(f, chunks[0], data, theme);
+    render_header(f, chunks[0], data, ui_state, theme, fmt, show_connections);
 
     // Quick stats
     let mut lines = Vec::new();
 
-    if let Some(iface) = data.interfaces.first() {
+    let interfaces = visible_interfaces(&data.interfaces, ui_state.show_all_interfaces);
+    if let Some(iface) = interfaces.first() {
         lines.push(Line::from(vec![
             Span::styled("Status: ", Style::default().fg(Color::Gray)),
             Span::styled(
@@ -127,7 +172,7 @@ fn render_compact(f: &mut Frame, area: Rect, data: &crate::monitors::NetworkData
         lines.push(Line::from(vec![
             Span::styled("Download: ", Style::default().fg(Color::Gray)),
             Span::styled(
-                format!("{:.2} Mbps", iface.download_speed),
+                fmt.rate(iface.download_speed),
                 Style::default()
                     .fg(Color::Green)
                     .add_modifier(Modifier::BOLD),
@@ -135,7 +180,7 @@ fn render_compact(f: &mut Frame, area: Rect, data: &crate::monitors::NetworkData
             Span::raw("  "),
             Span::styled("Upload: ", Style::default().fg(Color::Gray)),
             Span::styled(
-                format!("{:.2} Mbps", iface.upload_speed),
+                fmt.rate(iface.upload_speed),
                 Style::default()
                     .fg(Color::Cyan)
                     .add_modifier(Modifier::BOLD),
@@ -145,13 +190,13 @@ fn render_compact(f: &mut Frame, area: Rect, data: &crate::monitors::NetworkData
         lines.push(Line::from(vec![
             Span::styled("Total RX: ", Style::default().fg(Color::Gray)),
             Span::styled(
-                format_bytes(iface.bytes_received),
+                fmt.bytes(iface.bytes_received),
                 Style::default().fg(Color::White),
             ),
             Span::raw("  "),
             Span::styled("TX: ", Style::default().fg(Color::Gray)),
             Span::styled(
-                format_bytes(iface.bytes_sent),
+                fmt.bytes(iface.bytes_sent),
                 Style::default().fg(Color::White),
             ),
         ]));
@@ -165,20 +210,73 @@ fn render_compact(f: &mut Frame, area: Rect, data: &crate::monitors::NetworkData
     let paragraph = Paragraph::new(lines).block(block);
     f.render_widget(paragraph, chunks[1]);
 
-    // Compact connections (top 5)
-    render_connections_compact(f, chunks[2], data, theme);
+    // Compact connections
+    if show_connections {
+        render_connections_compact(f, chunks[2], data, ui_state, theme);
+    }
 }
 
-fn render_header(f: &mut Frame, area: Rect, data: &crate::monitors::NetworkData, theme: &Theme) {
-    let header_text = if let Some(iface) = data.interfaces.first() {
-        format!(
-            "{} | {} | ↓ {:.2} Mbps ↑ {:.2} Mbps | Connections: {}",
+fn render_action_confirm(f: &mut Frame, area: Rect, action: &NetworkAction) {
+    let rect = centered_rect(50, 30, area);
+
+    f.render_widget(Clear, rect);
+
+    let warning = match action {
+        NetworkAction::SetAdapterEnabled { enabled: false, .. } => {
+            "Connections over this adapter will drop."
+        }
+        NetworkAction::SetAdapterEnabled { enabled: true, .. } => {
+            "The adapter may take a few seconds to come up."
+        }
+        NetworkAction::FlushDns => "Cached name lookups will be resolved again.",
+    };
+    let text = vec![
+        Line::from(Span::styled(
+            action.describe(),
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(warning),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Enter/Y", Style::default().fg(Color::Cyan)),
+            Span::raw(": Confirm  "),
+            Span::styled("Esc/N", Style::default().fg(Color::Cyan)),
+            Span::raw(": Cancel"),
+        ]),
+    ];
+
+    let block = Block::default()
+        .title("Confirm Network Change")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+
+    let paragraph = Paragraph::new(text).block(block);
+    f.render_widget(paragraph, rect);
+}
+
+fn render_header(
+    f: &mut Frame,
+    area: Rect,
+    data: &crate::monitors::NetworkData,
+    ui_state: &NetworkUIState,
+    theme: &Theme,
+    fmt: &FormatOptions,
+    show_connections: bool,
+) {
+    let interfaces = visible_interfaces(&data.interfaces, ui_state.show_all_interfaces);
+    let header_text = if let Some(iface) = interfaces.first() {
+        let mut text = format!(
+            "{} | {} | ↓ {} ↑ {}",
             iface.name,
             iface.status,
-            iface.download_speed,
-            iface.upload_speed,
-            data.connections.len()
-        )
+            fmt.rate(iface.download_speed),
+            fmt.rate(iface.upload_speed),
+        );
+        if show_connections {
+            text.push_str(&format!(" | Connections: {}", data.connections.len()));
+        }
+        text
     } else {
         "No active network interfaces".to_string()
     };
@@ -200,13 +298,29 @@ fn render_interface_details(
     f: &mut Frame,
     area: Rect,
     data: &crate::monitors::NetworkData,
+    ui_state: &NetworkUIState,
     theme: &Theme,
+    fmt: &FormatOptions,
 ) {
-    if let Some(iface) = data.interfaces.first() {
+    let interfaces = visible_interfaces(&data.interfaces, ui_state.show_all_interfaces);
+    let index = ui_state
+        .selected_interface
+        .min(interfaces.len().saturating_sub(1));
+    if let Some(iface) = interfaces.get(index) {
         let lines = vec![
             Line::from(vec![
                 Span::styled("Interface: ", Style::default().fg(Color::Gray)),
                 Span::styled(&iface.description, Style::default().fg(Color::White)),
+                Span::raw("  "),
+                Span::styled("Status: ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    &iface.status,
+                    Style::default().fg(if iface.status.eq_ignore_ascii_case("up") {
+                        Color::Green
+                    } else {
+                        theme.warning_color
+                    }),
+                ),
             ]),
             Line::from(vec![
                 Span::styled("IPv4: ", Style::default().fg(Color::Gray)),
@@ -251,22 +365,33 @@ fn render_interface_details(
             Line::from(vec![
                 Span::styled("Total Received: ", Style::default().fg(Color::Gray)),
                 Span::styled(
-                    format_bytes(iface.bytes_received),
+                    fmt.bytes(iface.bytes_received),
                     Style::default().fg(Color::Green),
                 ),
                 Span::raw("  "),
                 Span::styled("Total Sent: ", Style::default().fg(Color::Gray)),
                 Span::styled(
-                    format_bytes(iface.bytes_sent),
+                    fmt.bytes(iface.bytes_sent),
                     Style::default().fg(Color::Cyan),
                 ),
             ]),
         ];
 
+        let mut title = if interfaces.len() > 1 {
+            format!("Interface Details ({}/{}: {})", index + 1, interfaces.len(), iface.name)
+        } else {
+            "Interface Details".to_string()
+        };
+        let hidden = data.interfaces.iter().filter(|i| i.hidden).count();
+        if ui_state.show_all_interfaces {
+            title.push_str(" [All]");
+        } else if hidden > 0 {
+            title.push_str(&format!(" [{} hidden, a: All]", hidden));
+        }
         let block = Block::default()
             .borders(Borders::ALL)
-            .title("Interface Details")
-            .border_style(Style::default().fg(theme.network_color));
+            .title(title)
+            .border_style(focus_border(ui_state, NetworkView::Interfaces, theme));
 
         let paragraph = Paragraph::new(lines).block(block);
         f.render_widget(paragraph, area);
@@ -277,77 +402,116 @@ fn render_traffic_graphs(
     f: &mut Frame,
     area: Rect,
     data: &crate::monitors::NetworkData,
+    ui_state: &NetworkUIState,
     _theme: &Theme,
+    fmt: &FormatOptions,
 ) {
+    let all = ui_state.show_all_interfaces;
+    let selected = visible_interfaces(&data.interfaces, all)
+        .get(ui_state.selected_interface)
+        .and_then(|iface| Some((iface.name.as_str(), data.interface_history.get(&iface.name)?)));
+
+    // Aggregate on the left, the selected interface beside it
+    let Some((name, history)) = selected else {
+        render_traffic_graph(f, area, traffic_title(all), &data.traffic_history, all, fmt);
+        return;
+    };
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(area);
+    render_traffic_graph(f, chunks[0], traffic_title(all), &data.traffic_history, all, fmt);
+    render_traffic_graph(f, chunks[1], &format!("Traffic: {}", name), history, false, fmt);
+}
 
-    // Download graph
-    if !data.traffic_history.is_empty() {
-        let download_data: Vec<u64> = data
-            .traffic_history
-            .iter()
-            .map(|s| (s.download_mbps * 100.0) as u64)
-            .collect();
+fn traffic_title(all: bool) -> &'static str {
+    if all {
+        "Traffic (all interfaces)"
+    } else {
+        "Traffic"
+    }
+}
 
-        let max_download = download_data.iter().max().copied().unwrap_or(1).max(1);
-        let max_download_mbps = max_download as f64 / 100.0;
+fn render_traffic_graph(
+    f: &mut Frame,
+    area: Rect,
+    title: &str,
+    history: &VecDeque<TrafficSample>,
+    all: bool,
+    fmt: &FormatOptions,
+) {
+    let download: VecDeque<f64> = history
+        .iter()
+        .map(|s| fmt.rate_value(if all { s.all_download_mbps } else { s.download_mbps }))
+        .collect();
+    let upload: VecDeque<f64> = history
+        .iter()
+        .map(|s| fmt.rate_value(if all { s.all_upload_mbps } else { s.upload_mbps }))
+        .collect();
 
-        let sparkline = Sparkline::default()
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title(format!("Download (peak {:.2} Mbps)", max_download_mbps))
-                    .border_style(Style::default().fg(Color::Green)),
-            )
-            .data(&download_data)
-            .style(Style::default().fg(Color::Green))
-            .max(max_download);
+    let sample_secs = match (history.front(), history.back()) {
+        (Some(first), Some(last)) if history.len() > 1 => {
+            last.timestamp.saturating_sub(first.timestamp) as f64 / (history.len() - 1) as f64
+        }
+        _ => 1.0,
+    };
 
-        f.render_widget(sparkline, chunks[0]);
-    } else {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .title("Download")
-            .border_style(Style::default().fg(Color::Green));
+    Graph::new(title, Color::Green)
+        .series("↓ Download", &download, Color::Green)
+        .series("↑ Upload", &upload, Color::Cyan)
+        .unit(fmt.rate_unit())
+        .sample_secs(sample_secs)
+        .render(f, area);
+}
 
-        let text = Paragraph::new("Collecting data...").block(block);
-        f.render_widget(text, chunks[0]);
-    }
+fn render_latency(
+    f: &mut Frame,
+    area: Rect,
+    data: &crate::monitors::NetworkData,
+    theme: &Theme,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![
+            Constraint::Ratio(1, data.latency.len() as u32);
+            data.latency.len()
+        ])
+        .split(area);
+
+    for (ping, chunk) in data.latency.iter().zip(chunks.iter()) {
+        let (rtt, color) = match ping.rtt_ms {
+            Some(rtt) => (format!("{:.0} ms", rtt), Color::Green),
+            None => ("timeout".to_string(), theme.error_color),
+        };
+        let color = if ping.rtt_ms.is_some() && ping.loss_pct > 0.0 {
+            theme.warning_color
+        } else {
+            color
+        };
 
-    // Upload graph
-    if !data.traffic_history.is_empty() {
-        let upload_data: Vec<u64> = data
-            .traffic_history
+        // Timeouts are drawn as empty bars
+        let history: Vec<u64> = ping
+            .history
             .iter()
-            .map(|s| (s.upload_mbps * 100.0) as u64)
+            .map(|sample| sample.map(|rtt| rtt.round() as u64).unwrap_or(0))
             .collect();
-
-        let max_upload = upload_data.iter().max().copied().unwrap_or(1).max(1);
-        let max_upload_mbps = max_upload as f64 / 100.0;
+        let max = history.iter().max().copied().unwrap_or(1).max(1);
 
         let sparkline = Sparkline::default()
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title(format!("Upload (peak {:.2} Mbps)", max_upload_mbps))
-                    .border_style(Style::default().fg(Color::Cyan)),
+                    .title(format!(
+                        "{}: {}  loss {:.0}%",
+                        ping.target, rtt, ping.loss_pct
+                    ))
+                    .border_style(Style::default().fg(color)),
             )
-            .data(&upload_data)
-            .style(Style::default().fg(Color::Cyan))
-            .max(max_upload);
-
-        f.render_widget(sparkline, chunks[1]);
-    } else {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .title("Upload")
-            .border_style(Style::default().fg(Color::Cyan));
+            .data(&history)
+            .style(Style::default().fg(color))
+            .max(max);
 
-        let text = Paragraph::new("Collecting data...").block(block);
-        f.render_widget(text, chunks[1]);
+        f.render_widget(sparkline, *chunk);
     }
 }
 
@@ -355,10 +519,11 @@ fn render_connections_table(
     f: &mut Frame,
     area: Rect,
     data: &crate::monitors::NetworkData,
+    ui_state: &NetworkUIState,
     theme: &Theme,
 ) {
     let header = Row::new(vec![
-        "Process", "PID", "Protocol", "Local", "Remote", "State",
+        "Process", "PID", "Protocol", "IP", "Local", "Remote", "State",
     ])
     .style(
         Style::default()
@@ -367,29 +532,43 @@ fn render_connections_table(
     )
     .bottom_margin(0);
 
-    let rows: Vec<Row> = data
-        .connections
+    let connections = visible_connections(
+        &data.connections,
+        ui_state.connection_filter,
+        ui_state.family_filter,
+    );
+    let selected = selected_row(ui_state, NetworkView::Connections);
+    let (start, visible) = table_window(area, connections.len(), selected, ui_state.scroll_offset);
+
+    // Endpoint columns are 25% each; long IPv6 addresses get shortened to fit
+    let endpoint_width = (area.width.saturating_sub(2) as usize * 25 / 100).max(8);
+    let rows: Vec<Row> = connections
         .iter()
-        .map(|conn| {
+        .enumerate()
+        .skip(start)
+        .take(visible)
+        .map(|(i, conn)| {
             Row::new(vec![
                 conn.process_name.clone(),
                 format!("{}", conn.pid),
                 conn.protocol.clone(),
-                format!("{}:{}", conn.local_address, conn.local_port),
-                format!("{}:{}", conn.remote_address, conn.remote_port),
+                conn.address_family.as_str().to_string(),
+                abbreviate(&conn.local_endpoint(), endpoint_width),
+                abbreviate(&conn.remote_endpoint(), endpoint_width),
                 conn.state.clone(),
             ])
-            .style(Style::default().fg(Color::White))
+            .style(row_style(selected == Some(i)))
         })
         .collect();
 
     let widths = [
-        Constraint::Percentage(20), // Process
-        Constraint::Percentage(8),  // PID
-        Constraint::Percentage(10), // Protocol
+        Constraint::Percentage(17), // Process
+        Constraint::Percentage(7),  // PID
+        Constraint::Percentage(7),  // Protocol
+        Constraint::Percentage(6),  // IP
         Constraint::Percentage(25), // Local
         Constraint::Percentage(25), // Remote
-        Constraint::Percentage(12), // State
+        Constraint::Percentage(13), // State
     ];
 
     let table = Table::new(rows, widths)
@@ -397,8 +576,8 @@ fn render_connections_table(
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(format!("Active Connections ({})", data.connections.len()))
-                .border_style(Style::default().fg(theme.network_color)),
+                .title(connections_title(ui_state, connections.len(), start, visible))
+                .border_style(focus_border(ui_state, NetworkView::Connections, theme)),
         )
         .column_spacing(1);
 
@@ -409,6 +588,7 @@ fn render_connections_compact(
     f: &mut Frame,
     area: Rect,
     data: &crate::monitors::NetworkData,
+    ui_state: &NetworkUIState,
     theme: &Theme,
 ) {
     let header = Row::new(vec!["Process", "Remote", "State"])
@@ -419,17 +599,27 @@ fn render_connections_compact(
         )
         .bottom_margin(0);
 
-    let rows: Vec<Row> = data
-        .connections
+    let connections = visible_connections(
+        &data.connections,
+        ui_state.connection_filter,
+        ui_state.family_filter,
+    );
+    let selected = selected_row(ui_state, NetworkView::Connections);
+    let (start, visible) = table_window(area, connections.len(), selected, ui_state.scroll_offset);
+
+    let endpoint_width = (area.width.saturating_sub(2) as usize * 40 / 100).max(8);
+    let rows: Vec<Row> = connections
         .iter()
-        .take(5)
-        .map(|conn| {
+        .enumerate()
+        .skip(start)
+        .take(visible)
+        .map(|(i, conn)| {
             Row::new(vec![
                 format!("{} ({})", conn.process_name, conn.pid),
-                format!("{}:{}", conn.remote_address, conn.remote_port),
+                abbreviate(&conn.remote_endpoint(), endpoint_width),
                 conn.state.clone(),
             ])
-            .style(Style::default().fg(Color::White))
+            .style(row_style(selected == Some(i)))
         })
         .collect();
 
@@ -444,23 +634,92 @@ fn render_connections_compact(
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(format!("Active Connections ({})", data.connections.len()))
-                .border_style(Style::default().fg(theme.network_color)),
+                .title(connections_title(ui_state, connections.len(), start, visible))
+                .border_style(focus_border(ui_state, NetworkView::Connections, theme)),
         )
         .column_spacing(1);
 
     f.render_widget(table, area);
 }
 
+/// First row and row count that fit in `area` (borders and header take 3
+/// lines), scrolled so the selected row stays visible.
+fn table_window(
+    area: Rect,
+    total: usize,
+    selected: Option<usize>,
+    scroll_offset: usize,
+) -> (usize, usize) {
+    let visible = area.height.saturating_sub(3) as usize;
+    let mut start = scroll_offset;
+    if let Some(selected) = selected {
+        if visible > 0 && selected >= start + visible {
+            start = selected + 1 - visible;
+        }
+    }
+    (start.min(total.saturating_sub(visible)), visible)
+}
+
+/// The selected row index, if `view` is the focused panel.
+fn selected_row(ui_state: &NetworkUIState, view: NetworkView) -> Option<usize> {
+    (ui_state.view == view).then_some(ui_state.selected_index)
+}
+
+fn row_style(selected: bool) -> Style {
+    if selected {
+        Style::default().fg(Color::Black).bg(Color::Cyan)
+    } else {
+        Style::default().fg(Color::White)
+    }
+}
+
+fn focus_border(ui_state: &NetworkUIState, view: NetworkView, theme: &Theme) -> Style {
+    Style::default().fg(if ui_state.view == view {
+        Color::Yellow
+    } else {
+        theme.network_color
+    })
+}
+
+fn connections_title(ui_state: &NetworkUIState, total: usize, start: usize, visible: usize) -> String {
+    let range = if total > visible {
+        format!(" {}-{} of {}", start + 1, (start + visible).min(total), total)
+    } else {
+        format!(" ({})", total)
+    };
+    format!(
+        "Connections [{} {}]{} - F: Filter  I: IP  ←/→: Panel  ↑/↓/PgUp/PgDn: Select",
+        ui_state.connection_filter.as_str(),
+        ui_state.family_filter.as_str(),
+        range
+    )
+}
+
+/// Shorten `text` to `max` characters by eliding its middle, keeping the
+/// port at the end visible.
+fn abbreviate(text: &str, max: usize) -> String {
+    let len = text.chars().count();
+    if len <= max {
+        return text.to_string();
+    }
+    let tail = (max - 1) / 2;
+    let head = max - 1 - tail;
+    let start: String = text.chars().take(head).collect();
+    let end: String = text.chars().skip(len - tail).collect();
+    format!("{}…{}", start, end)
+}
+
 fn render_bandwidth_consumers(
     f: &mut Frame,
     area: Rect,
     data: &crate::monitors::NetworkData,
+    ui_state: &NetworkUIState,
     theme: &Theme,
+    fmt: &FormatOptions,
 ) {
-    let has_estimated = data.bandwidth_consumers.iter().any(|c| c.estimated);
+    let any_measured = data.bandwidth_consumers.iter().any(|c| c.is_measured());
     let header = Row::new(vec![
-        "Process", "PID", "Download", "Upload", "Total RX", "Total TX",
+        "Process", "PID", "Conns", "Download", "Upload", "Total RX", "Total TX",
     ])
     .style(
         Style::default()
@@ -469,69 +728,63 @@ fn render_bandwidth_consumers(
     )
     .bottom_margin(0);
 
+    let total = data.bandwidth_consumers.len();
+    let selected = selected_row(ui_state, NetworkView::Consumers);
+    let (start, visible) = table_window(area, total, selected, ui_state.scroll_offset);
+
+    let na = || Cell::from("N/A").style(Style::default().fg(Color::DarkGray));
     let rows: Vec<Row> = data
         .bandwidth_consumers
         .iter()
-        .take(10)
-        .map(|consumer| {
-            let name = if consumer.estimated {
-                format!("~{}", consumer.process_name)
-            } else {
-                consumer.process_name.clone()
+        .enumerate()
+        .skip(start)
+        .take(visible)
+        .map(|(i, consumer)| {
+            let speed = |value: Option<f64>| match value {
+                Some(mbps) => Cell::from(fmt.rate(mbps)),
+                None => na(),
             };
-            let download = if consumer.estimated {
-                format!("~{:.2} Mbps", consumer.download_speed)
-            } else {
-                format!("{:.2} Mbps", consumer.download_speed)
-            };
-            let upload = if consumer.estimated {
-                format!("~{:.2} Mbps", consumer.upload_speed)
-            } else {
-                format!("{:.2} Mbps", consumer.upload_speed)
-            };
-            let total_rx = if consumer.estimated {
-                format!("~{}", format_bytes(consumer.total_bytes_received))
-            } else {
-                format_bytes(consumer.total_bytes_received)
-            };
-            let total_tx = if consumer.estimated {
-                format!("~{}", format_bytes(consumer.total_bytes_sent))
-            } else {
-                format_bytes(consumer.total_bytes_sent)
+            let bytes = |value: Option<u64>| match value {
+                Some(total) => Cell::from(fmt.bytes(total)),
+                None => na(),
             };
 
             Row::new(vec![
-                name,
-                format!("{}", consumer.pid),
-                download,
-                upload,
-                total_rx,
-                total_tx,
+                Cell::from(consumer.process_name.clone()),
+                Cell::from(format!("{}", consumer.pid)),
+                Cell::from(format!("{}", consumer.connection_count)),
+                speed(consumer.download_speed),
+                speed(consumer.upload_speed),
+                bytes(consumer.total_bytes_received),
+                bytes(consumer.total_bytes_sent),
             ])
-            .style(Style::default().fg(Color::White))
+            .style(row_style(selected == Some(i)))
         })
         .collect();
 
     let widths = [
         Constraint::Percentage(20), // Process
-        Constraint::Percentage(10), // PID
-        Constraint::Percentage(15), // Download
-        Constraint::Percentage(15), // Upload
-        Constraint::Percentage(20), // Total RX
-        Constraint::Percentage(20), // Total TX
+        Constraint::Percentage(8),  // PID
+        Constraint::Percentage(8),  // Conns
+        Constraint::Percentage(16), // Download
+        Constraint::Percentage(16), // Upload
+        Constraint::Percentage(16), // Total RX
+        Constraint::Percentage(16), // Total TX
     ];
 
+    let note = if !data.bandwidth_consumers.is_empty() && !any_measured {
+        " - bytes need Administrator"
+    } else {
+        ""
+    };
+
     let table = Table::new(rows, widths)
         .header(header)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(format!(
-                    "Bandwidth Consumers{} (Top {})",
-                    if has_estimated { " ~est." } else { "" },
-                    data.bandwidth_consumers.len().min(10)
-                ))
-                .border_style(Style::default().fg(theme.network_color)),
+                .title(format!("Bandwidth Consumers ({}){}", total, note))
+                .border_style(focus_border(ui_state, NetworkView::Consumers, theme)),
         )
         .column_spacing(1);
 
@@ -12,12 +12,17 @@ use crate::app::{
         sort_ollama_models, ChatRole, OllamaActivityView, OllamaInputMode,
         OllamaModelSortColumn, OllamaPanelFocus, OllamaRunningSortColumn, OllamaView,
     },
-    App,
+    App, TabType,
 };
 use crate::ui::theme::Theme;
 use crate::integrations::ollama::ChatLogEntry;
+use crate::utils::format::safe_percent;
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
+    if super::render_if_disabled(f, area, app, TabType::Ollama, "Ollama Manager") {
+        return;
+    }
+
     let ollama_data = app.state.ollama_data.read();
     let ollama_error = app.state.ollama_error.read();
 
@@ -791,12 +796,10 @@ fn render_vram_panel(f: &mut Frame, area: Rect, app: &App, theme: &Theme) {
 
     // VRAM usage gauge (from GPU data if available)
     let gpu_data = app.state.gpu_data.read();
-    let (vram_used, vram_total, vram_percent) = if let Some(gpu) = gpu_data.as_ref() {
-        let percent = if gpu.memory_total > 0 {
-            (gpu.memory_used as f64 / gpu.memory_total as f64) * 100.0
-        } else {
-            0.0
-        };
+    let (vram_used, vram_total, vram_percent) = if let Some(gpu) =
+        gpu_data.as_ref().and_then(|data| data.primary())
+    {
+        let percent = safe_percent(gpu.memory_used, gpu.memory_total);
         (gpu.memory_used, gpu.memory_total, percent as f32)
     } else {
         (0, 0, 0.0)
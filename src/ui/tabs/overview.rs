@@ -0,0 +1,303 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Gauge, Paragraph},
+    Frame,
+};
+
+use crate::app::monitors_task::MonitorStatus;
+use crate::app::{App, TabType};
+use crate::ui::theme::Theme;
+use crate::utils::format::{safe_percent, FormatOptions};
+
+/// Card height: border, gauge, stat line, border
+const CARD_HEIGHT: u16 = 4;
+
+enum CardBody {
+    Ready {
+        percent: f64,
+        /// Gauge colour; usage-based except where a full bar isn't a warning
+        color: Color,
+        label: String,
+        stat: String,
+    },
+    /// Disabled, loading or failed monitors show one line instead of a gauge
+    Status(String, Color),
+}
+
+struct Card {
+    title: &'static str,
+    color: Color,
+    body: CardBody,
+}
+
+pub fn render(f: &mut Frame, area: Rect, app: &App) {
+    let config = app.state.config.read();
+    let theme = Theme::from_config(&config);
+    let fmt = FormatOptions::from_config(&config);
+
+    let cards = [
+        cpu_card(app, &theme),
+        ram_card(app, &theme, &fmt),
+        gpu_card(app, &theme, &fmt),
+        disk_card(app, &theme, &fmt),
+        network_card(app, &theme, &fmt),
+    ];
+
+    let columns = if area.width >= 100 { 2 } else { 1 };
+    let rows: Vec<&[Card]> = cards.chunks(columns).collect();
+    let row_areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            rows.iter()
+                .map(|_| Constraint::Length(CARD_HEIGHT))
+                .chain(std::iter::once(Constraint::Min(0)))
+                .collect::<Vec<_>>(),
+        )
+        .split(area);
+
+    for (row, row_area) in rows.iter().zip(row_areas.iter()) {
+        let cells = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, columns as u32); columns])
+            .split(*row_area);
+        for (card, cell) in row.iter().zip(cells.iter()) {
+            render_card(f, *cell, card);
+        }
+    }
+}
+
+fn render_card(f: &mut Frame, area: Rect, card: &Card) {
+    let block = Block::default()
+        .title(card.title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(card.color));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    match &card.body {
+        CardBody::Ready {
+            percent,
+            color,
+            label,
+            stat,
+        } => {
+            let lines = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(1), Constraint::Length(1)])
+                .split(inner);
+            let percent = percent.clamp(0.0, 100.0);
+            let gauge = Gauge::default()
+                .gauge_style(Style::default().fg(*color).add_modifier(Modifier::BOLD))
+                .ratio(percent / 100.0)
+                .label(label.clone());
+            f.render_widget(gauge, lines[0]);
+            f.render_widget(
+                Paragraph::new(stat.clone()).style(Style::default().fg(Color::Gray)),
+                lines[1],
+            );
+        }
+        CardBody::Status(message, color) => {
+            f.render_widget(
+                Paragraph::new(Line::from(message.clone())).style(Style::default().fg(*color)),
+                inner,
+            );
+        }
+    }
+}
+
+/// The monitor's data, or the status line to show in its place.
+fn card_data<'a, T>(
+    app: &App,
+    tab: TabType,
+    error: Option<&String>,
+    data: Option<&'a T>,
+    theme: &Theme,
+) -> Result<&'a T, CardBody> {
+    if app.state.monitor_status(tab) == MonitorStatus::Disabled {
+        return Err(CardBody::Status(
+            "Disabled in config".to_string(),
+            Color::DarkGray,
+        ));
+    }
+    if let Some(message) = error {
+        return Err(CardBody::Status(
+            format!("Unavailable: {}", message),
+            theme.error_color,
+        ));
+    }
+    data.ok_or_else(|| CardBody::Status("Loading...".to_string(), Color::Yellow))
+}
+
+fn cpu_card(app: &App, theme: &Theme) -> Card {
+    let data = app.state.cpu_data.read();
+    let error = app.state.cpu_error.read();
+    let body = match card_data(app, TabType::Cpu, error.as_ref(), data.as_ref(), theme) {
+        Ok(cpu) => {
+            let mut stat = format!(
+                "{}C/{}T · {:.2} GHz",
+                cpu.core_count, cpu.thread_count, cpu.frequency.avg_frequency
+            );
+            if let Some(temp) = cpu.temperature {
+                stat.push_str(&format!(" · {:.0}°C", temp));
+            }
+            CardBody::Ready {
+                percent: cpu.overall_usage as f64,
+                color: theme.get_usage_color(cpu.overall_usage),
+                label: format!("{:.0}%", cpu.overall_usage),
+                stat,
+            }
+        }
+        Err(status) => status,
+    };
+
+    Card {
+        title: "CPU",
+        color: theme.cpu_color,
+        body,
+    }
+}
+
+fn ram_card(app: &App, theme: &Theme, fmt: &FormatOptions) -> Card {
+    let data = app.state.ram_data.read();
+    let error = app.state.ram_error.read();
+    let body = match card_data(app, TabType::Ram, error.as_ref(), data.as_ref(), theme) {
+        Ok(ram) => {
+            let percent = safe_percent(ram.used, ram.total);
+            CardBody::Ready {
+                percent,
+                color: theme.get_usage_color(percent as f32),
+                label: format!("{:.0}%", percent),
+                stat: format!(
+                    "{} / {} · commit {:.0}%",
+                    fmt.bytes(ram.used),
+                    fmt.bytes(ram.total),
+                    ram.commit_percent
+                ),
+            }
+        }
+        Err(status) => status,
+    };
+
+    Card {
+        title: "RAM",
+        color: theme.ram_color,
+        body,
+    }
+}
+
+fn gpu_card(app: &App, theme: &Theme, fmt: &FormatOptions) -> Card {
+    let data = app.state.gpu_data.read();
+    let error = app.state.gpu_error.read();
+    let body = match card_data(app, TabType::Gpu, error.as_ref(), data.as_ref(), theme)
+        .map(|gpu| gpu.busiest())
+    {
+        Ok(Some(device)) => {
+            let mut stat = format!(
+                "VRAM {} / {}",
+                fmt.bytes(device.memory_used),
+                fmt.bytes(device.memory_total)
+            );
+            if device.temperature > 0.0 {
+                stat.push_str(&format!(" · {:.0}°C", device.temperature));
+            }
+            CardBody::Ready {
+                percent: device.utilization as f64,
+                color: theme.get_usage_color(device.utilization),
+                label: format!("{:.0}% {}", device.utilization, device.name),
+                stat,
+            }
+        }
+        Ok(None) => CardBody::Status("No GPU detected".to_string(), Color::DarkGray),
+        Err(status) => status,
+    };
+
+    Card {
+        title: "GPU",
+        color: theme.gpu_color,
+        body,
+    }
+}
+
+fn disk_card(app: &App, theme: &Theme, fmt: &FormatOptions) -> Card {
+    let data = app.state.disk_data.read();
+    let error = app.state.disk_error.read();
+    let body = match card_data(app, TabType::Disk, error.as_ref(), data.as_ref(), theme) {
+        Ok(disk) => {
+            let read: f64 = disk.io_stats.iter().map(|io| io.read_speed).sum();
+            let write: f64 = disk.io_stats.iter().map(|io| io.write_speed).sum();
+            // The fullest drive is the one worth watching
+            let fullest = disk
+                .logical_drives
+                .iter()
+                .filter(|drive| drive.total > 0)
+                .map(|drive| (drive, drive.used as f64 / drive.total as f64 * 100.0))
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+
+            match fullest {
+                Some((drive, percent)) => CardBody::Ready {
+                    percent,
+                    color: theme.get_usage_color(percent as f32),
+                    label: format!(
+                        "{} {:.0}% ({} free)",
+                        drive.letter,
+                        percent,
+                        fmt.bytes(drive.free)
+                    ),
+                    stat: format!("R {:.1} MB/s · W {:.1} MB/s", read, write),
+                },
+                None => CardBody::Status("No drives reported".to_string(), Color::DarkGray),
+            }
+        }
+        Err(status) => status,
+    };
+
+    Card {
+        title: "Disk",
+        color: theme.disk_color,
+        body,
+    }
+}
+
+fn network_card(app: &App, theme: &Theme, fmt: &FormatOptions) -> Card {
+    let data = app.state.network_data.read();
+    let error = app.state.network_error.read();
+    let body = match card_data(app, TabType::Network, error.as_ref(), data.as_ref(), theme) {
+        Ok(network) => {
+            let (download, upload) = network
+                .traffic_history
+                .back()
+                .map(|s| (s.download_mbps, s.upload_mbps))
+                .unwrap_or((0.0, 0.0));
+            // Throughput has no fixed maximum, so scale against the recent peak
+            let peak = network
+                .traffic_history
+                .iter()
+                .map(|s| s.download_mbps + s.upload_mbps)
+                .fold(0.0, f64::max);
+            let percent = if peak > 0.0 {
+                (download + upload) / peak * 100.0
+            } else {
+                0.0
+            };
+            CardBody::Ready {
+                percent,
+                color: theme.network_color,
+                label: format!("↓ {}  ↑ {}", fmt.rate(download), fmt.rate(upload)),
+                stat: format!(
+                    "{} connections · peak {}",
+                    network.connections.len(),
+                    fmt.rate(peak)
+                ),
+            }
+        }
+        Err(status) => status,
+    };
+
+    Card {
+        title: "Network",
+        color: theme.network_color,
+        body,
+    }
+}
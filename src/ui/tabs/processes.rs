@@ -2,17 +2,23 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, Wrap},
     Frame,
 };
-use std::cmp::Ordering;
 
-use crate::app::{state::ProcessSortColumn, App};
-use crate::monitors::processes::ProcessEntry;
+use crate::app::{
+    state::{process_rows, ProcessColumn, ProcessDetailView, ProcessSortColumn},
+    App, TabType,
+};
+use crate::ui::centered_rect;
 use crate::ui::theme::Theme;
-use crate::utils::format::format_bytes;
+use crate::utils::format::FormatOptions;
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
+    if super::render_if_disabled(f, area, app, TabType::Processes, "Process Monitor") {
+        return;
+    }
+
     let process_data = app.state.process_data.read();
     let process_error = app.state.process_error.read();
 
@@ -32,11 +38,20 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
     } else if let Some(data) = process_data.as_ref() {
         let config = app.state.config.read();
         let theme = Theme::from_config(&config);
+        let fmt = FormatOptions::from_config(&config);
 
         if app.state.compact_mode {
-            render_compact(f, area, data, app, &theme);
+            render_compact(f, area, data, app, &theme, &fmt);
         } else {
-            render_full(f, area, data, app, &theme);
+            render_full(f, area, data, app, &theme, &fmt);
+        }
+
+        if app.state.processes_state.show_kill_confirm {
+            render_kill_confirm(f, area, app);
+        }
+
+        if let Some(view) = app.state.processes_state.detail.as_ref() {
+            render_process_detail(f, area, view, &theme, &fmt);
         }
     } else {
         let block = Block::default()
@@ -58,6 +73,7 @@ fn render_full(
     data: &crate::monitors::ProcessData,
     app: &App,
     theme: &Theme,
+    fmt: &FormatOptions,
 ) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -69,13 +85,13 @@ fn render_full(
         .split(area);
 
     // Render header
-    render_header(f, chunks[0], data, theme);
+    render_header(f, chunks[0], data, theme, fmt);
 
     // Render process table
-    render_process_table(f, chunks[1], data, app, theme);
+    render_process_table(f, chunks[1], data, app, theme, fmt);
 
     // Render details panel
-    render_details_panel(f, chunks[2], data, app, theme);
+    render_details_panel(f, chunks[2], data, app, theme, fmt);
 }
 
 fn render_compact(
@@ -84,6 +100,7 @@ fn render_compact(
     data: &crate::monitors::ProcessData,
     app: &App,
     theme: &Theme,
+    fmt: &FormatOptions,
 ) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -94,10 +111,10 @@ fn render_compact(
         .split(area);
 
     // Render header
-    render_header(f, chunks[0], data, theme);
+    render_header(f, chunks[0], data, theme, fmt);
 
     // Render process table
-    render_process_table(f, chunks[1], data, app, theme);
+    render_process_table(f, chunks[1], data, app, theme, fmt);
 }
 
 fn render_header(
@@ -105,6 +122,7 @@ fn render_header(
     area: Rect,
     data: &crate::monitors::ProcessData,
     _theme: &Theme,
+    fmt: &FormatOptions,
 ) {
     let total_processes = data.processes.len();
     let total_memory: u64 = data.processes.iter().map(|p| p.memory).sum();
@@ -121,7 +139,7 @@ fn render_header(
         Span::raw("  "),
         Span::styled("Total Memory: ", Style::default().fg(Color::Gray)),
         Span::styled(
-            format_bytes(total_memory),
+            fmt.bytes(total_memory),
             Style::default()
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
@@ -151,26 +169,10 @@ fn render_process_table(
     data: &crate::monitors::ProcessData,
     app: &App,
     _theme: &Theme,
+    fmt: &FormatOptions,
 ) {
     // Sort and filter processes
-    let mut processes = data.processes.clone();
-
-    // Apply filter if any
-    if !app.state.processes_state.filter.is_empty() {
-        let filter = app.state.processes_state.filter.to_lowercase();
-        processes.retain(|p| {
-            p.name.to_lowercase().contains(&filter)
-                || p.user.to_lowercase().contains(&filter)
-                || p.pid.to_string().contains(&filter)
-        });
-    }
-
-    // Apply sorting
-    sort_processes(
-        &mut processes,
-        app.state.processes_state.sort_column,
-        app.state.processes_state.sort_ascending,
-    );
+    let processes = process_rows(&data.processes, &app.state.processes_state);
 
     let selected_index = if processes.is_empty() {
         0
@@ -186,6 +188,7 @@ fn render_process_table(
     let header_height = 1u16;
     let visible_rows = content_height
         .saturating_sub(header_height + footer_height) as usize;
+    app.state.processes_state.last_visible_rows.set(visible_rows);
 
     let mut scroll_offset = app.state.processes_state.scroll_offset;
     if selected_index < scroll_offset {
@@ -208,80 +211,22 @@ fn render_process_table(
         "↓"
     };
 
-    let headers = vec![
-        Cell::from(
-            if app.state.processes_state.sort_column == ProcessSortColumn::Pid {
-                format!("PID {}", sort_indicator)
-            } else {
-                "PID".to_string()
-            },
-        )
-        .style(
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Cell::from(
-            if app.state.processes_state.sort_column == ProcessSortColumn::Name {
-                format!("Name {}", sort_indicator)
-            } else {
-                "Name".to_string()
-            },
-        )
-        .style(
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Cell::from(
-            if app.state.processes_state.sort_column == ProcessSortColumn::Cpu {
-                format!("CPU% {}", sort_indicator)
-            } else {
-                "CPU%".to_string()
-            },
-        )
-        .style(
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Cell::from(
-            if app.state.processes_state.sort_column == ProcessSortColumn::Memory {
-                format!("Memory {}", sort_indicator)
-            } else {
-                "Memory".to_string()
-            },
-        )
-        .style(
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Cell::from(
-            if app.state.processes_state.sort_column == ProcessSortColumn::Threads {
-                format!("Threads {}", sort_indicator)
-            } else {
-                "Threads".to_string()
-            },
-        )
-        .style(
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Cell::from(
-            if app.state.processes_state.sort_column == ProcessSortColumn::User {
-                format!("User {}", sort_indicator)
+    let columns = app.state.config.read().monitors.processes.columns();
+    let headers: Vec<Cell> = columns
+        .iter()
+        .map(|column| {
+            let title = if column.sort_column() == Some(app.state.processes_state.sort_column) {
+                format!("{} {}", column.title(), sort_indicator)
             } else {
-                "User".to_string()
-            },
-        )
-        .style(
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        ),
-    ];
+                column.title().to_string()
+            };
+            Cell::from(title).style(
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )
+        })
+        .collect();
 
     let header = Row::new(headers).height(1);
 
@@ -291,48 +236,100 @@ fn render_process_table(
         .enumerate()
         .skip(scroll_offset)
         .take(visible_rows.max(0))
-        .map(|(i, process)| {
+        .map(|(i, row)| {
+            let process = &row.process;
             let style = if i == selected_index {
                 Style::default().fg(Color::Black).bg(Color::Cyan)
+            } else if row.pinned {
+                Style::default().fg(Color::Yellow)
             } else {
                 Style::default().fg(Color::White)
             };
 
-            Row::new(vec![
-                Cell::from(format!("{}", process.pid)).style(style),
-                Cell::from(process.name.clone()).style(style),
-                Cell::from(format!("{:.1}", process.cpu_usage)).style(style),
-                Cell::from(format_bytes(process.memory)).style(style),
-                Cell::from(format!("{}", process.threads)).style(style),
-                Cell::from(process.user.clone()).style(style),
-            ])
+            let marker = match (row.has_children, row.collapsed) {
+                (true, true) => "▸ ",
+                (true, false) => "▾ ",
+                _ => "",
+            };
+            let pin = if row.pinned { "★ " } else { "" };
+
+            Row::new(columns.iter().map(|column| {
+                let text = match column {
+                    ProcessColumn::Name => {
+                        format!("{}{}{}{}", row.prefix, marker, pin, process.name)
+                    }
+                    _ => column.cell_text(row, fmt),
+                };
+                Cell::from(text).style(style)
+            }))
         })
         .collect();
 
-    // Hotkeys hint
+    // Hotkeys hint, listing only the sortable columns on screen
+    let sortable: Vec<ProcessColumn> = columns
+        .iter()
+        .copied()
+        .filter(|column| column.sort_column().is_some())
+        .collect();
+    let sort_keys = sortable
+        .iter()
+        .map(|column| sort_key(*column))
+        .collect::<Vec<_>>()
+        .join("/");
+    let sort_titles = sortable
+        .iter()
+        .map(|column| column.title().trim_end_matches('%'))
+        .collect::<Vec<_>>()
+        .join("/");
     let hotkeys = vec![Line::from(vec![
         Span::styled("↑/↓", Style::default().fg(Color::Cyan)),
         Span::raw(": Navigate  "),
-        Span::styled("p/n/c/m/t/u", Style::default().fg(Color::Cyan)),
-        Span::raw(": Sort by PID/Name/CPU/Memory/Threads/User  "),
+        Span::styled(sort_keys, Style::default().fg(Color::Cyan)),
+        Span::raw(format!(": Sort by {}  ", sort_titles)),
         Span::styled("PgUp/PgDn", Style::default().fg(Color::Cyan)),
-        Span::raw(": Page Up/Down"),
+        Span::raw(": Page Up/Down  "),
+        Span::styled("k", Style::default().fg(Color::Cyan)),
+        Span::raw(": Kill  "),
+        Span::styled("/", Style::default().fg(Color::Cyan)),
+        Span::raw(": Filter  "),
+        Span::styled("T", Style::default().fg(Color::Cyan)),
+        Span::raw(": Tree  "),
+        Span::styled("Space", Style::default().fg(Color::Cyan)),
+        Span::raw(": Collapse  "),
+        Span::styled("e", Style::default().fg(Color::Cyan)),
+        Span::raw(": Export  "),
+        Span::styled("*", Style::default().fg(Color::Cyan)),
+        Span::raw(": Pin"),
     ])];
 
+    let view = if app.state.processes_state.tree_view {
+        "Processes (tree)"
+    } else {
+        "Processes"
+    };
+    let state = &app.state.processes_state;
+    let mut title = view.to_string();
+    if state.filter_editing {
+        title.push_str(&format!(" [Filter: {}_] Enter: Apply  Esc: Clear", state.filter));
+    } else if !state.filter.is_empty() {
+        title.push_str(&format!(
+            " [Filter: {}] {} match{}",
+            state.filter,
+            processes.len(),
+            if processes.len() == 1 { "" } else { "es" }
+        ));
+    }
+    if let Some(status) = &state.action_status {
+        title.push_str(&format!(" - {}", status));
+    }
+
     let block = Block::default()
-        .title("Processes")
+        .title(title)
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Cyan));
 
     // Calculate constraints for table columns
-    let widths = [
-        Constraint::Length(8),  // PID
-        Constraint::Min(20),    // Name
-        Constraint::Length(8),  // CPU%
-        Constraint::Length(12), // Memory
-        Constraint::Length(10), // Threads
-        Constraint::Min(15),    // User
-    ];
+    let widths: Vec<Constraint> = columns.iter().map(|column| column_width(*column)).collect();
 
     let table = Table::new(rows, widths)
         .header(header)
@@ -355,30 +352,41 @@ fn render_process_table(
     }
 }
 
+fn column_width(column: ProcessColumn) -> Constraint {
+    match column {
+        ProcessColumn::Pid | ProcessColumn::ParentPid | ProcessColumn::Cpu => Constraint::Length(8),
+        ProcessColumn::Name => Constraint::Min(20),
+        ProcessColumn::Memory => Constraint::Length(12),
+        ProcessColumn::Threads | ProcessColumn::Handles => Constraint::Length(10),
+        ProcessColumn::User => Constraint::Min(15),
+        ProcessColumn::Started => Constraint::Length(20),
+        ProcessColumn::Command => Constraint::Min(30),
+    }
+}
+
+/// Default key for each sortable column, as listed in the hotkeys hint.
+fn sort_key(column: ProcessColumn) -> &'static str {
+    match column.sort_column() {
+        Some(ProcessSortColumn::Pid) => "p",
+        Some(ProcessSortColumn::Name) => "n",
+        Some(ProcessSortColumn::Cpu) => "c",
+        Some(ProcessSortColumn::Memory) => "m",
+        Some(ProcessSortColumn::Threads) => "t",
+        Some(ProcessSortColumn::User) => "u",
+        None => "",
+    }
+}
+
 fn render_details_panel(
     f: &mut Frame,
     area: Rect,
     data: &crate::monitors::ProcessData,
     app: &App,
     _theme: &Theme,
+    fmt: &FormatOptions,
 ) {
     // Sort and filter processes (same as in table)
-    let mut processes = data.processes.clone();
-
-    if !app.state.processes_state.filter.is_empty() {
-        let filter = app.state.processes_state.filter.to_lowercase();
-        processes.retain(|p| {
-            p.name.to_lowercase().contains(&filter)
-                || p.user.to_lowercase().contains(&filter)
-                || p.pid.to_string().contains(&filter)
-        });
-    }
-
-    sort_processes(
-        &mut processes,
-        app.state.processes_state.sort_column,
-        app.state.processes_state.sort_ascending,
-    );
+    let processes = process_rows(&data.processes, &app.state.processes_state);
 
     let selected_index = if processes.is_empty() {
         0
@@ -390,7 +398,7 @@ fn render_details_panel(
     };
 
     // Get selected process
-    if let Some(process) = processes.get(selected_index) {
+    if let Some(process) = processes.get(selected_index).map(|row| &row.process) {
         let mut details = Vec::new();
 
         details.push(Line::from(vec![Span::styled(
@@ -409,6 +417,15 @@ fn render_details_panel(
                 Style::default().fg(Color::White),
             ),
             Span::raw("  "),
+            Span::styled("Parent: ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                process
+                    .parent_pid
+                    .map(|ppid| ppid.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+                Style::default().fg(Color::White),
+            ),
+            Span::raw("  "),
             Span::styled("Name: ", Style::default().fg(Color::Gray)),
             Span::styled(
                 &process.name,
@@ -444,7 +461,7 @@ fn render_details_panel(
             Span::raw("  "),
             Span::styled("Memory: ", Style::default().fg(Color::Gray)),
             Span::styled(
-                format_bytes(process.memory),
+                fmt.bytes(process.memory),
                 Style::default().fg(Color::Yellow),
             ),
         ]));
@@ -452,13 +469,13 @@ fn render_details_panel(
         details.push(Line::from(vec![
             Span::styled("I/O Read: ", Style::default().fg(Color::Gray)),
             Span::styled(
-                format_bytes(process.io_read_bytes),
+                fmt.bytes(process.io_read_bytes),
                 Style::default().fg(Color::Blue),
             ),
             Span::raw("  "),
             Span::styled("I/O Write: ", Style::default().fg(Color::Gray)),
             Span::styled(
-                format_bytes(process.io_write_bytes),
+                fmt.bytes(process.io_write_bytes),
                 Style::default().fg(Color::Magenta),
             ),
         ]));
@@ -506,24 +523,164 @@ fn render_details_panel(
     }
 }
 
-fn sort_processes(processes: &mut Vec<ProcessEntry>, column: ProcessSortColumn, ascending: bool) {
-    processes.sort_by(|a, b| {
-        let cmp = match column {
-            ProcessSortColumn::Pid => a.pid.cmp(&b.pid),
-            ProcessSortColumn::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
-            ProcessSortColumn::Cpu => a
-                .cpu_usage
-                .partial_cmp(&b.cpu_usage)
-                .unwrap_or(Ordering::Equal),
-            ProcessSortColumn::Memory => a.memory.cmp(&b.memory),
-            ProcessSortColumn::Threads => a.threads.cmp(&b.threads),
-            ProcessSortColumn::User => a.user.to_lowercase().cmp(&b.user.to_lowercase()),
-        };
+fn render_kill_confirm(f: &mut Frame, area: Rect, app: &App) {
+    let label = match &app.state.processes_state.pending_kill {
+        Some(target) => format!("{} (PID {})", target.name, target.pid),
+        None => "selected process".to_string(),
+    };
+    let rect = centered_rect(50, 30, area);
 
-        if ascending {
-            cmp
-        } else {
-            cmp.reverse()
+    f.render_widget(Clear, rect);
+
+    let text = vec![
+        Line::from(vec![
+            Span::styled(
+                "Kill process",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(": "),
+            Span::styled(label, Style::default().fg(Color::White)),
+        ]),
+        Line::from(""),
+        Line::from("Unsaved work in this process will be lost."),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Enter/Y", Style::default().fg(Color::Cyan)),
+            Span::raw(": Confirm  "),
+            Span::styled("Esc/N", Style::default().fg(Color::Cyan)),
+            Span::raw(": Cancel"),
+        ]),
+    ];
+
+    let block = Block::default()
+        .title("Confirm Kill")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Red));
+
+    let paragraph = Paragraph::new(text).block(block);
+    f.render_widget(paragraph, rect);
+}
+
+fn render_process_detail(
+    f: &mut Frame,
+    area: Rect,
+    view: &ProcessDetailView,
+    theme: &Theme,
+    fmt: &FormatOptions,
+) {
+    let process = &view.process;
+    let rect = centered_rect(70, 60, area);
+
+    f.render_widget(Clear, rect);
+
+    let field = |label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<15}", label), Style::default().fg(Color::Gray)),
+            Span::styled(value, Style::default().fg(Color::White)),
+        ])
+    };
+    let or_dash = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+
+    let mut text = vec![
+        Line::from(vec![
+            Span::styled(
+                process.name.clone(),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("  PID {}", process.pid),
+                Style::default().fg(Color::Gray),
+            ),
+        ]),
+        Line::from(""),
+    ];
+
+    match view.details.read().as_ref() {
+        None => text.push(Line::from(Span::styled(
+            "Loading details...",
+            Style::default().fg(Color::DarkGray),
+        ))),
+        Some(Err(message)) => text.push(Line::from(Span::styled(
+            format!("Details unavailable: {}", message),
+            Style::default().fg(theme.warning_color),
+        ))),
+        Some(Ok(details)) => {
+            text.push(field(
+                "Executable",
+                or_dash(details.executable_path.clone()),
+            ));
+            text.push(field(
+                "Command line",
+                or_dash(
+                    details
+                        .command_line
+                        .clone()
+                        .or_else(|| process.command_line.clone()),
+                ),
+            ));
+            text.push(field(
+                "Parent PID",
+                or_dash(
+                    details
+                        .parent_pid
+                        .or(process.parent_pid)
+                        .map(|ppid| ppid.to_string()),
+                ),
+            ));
+            text.push(field(
+                "Started",
+                or_dash(
+                    details
+                        .start_time
+                        .clone()
+                        .or_else(|| process.start_time.clone()),
+                ),
+            ));
+            text.push(field(
+                "Threads",
+                details
+                    .threads
+                    .map(|threads| threads.to_string())
+                    .unwrap_or_else(|| process.threads.to_string()),
+            ));
+            let handles_label = if cfg!(target_os = "linux") {
+                "Open files"
+            } else {
+                "Handles"
+            };
+            text.push(field(
+                handles_label,
+                or_dash(details.handles.map(|handles| handles.to_string())),
+            ));
+            text.push(field(
+                "Working set",
+                or_dash(details.working_set.map(|bytes| fmt.bytes(bytes))),
+            ));
+            text.push(field(
+                "Private bytes",
+                or_dash(details.private_bytes.map(|bytes| fmt.bytes(bytes))),
+            ));
+            if let Some(modules) = details.module_count {
+                text.push(field("Modules", modules.to_string()));
+            }
         }
-    });
+    }
+
+    text.push(Line::from(""));
+    text.push(Line::from(vec![
+        Span::styled("Esc/Enter", Style::default().fg(Color::Cyan)),
+        Span::raw(": Close"),
+    ]));
+
+    let block = Block::default()
+        .title("Process Details")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let paragraph = Paragraph::new(text)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    f.render_widget(paragraph, rect);
 }
@@ -2,17 +2,22 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Gauge, Paragraph, Row, Table},
+    widgets::{Block, Borders, Gauge, Paragraph, Row, Sparkline, Table},
     Frame,
 };
 
-use crate::app::App;
+use crate::app::{App, TabType};
 use crate::app::state::{RamPanelFocus, RamProcessSortColumn};
 use crate::monitors::ram::ProcessMemoryInfo;
 use crate::ui::theme::Theme;
-use crate::utils::format::{create_progress_bar, format_bytes};
+use crate::ui::widgets::graph::fit_sparkline;
+use crate::utils::format::{create_progress_bar, safe_percent, FormatOptions};
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
+    if super::render_if_disabled(f, area, app, TabType::Ram, "RAM Monitor") {
+        return;
+    }
+
     let ram_data = app.state.ram_data.read();
     let ram_error = app.state.ram_error.read();
 
@@ -32,11 +37,12 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
     } else if let Some(data) = ram_data.as_ref() {
         let config = app.state.config.read();
         let theme = Theme::from_config(&config);
+        let fmt = FormatOptions::from_config(&config);
 
         if app.state.compact_mode {
-            render_compact(f, area, data, &theme);
+            render_compact(f, area, data, &theme, &fmt);
         } else {
-            render_full(f, area, data, app, &theme);
+            render_full(f, area, data, app, &theme, &fmt);
         }
     } else {
         let block = Block::default()
@@ -58,6 +64,7 @@ fn render_full(
     data: &crate::monitors::RamData,
     app: &App,
     theme: &Theme,
+    fmt: &FormatOptions,
 ) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -67,6 +74,7 @@ fn render_full(
             Constraint::Length(3), // Committed memory
             Constraint::Length(3), // Pagefile gauge
             Constraint::Length(9), // Memory breakdown
+            Constraint::Length(5), // History
             Constraint::Min(8),    // Top processes
         ])
         .split(area);
@@ -74,7 +82,7 @@ fn render_full(
     // Header
     let header = format!(
         "RAM: {} Total  |  Type: {}  |  Speed: {}",
-        format_bytes(data.total),
+        fmt.bytes(data.total),
         data.type_name,
         data.speed
     );
@@ -92,11 +100,7 @@ fn render_full(
     f.render_widget(header_text, chunks[0]);
 
     // Overall usage gauge
-    let usage_percent = if data.total > 0 {
-        ((data.used as f64 / data.total as f64) * 100.0).min(100.0) as u16
-    } else {
-        0
-    };
+    let usage_percent = safe_percent(data.used, data.total) as u16;
     let gauge = Gauge::default()
         .block(Block::default().borders(Borders::ALL).title("Memory Usage"))
         .gauge_style(
@@ -108,8 +112,8 @@ fn render_full(
         .label(format!(
             "{}% - {} / {}",
             usage_percent,
-            format_bytes(data.used),
-            format_bytes(data.total)
+            fmt.bytes(data.used),
+            fmt.bytes(data.total)
         ));
 
     f.render_widget(gauge, chunks[1]);
@@ -131,25 +135,43 @@ fn render_full(
         .label(format!(
             "{}% - {} / {} (Physical + Pagefile)",
             commit_percent,
-            format_bytes(data.committed),
-            format_bytes(data.commit_limit)
+            fmt.bytes(data.committed),
+            fmt.bytes(data.commit_limit)
         ));
 
     f.render_widget(commit_gauge, chunks[2]);
 
     // Pagefile gauge
-    render_pagefile_gauge(f, chunks[3], data, theme);
+    render_pagefile_gauge(f, chunks[3], data, theme, fmt);
 
     // Memory breakdown
     let breakdown_focused = app.state.ram_state.focused_panel == RamPanelFocus::Breakdown;
-    render_memory_breakdown(f, chunks[4], data, theme, breakdown_focused);
+    if data.modules.is_empty() {
+        render_memory_breakdown(f, chunks[4], data, theme, fmt, breakdown_focused);
+    } else {
+        let breakdown_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(chunks[4]);
+        render_memory_breakdown(f, breakdown_chunks[0], data, theme, fmt, breakdown_focused);
+        render_memory_modules(f, breakdown_chunks[1], data, theme, fmt);
+    }
+
+    // Usage history
+    render_history(f, chunks[5], data, theme);
 
     // Top processes
     let processes_focused = app.state.ram_state.focused_panel == RamPanelFocus::TopProcesses;
-    render_top_processes(f, chunks[5], data, app, theme, processes_focused);
+    render_top_processes(f, chunks[6], data, app, theme, fmt, processes_focused);
 }
 
-fn render_compact(f: &mut Frame, area: Rect, data: &crate::monitors::RamData, theme: &Theme) {
+fn render_compact(
+    f: &mut Frame,
+    area: Rect,
+    data: &crate::monitors::RamData,
+    theme: &Theme,
+    fmt: &FormatOptions,
+) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -161,8 +183,8 @@ fn render_compact(f: &mut Frame, area: Rect, data: &crate::monitors::RamData, th
     // Header
     let header = format!(
         "RAM: {} / {}",
-        format_bytes(data.used),
-        format_bytes(data.total)
+        fmt.bytes(data.used),
+        fmt.bytes(data.total)
     );
     let header_block = Block::default()
         .borders(Borders::ALL)
@@ -177,11 +199,7 @@ fn render_compact(f: &mut Frame, area: Rect, data: &crate::monitors::RamData, th
     f.render_widget(header_text, chunks[0]);
 
     // Compact memory info
-    let usage_percent = if data.total > 0 {
-        ((data.used as f64 / data.total as f64) * 100.0).min(100.0) as f32
-    } else {
-        0.0
-    };
+    let usage_percent = safe_percent(data.used, data.total) as f32;
     let commit_percent = data.commit_percent as f32;
 
     let mut info_text = vec![
@@ -211,11 +229,8 @@ fn render_compact(f: &mut Frame, area: Rect, data: &crate::monitors::RamData, th
 
     // Add pagefile info if configured
     if data.total_pagefile_size > 0 {
-        let pagefile_percent = if data.total_pagefile_size > 0 {
-            ((data.total_pagefile_used as f64 / data.total_pagefile_size as f64) * 100.0) as f32
-        } else {
-            0.0
-        };
+        let pagefile_percent =
+            safe_percent(data.total_pagefile_used, data.total_pagefile_size) as f32;
 
         info_text.push(Line::from(vec![
             Span::raw("Pagefile:  "),
@@ -244,63 +259,64 @@ fn render_memory_breakdown(
     area: Rect,
     data: &crate::monitors::RamData,
     theme: &Theme,
+    fmt: &FormatOptions,
     focused: bool,
 ) {
     let mut breakdown_text = vec![
         Line::from(vec![
             Span::raw("  In Use:     "),
             Span::styled(
-                format!("{:>12}  ", format_bytes(data.in_use)),
+                format!("{:>12}  ", fmt.bytes(data.in_use)),
                 Style::default()
                     .fg(theme.ram_color)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::raw(create_progress_bar(
-                ((data.in_use as f64 / data.total as f64) * 100.0) as f32,
+                safe_percent(data.in_use, data.total) as f32,
                 30,
             )),
         ]),
         Line::from(vec![
             Span::raw("  Available:  "),
             Span::styled(
-                format!("{:>12}  ", format_bytes(data.available)),
+                format!("{:>12}  ", fmt.bytes(data.available)),
                 Style::default().fg(Color::Green),
             ),
             Span::raw(create_progress_bar(
-                ((data.available as f64 / data.total as f64) * 100.0) as f32,
+                safe_percent(data.available, data.total) as f32,
                 30,
             )),
         ]),
         Line::from(vec![
             Span::raw("  Cached:     "),
             Span::styled(
-                format!("{:>12}  ", format_bytes(data.cached)),
+                format!("{:>12}  ", fmt.bytes(data.cached)),
                 Style::default().fg(Color::Cyan),
             ),
             Span::raw(create_progress_bar(
-                ((data.cached as f64 / data.total as f64) * 100.0) as f32,
+                safe_percent(data.cached, data.total) as f32,
                 30,
             )),
         ]),
         Line::from(vec![
             Span::raw("  Standby:    "),
             Span::styled(
-                format!("{:>12}  ", format_bytes(data.standby)),
+                format!("{:>12}  ", fmt.bytes(data.standby)),
                 Style::default().fg(Color::Blue),
             ),
             Span::raw(create_progress_bar(
-                ((data.standby as f64 / data.total as f64) * 100.0) as f32,
+                safe_percent(data.standby, data.total) as f32,
                 30,
             )),
         ]),
         Line::from(vec![
             Span::raw("  Modified:   "),
             Span::styled(
-                format!("{:>12}  ", format_bytes(data.modified)),
+                format!("{:>12}  ", fmt.bytes(data.modified)),
                 Style::default().fg(Color::Yellow),
             ),
             Span::raw(create_progress_bar(
-                ((data.modified as f64 / data.total as f64) * 100.0) as f32,
+                safe_percent(data.modified, data.total) as f32,
                 30,
             )),
         ]),
@@ -319,12 +335,12 @@ fn render_memory_breakdown(
             breakdown_text.push(Line::from(vec![
                 Span::raw(pf_name),
                 Span::styled(
-                    format!("{:>12}  ", format_bytes(pf.current_usage)),
+                    format!("{:>12}  ", fmt.bytes(pf.current_usage)),
                     Style::default().fg(Color::Magenta),
                 ),
                 Span::raw(create_progress_bar(pf.usage_percent as f32, 30)),
                 Span::styled(
-                    format!(" / {}", format_bytes(pf.total_size)),
+                    format!(" / {}", fmt.bytes(pf.total_size)),
                     Style::default().fg(Color::Gray),
                 ),
             ]));
@@ -347,11 +363,133 @@ fn render_memory_breakdown(
     f.render_widget(breakdown_para, area);
 }
 
+fn render_memory_modules(
+    f: &mut Frame,
+    area: Rect,
+    data: &crate::monitors::RamData,
+    theme: &Theme,
+    fmt: &FormatOptions,
+) {
+    let header = Row::new(vec!["Slot", "Size", "Type", "Speed", "Module"])
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> = data
+        .modules
+        .iter()
+        .map(|module| {
+            let speed = module
+                .speed
+                .map(|speed| format!("{} MT/s", speed))
+                .unwrap_or_else(|| "N/A".to_string());
+            let description = [module.manufacturer.as_str(), module.part_number.as_str()]
+                .iter()
+                .filter(|part| !part.is_empty())
+                .copied()
+                .collect::<Vec<_>>()
+                .join(" ");
+            Row::new(vec![
+                module.slot.clone(),
+                fmt.bytes(module.capacity),
+                module.memory_type.clone(),
+                speed,
+                description,
+            ])
+        })
+        .collect();
+
+    // Sticks that differ from the first one are worth a second look
+    let mismatched = data.modules.iter().any(|module| {
+        let first = &data.modules[0];
+        module.capacity != first.capacity
+            || module.speed != first.speed
+            || module.part_number != first.part_number
+    });
+    let slots = match data.total_slots {
+        Some(total) if total as usize >= data.modules.len() => format!(
+            "{}/{} slots, {} empty",
+            data.modules.len(),
+            total,
+            total as usize - data.modules.len()
+        ),
+        _ => format!("{} populated", data.modules.len()),
+    };
+    let title = if mismatched {
+        format!("Memory Modules ({}, mismatched)", slots)
+    } else {
+        format!("Memory Modules ({})", slots)
+    };
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(10),
+            Constraint::Length(9),
+            Constraint::Length(10),
+            Constraint::Length(10),
+            Constraint::Min(10),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(Style::default().fg(if mismatched {
+                theme.warning_color
+            } else {
+                theme.ram_color
+            })),
+    )
+    .style(Style::default().fg(Color::White));
+
+    f.render_widget(table, area);
+}
+
+fn render_history(f: &mut Frame, area: Rect, data: &crate::monitors::RamData, theme: &Theme) {
+    let mut series = vec![
+        ("Usage History", &data.usage_history, theme.ram_color),
+        ("Commit History", &data.commit_history, Color::Yellow),
+    ];
+    if !data.pagefiles.is_empty() {
+        series.push(("Pagefile History", &data.pagefile_history, Color::Magenta));
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![
+            Constraint::Ratio(1, series.len() as u32);
+            series.len()
+        ])
+        .split(area);
+
+    for (i, (title, history, color)) in series.into_iter().enumerate() {
+        let values: Vec<u64> = history
+            .iter()
+            .map(|&v| v.clamp(0.0, 100.0) as u64)
+            .collect();
+        let values = fit_sparkline(&values, chunks[i].width.saturating_sub(2));
+
+        let sparkline = Sparkline::default()
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(title)
+                    .border_style(Style::default().fg(color)),
+            )
+            .data(&values)
+            .style(Style::default().fg(color))
+            .max(100);
+
+        f.render_widget(sparkline, chunks[i]);
+    }
+}
+
 fn render_pagefile_gauge(
     f: &mut Frame,
     area: Rect,
     data: &crate::monitors::RamData,
     theme: &Theme,
+    fmt: &FormatOptions,
 ) {
     if data.total_pagefile_size == 0 {
         // No pagefile configured
@@ -386,19 +524,15 @@ fn render_pagefile_gauge(
             .label(format!(
                 "{}% - {} / {}",
                 pagefile_percent,
-                format_bytes(pf.current_usage),
-                format_bytes(pf.total_size)
+                fmt.bytes(pf.current_usage),
+                fmt.bytes(pf.total_size)
             ));
 
         f.render_widget(gauge, area);
     } else {
         // Multiple pagefiles - show total
-        let total_percent = if data.total_pagefile_size > 0 {
-            ((data.total_pagefile_used as f64 / data.total_pagefile_size as f64) * 100.0).min(100.0)
-                as u16
-        } else {
-            0
-        };
+        let total_percent =
+            safe_percent(data.total_pagefile_used, data.total_pagefile_size) as u16;
 
         let gauge = Gauge::default()
             .block(
@@ -416,8 +550,8 @@ fn render_pagefile_gauge(
             .label(format!(
                 "{}% - {} / {}",
                 total_percent,
-                format_bytes(data.total_pagefile_used),
-                format_bytes(data.total_pagefile_size)
+                fmt.bytes(data.total_pagefile_used),
+                fmt.bytes(data.total_pagefile_size)
             ));
 
         f.render_widget(gauge, area);
@@ -430,6 +564,7 @@ fn render_top_processes(
     data: &crate::monitors::RamData,
     app: &App,
     theme: &Theme,
+    fmt: &FormatOptions,
     focused: bool,
 ) {
     let mut processes = data.top_processes.clone();
@@ -530,8 +665,8 @@ fn render_top_processes(
             let cells = vec![
                 ratatui::widgets::Cell::from(proc.pid.to_string()),
                 ratatui::widgets::Cell::from(proc.name.clone()),
-                ratatui::widgets::Cell::from(format_bytes(proc.working_set)),
-                ratatui::widgets::Cell::from(format_bytes(proc.private_bytes)),
+                ratatui::widgets::Cell::from(fmt.bytes(proc.working_set)),
+                ratatui::widgets::Cell::from(fmt.bytes(proc.private_bytes)),
             ];
             Row::new(cells).height(1).style(style)
         })
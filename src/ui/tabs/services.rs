@@ -7,13 +7,17 @@ use ratatui::{
 };
 
 use crate::app::{
-    state::{ServiceSortColumn, ServiceStatusFilter, ServicesPanelFocus},
-    App,
+    state::{visible_services, ServiceSortColumn, ServiceStatusFilter, ServicesPanelFocus},
+    App, TabType,
 };
-use crate::monitors::services::{ServiceEntry, ServiceStatus};
+use crate::monitors::services::ServiceStatus;
 use crate::ui::theme::Theme;
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
+    if super::render_if_disabled(f, area, app, TabType::Services, "Service Monitor") {
+        return;
+    }
+
     let service_data = app.state.service_data.read();
     let service_error = app.state.service_error.read();
 
@@ -175,25 +179,7 @@ fn render_service_table(
     theme: &Theme,
 ) {
     // Filter and sort services
-    let mut services = data.services.clone();
-
-    // Apply status filter
-    match app.state.services_state.status_filter {
-        ServiceStatusFilter::Running => {
-            services.retain(|s| s.status == ServiceStatus::Running);
-        }
-        ServiceStatusFilter::Stopped => {
-            services.retain(|s| s.status == ServiceStatus::Stopped);
-        }
-        ServiceStatusFilter::All => {}
-    }
-
-    // Apply sorting
-    sort_services(
-        &mut services,
-        app.state.services_state.sort_column,
-        app.state.services_state.sort_ascending,
-    );
+    let services = visible_services(&data.services, &app.state.services_state);
     let selected_index = if services.is_empty() {
         0
     } else {
@@ -208,6 +194,7 @@ fn render_service_table(
     let header_height = 1u16;
     let visible_rows = content_height
         .saturating_sub(header_height + footer_height) as usize;
+    app.state.services_state.last_visible_rows.set(visible_rows);
 
     let mut scroll_offset = app.state.services_state.scroll_offset;
     if selected_index < scroll_offset {
@@ -291,8 +278,11 @@ fn render_service_table(
         .take(visible_rows.max(0))
         .map(|(i, service)| {
             let is_selected = i == selected_index;
+            let is_pinned = app.state.services_state.pinned.contains(&service.name);
             let base_style = if is_selected {
                 Style::default().fg(Color::Black).bg(Color::Cyan)
+            } else if is_pinned {
+                Style::default().fg(Color::Yellow)
             } else {
                 Style::default().fg(Color::White)
             };
@@ -318,7 +308,12 @@ fn render_service_table(
             };
 
             Row::new(vec![
-                Cell::from(service.name.clone()).style(base_style),
+                Cell::from(if is_pinned {
+                    format!("★ {}", service.name)
+                } else {
+                    service.name.clone()
+                })
+                .style(base_style),
                 Cell::from(service.display_name.clone()).style(base_style),
                 Cell::from(service.status.as_str()).style(status_style),
                 Cell::from(service.start_type.as_str()).style(base_style),
@@ -326,19 +321,33 @@ fn render_service_table(
         })
         .collect();
 
-    // Hotkeys hint
-    let hotkeys = vec![Line::from(vec![
-        Span::styled("↑/↓", Style::default().fg(Color::Cyan)),
-        Span::raw(": Navigate  "),
-        Span::styled("n/d/s/t", Style::default().fg(Color::Cyan)),
-        Span::raw(": Sort by Name/Display/Status/Type  "),
-        Span::styled("f", Style::default().fg(Color::Cyan)),
-        Span::raw(": Filter  "),
-        Span::styled("Left/Right", Style::default().fg(Color::Cyan)),
-        Span::raw(": Focus  "),
-        Span::styled("PgUp/PgDn", Style::default().fg(Color::Cyan)),
-        Span::raw(": Page"),
-    ])];
+    // Hotkeys hint, replaced by the search line while a search is active
+    let state = &app.state.services_state;
+    let hotkeys = if state.filter_editing || !state.filter.is_empty() {
+        let cursor = if state.filter_editing { "_" } else { "" };
+        let keys = if state.filter_editing {
+            "Enter: Apply  Esc: Clear"
+        } else {
+            "/: Edit"
+        };
+        vec![Line::from(vec![
+            Span::styled("Search: ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("{}{}", state.filter, cursor),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(
+                "  {} match{}  ",
+                services.len(),
+                if services.len() == 1 { "" } else { "es" }
+            )),
+            Span::styled(keys, Style::default().fg(Color::Cyan)),
+        ])]
+    } else {
+        hotkeys_hint()
+    };
 
     let table_focused = app.state.services_state.focused_panel == ServicesPanelFocus::Table;
     let border_color = if table_focused {
@@ -381,6 +390,27 @@ fn render_service_table(
     }
 }
 
+fn hotkeys_hint() -> Vec<Line<'static>> {
+    vec![Line::from(vec![
+        Span::styled("↑/↓", Style::default().fg(Color::Cyan)),
+        Span::raw(": Navigate  "),
+        Span::styled("n/d/s/t", Style::default().fg(Color::Cyan)),
+        Span::raw(": Sort by Name/Display/Status/Type  "),
+        Span::styled("f", Style::default().fg(Color::Cyan)),
+        Span::raw(": Filter  "),
+        Span::styled("/", Style::default().fg(Color::Cyan)),
+        Span::raw(": Search  "),
+        Span::styled("Left/Right", Style::default().fg(Color::Cyan)),
+        Span::raw(": Focus  "),
+        Span::styled("PgUp/PgDn", Style::default().fg(Color::Cyan)),
+        Span::raw(": Page  "),
+        Span::styled("S/X/R", Style::default().fg(Color::Cyan)),
+        Span::raw(": Start/Stop/Restart  "),
+        Span::styled("*", Style::default().fg(Color::Cyan)),
+        Span::raw(": Pin"),
+    ])]
+}
+
 fn render_details_panel(
     f: &mut Frame,
     area: Rect,
@@ -389,23 +419,7 @@ fn render_details_panel(
     theme: &Theme,
 ) {
     // Filter services (same as in table)
-    let mut services = data.services.clone();
-
-    match app.state.services_state.status_filter {
-        ServiceStatusFilter::Running => {
-            services.retain(|s| s.status == ServiceStatus::Running);
-        }
-        ServiceStatusFilter::Stopped => {
-            services.retain(|s| s.status == ServiceStatus::Stopped);
-        }
-        ServiceStatusFilter::All => {}
-    }
-
-    sort_services(
-        &mut services,
-        app.state.services_state.sort_column,
-        app.state.services_state.sort_ascending,
-    );
+    let services = visible_services(&data.services, &app.state.services_state);
 
     let selected_index = if services.is_empty() {
         0
@@ -565,63 +579,3 @@ fn render_details_panel(
         f.render_widget(text, area);
     }
 }
-
-fn sort_services(services: &mut Vec<ServiceEntry>, column: ServiceSortColumn, ascending: bool) {
-    services.sort_by(|a, b| {
-        let cmp = match column {
-            ServiceSortColumn::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
-            ServiceSortColumn::DisplayName => a
-                .display_name
-                .to_lowercase()
-                .cmp(&b.display_name.to_lowercase()),
-            ServiceSortColumn::Status => {
-                // Sort by status priority: Running > Paused > Starting/Stopping > Stopped
-                let a_priority = match a.status {
-                    ServiceStatus::Running => 0,
-                    ServiceStatus::Paused => 1,
-                    ServiceStatus::StartPending | ServiceStatus::ContinuePending => 2,
-                    ServiceStatus::StopPending | ServiceStatus::PausePending => 3,
-                    ServiceStatus::Stopped => 4,
-                    ServiceStatus::Unknown => 5,
-                };
-                let b_priority = match b.status {
-                    ServiceStatus::Running => 0,
-                    ServiceStatus::Paused => 1,
-                    ServiceStatus::StartPending | ServiceStatus::ContinuePending => 2,
-                    ServiceStatus::StopPending | ServiceStatus::PausePending => 3,
-                    ServiceStatus::Stopped => 4,
-                    ServiceStatus::Unknown => 5,
-                };
-                a_priority.cmp(&b_priority)
-            }
-            ServiceSortColumn::StartType => {
-                // Sort by start type priority: Automatic > Auto (Delayed) > Manual > Disabled
-                let a_priority = match a.start_type {
-                    crate::monitors::services::ServiceStartType::Automatic => 0,
-                    crate::monitors::services::ServiceStartType::AutomaticDelayedStart => 1,
-                    crate::monitors::services::ServiceStartType::Manual => 2,
-                    crate::monitors::services::ServiceStartType::Disabled => 3,
-                    crate::monitors::services::ServiceStartType::Unknown => 4,
-                };
-                let b_priority = match b.start_type {
-                    crate::monitors::services::ServiceStartType::Automatic => 0,
-                    crate::monitors::services::ServiceStartType::AutomaticDelayedStart => 1,
-                    crate::monitors::services::ServiceStartType::Manual => 2,
-                    crate::monitors::services::ServiceStartType::Disabled => 3,
-                    crate::monitors::services::ServiceStartType::Unknown => 4,
-                };
-                a_priority.cmp(&b_priority)
-            }
-        };
-
-        if ascending {
-            cmp
-        } else {
-            cmp.reverse()
-        }
-    });
-}
-
-
-
-
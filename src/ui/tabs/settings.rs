@@ -1,8 +1,97 @@
-use ratatui::{layout::Rect, style::{Color, Style}, widgets::{Block, Borders, Paragraph}, Frame};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::app::settings::{FieldKind, FIELDS};
 use crate::app::App;
+use crate::ui::theme::Theme;
+
+pub fn render(f: &mut Frame, area: Rect, app: &App) {
+    let config = app.state.config.read();
+    let theme = Theme::from_config(&config);
+    let ui_state = &app.state.settings_state;
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(5), Constraint::Length(3)])
+        .split(area);
+
+    let label_width = FIELDS.iter().map(|field| field.label.len()).max().unwrap_or(0);
+    let visible = chunks[0].height.saturating_sub(2) as usize;
+    let start = (ui_state.selected_index + 1).saturating_sub(visible);
+
+    let lines: Vec<Line> = FIELDS
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(visible)
+        .map(|(i, field)| {
+            let selected = i == ui_state.selected_index;
+            let value = match (&ui_state.input, selected) {
+                (Some(input), true) => format!("[{}_]", input),
+                _ => field.value(&config).display(),
+            };
+            let hint = match field.kind {
+                FieldKind::Number { min, max, .. } => format!("{}-{}", min, max),
+                FieldKind::Choice(options) => options.join(" / "),
+                FieldKind::TabEnabled(_) => "on / off".to_string(),
+            };
+
+            let label_style = if selected {
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            let mut spans = vec![
+                Span::raw(if selected { "► " } else { "  " }),
+                Span::styled(format!("{:<width$}", field.label, width = label_width), label_style),
+                Span::raw("  "),
+                Span::styled(
+                    format!("{:<12}", value),
+                    Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(format!("  ({})", hint), Style::default().fg(Color::DarkGray)),
+            ];
+            if selected {
+                if let Some(error) = &ui_state.error {
+                    spans.push(Span::styled(
+                        format!("  ✗ {}", error),
+                        Style::default().fg(theme.error_color),
+                    ));
+                }
+            }
+            Line::from(spans)
+        })
+        .collect();
+
+    let block = Block::default()
+        .title("Settings (saved to config.toml)")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.foreground));
+    f.render_widget(Paragraph::new(lines).block(block), chunks[0]);
 
-pub fn render(f: &mut Frame, area: Rect, _app: &App) {
-    let block = Block::default().title("Settings").borders(Borders::ALL).border_style(Style::default().fg(Color::Gray));
-    let text = Paragraph::new("Settings - Coming soon").block(block);
-    f.render_widget(text, area);
+    let status = if ui_state.input.is_some() {
+        Line::from("Type a value  [Enter] Save  [Esc] Cancel")
+    } else if let Some(status) = &ui_state.status {
+        Line::from(vec![
+            Span::styled(status.clone(), Style::default().fg(theme.success_color)),
+            Span::styled(
+                "  │  [↑/↓] Select  [←/→] Change  [Enter] Edit",
+                Style::default().fg(Color::Gray),
+            ),
+        ])
+    } else {
+        Line::from(Span::styled(
+            "[↑/↓] Select  [←/→] Change  [Enter] Edit",
+            Style::default().fg(Color::Gray),
+        ))
+    };
+    f.render_widget(
+        Paragraph::new(status).block(Block::default().borders(Borders::ALL)),
+        chunks[1],
+    );
 }
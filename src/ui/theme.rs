@@ -1,20 +1,16 @@
 use ratatui::style::Color;
+use std::str::FromStr;
+
+use crate::app::config::ThemeColors;
 use crate::app::Config;
 
-pub fn parse_color(hex: &str) -> Color {
-    if hex.starts_with('#') && hex.len() == 7 {
-        if let (Ok(r), Ok(g), Ok(b)) = (
-            u8::from_str_radix(&hex[1..3], 16),
-            u8::from_str_radix(&hex[3..5], 16),
-            u8::from_str_radix(&hex[5..7], 16),
-        ) {
-            return Color::Rgb(r, g, b);
-        }
-    }
-    Color::White
+/// Parse `"#rrggbb"` or a named color (`"red"`, `"lightblue"`, ...).
+pub fn parse_color(value: &str) -> Option<Color> {
+    Color::from_str(value.trim()).ok()
 }
 
 /// Theme helper that provides colors from the config
+#[derive(Debug, Clone, Copy)]
 pub struct Theme {
     #[allow(dead_code)]
     pub background: Color,
@@ -29,21 +25,87 @@ pub struct Theme {
     pub success_color: Color,
 }
 
+const DARK: Theme = Theme {
+    background: Color::Rgb(0x1e, 0x1e, 0x2e),
+    foreground: Color::Rgb(0xcd, 0xd6, 0xf4),
+    cpu_color: Color::Rgb(0xf3, 0x8b, 0xa8),
+    gpu_color: Color::Rgb(0x94, 0xe2, 0xd5),
+    ram_color: Color::Rgb(0x89, 0xb4, 0xfa),
+    disk_color: Color::Rgb(0xa6, 0xe3, 0xa1),
+    network_color: Color::Rgb(0xf9, 0xe2, 0xaf),
+    warning_color: Color::Rgb(0xfa, 0xb3, 0x87),
+    error_color: Color::Rgb(0xf3, 0x8b, 0xa8),
+    success_color: Color::Rgb(0xa6, 0xe3, 0xa1),
+};
+
+const LIGHT: Theme = Theme {
+    background: Color::Rgb(0xef, 0xf1, 0xf5),
+    foreground: Color::Rgb(0x4c, 0x4f, 0x69),
+    cpu_color: Color::Rgb(0xd2, 0x0f, 0x39),
+    gpu_color: Color::Rgb(0x17, 0x92, 0x99),
+    ram_color: Color::Rgb(0x1e, 0x66, 0xf5),
+    disk_color: Color::Rgb(0x40, 0xa0, 0x2b),
+    network_color: Color::Rgb(0xdf, 0x8e, 0x1d),
+    warning_color: Color::Rgb(0xfe, 0x64, 0x0b),
+    error_color: Color::Rgb(0xd2, 0x0f, 0x39),
+    success_color: Color::Rgb(0x40, 0xa0, 0x2b),
+};
+
+const NORD: Theme = Theme {
+    background: Color::Rgb(0x2e, 0x34, 0x40),
+    foreground: Color::Rgb(0xd8, 0xde, 0xe9),
+    cpu_color: Color::Rgb(0xbf, 0x61, 0x6a),
+    gpu_color: Color::Rgb(0x8f, 0xbc, 0xbb),
+    ram_color: Color::Rgb(0x81, 0xa1, 0xc1),
+    disk_color: Color::Rgb(0xa3, 0xbe, 0x8c),
+    network_color: Color::Rgb(0xeb, 0xcb, 0x8b),
+    warning_color: Color::Rgb(0xd0, 0x87, 0x70),
+    error_color: Color::Rgb(0xbf, 0x61, 0x6a),
+    success_color: Color::Rgb(0xa3, 0xbe, 0x8c),
+};
+
+/// Built-in palette by name.
+pub fn preset(name: &str) -> Option<Theme> {
+    match name.trim().to_lowercase().as_str() {
+        "dark" => Some(DARK),
+        "light" => Some(LIGHT),
+        "nord" => Some(NORD),
+        _ => None,
+    }
+}
+
 impl Theme {
     pub fn from_config(config: &Config) -> Self {
-        let dark_theme = &config.theme.dark;
-
-        Self {
-            background: parse_color(&dark_theme.background),
-            foreground: parse_color(&dark_theme.foreground),
-            cpu_color: parse_color(&dark_theme.cpu_color),
-            gpu_color: parse_color(&dark_theme.gpu_color),
-            ram_color: parse_color(&dark_theme.ram_color),
-            disk_color: parse_color(&dark_theme.disk_color),
-            network_color: parse_color(&dark_theme.network_color),
-            warning_color: parse_color(&dark_theme.warning_color),
-            error_color: parse_color(&dark_theme.error_color),
-            success_color: parse_color(&dark_theme.success_color),
+        let theme_config = &config.theme;
+        let mut theme = preset(&theme_config.preset).unwrap_or(DARK);
+
+        if let Some(legacy) = theme_config.dark.as_ref() {
+            theme.apply(&legacy.as_colors());
+        }
+        theme.apply(&theme_config.overrides);
+
+        theme
+    }
+
+    /// Replace every role that has a valid color in `colors`.
+    fn apply(&mut self, colors: &ThemeColors) {
+        let slots = [
+            (&mut self.background, &colors.background),
+            (&mut self.foreground, &colors.foreground),
+            (&mut self.cpu_color, &colors.cpu_color),
+            (&mut self.gpu_color, &colors.gpu_color),
+            (&mut self.ram_color, &colors.ram_color),
+            (&mut self.disk_color, &colors.disk_color),
+            (&mut self.network_color, &colors.network_color),
+            (&mut self.warning_color, &colors.warning_color),
+            (&mut self.error_color, &colors.error_color),
+            (&mut self.success_color, &colors.success_color),
+        ];
+
+        for (slot, value) in slots {
+            if let Some(color) = value.as_deref().and_then(parse_color) {
+                *slot = color;
+            }
         }
     }
 
@@ -72,3 +134,23 @@ impl Theme {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_take_precedence_over_preset() {
+        let mut config: Config = toml::from_str(include_str!("../../config.toml")).unwrap();
+        config.theme.preset = "nord".to_string();
+        config.theme.overrides.cpu_color = Some("#e06c75".to_string());
+        config.theme.overrides.gpu_color = Some("lightblue".to_string());
+        config.theme.overrides.ram_color = Some("not-a-color".to_string());
+
+        let theme = Theme::from_config(&config);
+        assert_eq!(theme.cpu_color, Color::Rgb(0xe0, 0x6c, 0x75));
+        assert_eq!(theme.gpu_color, Color::LightBlue);
+        assert_eq!(theme.ram_color, NORD.ram_color);
+        assert_eq!(theme.disk_color, NORD.disk_color);
+    }
+}
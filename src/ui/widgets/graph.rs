@@ -1,43 +1,193 @@
-#![allow(dead_code)]
+use std::collections::VecDeque;
 
 use ratatui::{
     layout::Rect,
     style::{Color, Style},
-    widgets::{Block, Borders},
+    symbols::Marker,
+    text::Span,
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Paragraph},
     Frame,
 };
 
-pub struct Graph {
-    pub data: Vec<f64>,
-    pub max_value: f64,
+/// One line on a [`Graph`], oldest sample first.
+pub struct GraphSeries<'a> {
+    pub name: &'a str,
+    pub data: &'a VecDeque<f64>,
     pub color: Color,
 }
 
-impl Graph {
-    pub fn new(color: Color) -> Self {
+/// Braille line plot of up to two history series with a labelled Y scale,
+/// a time X axis ending at "now" and a legend.
+pub struct Graph<'a> {
+    title: String,
+    series: Vec<GraphSeries<'a>>,
+    unit: &'a str,
+    sample_secs: f64,
+    border_color: Color,
+}
+
+impl<'a> Graph<'a> {
+    pub fn new(title: impl Into<String>, border_color: Color) -> Self {
         Self {
-            data: Vec::new(),
-            max_value: 100.0,
-            color,
+            title: title.into(),
+            series: Vec::new(),
+            unit: "",
+            sample_secs: 1.0,
+            border_color,
         }
     }
 
-    pub fn add_point(&mut self, value: f64) {
-        self.data.push(value);
-        // Keep only last N points based on graph width (320 pixels / 4 = 80 points)
-        if self.data.len() > 80 {
-            self.data.remove(0);
-        }
+    pub fn series(mut self, name: &'a str, data: &'a VecDeque<f64>, color: Color) -> Self {
+        self.series.push(GraphSeries { name, data, color });
+        self
+    }
+
+    /// Suffix for the Y axis labels, e.g. "MB/s".
+    pub fn unit(mut self, unit: &'a str) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Seconds between samples, used to label the time axis.
+    pub fn sample_secs(mut self, secs: f64) -> Self {
+        self.sample_secs = secs.max(0.1);
+        self
     }
 
-    pub fn render(&self, f: &mut Frame, area: Rect, title: &str) {
+    pub fn render(self, f: &mut Frame, area: Rect) {
         let block = Block::default()
-            .title(title)
+            .title(self.title.clone())
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(self.color));
+            .border_style(Style::default().fg(self.border_color));
+
+        let samples = self.series.iter().map(|s| s.data.len()).max().unwrap_or(0);
+        if samples == 0 {
+            let text = Paragraph::new("Collecting data...")
+                .block(block)
+                .style(Style::default().fg(Color::Gray));
+            f.render_widget(text, area);
+            return;
+        }
+
+        let points: Vec<Vec<(f64, f64)>> = self
+            .series
+            .iter()
+            .map(|s| series_points(s.data, samples))
+            .collect();
+        let datasets: Vec<Dataset> = self
+            .series
+            .iter()
+            .zip(&points)
+            .map(|(series, points)| {
+                Dataset::default()
+                    .name(series.name)
+                    .marker(Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(series.color))
+                    .data(points)
+            })
+            .collect();
+
+        let y_max = y_bound(&self.series);
+        let x_max = samples.saturating_sub(1).max(1) as f64;
+        let span_secs = (x_max * self.sample_secs).round() as u64;
+        let label_style = Style::default().fg(Color::Gray);
+
+        let chart = Chart::new(datasets)
+            .block(block)
+            .x_axis(
+                Axis::default()
+                    .style(label_style)
+                    .bounds([0.0, x_max])
+                    .labels(vec![
+                        Span::raw(format!("-{}s", span_secs)),
+                        Span::raw("now"),
+                    ]),
+            )
+            .y_axis(
+                Axis::default()
+                    .style(label_style)
+                    .bounds([0.0, y_max])
+                    .labels(vec![
+                        Span::raw(format_value(0.0, self.unit)),
+                        Span::raw(format_value(y_max, self.unit)),
+                    ]),
+            );
+
+        f.render_widget(chart, area);
+    }
+}
+
+/// Averages `data` into at most `width` columns, so a sparkline shows the
+/// whole history instead of dropping the newest samples off its right edge.
+pub fn fit_sparkline(data: &[u64], width: u16) -> Vec<u64> {
+    let width = width.max(1) as usize;
+    if data.len() <= width {
+        return data.to_vec();
+    }
+    (0..width)
+        .map(|column| {
+            let bucket = &data[column * data.len() / width..(column + 1) * data.len() / width];
+            bucket.iter().sum::<u64>() / bucket.len().max(1) as u64
+        })
+        .collect()
+}
+
+/// Right-aligns shorter series so every line ends at "now".
+fn series_points(data: &VecDeque<f64>, samples: usize) -> Vec<(f64, f64)> {
+    let offset = samples - data.len();
+    data.iter()
+        .enumerate()
+        .map(|(i, &value)| ((offset + i) as f64, value.max(0.0)))
+        .collect()
+}
+
+/// Top of the Y axis, following the largest sample.
+fn y_bound(series: &[GraphSeries]) -> f64 {
+    let peak = series
+        .iter()
+        .flat_map(|s| s.data.iter().copied())
+        .fold(0.0_f64, f64::max);
+    // A little headroom keeps the peak off the top border
+    let bound = peak * 1.1;
+    if bound > 0.0 {
+        bound
+    } else {
+        1.0
+    }
+}
+
+fn format_value(value: f64, unit: &str) -> String {
+    let number = if value >= 100.0 {
+        format!("{:.0}", value)
+    } else {
+        format!("{:.1}", value)
+    };
+    if unit.is_empty() {
+        number
+    } else {
+        format!("{} {}", number, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scales_and_aligns_series() {
+        let long: VecDeque<f64> = [1.0, 2.0, 10.0].into_iter().collect();
+        let short: VecDeque<f64> = [4.0].into_iter().collect();
+        let series = [
+            GraphSeries { name: "a", data: &long, color: Color::Green },
+            GraphSeries { name: "b", data: &short, color: Color::Cyan },
+        ];
 
-        // TODO: Implement actual graph rendering
-        // For now, just render the block
-        f.render_widget(block, area);
+        assert!((y_bound(&series) - 11.0).abs() < 1e-9);
+        assert_eq!(y_bound(&[]), 1.0);
+        assert_eq!(series_points(&short, 3), vec![(2.0, 4.0)]);
+        assert_eq!(fit_sparkline(&[1, 3, 5, 7, 9, 11], 3), vec![2, 6, 10]);
+        assert_eq!(fit_sparkline(&[1, 2], 10), vec![1, 2]);
+        assert_eq!(format_value(250.0, "MB/s"), "250 MB/s");
     }
 }
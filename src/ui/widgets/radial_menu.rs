@@ -1,21 +1,48 @@
-// Radial menu for command history circular selection
-#![allow(dead_code)]
+// Radial menu for jumping between tabs
+use std::f64::consts::{FRAC_PI_2, TAU};
+
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::TabType;
+
+/// Widest label ("Disk Analyzer") plus the selection markers.
+const LABEL_WIDTH: u16 = 15;
+/// Vertical radius of the ring in rows; columns use twice this because
+/// terminal cells are roughly twice as tall as they are wide.
+const MAX_RADIUS: u16 = 8;
+const MIN_RADIUS: u16 = 4;
+/// Clicks this close to the centre (in rows) pick nothing.
+const DEAD_ZONE: f64 = 1.5;
 
 pub struct RadialMenu {
-    pub items: Vec<String>,
+    pub items: Vec<TabType>,
     pub selected_index: usize,
 }
 
+/// Where the menu sits on screen; `radius` is `None` for the list fallback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct MenuGeometry {
+    area: Rect,
+    radius: Option<u16>,
+}
+
 impl RadialMenu {
-    pub fn new() -> Self {
+    pub fn new(items: Vec<TabType>, current: TabType) -> Self {
+        let selected_index = items.iter().position(|&t| t == current).unwrap_or(0);
         Self {
-            items: Vec::new(),
-            selected_index: 0,
+            items,
+            selected_index,
         }
     }
 
-    pub fn set_items(&mut self, items: Vec<String>) {
-        self.items = items;
+    pub fn selected(&self) -> Option<TabType> {
+        self.items.get(self.selected_index).copied()
     }
 
     pub fn next(&mut self) {
@@ -33,4 +60,195 @@ impl RadialMenu {
             }
         }
     }
+
+    /// The tab under a click at (`column`, `row`), if any. `screen` must be the
+    /// same area the menu was rendered into.
+    pub fn hit_test(&self, screen: Rect, column: u16, row: u16) -> Option<TabType> {
+        let geometry = self.geometry(screen);
+        let area = geometry.area;
+        if column < area.x || column >= area.right() || row < area.y || row >= area.bottom() {
+            return None;
+        }
+
+        let Some(radius) = geometry.radius else {
+            // List fallback: one tab per row inside the border
+            let index = row.checked_sub(area.y + 1)? as usize;
+            return self.items.get(index).copied();
+        };
+
+        let (cx, cy) = center(area);
+        let dx = (column as f64 - cx as f64) / 2.0;
+        let dy = row as f64 - cy as f64;
+        if (dx * dx + dy * dy).sqrt() < DEAD_ZONE.min(radius as f64) {
+            return None;
+        }
+
+        self.items.get(self.sector_at(dx, dy)).copied()
+    }
+
+    pub fn render(&self, f: &mut Frame, screen: Rect, current: TabType) {
+        let geometry = self.geometry(screen);
+        let area = geometry.area;
+        f.render_widget(Clear, area);
+
+        let block = Block::default()
+            .title("Go to tab [←/→] [Enter] [Esc]")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow))
+            .style(Style::default().bg(Color::Black));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let Some(radius) = geometry.radius else {
+            let lines: Vec<Line> = self
+                .items
+                .iter()
+                .enumerate()
+                .map(|(i, &tab)| self.label_line(i, tab, current))
+                .collect();
+            f.render_widget(Paragraph::new(lines), inner);
+            return;
+        };
+
+        let (cx, cy) = center(area);
+
+        // Faint ring so the layout reads as a circle
+        let ring_steps = radius as usize * 12;
+        for step in 0..ring_steps {
+            let (x, y) = ring_point(cx, cy, radius, step as f64 / ring_steps as f64 * TAU);
+            f.buffer_mut()
+                .get_mut(x, y)
+                .set_symbol("·")
+                .set_style(Style::default().fg(Color::DarkGray));
+        }
+
+        let center_label = self.selected().map_or("", |tab| tab.as_str());
+        let center_area = Rect::new(inner.x, cy, inner.width, 1);
+        f.render_widget(
+            Paragraph::new(Span::styled(
+                center_label,
+                Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+            ))
+            .alignment(Alignment::Center),
+            center_area,
+        );
+
+        for (i, &tab) in self.items.iter().enumerate() {
+            let (x, y) = ring_point(cx, cy, radius, self.angle(i));
+            let left = x
+                .saturating_sub(LABEL_WIDTH / 2)
+                .clamp(area.x + 1, area.right() - 1 - LABEL_WIDTH);
+            f.render_widget(
+                Paragraph::new(self.label_line(i, tab, current)).alignment(Alignment::Center),
+                Rect::new(left, y, LABEL_WIDTH, 1),
+            );
+        }
+    }
+
+    fn label_line(&self, index: usize, tab: TabType, current: TabType) -> Line<'static> {
+        let selected = index == self.selected_index;
+        let mut style = Style::default().fg(if tab == current { Color::Cyan } else { Color::White });
+        if selected {
+            style = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+        }
+        let text = if selected {
+            format!("► {}", tab.as_str())
+        } else {
+            tab.as_str().to_string()
+        };
+        Line::from(Span::styled(text, style))
+    }
+
+    /// Item 0 sits at the top and the rest follow clockwise.
+    fn angle(&self, index: usize) -> f64 {
+        -FRAC_PI_2 + TAU * index as f64 / self.items.len().max(1) as f64
+    }
+
+    fn sector_at(&self, dx: f64, dy: f64) -> usize {
+        let count = self.items.len().max(1);
+        let turn = (dy.atan2(dx) + FRAC_PI_2).rem_euclid(TAU) / TAU;
+        (turn * count as f64).round() as usize % count
+    }
+
+    fn geometry(&self, screen: Rect) -> MenuGeometry {
+        // Room for the ring, a label overhanging each side and the border
+        let radius = screen
+            .height
+            .saturating_sub(4)
+            .min(screen.width.saturating_sub(LABEL_WIDTH + 2) / 4)
+            / 2;
+        let radius = radius.min(MAX_RADIUS);
+
+        if radius >= MIN_RADIUS {
+            let width = radius * 4 + LABEL_WIDTH + 2;
+            let height = radius * 2 + 3;
+            return MenuGeometry {
+                area: centered(screen, width, height),
+                radius: Some(radius),
+            };
+        }
+
+        let width = (LABEL_WIDTH + 2).max(32).min(screen.width);
+        let height = (self.items.len() as u16 + 2).min(screen.height);
+        MenuGeometry {
+            area: centered(screen, width, height),
+            radius: None,
+        }
+    }
+}
+
+fn centered(screen: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(screen.width);
+    let height = height.min(screen.height);
+    Rect::new(
+        screen.x + (screen.width - width) / 2,
+        screen.y + (screen.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+fn center(area: Rect) -> (u16, u16) {
+    (area.x + area.width / 2, area.y + area.height / 2)
+}
+
+fn ring_point(cx: u16, cy: u16, radius: u16, angle: f64) -> (u16, u16) {
+    let x = cx as f64 + angle.cos() * radius as f64 * 2.0;
+    let y = cy as f64 + angle.sin() * radius as f64;
+    (x.round() as u16, y.round() as u16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clicks_map_to_sectors() {
+        let menu = RadialMenu::new(TabType::all(), TabType::Cpu);
+        let screen = Rect::new(0, 0, 120, 40);
+        let geometry = menu.geometry(screen);
+        let radius = geometry.radius.expect("room for the ring");
+        let (cx, cy) = center(geometry.area);
+
+        // Top of the ring is the first tab, the centre is a dead zone
+        assert_eq!(menu.hit_test(screen, cx, cy - radius), Some(TabType::Overview));
+        assert_eq!(menu.hit_test(screen, cx, cy), None);
+        // Every label position hits its own tab
+        for (i, &tab) in menu.items.iter().enumerate() {
+            let (x, y) = ring_point(cx, cy, radius, menu.angle(i));
+            assert_eq!(menu.hit_test(screen, x, y), Some(tab));
+        }
+        assert_eq!(menu.hit_test(screen, 0, 0), None);
+    }
+
+    #[test]
+    fn small_terminals_fall_back_to_a_list() {
+        let menu = RadialMenu::new(TabType::all(), TabType::Cpu);
+        let screen = Rect::new(0, 0, 40, 14);
+        let geometry = menu.geometry(screen);
+        assert_eq!(geometry.radius, None);
+
+        let area = geometry.area;
+        assert_eq!(menu.hit_test(screen, area.x + 2, area.y + 2), Some(TabType::all()[1]));
+    }
 }
@@ -0,0 +1,20 @@
+use anyhow::{Context, Result};
+use arboard::Clipboard;
+use parking_lot::Mutex;
+
+/// Opened on first use and kept for the whole run: on X11 and Wayland the
+/// copied text is served by this process and is lost with the last handle.
+static CLIPBOARD: Mutex<Option<Clipboard>> = Mutex::new(None);
+
+/// Put `text` on the system clipboard. Fails when there is no clipboard,
+/// e.g. over SSH or on a headless box.
+pub fn copy_text(text: &str) -> Result<()> {
+    let mut slot = CLIPBOARD.lock();
+    let clipboard = match slot.take() {
+        Some(clipboard) => clipboard,
+        None => Clipboard::new().context("No clipboard available")?,
+    };
+    slot.insert(clipboard)
+        .set_text(text)
+        .context("Failed to set clipboard text")
+}
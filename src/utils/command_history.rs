@@ -1,18 +1,53 @@
+use parking_lot::Mutex;
+use std::cell::Cell;
 use std::collections::VecDeque;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// History file name, stored beside config.toml.
+pub const HISTORY_FILE: &str = "command_history.txt";
 
 /// Command history with circular menu support
 pub struct CommandHistory {
     commands: VecDeque<String>,
     max_size: usize,
     selected_index: usize,
+    /// First entry shown in the popup; moved by `scroll_offset` at render time
+    scroll_offset: Cell<usize>,
+    /// File the history is loaded from and saved to; `None` keeps it in memory
+    path: Option<PathBuf>,
+    /// Newest contents not yet written; writers always take the latest
+    pending: Arc<Mutex<Option<String>>>,
+    /// Held while writing, so saves reach the file one at a time
+    write_lock: Arc<Mutex<()>>,
 }
 
 impl CommandHistory {
-    pub fn new(max_size: usize) -> Self {
+    /// History bounded to `max_size` entries, seeded from `path` (one command
+    /// per line, newest first) when given.
+    pub fn new(max_size: usize, path: Option<PathBuf>) -> Self {
+        let commands = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|content| {
+                content
+                    .lines()
+                    .filter(|line| !line.trim().is_empty())
+                    .take(max_size)
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
         Self {
-            commands: VecDeque::new(),
+            commands,
             max_size,
             selected_index: 0,
+            scroll_offset: Cell::new(0),
+            path,
+            pending: Arc::new(Mutex::new(None)),
+            write_lock: Arc::new(Mutex::new(())),
         }
     }
 
@@ -34,6 +69,30 @@ impl CommandHistory {
         }
 
         self.selected_index = 0;
+        self.save();
+    }
+
+    /// Rewrite the history file off the UI thread. Saves that finish out of
+    /// order still leave the newest history on disk.
+    fn save(&self) {
+        let Some(path) = self.path.clone() else {
+            return;
+        };
+        let mut content = Vec::from(self.commands.clone()).join("\n");
+        content.push('\n');
+        *self.pending.lock() = Some(content);
+
+        let (pending, write_lock) = (Arc::clone(&self.pending), Arc::clone(&self.write_lock));
+        tokio::task::spawn_blocking(move || {
+            let _writing = write_lock.lock();
+            // An earlier task may already have written these contents
+            let Some(content) = pending.lock().take() else {
+                return;
+            };
+            if let Err(e) = write_atomically(&path, &content) {
+                log::warn!("Failed to save command history to {}: {}", path.display(), e);
+            }
+        });
     }
 
     pub fn get_selected(&self) -> Option<&String> {
@@ -64,7 +123,91 @@ impl CommandHistory {
         self.selected_index
     }
 
+    /// First entry to draw in a list `height` rows tall. The window only moves
+    /// when the selection would leave it.
+    pub fn scroll_offset(&self, height: usize) -> usize {
+        let height = height.max(1);
+        let mut offset = self.scroll_offset.get();
+        if self.selected_index < offset {
+            offset = self.selected_index;
+        } else if self.selected_index >= offset + height {
+            offset = self.selected_index + 1 - height;
+        }
+        offset = offset.min(self.commands.len().saturating_sub(height));
+        self.scroll_offset.set(offset);
+        offset
+    }
+
     pub fn handle_mouse_click(&mut self, _x: u16, _y: u16) {
         // TODO: Implement radial menu mouse selection
     }
 }
+
+/// Write through a temporary file so an interrupted save can't truncate it.
+fn write_atomically(path: &Path, content: &str) -> std::io::Result<()> {
+    let temp = path.with_extension("tmp");
+    fs::write(&temp, content)?;
+    fs::rename(&temp, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scroll_follows_the_selection() {
+        let mut history = CommandHistory::new(10, None);
+        for i in 0..10 {
+            history.add(format!("cmd {i}"));
+        }
+
+        assert_eq!(history.scroll_offset(4), 0);
+        for _ in 0..5 {
+            history.next();
+        }
+        assert_eq!(history.scroll_offset(4), 2);
+        history.previous();
+        assert_eq!(history.scroll_offset(4), 2);
+        history.previous();
+        history.previous();
+        history.previous();
+        assert_eq!(history.scroll_offset(4), 1);
+
+        // Wrapping past the top jumps to the end of the list
+        history.previous();
+        history.previous();
+        assert_eq!(history.scroll_offset(4), 6);
+    }
+
+    #[test]
+    fn loads_saved_history_up_to_the_limit() {
+        let path = std::env::temp_dir()
+            .join(format!("tui-plus-history-{}.txt", std::process::id()));
+        fs::write(&path, "newest\n\nolder\noldest\n").unwrap();
+
+        let history = CommandHistory::new(2, Some(path.clone()));
+        fs::remove_file(&path).ok();
+        assert_eq!(Vec::from(history.get_all().clone()), ["newest", "older"]);
+    }
+
+    #[test]
+    fn rapid_adds_leave_the_newest_history_saved() {
+        let path = std::env::temp_dir()
+            .join(format!("tui-plus-history-save-{}.txt", std::process::id()));
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        {
+            let _guard = runtime.enter();
+            let mut history = CommandHistory::new(10, Some(path.clone()));
+            for i in 0..50 {
+                history.add(format!("cmd {i}"));
+            }
+        }
+        // Waits for the blocking save tasks
+        drop(runtime);
+
+        let saved = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).ok();
+        assert_eq!(saved.lines().next(), Some("cmd 49"));
+        assert_eq!(saved.lines().count(), 10);
+    }
+}
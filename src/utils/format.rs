@@ -1,15 +1,140 @@
+use crate::app::config::Config;
+
 /// Format bytes to human-readable format
 pub fn format_bytes(bytes: u64) -> String {
-    const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
-    let mut size = bytes as f64;
-    let mut unit_index = 0;
+    FormatOptions::default().bytes(bytes)
+}
+
+/// How sizes are scaled and labelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteUnits {
+    /// 1024-based with KB/MB labels, as Windows shows them
+    Jedec,
+    /// 1024-based with KiB/MiB labels
+    Binary,
+    /// 1000-based with KB/MB labels
+    Decimal,
+}
+
+impl ByteUnits {
+    pub fn from_config(value: &str) -> Self {
+        match value.to_lowercase().as_str() {
+            "binary" | "iec" => ByteUnits::Binary,
+            "decimal" | "si" => ByteUnits::Decimal,
+            _ => ByteUnits::Jedec,
+        }
+    }
 
-    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
-        size /= 1024.0;
-        unit_index += 1;
+    fn base(self) -> f64 {
+        match self {
+            ByteUnits::Decimal => 1000.0,
+            ByteUnits::Jedec | ByteUnits::Binary => 1024.0,
+        }
     }
 
-    format!("{:.2} {}", size, UNITS[unit_index])
+    fn labels(self) -> &'static [&'static str] {
+        match self {
+            ByteUnits::Binary => &["B", "KiB", "MiB", "GiB", "TiB"],
+            ByteUnits::Jedec | ByteUnits::Decimal => &["B", "KB", "MB", "GB", "TB"],
+        }
+    }
+}
+
+/// Unit preferences from `[general]`, built per frame like `Theme`.
+#[derive(Debug, Clone, Copy)]
+pub struct FormatOptions {
+    pub byte_units: ByteUnits,
+    pub decimal_places: usize,
+    /// Show network rates in bytes per second instead of megabits
+    pub rate_in_bytes: bool,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            byte_units: ByteUnits::Jedec,
+            decimal_places: 2,
+            rate_in_bytes: false,
+        }
+    }
+}
+
+impl FormatOptions {
+    pub fn from_config(config: &Config) -> Self {
+        let general = &config.general;
+        Self {
+            byte_units: ByteUnits::from_config(&general.byte_units),
+            decimal_places: general.decimal_places.min(6),
+            rate_in_bytes: general.network_units.eq_ignore_ascii_case("bytes"),
+        }
+    }
+
+    pub fn bytes(&self, bytes: u64) -> String {
+        let units = self.byte_units.labels();
+        let base = self.byte_units.base();
+        let mut size = bytes as f64;
+        let mut unit_index = 0;
+
+        while size >= base && unit_index < units.len() - 1 {
+            size /= base;
+            unit_index += 1;
+        }
+
+        format!("{:.*} {}", self.decimal_places, size, units[unit_index])
+    }
+
+    /// A network rate the monitors report in Mbps, e.g. `12.50 Mbps` or
+    /// `1.49 MB/s`.
+    pub fn rate(&self, mbps: f64) -> String {
+        if self.rate_in_bytes {
+            format!("{}/s", self.bytes((mbps.max(0.0) * 1_000_000.0 / 8.0) as u64))
+        } else {
+            format!("{:.*} Mbps", self.decimal_places, mbps)
+        }
+    }
+
+    /// `mbps` in `rate_unit()`, for graphs and columns that print the unit
+    /// once.
+    pub fn rate_value(&self, mbps: f64) -> f64 {
+        if self.rate_in_bytes {
+            let mega = self.byte_units.base() * self.byte_units.base();
+            mbps * 1_000_000.0 / 8.0 / mega
+        } else {
+            mbps
+        }
+    }
+
+    pub fn rate_unit(&self) -> &'static str {
+        match (self.rate_in_bytes, self.byte_units) {
+            (false, _) => "Mbps",
+            (true, ByteUnits::Binary) => "MiB/s",
+            (true, _) => "MB/s",
+        }
+    }
+}
+
+/// Format a duration in seconds as `3d 4h 12m`, dropping leading zero units
+pub fn format_uptime(seconds: u64) -> String {
+    let days = seconds / 86_400;
+    let hours = seconds % 86_400 / 3_600;
+    let minutes = seconds % 3_600 / 60;
+
+    if days > 0 {
+        format!("{}d {}h {}m", days, hours, minutes)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+/// `part` as a percentage of `whole`, capped at 100. A zero `whole` (a
+/// monitor that failed to report a total) gives 0 rather than NaN.
+pub fn safe_percent(part: u64, whole: u64) -> f64 {
+    if whole == 0 {
+        return 0.0;
+    }
+    (part as f64 / whole as f64 * 100.0).min(100.0)
 }
 
 /// Format percentage
@@ -28,3 +153,59 @@ pub fn create_progress_bar(percentage: f32, width: usize) -> String {
         "░".repeat(empty)
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_bytes_in_each_unit_system() {
+        assert_eq!(format_bytes(1536), "1.50 KB");
+        assert_eq!(format_bytes(512), "512.00 B");
+
+        let binary = FormatOptions {
+            byte_units: ByteUnits::Binary,
+            decimal_places: 1,
+            ..FormatOptions::default()
+        };
+        assert_eq!(binary.bytes(3 * 1024 * 1024), "3.0 MiB");
+
+        let decimal = FormatOptions {
+            byte_units: ByteUnits::Decimal,
+            ..FormatOptions::default()
+        };
+        assert_eq!(decimal.bytes(1_500_000), "1.50 MB");
+        assert_eq!(decimal.bytes(1024), "1.02 KB");
+    }
+
+    #[test]
+    fn formats_rates_in_bits_or_bytes() {
+        let bits = FormatOptions::default();
+        assert_eq!(bits.rate(12.5), "12.50 Mbps");
+        assert_eq!(bits.rate_unit(), "Mbps");
+
+        let bytes = FormatOptions {
+            byte_units: ByteUnits::Decimal,
+            rate_in_bytes: true,
+            ..FormatOptions::default()
+        };
+        assert_eq!(bytes.rate(8.0), "1.00 MB/s");
+        assert!((bytes.rate_value(8.0) - 1.0).abs() < 1e-9);
+        assert_eq!(bytes.rate_unit(), "MB/s");
+    }
+
+    #[test]
+    fn percent_of_zero_total_is_zero() {
+        assert_eq!(safe_percent(50, 200), 25.0);
+        assert_eq!(safe_percent(5, 0), 0.0);
+        assert_eq!(safe_percent(300, 200), 100.0);
+    }
+
+    #[test]
+    fn uptime_drops_leading_zero_units() {
+        assert_eq!(format_uptime(3 * 86_400 + 4 * 3_600 + 12 * 60 + 59), "3d 4h 12m");
+        assert_eq!(format_uptime(86_400), "1d 0h 0m");
+        assert_eq!(format_uptime(2 * 3_600 + 5 * 60), "2h 5m");
+        assert_eq!(format_uptime(59), "0m");
+    }
+}
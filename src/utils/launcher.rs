@@ -0,0 +1,184 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::process::{Command, Stdio};
+#[cfg(not(windows))]
+use std::time::{Duration, Instant};
+
+/// Terminals tried in order on Linux, after `$TERMINAL`. Each starts its
+/// shell in the working directory it is launched from.
+#[cfg(not(any(windows, target_os = "macos")))]
+const TERMINALS: &[&str] = &[
+    "x-terminal-emulator",
+    "gnome-terminal",
+    "konsole",
+    "xfce4-terminal",
+    "alacritty",
+    "kitty",
+    "xterm",
+];
+
+/// Show `path` in the platform file manager. For a file, its folder opens
+/// with the file selected where the file manager supports it.
+pub fn open_in_file_manager(path: &Path) -> Result<()> {
+    if !path.exists() {
+        anyhow::bail!("{} no longer exists", path.display());
+    }
+
+    #[cfg(windows)]
+    let command = {
+        use std::os::windows::process::CommandExt;
+        let mut command = Command::new("explorer");
+        if path.is_file() {
+            // explorer only accepts the quotes after the comma
+            command.raw_arg(format!("/select,\"{}\"", path.display()));
+        } else {
+            command.arg(path);
+        }
+        command
+    };
+
+    #[cfg(target_os = "macos")]
+    let command = {
+        let mut command = Command::new("open");
+        if path.is_file() {
+            command.arg("-R");
+        }
+        command.arg(path);
+        command
+    };
+
+    // xdg-open has no way to select a file, so files open their folder
+    #[cfg(not(any(windows, target_os = "macos")))]
+    let command = {
+        let mut command = Command::new("xdg-open");
+        command.arg(folder_of(path));
+        command
+    };
+
+    spawn_detached(command)
+}
+
+/// Open a terminal window with its shell started in `path`, or in the
+/// folder containing it when `path` is a file.
+pub fn open_terminal(path: &Path) -> Result<()> {
+    let dir = folder_of(path);
+    if !dir.is_dir() {
+        anyhow::bail!("{} no longer exists", dir.display());
+    }
+
+    #[cfg(windows)]
+    let candidates = {
+        let mut candidates = Vec::new();
+        // wt splits its command line into subcommands at `;`, even inside quotes
+        if !dir.to_string_lossy().contains(';') {
+            let mut terminal = Command::new("wt");
+            terminal.arg("-d").arg(dir);
+            candidates.push(terminal);
+        }
+        // Not `cmd /C start`: cmd.exe would run `&`-separated commands in a
+        // folder name, while a single-quoted PowerShell string is literal
+        let mut console = Command::new("powershell");
+        console.args(["-NoProfile", "-Command"]).arg(format!(
+            "Start-Process powershell -WorkingDirectory {}",
+            powershell_quote(&dir.display().to_string())
+        ));
+        candidates.push(console);
+        candidates
+    };
+
+    #[cfg(target_os = "macos")]
+    let candidates = {
+        let mut command = Command::new("open");
+        command.args(["-a", "Terminal"]).arg(dir);
+        vec![command]
+    };
+
+    #[cfg(not(any(windows, target_os = "macos")))]
+    let candidates = {
+        let preferred = std::env::var("TERMINAL").ok().filter(|t| !t.trim().is_empty());
+        preferred
+            .iter()
+            .map(String::as_str)
+            .chain(TERMINALS.iter().copied())
+            .map(|program| {
+                let mut command = Command::new(program);
+                command.current_dir(dir);
+                command
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut last_error = None;
+    for command in candidates {
+        match spawn_detached(command) {
+            Ok(()) => return Ok(()),
+            Err(e) => last_error = Some(e),
+        }
+    }
+    Err(last_error.unwrap_or_else(|| anyhow::anyhow!("No terminal available")))
+}
+
+/// `value` as a single-quoted PowerShell string. PowerShell also ends such
+/// strings at typographic quotes, so those are doubled like `'`.
+#[cfg(windows)]
+fn powershell_quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('\'');
+    for c in value.chars() {
+        if matches!(c, '\'' | '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}') {
+            quoted.push(c);
+        }
+        quoted.push(c);
+    }
+    quoted.push('\'');
+    quoted
+}
+
+fn folder_of(path: &Path) -> &Path {
+    if path.is_file() {
+        path.parent().unwrap_or(path)
+    } else {
+        path
+    }
+}
+
+/// How long a launcher gets to fail before it counts as started. Openers
+/// and terminals that can't reach a display (no DISPLAY, over SSH) exit
+/// well within this.
+#[cfg(not(windows))]
+const LAUNCH_GRACE: Duration = Duration::from_millis(500);
+
+/// Start `command` without tying it to the TUI's terminal; it is reaped on
+/// a background thread so it doesn't linger as a zombie. Outside Windows a
+/// quick non-zero exit is reported as a failure too. Explorer's exit code
+/// means nothing, so Windows only reports failures to start.
+fn spawn_detached(mut command: Command) -> Result<()> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("Failed to run {}", program))?;
+
+    #[cfg(not(windows))]
+    {
+        let started = Instant::now();
+        while started.elapsed() < LAUNCH_GRACE {
+            match child.try_wait() {
+                Ok(Some(status)) if status.success() => return Ok(()),
+                Ok(Some(status)) => anyhow::bail!("{} failed ({})", program, status),
+                Ok(None) => std::thread::sleep(Duration::from_millis(25)),
+                Err(e) => {
+                    log::debug!("Could not check on {}: {}", program, e);
+                    break;
+                }
+            }
+        }
+    }
+
+    std::thread::spawn(move || {
+        let _ = child.wait();
+    });
+    Ok(())
+}
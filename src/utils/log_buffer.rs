@@ -0,0 +1,111 @@
+use chrono::{DateTime, Local};
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+use std::sync::{Arc, OnceLock};
+
+/// Records kept for the log viewer; older ones are dropped first.
+const CAPACITY: usize = 500;
+
+#[derive(Debug, Clone)]
+pub struct LogRecord {
+    pub time: DateTime<Local>,
+    pub level: log::Level,
+    pub target: String,
+    pub message: String,
+}
+
+/// The most recent log records, oldest first.
+#[derive(Clone, Default)]
+pub struct LogBuffer {
+    records: Arc<Mutex<VecDeque<LogRecord>>>,
+}
+
+impl LogBuffer {
+    pub fn push(&self, record: LogRecord) {
+        let mut records = self.records.lock();
+        if records.len() >= CAPACITY {
+            records.pop_front();
+        }
+        records.push_back(record);
+    }
+
+    pub fn snapshot(&self) -> Vec<LogRecord> {
+        self.records.lock().iter().cloned().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.lock().len()
+    }
+}
+
+static SHARED: OnceLock<LogBuffer> = OnceLock::new();
+
+/// The buffer the installed logger writes to. Empty (and never filled) when
+/// `install` was not called, e.g. in tests.
+pub fn shared() -> LogBuffer {
+    SHARED.get_or_init(LogBuffer::default).clone()
+}
+
+/// Forwards every record to env_logger and keeps a copy of the ones its
+/// filter lets through.
+struct BufferedLogger {
+    inner: env_logger::Logger,
+    buffer: LogBuffer,
+}
+
+impl log::Log for BufferedLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.inner.matches(record) {
+            return;
+        }
+        self.inner.log(record);
+        self.buffer.push(LogRecord {
+            time: Local::now(),
+            level: record.level(),
+            target: record.target().to_string(),
+            message: record.args().to_string(),
+        });
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+/// Install `inner` as the global logger, also capturing into `shared()`.
+pub fn install(inner: env_logger::Logger) {
+    let max_level = inner.filter();
+    let logger = BufferedLogger {
+        inner,
+        buffer: shared(),
+    };
+    if log::set_boxed_logger(Box::new(logger)).is_ok() {
+        log::set_max_level(max_level);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drops_oldest_records_past_capacity() {
+        let buffer = LogBuffer::default();
+        for i in 0..CAPACITY + 5 {
+            buffer.push(LogRecord {
+                time: Local::now(),
+                level: log::Level::Info,
+                target: "test".to_string(),
+                message: i.to_string(),
+            });
+        }
+        let records = buffer.snapshot();
+        assert_eq!(records.len(), CAPACITY);
+        assert_eq!(records[0].message, "5");
+        assert_eq!(records[CAPACITY - 1].message, (CAPACITY + 4).to_string());
+    }
+}
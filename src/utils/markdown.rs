@@ -0,0 +1,54 @@
+/// Render tab data as a GitHub-flavored Markdown table, for pasting into
+/// issues and reports.
+pub trait ToMarkdown {
+    fn to_markdown(&self) -> String;
+}
+
+/// Build a Markdown table from a header row and string cells.
+pub fn table<I>(headers: &[&str], rows: I) -> String
+where
+    I: IntoIterator<Item = Vec<String>>,
+{
+    let mut out = String::new();
+    push_row(&mut out, headers.iter().copied());
+    push_row(&mut out, headers.iter().map(|_| "---"));
+    for row in rows {
+        push_row(&mut out, row.iter().map(String::as_str));
+    }
+    out
+}
+
+fn push_row<'a>(out: &mut String, cells: impl Iterator<Item = &'a str>) {
+    out.push('|');
+    for cell in cells {
+        out.push(' ');
+        out.push_str(&escape(cell));
+        out.push_str(" |");
+    }
+    out.push('\n');
+}
+
+/// Pipes end a cell and newlines end the row, so neither may appear raw.
+fn escape(cell: &str) -> String {
+    cell.replace('|', "\\|")
+        .replace("\r\n", " ")
+        .replace(['\r', '\n'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_pipes_and_newlines_in_cells() {
+        let markdown = table(
+            &["Name", "Command"],
+            vec![vec!["sh".to_string(), "a | b\nc".to_string()]],
+        );
+
+        assert_eq!(
+            markdown,
+            "| Name | Command |\n| --- | --- |\n| sh | a \\| b c |\n"
+        );
+    }
+}
@@ -1,5 +1,11 @@
 pub mod format;
 pub mod json;
+pub mod markdown;
+pub mod clipboard;
 pub mod command_history;
+pub mod elevation;
+pub mod launcher;
+pub mod log_buffer;
 
+pub use elevation::is_elevated;
 pub use json::*;